@@ -0,0 +1,266 @@
+//! Persisted point-by-point comparison against the best answer
+//!
+//! Converts `ComparisonAgent` results into stored comparisons, turns missing
+//! points into improvement items and tracks how the match evolves across
+//! attempts at the same question.
+
+use crate::db::{
+    AnswerComparison, ComparisonAttempt, ComparisonEvolution, ComparisonPoint, ImprovementItem,
+    Repository, now,
+};
+use crate::rig_adapter::agents::comparison::{ComparisonResult, MatchStatus};
+use anyhow::Result;
+
+/// Tracker for answer comparisons and improvement items
+pub struct AnswerComparisonTracker;
+
+impl AnswerComparisonTracker {
+    /// Convert an agent comparison result into a storable comparison
+    pub fn from_result(
+        question_hash: &str,
+        question: &str,
+        answer_id: Option<i64>,
+        user_answer: &str,
+        best_answer_version: i32,
+        result: &ComparisonResult,
+    ) -> AnswerComparison {
+        AnswerComparison {
+            id: None,
+            answer_id,
+            question_hash: question_hash.to_string(),
+            question: question.to_string(),
+            user_answer: user_answer.to_string(),
+            best_answer_version,
+            overall_match: result.overall_match.clamp(0.0, 1.0),
+            points: result
+                .comparisons
+                .iter()
+                .map(|c| ComparisonPoint {
+                    aspect: c.aspect.clone(),
+                    best_answer_point: c.best_answer_point.clone(),
+                    user_answer_point: c.user_answer_point.clone(),
+                    match_status: c.match_status.as_str().to_string(),
+                    suggestion: c.suggestion.clone(),
+                })
+                .collect(),
+            missing_points: result.missing_points.clone(),
+            extra_points: result.extra_points.clone(),
+            created_at: now(),
+        }
+    }
+
+    /// Save comparison and update improvement items of its question
+    ///
+    /// # Returns
+    /// * `Ok(AnswerComparison)` - Saved comparison with its id
+    /// * `Err` - Error if persisting fails
+    pub fn record(db: &Repository, mut comparison: AnswerComparison) -> Result<AnswerComparison> {
+        let comparison_id = db.save_answer_comparison(&comparison)?;
+        comparison.id = Some(comparison_id);
+
+        db.sync_improvement_items(
+            &comparison.question_hash,
+            &comparison.question,
+            comparison_id,
+            &Self::improvement_points(&comparison),
+        )?;
+
+        Ok(comparison)
+    }
+
+    /// Load comparison history and improvement items of a question
+    pub fn evolution(
+        db: &Repository,
+        question_hash: &str,
+        question: &str,
+    ) -> Result<ComparisonEvolution> {
+        let comparisons = db.get_answer_comparisons(question_hash)?;
+        let items = db.get_improvement_items(None, Some(question_hash))?;
+        Ok(Self::build_evolution(question, &comparisons, items))
+    }
+
+    /// Build evolution summary from comparisons ordered oldest first
+    pub fn build_evolution(
+        question: &str,
+        comparisons: &[AnswerComparison],
+        items: Vec<ImprovementItem>,
+    ) -> ComparisonEvolution {
+        let mut attempts: Vec<ComparisonAttempt> = Vec::with_capacity(comparisons.len());
+        let mut previous: Option<&AnswerComparison> = None;
+
+        for comparison in comparisons {
+            let count = |status: MatchStatus| {
+                comparison.points.iter().filter(|p| p.match_status == status.as_str()).count() as i32
+            };
+
+            let newly_covered = match previous {
+                Some(prev) => {
+                    let current = Self::improvement_points(comparison);
+                    Self::improvement_points(prev)
+                        .into_iter()
+                        .filter(|p| !current.contains(p))
+                        .collect()
+                }
+                None => Vec::new(),
+            };
+
+            attempts.push(ComparisonAttempt {
+                comparison_id: comparison.id.unwrap_or_default(),
+                created_at: comparison.created_at.clone(),
+                overall_match: comparison.overall_match,
+                match_delta: previous.map(|p| comparison.overall_match - p.overall_match),
+                matched_count: count(MatchStatus::Matched),
+                partial_count: count(MatchStatus::Partial),
+                missing_count: count(MatchStatus::Missing),
+                newly_covered,
+            });
+            previous = Some(comparison);
+        }
+
+        let best_match = attempts.iter().map(|a| a.overall_match).fold(0.0, f32::max);
+        let total_improvement = match (attempts.first(), attempts.last()) {
+            (Some(first), Some(last)) => last.overall_match - first.overall_match,
+            _ => 0.0,
+        };
+
+        let (open_items, resolved_items): (Vec<ImprovementItem>, Vec<ImprovementItem>) = items
+            .into_iter()
+            .filter(|i| i.status != "dismissed")
+            .partition(|i| i.status == "open");
+
+        ComparisonEvolution {
+            question: question.to_string(),
+            attempts,
+            best_match,
+            total_improvement,
+            open_items,
+            resolved_items,
+        }
+    }
+
+    /// Missing points of a comparison, including aspects marked as missing
+    pub fn improvement_points(comparison: &AnswerComparison) -> Vec<String> {
+        let from_aspects = comparison
+            .points
+            .iter()
+            .filter(|p| p.match_status == MatchStatus::Missing.as_str())
+            .map(|p| p.best_answer_point.clone());
+
+        let mut points: Vec<String> = Vec::new();
+        for point in comparison.missing_points.iter().cloned().chain(from_aspects) {
+            let point = point.trim().to_string();
+            if !point.is_empty() && !points.contains(&point) {
+                points.push(point);
+            }
+        }
+        points
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_database;
+    use crate::rig_adapter::agents::comparison::PointComparison;
+
+    fn point(aspect: &str, best: &str, status: MatchStatus) -> PointComparison {
+        PointComparison {
+            aspect: aspect.to_string(),
+            best_answer_point: best.to_string(),
+            user_answer_point: String::new(),
+            match_status: status,
+            suggestion: String::new(),
+        }
+    }
+
+    fn comparison(overall_match: f32, points: Vec<PointComparison>, missing: &[&str]) -> AnswerComparison {
+        let result = ComparisonResult {
+            overall_match,
+            comparisons: points,
+            missing_points: missing.iter().map(|p| p.to_string()).collect(),
+            extra_points: Vec::new(),
+        };
+        AnswerComparisonTracker::from_result("hash", "讲讲缓存一致性", None, "先删缓存再写库", 1, &result)
+    }
+
+    #[test]
+    fn test_from_result_and_improvement_points() {
+        let comparison = comparison(
+            1.4,
+            vec![
+                point("方案", "延迟双删", MatchStatus::Missing),
+                point("场景", "读多写少", MatchStatus::Matched),
+            ],
+            &[" 失败重试 ", "延迟双删", ""],
+        );
+        assert_eq!(comparison.overall_match, 1.0);
+        assert_eq!(comparison.points[0].match_status, "missing");
+
+        // Trimmed, deduplicated, explicit missing points first
+        assert_eq!(
+            AnswerComparisonTracker::improvement_points(&comparison),
+            vec!["失败重试".to_string(), "延迟双删".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_build_evolution() {
+        let mut first = comparison(0.4, vec![point("方案", "延迟双删", MatchStatus::Missing)], &["失败重试"]);
+        first.id = Some(1);
+        let mut second = comparison(
+            0.7,
+            vec![point("方案", "延迟双删", MatchStatus::Partial), point("场景", "读多写少", MatchStatus::Matched)],
+            &["失败重试"],
+        );
+        second.id = Some(2);
+
+        let item = |point: &str, status: &str| ImprovementItem {
+            id: None,
+            question_hash: "hash".to_string(),
+            question: "讲讲缓存一致性".to_string(),
+            point: point.to_string(),
+            status: status.to_string(),
+            occurrences: 1,
+            source_comparison_id: Some(1),
+            resolved_comparison_id: None,
+            created_at: now(),
+            updated_at: now(),
+        };
+        let items = vec![item("失败重试", "open"), item("延迟双删", "resolved"), item("监控", "dismissed")];
+
+        let evolution = AnswerComparisonTracker::build_evolution("讲讲缓存一致性", &[first, second], items);
+        assert_eq!(evolution.attempts.len(), 2);
+        assert!(evolution.attempts[0].match_delta.is_none());
+        assert!(evolution.attempts[0].newly_covered.is_empty());
+        assert!((evolution.attempts[1].match_delta.unwrap() - 0.3).abs() < 1e-6);
+        assert_eq!(evolution.attempts[1].newly_covered, vec!["延迟双删".to_string()]);
+        assert_eq!(
+            (evolution.attempts[1].matched_count, evolution.attempts[1].partial_count, evolution.attempts[1].missing_count),
+            (1, 1, 0)
+        );
+        assert!((evolution.best_match - 0.7).abs() < 1e-6);
+        assert!((evolution.total_improvement - 0.3).abs() < 1e-6);
+        assert_eq!(evolution.open_items.len(), 1);
+        assert_eq!(evolution.resolved_items.len(), 1);
+
+        let empty = AnswerComparisonTracker::build_evolution("q", &[], Vec::new());
+        assert_eq!((empty.best_match, empty.total_improvement), (0.0, 0.0));
+    }
+
+    #[test]
+    fn test_record_resolves_covered_points() {
+        let repo = Repository::new(init_database(":memory:".into()).unwrap());
+        let first = comparison(0.4, vec![point("方案", "延迟双删", MatchStatus::Missing)], &["失败重试"]);
+        AnswerComparisonTracker::record(&repo, first).unwrap();
+        let second = comparison(0.8, vec![point("方案", "延迟双删", MatchStatus::Matched)], &["失败重试"]);
+        let saved = AnswerComparisonTracker::record(&repo, second).unwrap();
+        assert!(saved.id.is_some());
+
+        let evolution = AnswerComparisonTracker::evolution(&repo, "hash", "讲讲缓存一致性").unwrap();
+        assert_eq!(evolution.attempts.len(), 2);
+        assert_eq!(evolution.attempts[1].newly_covered, vec!["延迟双删".to_string()]);
+        let open: Vec<&str> = evolution.open_items.iter().map(|i| i.point.as_str()).collect();
+        let resolved: Vec<&str> = evolution.resolved_items.iter().map(|i| i.point.as_str()).collect();
+        assert_eq!((open, resolved), (vec!["失败重试"], vec!["延迟双删"]));
+    }
+}
@@ -80,18 +80,24 @@ impl BackupManager {
     pub fn import_data(repo: &Repository, backup_data: &BackupData) -> std::io::Result<()> {
         // Insert resumes
         for resume in &backup_data.resumes {
-            let _ = repo.save_resume(
+            let structured = if resume.structured.is_empty() { "{}".to_string() } else { resume.structured.clone() };
+            let _ = repo.save_imported_resume(
                 resume.title.clone(),
                 resume.content.clone(),
+                structured,
             ).map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
         }
         
         // Insert job descriptions
         for job_desc in &backup_data.job_descriptions {
-            let _ = repo.save_job_description(
+            let id = repo.save_job_description(
                 job_desc.title.clone(),
                 job_desc.content.clone(),
             ).map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
+            if !job_desc.structured.is_empty() {
+                repo.update_job_description_structured(id, &job_desc.structured)
+                    .map_err(|e| std::io::Error::other(e.to_string()))?;
+            }
         }
         
         // Insert sessions
@@ -103,7 +109,7 @@ impl BackupManager {
             ).map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
         }
         
-        // Insert answers
+        // Insert answers; bank entries are restored under new ids, so the bank link is dropped
         for answer in &backup_data.answers {
             let _ = repo.save_answer(
                 answer.session_id,
@@ -111,6 +117,9 @@ impl BackupManager {
                 answer.question.clone(),
                 answer.answer.clone(),
                 answer.feedback.clone(),
+                answer.question_issued_at.clone(),
+                answer.answer_submitted_at.clone(),
+                None,
             ).map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, e.to_string()))?;
         }
         
@@ -0,0 +1,344 @@
+//! Scoring consistency measurement
+//!
+//! Evaluates the same answer several times and reports how much the scores
+//! move between runs, so noisy evaluations can be flagged and our overall
+//! scoring noise quantified.
+
+use super::judge::{BlendWeights, JudgeScorer};
+use super::rubric::{RubricEngine, RubricEvaluation};
+use super::trace::ScoreTrace;
+use crate::db::{CriterionScore, Repository};
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// `user_config` key holding the evaluation temperature
+pub const EVALUATION_TEMPERATURE_CONFIG_KEY: &str = "evaluation_temperature";
+
+/// Temperature used by `analyze_answer` when none is configured
+pub const DEFAULT_EVALUATION_TEMPERATURE: f32 = 0.7;
+
+/// Default and maximum number of runs per answer
+pub const DEFAULT_RUNS: usize = 5;
+pub const MAX_RUNS: usize = 10;
+
+/// Standard deviation (on the 1-10 scale) above which a dimension is unstable
+const UNSTABLE_STD_DEV: f32 = 1.0;
+
+/// Spread of one score dimension across runs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DimensionStats {
+    pub dimension: String,
+    pub samples: usize,
+    pub mean: f32,
+    pub variance: f32,
+    pub std_dev: f32,
+    pub median: f32,
+    pub min: f32,
+    pub max: f32,
+    pub unstable: bool,
+}
+
+/// Consistency of repeated evaluations of one answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConsistencyReport {
+    pub answer_id: Option<i64>,
+    pub runs: usize,
+    pub temperature: f32,
+    pub overall: DimensionStats,
+    pub overall_samples: Vec<f32>,       // Overall score of every run, in run order
+    pub dimensions: Vec<DimensionStats>,
+    pub unstable: bool,
+    pub canonical: RubricEvaluation,     // Median scores, feedback from the run closest to the median
+    pub stored: bool,
+}
+
+/// Scoring noise over a sample of historical answers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NoiseReport {
+    pub answers_evaluated: usize,
+    pub runs_per_answer: usize,
+    pub mean_overall_std_dev: f32,
+    pub max_overall_std_dev: f32,
+    pub unstable_rate: f32,              // 0-100: share of answers with an unstable dimension
+    pub noisiest_dimensions: Vec<(String, f32)>, // Mean std dev per dimension, noisiest first
+    pub reports: Vec<ConsistencyReport>,
+}
+
+/// Statistics over repeated rubric evaluations
+pub struct ConsistencyAnalyzer;
+
+impl ConsistencyAnalyzer {
+    /// Make the median evaluation the answer's canonical score
+    ///
+    /// Stores the median criterion scores (traced as the `rubric` component)
+    /// and keeps the run scores in the score trace. The stored overall score is
+    /// then settled by `JudgeScorer::settle_overall`: each run, and a judge
+    /// score if the answer was judged, is blended with the heuristic score and
+    /// the median of the blended samples becomes the overall score.
+    /// Returns `false` when the answer has not been analyzed yet
+    pub fn store_median(
+        db: &Repository,
+        answer_id: i64,
+        rubric_id: Option<i64>,
+        report: &ConsistencyReport,
+        weights: BlendWeights,
+    ) -> Result<bool> {
+        if !RubricEngine::store(db, answer_id, rubric_id, &report.canonical.criterion_scores)? {
+            return Ok(false);
+        }
+        let Some(analysis) = db.get_answer_analysis(answer_id)? else {
+            return Ok(false);
+        };
+        let judge = db.get_judge_score(answer_id)?;
+
+        let mut trace: ScoreTrace = serde_json::from_str(&analysis.score_trace).unwrap_or_default();
+        trace.consistency_samples = report.overall_samples.clone();
+        let overall = JudgeScorer::settle_overall(&mut trace, analysis.overall_score, judge.as_ref(), weights);
+
+        db.update_answer_scores(answer_id, analysis.content_score, analysis.logic_score, analysis.job_match_score, overall)?;
+        db.save_score_trace(answer_id, &serde_json::to_string(&trace)?)?;
+
+        Ok(true)
+    }
+
+    /// Summarize repeated evaluations of the same answer; `None` with fewer than two runs
+    pub fn summarize(
+        answer_id: Option<i64>,
+        evaluations: &[RubricEvaluation],
+        temperature: f32,
+    ) -> Option<ConsistencyReport> {
+        if evaluations.len() < 2 {
+            return None;
+        }
+
+        let overall = Self::stats(
+            "overall",
+            evaluations.iter().map(|e| e.overall_score).collect(),
+        );
+
+        // Criteria in rubric order, as returned by the first run
+        let dimensions: Vec<DimensionStats> = evaluations[0]
+            .criterion_scores
+            .iter()
+            .map(|criterion| {
+                let scores = evaluations
+                    .iter()
+                    .filter_map(|e| e.criterion_scores.iter().find(|c| c.criterion == criterion.criterion))
+                    .map(|c| c.score)
+                    .collect();
+                Self::stats(&criterion.criterion, scores)
+            })
+            .collect();
+
+        let unstable = overall.unstable || dimensions.iter().any(|d| d.unstable);
+        let canonical = Self::median_evaluation(evaluations, &overall, &dimensions);
+
+        Some(ConsistencyReport {
+            answer_id,
+            runs: evaluations.len(),
+            temperature,
+            overall,
+            overall_samples: evaluations.iter().map(|e| e.overall_score).collect(),
+            dimensions,
+            unstable,
+            canonical,
+            stored: false,
+        })
+    }
+
+    /// Aggregate per-answer reports into a noise estimate
+    pub fn noise(reports: Vec<ConsistencyReport>, runs_per_answer: usize) -> NoiseReport {
+        let count = reports.len();
+        let std_devs: Vec<f32> = reports.iter().map(|r| r.overall.std_dev).collect();
+        let mean_overall_std_dev = if count == 0 { 0.0 } else { std_devs.iter().sum::<f32>() / count as f32 };
+        let max_overall_std_dev = std_devs.iter().cloned().fold(0.0, f32::max);
+        let unstable_rate = if count == 0 {
+            0.0
+        } else {
+            reports.iter().filter(|r| r.unstable).count() as f32 / count as f32 * 100.0
+        };
+
+        let mut by_dimension: Vec<(String, Vec<f32>)> = Vec::new();
+        for stats in reports.iter().flat_map(|r| r.dimensions.iter()) {
+            match by_dimension.iter_mut().find(|(name, _)| *name == stats.dimension) {
+                Some((_, values)) => values.push(stats.std_dev),
+                None => by_dimension.push((stats.dimension.clone(), vec![stats.std_dev])),
+            }
+        }
+        let mut noisiest_dimensions: Vec<(String, f32)> = by_dimension
+            .into_iter()
+            .map(|(name, values)| {
+                let mean = values.iter().sum::<f32>() / values.len() as f32;
+                (name, mean)
+            })
+            .collect();
+        noisiest_dimensions.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+        NoiseReport {
+            answers_evaluated: count,
+            runs_per_answer,
+            mean_overall_std_dev,
+            max_overall_std_dev,
+            unstable_rate,
+            noisiest_dimensions,
+            reports,
+        }
+    }
+
+    fn stats(dimension: &str, mut scores: Vec<f32>) -> DimensionStats {
+        let samples = scores.len();
+        if samples == 0 {
+            return DimensionStats {
+                dimension: dimension.to_string(),
+                samples,
+                mean: 0.0,
+                variance: 0.0,
+                std_dev: 0.0,
+                median: 0.0,
+                min: 0.0,
+                max: 0.0,
+                unstable: false,
+            };
+        }
+
+        scores.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let mean = scores.iter().sum::<f32>() / samples as f32;
+        let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f32>() / samples as f32;
+        let std_dev = variance.sqrt();
+        let median = if samples % 2 == 1 {
+            scores[samples / 2]
+        } else {
+            (scores[samples / 2 - 1] + scores[samples / 2]) / 2.0
+        };
+
+        DimensionStats {
+            dimension: dimension.to_string(),
+            samples,
+            mean,
+            variance,
+            std_dev,
+            median,
+            min: scores[0],
+            max: scores[samples - 1],
+            unstable: std_dev > UNSTABLE_STD_DEV,
+        }
+    }
+
+    /// Median scores per criterion, with text from the run closest to the median overall
+    fn median_evaluation(
+        evaluations: &[RubricEvaluation],
+        overall: &DimensionStats,
+        dimensions: &[DimensionStats],
+    ) -> RubricEvaluation {
+        let closest = evaluations
+            .iter()
+            .min_by(|a, b| {
+                let da = (a.overall_score - overall.median).abs();
+                let db = (b.overall_score - overall.median).abs();
+                da.partial_cmp(&db).unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap_or(&evaluations[0]);
+
+        let criterion_scores: Vec<CriterionScore> = closest
+            .criterion_scores
+            .iter()
+            .map(|score| {
+                let median = dimensions
+                    .iter()
+                    .find(|d| d.dimension == score.criterion)
+                    .map(|d| d.median)
+                    .unwrap_or(score.score);
+                CriterionScore { score: median, ..score.clone() }
+            })
+            .collect();
+
+        RubricEvaluation {
+            overall_score: RubricEngine::weighted_score(&criterion_scores).unwrap_or(overall.median),
+            criterion_scores,
+            ..closest.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn evaluation(overall: f32, depth: f32, clarity: f32) -> RubricEvaluation {
+        let criterion = |name: &str, score: f32| CriterionScore {
+            criterion: name.to_string(),
+            score,
+            weight: 0.5,
+            evidence: Vec::new(),
+            comment: String::new(),
+        };
+        RubricEvaluation {
+            rubric_id: None,
+            rubric_name: "test".to_string(),
+            overall_score: overall,
+            criterion_scores: vec![criterion("depth", depth), criterion("clarity", clarity)],
+            strengths: Vec::new(),
+            improvements: Vec::new(),
+            summary: format!("run {}", overall),
+        }
+    }
+
+    #[test]
+    fn test_summarize_flags_unstable_dimension() {
+        let runs = vec![evaluation(6.0, 3.0, 7.0), evaluation(7.0, 8.0, 7.0), evaluation(6.5, 6.0, 7.5)];
+        let report = ConsistencyAnalyzer::summarize(Some(1), &runs, 0.7).unwrap();
+
+        assert_eq!(report.runs, 3);
+        assert!((report.overall.mean - 6.5).abs() < 1e-5);
+        assert_eq!(report.overall.median, 6.5);
+        assert!(!report.overall.unstable);
+        assert!(report.dimensions[0].unstable);
+        assert!(!report.dimensions[1].unstable);
+        assert!(report.unstable);
+
+        // Median criterion scores, feedback from the median run
+        assert_eq!(report.canonical.summary, "run 6.5");
+        assert_eq!(report.canonical.criterion_scores[0].score, 6.0);
+        assert_eq!(report.canonical.criterion_scores[1].score, 7.0);
+        assert!((report.canonical.overall_score - 6.5).abs() < 1e-5);
+
+        assert!(ConsistencyAnalyzer::summarize(None, &runs[..1], 0.7).is_none());
+    }
+
+    #[test]
+    fn test_noise_ranks_dimensions() {
+        let noisy = ConsistencyAnalyzer::summarize(None, &[evaluation(5.0, 2.0, 6.0), evaluation(8.0, 9.0, 6.0)], 0.7).unwrap();
+        let stable = ConsistencyAnalyzer::summarize(None, &[evaluation(7.0, 7.0, 7.0), evaluation(7.0, 7.0, 7.0)], 0.7).unwrap();
+        let noise = ConsistencyAnalyzer::noise(vec![noisy, stable], 2);
+
+        assert_eq!(noise.answers_evaluated, 2);
+        assert_eq!(noise.unstable_rate, 50.0);
+        assert!((noise.mean_overall_std_dev - 0.75).abs() < 1e-5);
+        assert_eq!(noise.noisiest_dimensions[0].0, "depth");
+    }
+
+    #[test]
+    fn test_store_median_updates_overall_score() {
+        let db = Repository::new(crate::db::init_database(":memory:".into()).unwrap());
+        let session_id = db.create_interview_session(None, None, vec!["介绍一个项目".to_string()]).unwrap();
+        let answer_id = db
+            .save_answer(session_id, 0, "介绍一个项目".to_string(), "我负责存储引擎".to_string(), String::new(), None, None, None)
+            .unwrap();
+        let report = ConsistencyAnalyzer::summarize(Some(answer_id), &[evaluation(6.0, 5.0, 7.0), evaluation(8.0, 7.0, 9.0)], 0.7).unwrap();
+        assert!(!ConsistencyAnalyzer::store_median(&db, answer_id, None, &report, BlendWeights::default()).unwrap());
+
+        db.save_answer_analysis(answer_id, 5.0, 5.0, 5.0, 50.0, None, 5.0, "[]".to_string(), "[]".to_string(), "[]".to_string())
+            .unwrap();
+        assert!(ConsistencyAnalyzer::store_median(&db, answer_id, None, &report, BlendWeights::default()).unwrap());
+
+        // Runs 6.0 and 8.0 blended with the stored 5.0 give 5.5 and 6.5
+        let analysis = db.get_answer_analysis(answer_id).unwrap().unwrap();
+        assert!((analysis.overall_score - 6.0).abs() < 1e-5);
+        assert_eq!(analysis.content_score, 5.0);
+        assert!(analysis.criterion_scores.contains("depth"));
+        let trace: ScoreTrace = serde_json::from_str(&analysis.score_trace).unwrap();
+        let overall = trace.component("overall").unwrap();
+        assert_eq!(overall.entries.last().unwrap().rule, "consistency_median");
+        assert!((overall.score - analysis.overall_score).abs() < 1e-5);
+    }
+}
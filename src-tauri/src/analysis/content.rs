@@ -1,8 +1,15 @@
 //! Content analysis for interview answers
 //! Evaluates logic, job match, and keyword coverage
-
+//!
+//! JD terms are normalized through the skill taxonomy, so "K8s" in the JD is
+//! covered by "Kubernetes" in the answer and duplicates count once.
+
+use super::segmentation::Segmenter;
+use super::semantic_match::SemanticMatchResult;
+use super::taxonomy::SkillTaxonomy;
+use super::trace::{evidence, evidence_any, ComponentTrace, ScoreTrace};
+use crate::language::Language;
 use anyhow::Result;
-use std::collections::HashSet;
 
 /// Content analyzer for interview answers
 pub struct ContentAnalyzer;
@@ -15,20 +22,26 @@ pub struct ContentAnalysisResult {
     pub keyword_coverage: f32,       // 0-100: Keyword coverage percentage
     pub strengths: Vec<String>,      // Key strengths identified
     pub weaknesses: Vec<String>,     // Areas for improvement
+    pub semantic_match: Option<SemanticMatchResult>, // Per-requirement coverage from embeddings
+    pub trace: ScoreTrace,           // Rules behind logic, job match and keyword coverage
 }
 
 impl ContentAnalyzer {
     /// Analyze answer content across multiple dimensions
+    ///
+    /// `language` selects the language of strengths and weaknesses
     pub fn analyze(
         answer: &str,
         _question: &str,
         job_description: &str,
+        language: Language,
     ) -> Result<ContentAnalysisResult> {
-        let logic_score = Self::evaluate_logic(answer);
-        let job_match_score = Self::evaluate_job_match(answer, job_description);
-        let keyword_coverage = Self::evaluate_keyword_coverage(answer, job_description);
-        let strengths = Self::identify_strengths(answer, logic_score, job_match_score);
-        let weaknesses = Self::identify_weaknesses(answer, logic_score, job_match_score);
+        let logic = Self::evaluate_logic(answer);
+        let job_match = Self::evaluate_job_match(answer, job_description);
+        let keywords = Self::evaluate_keyword_coverage(answer, job_description);
+        let (logic_score, job_match_score, keyword_coverage) = (logic.score, job_match.score, keywords.score);
+        let strengths = Self::identify_strengths(answer, logic_score, job_match_score, language);
+        let weaknesses = Self::identify_weaknesses(answer, logic_score, job_match_score, language);
 
         Ok(ContentAnalysisResult {
             logic_score,
@@ -36,13 +49,56 @@ impl ContentAnalyzer {
             keyword_coverage,
             strengths,
             weaknesses,
+            semantic_match: None,
+            trace: ScoreTrace { components: vec![logic, job_match, keywords], ..Default::default() },
         })
     }
 
+    /// Blend an embedding-based match into the lexical job match score
+    ///
+    /// Semantic similarity catches paraphrases that keyword matching misses,
+    /// so it carries the larger weight. The lexical rules stay in the trace as
+    /// `job_match_lexical`.
+    pub fn blend_semantic_match(result: &mut ContentAnalysisResult, semantic: SemanticMatchResult) {
+        let mut blended = ComponentTrace::new("job_match");
+        blended.add("lexical_match", format!("{:.1} × 0.4", result.job_match_score), result.job_match_score * 0.4, None);
+        blended.add(
+            "semantic_match",
+            format!("{:.1} × 0.6 ({}/{})", semantic.score, semantic.addressed.len(), semantic.coverage.len()),
+            semantic.score * 0.6,
+            None,
+        );
+        let blended = blended.finish(1.0, 10.0);
+        
+        if let Some(mut lexical) = result.trace.take("job_match") {
+            lexical.component = "job_match_lexical".to_string();
+            result.trace.set(lexical);
+        }
+        result.job_match_score = blended.score;
+        result.trace.set(blended);
+        result.semantic_match = Some(semantic);
+    }
+
+    /// Recompute keyword coverage against structured JD keywords
+    ///
+    /// Tech stack and must-have terms are what the answer should cover;
+    /// segmenting the whole JD also counts boilerplate and nice-to-haves.
+    pub fn apply_job_keywords(result: &mut ContentAnalysisResult, answer: &str, keywords: &[String]) {
+        if keywords.is_empty() {
+            return;
+        }
+        let taxonomy = SkillTaxonomy::global();
+        let keywords = taxonomy.dedup_terms(keywords.to_vec());
+        let trace = Self::coverage_trace(answer, &keywords, "structured_keyword");
+        result.keyword_coverage = trace.score;
+        result.trace.set(trace);
+    }
+
     /// Evaluate answer logic and coherence (1-10)
-    fn evaluate_logic(answer: &str) -> f32 {
+    fn evaluate_logic(answer: &str) -> ComponentTrace {
         let answer_lower = answer.to_lowercase();
-        let mut score = 5.0;
+        let mut trace = ComponentTrace::new("logic");
+        trace.add("base", "", 5.0, None);
 
         // Structure indicators
         let structure_keywords = [
@@ -51,153 +107,166 @@ impl ContentAnalyzer {
             "首先", "其次", "最后", "此外", "然而", "因此"
         ];
 
-        let mut structure_count = 0;
         for keyword in structure_keywords.iter() {
             if answer_lower.contains(keyword) {
-                structure_count += 1;
+                trace.add("connective", *keyword, 0.8, evidence(answer, keyword));
             }
         }
 
-        score += (structure_count as f32) * 0.8;
-
         // Length evaluation (appropriate length indicates thought)
-        let word_count = answer.split_whitespace().count();
-        if word_count >= 100 && word_count <= 500 {
-            score += 1.5;
+        let word_count = Segmenter::global().word_count(answer);
+        if (100..=500).contains(&word_count) {
+            trace.add("length_in_range", format!("{} words", word_count), 1.5, None);
         } else if word_count > 500 {
-            score -= 0.5;
+            trace.add("too_long", format!("{} words", word_count), -0.5, None);
         }
 
         // Avoid rambling (multiple consecutive punctuation)
-        let rambling_indicators = answer.matches("...").count()
-            + answer.matches("？？").count()
-            + answer.matches(",,").count();
-        score -= (rambling_indicators as f32) * 0.5;
+        for pattern in ["...", "？？", ",,"] {
+            let count = answer.matches(pattern).count();
+            if count > 0 {
+                trace.add("rambling", format!("{} × {}", pattern, count), -0.5 * count as f32, evidence(answer, pattern));
+            }
+        }
 
-        score.max(1.0).min(10.0)
+        trace.finish(1.0, 10.0)
     }
 
     /// Evaluate job relevance (1-10)
-    fn evaluate_job_match(answer: &str, job_description: &str) -> f32 {
-        let answer_lower = answer.to_lowercase();
-        let jd_lower = job_description.to_lowercase();
+    fn evaluate_job_match(answer: &str, job_description: &str) -> ComponentTrace {
+        let segmenter = Segmenter::global();
+        let answer_text = segmenter.analyze(answer);
+        let mut trace = ComponentTrace::new("job_match");
+        trace.add("base", "", 2.0, None);
 
         // Extract key terms from job description
-        let mut jd_terms: HashSet<&str> = HashSet::new();
-        for word in jd_lower.split_whitespace() {
-            if word.len() > 3 && !Self::is_common_word(word) {
-                jd_terms.insert(word.trim_matches(|c| !char::is_alphanumeric(c)));
-            }
-        }
+        let taxonomy = SkillTaxonomy::global();
+        let jd_terms = taxonomy.dedup_terms(segmenter.keywords(job_description));
 
-        // Count matches
-        let mut matched_count = 0;
-        for term in &jd_terms {
-            if answer_lower.contains(term) {
-                matched_count += 1;
+        if jd_terms.is_empty() {
+            trace.add("no_jd_terms", "", 0.5 * 8.0, None);
+        } else {
+            // Each matched term is worth an equal share of 8 points
+            let share = 8.0 / jd_terms.len() as f32;
+            for term in jd_terms.iter().filter(|term| taxonomy.covers(&answer_text, term)) {
+                trace.add("jd_term", term.as_str(), share, Self::term_evidence(answer, &taxonomy, term));
             }
         }
 
-        let match_ratio = if jd_terms.is_empty() {
-            0.5
-        } else {
-            matched_count as f32 / jd_terms.len() as f32
-        };
-
-        let base_score = match_ratio * 8.0 + 2.0;
-        base_score.min(10.0)
+        trace.finish(1.0, 10.0)
     }
 
     /// Evaluate keyword coverage percentage (0-100)
-    fn evaluate_keyword_coverage(answer: &str, job_description: &str) -> f32 {
-        let answer_lower = answer.to_lowercase();
-        let jd_lower = job_description.to_lowercase();
-
-        // Extract important keywords from JD (length > 3, not common)
-        let jd_keywords: Vec<&str> = jd_lower
-            .split_whitespace()
-            .filter(|w| {
-                w.len() > 3 && !Self::is_common_word(w)
-            })
-            .collect();
-
-        if jd_keywords.is_empty() {
-            return 0.0;
-        }
+    fn evaluate_keyword_coverage(answer: &str, job_description: &str) -> ComponentTrace {
+        // Extract important keywords from JD (no stop words or single characters)
+        let taxonomy = SkillTaxonomy::global();
+        let jd_keywords = taxonomy.dedup_terms(Segmenter::global().keywords(job_description));
+        Self::coverage_trace(answer, &jd_keywords, "jd_keyword")
+    }
 
-        let mut covered = 0;
-        for kw in &jd_keywords {
-            if answer_lower.contains(kw) {
-                covered += 1;
+    /// Coverage percentage of `keywords`, one entry per covered keyword
+    fn coverage_trace(answer: &str, keywords: &[String], rule: &str) -> ComponentTrace {
+        let taxonomy = SkillTaxonomy::global();
+        let answer_text = Segmenter::global().analyze(answer);
+        let mut trace = ComponentTrace::new("keyword_coverage");
+        if !keywords.is_empty() {
+            let share = 100.0 / keywords.len() as f32;
+            for keyword in keywords.iter().filter(|kw| taxonomy.covers(&answer_text, kw)) {
+                trace.add(rule, keyword.as_str(), share, Self::term_evidence(answer, &taxonomy, keyword));
             }
         }
+        trace.finish(0.0, 100.0)
+    }
 
-        (covered as f32 / jd_keywords.len() as f32) * 100.0
+    /// Answer excerpt for a covered term, which may appear under any alias of its skill
+    fn term_evidence(answer: &str, taxonomy: &SkillTaxonomy, term: &str) -> Option<String> {
+        evidence(answer, term).or_else(|| {
+            let skill = taxonomy.resolve(term)?;
+            evidence_any(answer, std::iter::once(skill.name.as_str()).chain(skill.aliases.iter().map(String::as_str)))
+        })
     }
 
     /// Identify answer strengths
-    fn identify_strengths(answer: &str, logic_score: f32, job_match_score: f32) -> Vec<String> {
+    fn identify_strengths(answer: &str, logic_score: f32, job_match_score: f32, language: Language) -> Vec<String> {
         let mut strengths = Vec::new();
 
         if logic_score > 7.0 {
-            strengths.push("逻辑清晰，结构完整".to_string());
+            strengths.push(language.text("逻辑清晰，结构完整", "Clear logic and complete structure"));
         }
 
         if job_match_score > 7.0 {
-            strengths.push("高度匹配岗位要求".to_string());
+            strengths.push(language.text("高度匹配岗位要求", "Closely matches the job requirements"));
         }
 
         if answer.len() > 200 {
-            strengths.push("答案详细具体".to_string());
+            strengths.push(language.text("答案详细具体", "Detailed and specific answer"));
         }
 
         let answer_lower = answer.to_lowercase();
         if answer_lower.contains("example") || answer_lower.contains("例如") || answer_lower.contains("具体") {
-            strengths.push("提供了具体例子".to_string());
+            strengths.push(language.text("提供了具体例子", "Backed by concrete examples"));
         }
 
         if strengths.is_empty() {
-            strengths.push("有待改进".to_string());
+            strengths.push(language.text("有待改进", "Room for improvement"));
         }
 
         strengths
     }
 
     /// Identify answer weaknesses
-    fn identify_weaknesses(answer: &str, logic_score: f32, job_match_score: f32) -> Vec<String> {
+    fn identify_weaknesses(answer: &str, logic_score: f32, job_match_score: f32, language: Language) -> Vec<String> {
         let mut weaknesses = Vec::new();
 
         if logic_score < 5.0 {
-            weaknesses.push("逻辑不够清晰，需要更好的结构".to_string());
+            weaknesses.push(language.text("逻辑不够清晰，需要更好的结构", "Logic is unclear, the answer needs better structure"));
         }
 
         if job_match_score < 5.0 {
-            weaknesses.push("与岗位要求的匹配度有限".to_string());
+            weaknesses.push(language.text("与岗位要求的匹配度有限", "Limited match with the job requirements"));
         }
 
         if answer.len() < 50 {
-            weaknesses.push("答案过于简洁，缺乏详细信息".to_string());
+            weaknesses.push(language.text("答案过于简洁，缺乏详细信息", "Answer is too brief and lacks detail"));
         }
 
         let answer_lower = answer.to_lowercase();
         if !answer_lower.contains("example") && !answer_lower.contains("例如") {
-            weaknesses.push("缺少具体例子或案例支撑".to_string());
+            weaknesses.push(language.text("缺少具体例子或案例支撑", "Lacks concrete examples or cases"));
         }
 
         if answer.matches(',').count() + answer.matches('，').count() < 2 {
-            weaknesses.push("缺乏充分的展开和论述".to_string());
+            weaknesses.push(language.text("缺乏充分的展开和论述", "Points are not developed in enough depth"));
         }
 
         weaknesses
     }
+}
 
-    /// Check if word is common (should be filtered out)
-    fn is_common_word(word: &str) -> bool {
-        let common = [
-            "the", "a", "an", "and", "or", "is", "are", "be", "to", "in", "on", "at",
-            "of", "for", "with", "that", "this", "it", "by", "from", "as", "was",
-            "在", "的", "了", "是", "我", "有", "不", "一", "个", "为", "以",
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_content_traces_add_up() {
+        let answer = "首先，我负责把订单服务迁移到 Kubernetes，其次引入 Redis 缓存，最后接口延迟降低了 40%...";
+        let jd = "熟悉 K8s 和 Redis，有高并发系统经验";
+        let analysis = ContentAnalyzer::analyze(answer, "", jd, Language::default()).unwrap();
+        let scores = [
+            ("logic", analysis.logic_score),
+            ("job_match", analysis.job_match_score),
+            ("keyword_coverage", analysis.keyword_coverage),
         ];
-        common.contains(&word) || word.len() <= 2
+        for (name, score) in scores {
+            let trace = analysis.trace.component(name).unwrap();
+            let total: f32 = trace.entries.iter().map(|e| e.points).sum();
+            assert!((total - score).abs() < 1e-4, "{} trace does not add up", name);
+        }
+
+        let logic = analysis.trace.component("logic").unwrap();
+        assert!(logic.entries.iter().any(|e| e.rule == "connective" && e.evidence.as_deref().is_some_and(|ev| ev.contains("首先"))));
+        assert!(logic.entries.iter().any(|e| e.rule == "rambling" && e.points < 0.0));
+        let job_match = analysis.trace.component("job_match").unwrap();
+        assert!(job_match.entries.iter().any(|e| e.detail == "Kubernetes" && e.evidence.as_deref().is_some_and(|ev| ev.contains("Kubernetes"))));
     }
 }
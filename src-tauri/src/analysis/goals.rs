@@ -0,0 +1,489 @@
+//! Goal setting and progress tracking
+//!
+//! A goal is a target score for one dimension, or a number of practice
+//! sessions, to reach by a deadline. The current score of a dimension is its
+//! latest moving average from `TrendsAnalyzer`, falling back to the
+//! `ProfileGenerator` average for dimensions without a trend. The projection
+//! extends the current value at the current rate: the dimension's Theil-Sen
+//! slope per session times the recent practice pace in sessions per day.
+//!
+//! Goal statuses:
+//! - `achieved`: the current value reached the target (kept once reached)
+//! - `missed`: the deadline passed before the target was reached
+//! - `at_risk`: the projected value at the deadline falls short of the target
+//! - `active`: on track
+
+use crate::analysis::{ProfileGenerator, TrendsAnalyzer, TrendsReport};
+use crate::db::{Goal, InterviewProfile, Repository};
+use crate::language::Language;
+use anyhow::{bail, Result};
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Days of session history the practice pace is measured over
+const PACE_WINDOW_DAYS: i64 = 28;
+
+/// Shortest history the pace is averaged over, so a first busy day is not extrapolated
+const MIN_PACE_DAYS: i64 = 7;
+
+/// Dimensions a score goal can target
+const GOAL_DIMENSIONS: [(&str, &str, &str); 7] = [
+    ("overall", "综合得分", "Overall score"),
+    ("technical_depth", "技术深度", "Technical depth"),
+    ("communication", "沟通表达", "Communication"),
+    ("problem_solving", "问题解决", "Problem solving"),
+    ("domain_knowledge", "领域知识", "Domain knowledge"),
+    ("adaptability", "应变能力", "Adaptability"),
+    ("job_intention", "求职意向", "Job intention"),
+];
+
+/// What a goal measures
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalType {
+    DimensionScore,
+    SessionCount,
+}
+
+impl GoalType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalType::DimensionScore => "dimension_score",
+            GoalType::SessionCount => "session_count",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "dimension_score" => Some(GoalType::DimensionScore),
+            "session_count" => Some(GoalType::SessionCount),
+            _ => None,
+        }
+    }
+}
+
+/// Evaluated state of a goal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GoalStatus {
+    Active,
+    AtRisk,
+    Achieved,
+    Missed,
+}
+
+impl GoalStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GoalStatus::Active => "active",
+            GoalStatus::AtRisk => "at_risk",
+            GoalStatus::Achieved => "achieved",
+            GoalStatus::Missed => "missed",
+        }
+    }
+
+    fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(GoalStatus::Active),
+            "at_risk" => Some(GoalStatus::AtRisk),
+            "achieved" => Some(GoalStatus::Achieved),
+            "missed" => Some(GoalStatus::Missed),
+            _ => None,
+        }
+    }
+}
+
+/// Progress of a goal and where it ends up at the current rate
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalProgress {
+    pub goal: Goal,
+    pub label: String,
+    pub current_value: f32,
+    pub progress: f32,                   // Share of the way from baseline to target, 0-1
+    pub rate_per_day: f32,               // Points or sessions per day
+    pub projected_value: f32,            // Expected value at the deadline
+    pub days_remaining: i64,
+    pub on_track: bool,
+    pub status: GoalStatus,
+}
+
+/// Re-evaluated goals and the ones that just became achieved or at risk
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GoalCheck {
+    pub goals: Vec<GoalProgress>,
+    pub achieved: Vec<GoalProgress>,
+    pub at_risk: Vec<GoalProgress>,
+}
+
+/// Goal tracker for the active user
+pub struct GoalTracker;
+
+impl GoalTracker {
+    /// Create a goal; score goals remember the current score as their baseline
+    ///
+    /// # Arguments
+    /// * `dimension` - Required for score goals, ignored for session goals
+    /// * `target_value` - Score (0-100) or number of sessions
+    /// * `deadline` - Local date in YYYY-MM-DD, today or later
+    /// * `language` - Interview language of the trends the baseline is read from
+    pub fn create_goal(
+        repository: &Repository,
+        goal_type: GoalType,
+        dimension: Option<&str>,
+        target_value: f32,
+        deadline: &str,
+        language: Language,
+    ) -> Result<i64> {
+        if parse_deadline(deadline)? < Local::now().date_naive() {
+            bail!("Deadline {} is in the past", deadline);
+        }
+
+        match goal_type {
+            GoalType::DimensionScore => {
+                let Some(dimension) = dimension else {
+                    bail!("Score goals need a dimension");
+                };
+                if !GOAL_DIMENSIONS.iter().any(|(name, _, _)| *name == dimension) {
+                    bail!("Unknown dimension: {}", dimension);
+                }
+                if !(0.0..=100.0).contains(&target_value) {
+                    bail!("Target score must be between 0 and 100");
+                }
+                let profile = ProfileGenerator::generate_profile(repository, None)?;
+                let trends = TrendsAnalyzer::analyze(repository, language)?;
+                let baseline = Self::current_score(&profile, &trends, dimension);
+                repository.create_goal(goal_type.as_str(), Some(dimension), target_value, baseline, deadline)
+            }
+            GoalType::SessionCount => {
+                if target_value < 1.0 {
+                    bail!("Target session count must be at least 1");
+                }
+                repository.create_goal(goal_type.as_str(), None, target_value.round(), 0.0, deadline)
+            }
+        }
+    }
+
+    /// Evaluate all goals of the active user without storing anything
+    ///
+    /// `language` selects the language of the goal labels
+    pub fn evaluate(repository: &Repository, language: Language) -> Result<Vec<GoalProgress>> {
+        let goals = repository.get_goals()?;
+        if goals.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let profile = ProfileGenerator::generate_profile(repository, None)?;
+        let trends = TrendsAnalyzer::analyze(repository, language)?;
+        let session_dates: Vec<DateTime<Utc>> = repository
+            .get_interview_sessions()?
+            .iter()
+            .filter_map(|session| parse_timestamp(&session.created_at))
+            .collect();
+        let now = Utc::now();
+
+        Ok(goals
+            .into_iter()
+            .map(|goal| Self::progress(goal, &profile, &trends, &session_dates, now, language))
+            .collect())
+    }
+
+    /// Evaluate goals, store status changes and report the goals that became
+    /// achieved or at risk since the last check
+    pub fn check(repository: &Repository, language: Language) -> Result<GoalCheck> {
+        let mut goals = Self::evaluate(repository, language)?;
+        let mut achieved = Vec::new();
+        let mut at_risk = Vec::new();
+
+        for progress in goals.iter_mut() {
+            if progress.goal.status == progress.status.as_str() {
+                continue;
+            }
+            if let Some(id) = progress.goal.id {
+                repository.update_goal_status(id, progress.status.as_str())?;
+            }
+            progress.goal.status = progress.status.as_str().to_string();
+            match progress.status {
+                GoalStatus::Achieved => achieved.push(progress.clone()),
+                GoalStatus::AtRisk => at_risk.push(progress.clone()),
+                _ => {}
+            }
+        }
+
+        Ok(GoalCheck { goals, achieved, at_risk })
+    }
+
+    /// Stored status of the unfinished score goals per dimension, at risk
+    /// taking precedence, for recommendation prioritization
+    pub fn open_dimension_goals(repository: &Repository) -> Result<HashMap<String, GoalStatus>> {
+        let mut dimensions = HashMap::new();
+        for goal in repository.get_goals()? {
+            let status = GoalStatus::parse(&goal.status).unwrap_or(GoalStatus::Active);
+            if !matches!(status, GoalStatus::Active | GoalStatus::AtRisk) {
+                continue;
+            }
+            if let (Some(GoalType::DimensionScore), Some(dimension)) =
+                (GoalType::parse(&goal.goal_type), goal.dimension)
+            {
+                let entry = dimensions.entry(dimension).or_insert(status);
+                if status == GoalStatus::AtRisk {
+                    *entry = status;
+                }
+            }
+        }
+        Ok(dimensions)
+    }
+
+    fn progress(
+        goal: Goal,
+        profile: &InterviewProfile,
+        trends: &TrendsReport,
+        session_dates: &[DateTime<Utc>],
+        now: DateTime<Utc>,
+        language: Language,
+    ) -> GoalProgress {
+        let pace = Self::sessions_per_day(session_dates, now);
+        // Deadlines are picked as local calendar dates
+        let days_remaining = parse_deadline(&goal.deadline)
+            .map(|deadline| (deadline - now.with_timezone(&Local).date_naive()).num_days())
+            .unwrap_or(0);
+
+        let goal_type = GoalType::parse(&goal.goal_type).unwrap_or(GoalType::DimensionScore);
+        let (label, current_value, rate_per_day) = match goal_type {
+            GoalType::DimensionScore => {
+                let dimension = goal.dimension.as_deref().unwrap_or("overall");
+                let slope = trends
+                    .dimensions
+                    .iter()
+                    .find(|trend| trend.dimension == dimension)
+                    .and_then(|trend| trend.fit.as_ref())
+                    .map(|fit| fit.robust_slope)
+                    .unwrap_or(0.0);
+                (dimension_label(dimension, language), Self::current_score(profile, trends, dimension), slope * pace)
+            }
+            GoalType::SessionCount => {
+                let since = parse_timestamp(&goal.created_at);
+                let count = session_dates
+                    .iter()
+                    .filter(|date| since.is_none_or(|since| **date >= since))
+                    .count();
+                (language.text("练习场次", "Practice sessions"), count as f32, pace)
+            }
+        };
+
+        let mut projected_value = current_value + rate_per_day * days_remaining.max(0) as f32;
+        if goal_type == GoalType::DimensionScore {
+            projected_value = projected_value.clamp(0.0, 100.0);
+        }
+
+        let span = goal.target_value - goal.baseline_value;
+        let progress = if current_value >= goal.target_value {
+            1.0
+        } else if span > 0.0 {
+            ((current_value - goal.baseline_value) / span).clamp(0.0, 1.0)
+        } else {
+            0.0
+        };
+
+        let on_track = projected_value >= goal.target_value;
+        let status = if goal.status == GoalStatus::Achieved.as_str() || current_value >= goal.target_value {
+            GoalStatus::Achieved
+        } else if days_remaining < 0 {
+            GoalStatus::Missed
+        } else if !on_track {
+            GoalStatus::AtRisk
+        } else {
+            GoalStatus::Active
+        };
+
+        GoalProgress {
+            goal,
+            label,
+            current_value,
+            progress,
+            rate_per_day,
+            projected_value,
+            days_remaining,
+            on_track,
+            status,
+        }
+    }
+
+    /// Latest smoothed score of a dimension (0-100), or the profile average
+    /// when the dimension has no trend
+    fn current_score(profile: &InterviewProfile, trends: &TrendsReport, dimension: &str) -> f32 {
+        let smoothed = trends
+            .dimensions
+            .iter()
+            .find(|trend| trend.dimension == dimension)
+            .and_then(|trend| trend.points.last())
+            .map(|point| point.ema);
+        if let Some(score) = smoothed {
+            return score;
+        }
+
+        let dimensions = &profile.dimensions;
+        match dimension {
+            "overall" => profile.average_score * 10.0,
+            "technical_depth" => dimensions.technical_depth,
+            "communication" => dimensions.communication,
+            "problem_solving" => dimensions.problem_solving,
+            "domain_knowledge" => dimensions.domain_knowledge,
+            "adaptability" => dimensions.adaptability,
+            "job_intention" => dimensions.job_intention,
+            _ => 0.0,
+        }
+    }
+
+    /// Sessions per day over the pace window, or over the history if shorter
+    fn sessions_per_day(session_dates: &[DateTime<Utc>], now: DateTime<Utc>) -> f32 {
+        let Some(first) = session_dates.iter().min() else {
+            return 0.0;
+        };
+        let days = (now - *first).num_days().clamp(MIN_PACE_DAYS, PACE_WINDOW_DAYS);
+        let recent = session_dates
+            .iter()
+            .filter(|date| (now - **date).num_days() < days)
+            .count();
+        recent as f32 / days as f32
+    }
+}
+
+fn dimension_label(dimension: &str, language: Language) -> String {
+    GOAL_DIMENSIONS
+        .iter()
+        .find(|(name, _, _)| *name == dimension)
+        .map(|(_, zh, en)| language.text(zh, en))
+        .unwrap_or_else(|| dimension.to_string())
+}
+
+fn parse_deadline(deadline: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(deadline, "%Y-%m-%d")
+        .map_err(|_| anyhow::anyhow!("Invalid deadline {}, expected YYYY-MM-DD", deadline))
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|date| date.with_timezone(&Utc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::{dimensions, session_averages};
+    use crate::db::init_database;
+    use chrono::Duration;
+
+    fn profile() -> InterviewProfile {
+        InterviewProfile {
+            user_id: "1".to_string(),
+            dimensions: dimensions([60.0, 55.0, 60.0, 60.0, 50.0, 65.0]),
+            total_sessions: 6,
+            average_score: 6.0,
+            strongest_dimension: "job_intention".to_string(),
+            weakest_dimension: "adaptability".to_string(),
+            improvement_suggestions: vec![],
+            generated_at: String::new(),
+        }
+    }
+
+    /// Trends with the given overall scores (0-10), one session per day
+    fn trends(overall: &[f32]) -> TrendsReport {
+        TrendsAnalyzer::from_sessions(&session_averages(overall), Language::Zh)
+    }
+
+    fn goal(goal_type: GoalType, dimension: Option<&str>, target: f32, baseline: f32, deadline: NaiveDate) -> Goal {
+        Goal {
+            id: Some(1),
+            goal_type: goal_type.as_str().to_string(),
+            dimension: dimension.map(str::to_string),
+            target_value: target,
+            baseline_value: baseline,
+            deadline: deadline.format("%Y-%m-%d").to_string(),
+            status: "active".to_string(),
+            achieved_at: None,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_score_goal_projection() {
+        let now = DateTime::parse_from_rfc3339("2026-01-08T12:00:00Z").unwrap().with_timezone(&Utc);
+        let dates: Vec<DateTime<Utc>> = (0..7).map(|i| now - Duration::days(i)).collect();
+        let deadline = now.with_timezone(&Local).date_naive() + Duration::days(20);
+
+        // Improving by ~3 points per session, one session a day
+        let improving = trends(&[5.0, 5.4, 5.5, 6.1, 6.3, 6.8, 7.0]);
+        let progress = GoalTracker::progress(goal(GoalType::DimensionScore, Some("overall"), 80.0, 50.0, deadline), &profile(), &improving, &dates, now, Language::Zh);
+        assert_eq!(progress.status, GoalStatus::Active);
+        assert!(progress.on_track && progress.rate_per_day > 2.0);
+        assert!(progress.progress > 0.4 && progress.progress < 1.0);
+        assert_eq!(progress.label, "综合得分");
+        let english = GoalTracker::progress(goal(GoalType::DimensionScore, Some("overall"), 80.0, 50.0, deadline), &profile(), &improving, &dates, now, Language::En);
+        assert_eq!(english.label, "Overall score");
+
+        // A flat series does not reach the target
+        let flat = trends(&[6.0, 6.1, 5.9, 6.0, 6.1, 6.0, 5.9]);
+        let progress = GoalTracker::progress(goal(GoalType::DimensionScore, Some("overall"), 80.0, 60.0, deadline), &profile(), &flat, &dates, now, Language::Zh);
+        assert_eq!(progress.status, GoalStatus::AtRisk);
+        assert!(!progress.on_track && progress.projected_value < 65.0);
+
+        // Dimensions without a trend use the profile; an expired deadline is missed
+        let expired = now.with_timezone(&Local).date_naive() - Duration::days(1);
+        let progress = GoalTracker::progress(goal(GoalType::DimensionScore, Some("job_intention"), 80.0, 60.0, expired), &profile(), &flat, &dates, now, Language::Zh);
+        assert_eq!(progress.current_value, 65.0);
+        assert_eq!(progress.status, GoalStatus::Missed);
+    }
+
+    #[test]
+    fn test_session_goal_counts_since_creation() {
+        let now = DateTime::parse_from_rfc3339("2026-01-08T12:00:00Z").unwrap().with_timezone(&Utc);
+        let dates: Vec<DateTime<Utc>> = (0..14).map(|i| now - Duration::days(i)).collect();
+        let deadline = now.with_timezone(&Local).date_naive() + Duration::days(5);
+
+        // Seven sessions since the goal was created, one a day for five more days
+        let progress = GoalTracker::progress(goal(GoalType::SessionCount, None, 12.0, 0.0, deadline), &profile(), &trends(&[]), &dates, now, Language::Zh);
+        assert_eq!(progress.current_value, 8.0);
+        assert!((progress.rate_per_day - 1.0).abs() < 1e-6);
+        assert!(progress.on_track);
+        assert_eq!(progress.status, GoalStatus::Active);
+
+        let progress = GoalTracker::progress(goal(GoalType::SessionCount, None, 8.0, 0.0, deadline), &profile(), &trends(&[]), &dates, now, Language::Zh);
+        assert_eq!(progress.status, GoalStatus::Achieved);
+        assert_eq!(progress.progress, 1.0);
+    }
+
+    #[test]
+    fn test_check_reports_transitions_once() {
+        let path = std::env::temp_dir().join(format!("goals_{}.db", std::process::id()));
+        std::fs::remove_file(&path).ok();
+        let repo = Repository::new(init_database(path).unwrap());
+        let deadline = (Local::now().date_naive() + Duration::days(30)).format("%Y-%m-%d").to_string();
+
+        assert!(GoalTracker::create_goal(&repo, GoalType::DimensionScore, Some("unknown"), 80.0, &deadline, Language::Zh).is_err());
+        assert!(GoalTracker::create_goal(&repo, GoalType::SessionCount, None, 1.0, "2020-01-01", Language::Zh).is_err());
+        let score_goal = GoalTracker::create_goal(&repo, GoalType::DimensionScore, Some("technical_depth"), 80.0, &deadline, Language::Zh).unwrap();
+        GoalTracker::create_goal(&repo, GoalType::SessionCount, None, 1.0, &deadline, Language::Zh).unwrap();
+
+        // No practice yet: neither goal can be reached at the current rate
+        let check = GoalTracker::check(&repo, Language::Zh).unwrap();
+        assert_eq!(check.at_risk.len(), 2);
+        assert!(check.achieved.is_empty());
+        let open = GoalTracker::open_dimension_goals(&repo).unwrap();
+        assert_eq!(open.get("technical_depth"), Some(&GoalStatus::AtRisk));
+
+        repo.create_interview_session(None, None, vec!["介绍一个项目".to_string()]).unwrap();
+        let check = GoalTracker::check(&repo, Language::Zh).unwrap();
+        assert_eq!(check.achieved.len(), 1);
+        assert!(check.at_risk.is_empty());
+        assert_ne!(check.achieved[0].goal.id, Some(score_goal));
+        assert_eq!(check.achieved[0].goal.status, "achieved");
+
+        // Nothing changed since the last check
+        let check = GoalTracker::check(&repo, Language::Zh).unwrap();
+        assert!(check.achieved.is_empty() && check.at_risk.is_empty());
+        assert!(repo.get_goals().unwrap().iter().any(|goal| goal.achieved_at.is_some()));
+    }
+}
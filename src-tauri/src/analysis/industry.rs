@@ -1,10 +1,50 @@
 //! Industry comparison generator
 //!
-//! Compares user performance against simulated industry benchmarks
+//! Compares user performance against benchmark datasets. Datasets are
+//! imported from CSV or JSON files segmented by role, seniority and region,
+//! or built locally from anonymized per-user aggregates. Percentiles come from
+//! the empirical score distribution; the built-in estimate is only used for
+//! dimensions no dataset covers.
 
-use crate::db::{Repository, IndustryBenchmark, IndustryComparisonResult, now};
+use crate::db::{Repository, BenchmarkSample, BenchmarkSegment, IndustryBenchmark, IndustryComparisonResult, UserScoreAverages, now};
 use crate::analysis::ProfileGenerator;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Profile dimensions with benchmarks, with their Chinese display names
+const DIMENSIONS: &[(&str, &str)] = &[
+    ("technical_depth", "技术深度"),
+    ("communication", "沟通表达"),
+    ("problem_solving", "问题解决"),
+    ("domain_knowledge", "领域知识"),
+    ("adaptability", "应变能力"),
+];
+
+/// Estimated (average, top) per dimension for dimensions without benchmark data
+const BUILTIN_ESTIMATES: &[(&str, f32, f32)] = &[
+    ("technical_depth", 55.0, 85.0),
+    ("communication", 60.0, 90.0),
+    ("problem_solving", 52.0, 88.0),
+    ("domain_knowledge", 58.0, 82.0),
+    ("adaptability", 50.0, 80.0),
+];
+
+/// Fewer matching samples than this widens the segment (region, then seniority, then role)
+const MIN_SEGMENT_SAMPLES: usize = 20;
+
+/// Minimum number of users with analyses before a local benchmark is built
+const MIN_LOCAL_USERS: usize = 3;
+
+/// Source name of datasets built from this machine's users
+pub const LOCAL_SOURCE: &str = "local";
+
+/// Source name reported for the built-in estimate
+const BUILTIN_SOURCE: &str = "builtin";
+
+/// Largest benchmark file accepted for import
+const MAX_FILE_BYTES: u64 = 10 * 1024 * 1024;
 
 /// Generator for industry comparison data
 pub struct IndustryComparisonGenerator;
@@ -14,36 +54,34 @@ impl IndustryComparisonGenerator {
     ///
     /// # Arguments
     /// * `repository` - Database repository
-    /// * `user_id` - User identifier
+    /// * `segment` - Role, seniority and region to compare against
     ///
     /// # Returns
     /// * `Ok(IndustryComparisonResult)` - Comparison with industry benchmarks
     /// * `Err` - Error if generation fails
     pub fn generate_comparison(
         repository: &Repository,
-        user_id: &str,
+        segment: &BenchmarkSegment,
     ) -> Result<IndustryComparisonResult> {
         // Get user profile
-        let profile = ProfileGenerator::generate_profile(repository, user_id, None)?;
-        
+        let profile = ProfileGenerator::generate_profile(repository, None)?;
+
         if profile.total_sessions == 0 {
             return Self::create_empty_comparison();
         }
-        
-        // Simulated industry benchmarks (in a real app, these would come from aggregated data)
-        let industry_data = vec![
-            ("technical_depth", 55.0, 85.0),      // (dimension, avg, top)
-            ("communication", 60.0, 90.0),
-            ("problem_solving", 52.0, 88.0),
-            ("domain_knowledge", 58.0, 82.0),
-            ("adaptability", 50.0, 80.0),
-        ];
-        
+
+        let mut samples: HashMap<&str, Vec<(String, BenchmarkSample)>> = HashMap::new();
+        for (dimension, _) in DIMENSIONS {
+            samples.insert(dimension, repository.get_benchmark_samples(dimension)?);
+        }
+        let used_segment = Self::select_segment(&samples, segment);
+
         let mut benchmarks = Vec::new();
+        let mut sources: Vec<String> = Vec::new();
         let mut total_percentile = 0.0;
-        
-        for (dimension, industry_avg, industry_top) in industry_data {
-            let user_score = match dimension {
+
+        for (dimension, _) in DIMENSIONS {
+            let user_score = match *dimension {
                 "technical_depth" => profile.dimensions.technical_depth,
                 "communication" => profile.dimensions.communication,
                 "problem_solving" => profile.dimensions.problem_solving,
@@ -51,32 +89,61 @@ impl IndustryComparisonGenerator {
                 "adaptability" => profile.dimensions.adaptability,
                 _ => 0.0,
             };
-            
-            // Calculate percentile (simplified calculation)
-            let percentile = Self::calculate_percentile(user_score, industry_avg, industry_top);
-            total_percentile += percentile;
-            
-            benchmarks.push(IndustryBenchmark {
-                dimension: dimension.to_string(),
-                user_score,
-                industry_avg,
-                industry_top,
-                percentile,
-            });
-        }
-        
+
+            let matched: Vec<&(String, BenchmarkSample)> = samples[dimension]
+                .iter()
+                .filter(|(_, sample)| Self::matches(sample, &used_segment))
+                .collect();
+
+            let benchmark = if matched.is_empty() {
+                let (_, industry_avg, industry_top) = BUILTIN_ESTIMATES
+                    .iter()
+                    .find(|(d, _, _)| d == dimension)
+                    .copied()
+                    .unwrap_or((dimension, 50.0, 80.0));
+                Self::push_source(&mut sources, BUILTIN_SOURCE);
+                IndustryBenchmark {
+                    dimension: dimension.to_string(),
+                    user_score,
+                    industry_avg,
+                    industry_top,
+                    percentile: Self::estimate_percentile(user_score, industry_avg, industry_top),
+                    sample_size: 0,
+                }
+            } else {
+                for (dataset, _) in &matched {
+                    Self::push_source(&mut sources, dataset);
+                }
+                let mut scores: Vec<f32> = matched.iter().map(|(_, s)| s.score).collect();
+                scores.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+                IndustryBenchmark {
+                    dimension: dimension.to_string(),
+                    user_score,
+                    industry_avg: scores.iter().sum::<f32>() / scores.len() as f32,
+                    industry_top: Self::quantile(&scores, 0.9),
+                    percentile: Self::empirical_percentile(user_score, &scores),
+                    sample_size: scores.len() as i32,
+                }
+            };
+
+            total_percentile += benchmark.percentile;
+            benchmarks.push(benchmark);
+        }
+
         let overall_percentile = total_percentile / benchmarks.len() as f32;
         let user_level = Self::determine_level(overall_percentile);
-        
+
         Ok(IndustryComparisonResult {
             benchmarks,
             overall_percentile,
             user_level,
             comparison_count: profile.total_sessions,
+            data_source: sources.join(", "),
+            segment: used_segment,
             generated_at: now(),
         })
     }
-    
+
     /// Create empty comparison for new users
     fn create_empty_comparison() -> Result<IndustryComparisonResult> {
         Ok(IndustryComparisonResult {
@@ -84,25 +151,85 @@ impl IndustryComparisonGenerator {
             overall_percentile: 0.0,
             user_level: "新手".to_string(),
             comparison_count: 0,
+            data_source: String::new(),
+            segment: BenchmarkSegment::default(),
             generated_at: now(),
         })
     }
-    
-    /// Calculate percentile rank based on user score and industry data
-    fn calculate_percentile(user_score: f32, avg: f32, top: f32) -> f32 {
+
+    /// Narrowest segment where every dimension with data has enough samples
+    ///
+    /// Region is dropped first, then seniority, then role.
+    fn select_segment(
+        samples: &HashMap<&str, Vec<(String, BenchmarkSample)>>,
+        requested: &BenchmarkSegment,
+    ) -> BenchmarkSegment {
+        let candidates = [
+            requested.clone(),
+            BenchmarkSegment { region: None, ..requested.clone() },
+            BenchmarkSegment { role: requested.role.clone(), ..Default::default() },
+            BenchmarkSegment::default(),
+        ];
+
+        candidates
+            .iter()
+            .find(|candidate| {
+                samples.values().filter(|s| !s.is_empty()).all(|dimension_samples| {
+                    dimension_samples.iter().filter(|(_, s)| Self::matches(s, candidate)).count() >= MIN_SEGMENT_SAMPLES
+                })
+            })
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Whether a sample belongs to a segment; empty sample fields apply to every segment
+    fn matches(sample: &BenchmarkSample, segment: &BenchmarkSegment) -> bool {
+        let field = |value: &str, filter: &Option<String>| match filter.as_deref().map(str::trim) {
+            None | Some("") => true,
+            Some(filter) => value.trim().is_empty() || value.trim().eq_ignore_ascii_case(filter),
+        };
+        field(&sample.role, &segment.role) && field(&sample.seniority, &segment.seniority) && field(&sample.region, &segment.region)
+    }
+
+    fn push_source(sources: &mut Vec<String>, source: &str) {
+        if !sources.iter().any(|s| s == source) {
+            sources.push(source.to_string());
+        }
+    }
+
+    /// Share of benchmark scores below the user's, counting ties as half (0-100)
+    pub fn empirical_percentile(user_score: f32, sorted_scores: &[f32]) -> f32 {
+        if sorted_scores.is_empty() {
+            return 0.0;
+        }
+        let below = sorted_scores.iter().filter(|s| **s < user_score).count() as f32;
+        let equal = sorted_scores.iter().filter(|s| **s == user_score).count() as f32;
+        (below + equal * 0.5) / sorted_scores.len() as f32 * 100.0
+    }
+
+    /// Linearly interpolated quantile of sorted scores (`q` in 0-1)
+    fn quantile(sorted_scores: &[f32], q: f32) -> f32 {
+        let position = q * (sorted_scores.len() - 1) as f32;
+        let lower = position.floor() as usize;
+        let upper = position.ceil() as usize;
+        sorted_scores[lower] + (sorted_scores[upper] - sorted_scores[lower]) * (position - lower as f32)
+    }
+
+    /// Approximate percentile from an average and top score, for the built-in estimate
+    fn estimate_percentile(user_score: f32, avg: f32, top: f32) -> f32 {
         if user_score >= top {
             return 95.0 + (user_score - top) / (100.0 - top) * 5.0;
         }
-        
+
         if user_score >= avg {
             // Between average and top: 50-95 percentile
             return 50.0 + (user_score - avg) / (top - avg) * 45.0;
         }
-        
+
         // Below average: 0-50 percentile
         (user_score / avg * 50.0).max(0.0)
     }
-    
+
     /// Determine user level based on overall percentile
     fn determine_level(percentile: f32) -> String {
         match percentile as i32 {
@@ -114,3 +241,283 @@ impl IndustryComparisonGenerator {
         }
     }
 }
+
+/// One record of a JSON benchmark dataset: either `dimension` + `score` or a `scores` map
+#[derive(Debug, Deserialize)]
+struct BenchmarkRecord {
+    #[serde(default)]
+    role: String,
+    #[serde(default)]
+    seniority: String,
+    #[serde(default)]
+    region: String,
+    #[serde(default)]
+    dimension: Option<String>,
+    #[serde(default)]
+    score: Option<f32>,
+    #[serde(default)]
+    scores: HashMap<String, f32>,
+}
+
+/// JSON dataset: a record array, or an object with the records under `samples`
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum BenchmarkFile {
+    Records(Vec<BenchmarkRecord>),
+    Dataset { samples: Vec<BenchmarkRecord> },
+}
+
+/// Benchmark dataset parsing and local aggregation
+pub struct BenchmarkImporter;
+
+impl BenchmarkImporter {
+    /// Parse a CSV or JSON benchmark file
+    pub fn parse_file(path: &Path) -> Result<Vec<BenchmarkSample>> {
+        let size = std::fs::metadata(path).map_err(|e| anyhow!("Failed to read file: {}", e))?.len();
+        if size > MAX_FILE_BYTES {
+            return Err(anyhow!("Benchmark file is too large ({} MB, limit {} MB)", size / 1024 / 1024, MAX_FILE_BYTES / 1024 / 1024));
+        }
+        let content = std::fs::read_to_string(path).map_err(|e| anyhow!("Failed to read file: {}", e))?;
+        match path.extension().and_then(|e| e.to_str()).map(|e| e.to_lowercase()).as_deref() {
+            Some("csv") => Self::parse_csv(&content),
+            Some("json") => Self::parse_json(&content),
+            other => Err(anyhow!("Unsupported benchmark format: {}", other.unwrap_or(""))),
+        }
+    }
+
+    /// Parse CSV with a header row
+    ///
+    /// Long format has `dimension` and `score` columns; wide format has one
+    /// column per dimension. `role`, `seniority` and `region` are optional.
+    pub fn parse_csv(content: &str) -> Result<Vec<BenchmarkSample>> {
+        let rows = Self::csv_rows(content);
+        let Some((header, records)) = rows.split_first() else {
+            return Err(anyhow!("Benchmark CSV is empty"));
+        };
+        let header: Vec<String> = header.iter().map(|h| h.trim().to_lowercase()).collect();
+        let column = |name: &str| header.iter().position(|h| h == name);
+        let (role, seniority, region) = (column("role"), column("seniority"), column("region"));
+        let long_format = column("dimension").zip(column("score"));
+        let wide_columns: Vec<(usize, &'static str)> = header
+            .iter()
+            .enumerate()
+            .filter_map(|(i, h)| Self::dimension(h).map(|d| (i, d)))
+            .collect();
+        if long_format.is_none() && wide_columns.is_empty() {
+            return Err(anyhow!("Benchmark CSV needs dimension and score columns or one column per dimension"));
+        }
+
+        let mut samples = Vec::new();
+        for (line, record) in records.iter().enumerate() {
+            let line = line + 2;
+            let cell = |index: Option<usize>| index.and_then(|i| record.get(i)).map(|v| v.trim().to_string()).unwrap_or_default();
+            let mut push = |dimension: &'static str, value: &str| -> Result<()> {
+                samples.push(BenchmarkSample {
+                    role: cell(role),
+                    seniority: cell(seniority),
+                    region: cell(region),
+                    dimension: dimension.to_string(),
+                    score: Self::score(value).map_err(|e| anyhow!("Line {}: {}", line, e))?,
+                });
+                Ok(())
+            };
+            match long_format {
+                Some((dimension_column, score_column)) => {
+                    let name = cell(Some(dimension_column));
+                    let dimension = Self::dimension(&name).ok_or_else(|| anyhow!("Line {}: unknown dimension {}", line, name))?;
+                    push(dimension, &cell(Some(score_column)))?;
+                }
+                None => {
+                    for (index, dimension) in &wide_columns {
+                        let value = cell(Some(*index));
+                        if !value.is_empty() {
+                            push(dimension, &value)?;
+                        }
+                    }
+                }
+            }
+        }
+        Ok(samples)
+    }
+
+    /// Parse a JSON record array or `{"samples": [...]}` object
+    pub fn parse_json(content: &str) -> Result<Vec<BenchmarkSample>> {
+        let records = match serde_json::from_str(content).map_err(|e| anyhow!("Invalid benchmark JSON: {}", e))? {
+            BenchmarkFile::Records(records) => records,
+            BenchmarkFile::Dataset { samples } => samples,
+        };
+
+        let mut samples = Vec::new();
+        for (index, record) in records.into_iter().enumerate() {
+            let mut scores: Vec<(String, f32)> = record.scores.into_iter().collect();
+            if let (Some(dimension), Some(score)) = (record.dimension, record.score) {
+                scores.push((dimension, score));
+            }
+            if scores.is_empty() {
+                return Err(anyhow!("Record {}: no dimension scores", index + 1));
+            }
+            for (name, score) in scores {
+                let dimension = Self::dimension(&name).ok_or_else(|| anyhow!("Record {}: unknown dimension {}", index + 1, name))?;
+                samples.push(BenchmarkSample {
+                    role: record.role.trim().to_string(),
+                    seniority: record.seniority.trim().to_string(),
+                    region: record.region.trim().to_string(),
+                    dimension: dimension.to_string(),
+                    score: Self::check_score(score).map_err(|e| anyhow!("Record {}: {}", index + 1, e))?,
+                });
+            }
+        }
+        Ok(samples)
+    }
+
+    /// One unsegmented sample per user and dimension from per-user averages
+    ///
+    /// Uses the same dimension mapping as `ProfileGenerator`. Requires several
+    /// users so no single person's scores can be read back.
+    pub fn local_samples(averages: &[UserScoreAverages]) -> Result<Vec<BenchmarkSample>> {
+        let users: Vec<&UserScoreAverages> = averages.iter().filter(|a| a.answer_count > 0).collect();
+        if users.len() < MIN_LOCAL_USERS {
+            return Err(anyhow!(
+                "At least {} users with analyzed answers are needed for a local benchmark, found {}",
+                MIN_LOCAL_USERS,
+                users.len()
+            ));
+        }
+
+        let mut samples = Vec::new();
+        for user in users {
+            let scores = [
+                ("technical_depth", user.content_score * 10.0),
+                ("communication", user.expression_score.unwrap_or(7.0) * 10.0),
+                ("problem_solving", user.logic_score * 10.0),
+                ("domain_knowledge", user.job_match_score * 10.0),
+                ("adaptability", user.keyword_coverage),
+            ];
+            for (dimension, score) in scores {
+                samples.push(BenchmarkSample {
+                    role: String::new(),
+                    seniority: String::new(),
+                    region: String::new(),
+                    dimension: dimension.to_string(),
+                    score: score.clamp(0.0, 100.0),
+                });
+            }
+        }
+        Ok(samples)
+    }
+
+    /// Canonical dimension for a column or field name ("Technical Depth", "技术深度")
+    fn dimension(name: &str) -> Option<&'static str> {
+        let key = name.trim().to_lowercase().replace([' ', '-'], "_");
+        DIMENSIONS
+            .iter()
+            .find(|(dimension, label)| *dimension == key || *label == key)
+            .map(|(dimension, _)| *dimension)
+    }
+
+    fn score(value: &str) -> Result<f32> {
+        let score: f32 = value.trim().parse().map_err(|_| anyhow!("invalid score {}", value))?;
+        Self::check_score(score)
+    }
+
+    fn check_score(score: f32) -> Result<f32> {
+        if !(0.0..=100.0).contains(&score) {
+            return Err(anyhow!("score {} outside 0-100", score));
+        }
+        Ok(score)
+    }
+
+    /// Split CSV text into rows, honoring quoted fields with commas, quotes and newlines
+    fn csv_rows(content: &str) -> Vec<Vec<String>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false;
+        let mut chars = content.trim_start_matches('\u{FEFF}').chars().peekable();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '"' if quoted && chars.peek() == Some(&'"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                '"' => quoted = !quoted,
+                ',' if !quoted => row.push(std::mem::take(&mut field)),
+                '\r' if !quoted => {}
+                '\n' if !quoted => {
+                    row.push(std::mem::take(&mut field));
+                    if row.iter().any(|f| !f.trim().is_empty()) {
+                        rows.push(std::mem::take(&mut row));
+                    }
+                }
+                _ => field.push(c),
+            }
+        }
+        row.push(field);
+        if row.iter().any(|f| !f.trim().is_empty()) {
+            rows.push(row);
+        }
+        rows
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_csv_long_and_wide() {
+        let long = "role,seniority,region,dimension,score\n后端,senior,上海,technical_depth,72\n\"后端\",senior,,Problem Solving,64.5\n";
+        let samples = BenchmarkImporter::parse_csv(long).unwrap();
+        assert_eq!(samples.len(), 2);
+        assert_eq!(samples[1].dimension, "problem_solving");
+        assert_eq!(samples[1].region, "");
+
+        let wide = "role,技术深度,communication\nfrontend,80,\nfrontend,60,70\n";
+        let samples = BenchmarkImporter::parse_csv(wide).unwrap();
+        assert_eq!(samples.len(), 3);
+        assert!(samples.iter().all(|s| s.role == "frontend"));
+
+        assert!(BenchmarkImporter::parse_csv("dimension,score\ncharisma,50\n").is_err());
+        assert!(BenchmarkImporter::parse_csv("dimension,score\ncommunication,150\n").is_err());
+    }
+
+    #[test]
+    fn test_empirical_percentile_and_quantile() {
+        let scores: Vec<f32> = (1..=10).map(|i| i as f32 * 10.0).collect();
+        assert_eq!(IndustryComparisonGenerator::empirical_percentile(55.0, &scores), 50.0);
+        assert_eq!(IndustryComparisonGenerator::empirical_percentile(50.0, &scores), 45.0);
+        assert_eq!(IndustryComparisonGenerator::empirical_percentile(5.0, &scores), 0.0);
+        assert!((IndustryComparisonGenerator::quantile(&scores, 0.9) - 91.0).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_segment_falls_back_when_sparse() {
+        let sample = |region: &str, score: f32| {
+            ("survey".to_string(), BenchmarkSample {
+                role: "backend".into(),
+                seniority: "senior".into(),
+                region: region.into(),
+                dimension: "communication".into(),
+                score,
+            })
+        };
+        let mut data: Vec<(String, BenchmarkSample)> = (0..25).map(|i| sample("beijing", i as f32)).collect();
+        data.push(sample("shanghai", 50.0));
+        let samples = HashMap::from([("communication", data), ("adaptability", Vec::new())]);
+
+        let requested = BenchmarkSegment {
+            role: Some("Backend".into()),
+            seniority: Some("senior".into()),
+            region: Some("shanghai".into()),
+        };
+        let used = IndustryComparisonGenerator::select_segment(&samples, &requested);
+        assert_eq!(used.region, None);
+        assert_eq!(used.role.as_deref(), Some("Backend"));
+
+        let json = r#"{"samples": [{"role": "backend", "scores": {"adaptability": 40}}, {"dimension": "领域知识", "score": 65}]}"#;
+        let parsed = BenchmarkImporter::parse_json(json).unwrap();
+        assert_eq!(parsed.len(), 2);
+        assert_eq!(parsed[1].dimension, "domain_knowledge");
+    }
+}
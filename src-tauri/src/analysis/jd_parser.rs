@@ -0,0 +1,346 @@
+//! Structured job description parsing
+//!
+//! Rule-based extraction of responsibilities, must-have and nice-to-have
+//! requirements, tech stack, seniority, years of experience, location and
+//! company from a JD blob. An LLM response can refine the rule-based record;
+//! rule results fill in anything the model leaves out.
+
+use super::segmentation::Segmenter;
+use super::semantic_match::SemanticMatcher;
+use super::skill_gap::{Seniority, SkillGapAnalyzer};
+use crate::language::Language;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+/// Maximum length of a heading or "label: value" label
+const MAX_LABEL_CHARS: usize = 20;
+
+/// Maximum length of a line taken as the company name
+const MAX_COMPANY_CHARS: usize = 30;
+
+/// JD blocks introduced by a heading
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    Responsibilities,
+    Requirements,
+    NiceToHave,
+}
+
+/// Heading keywords; nice-to-have is checked first so "preferred qualifications"
+/// does not land in requirements
+const HEADINGS: &[(Block, &[&str])] = &[
+    (Block::NiceToHave, &["加分项", "优先条件", "加分", "nice to have", "nice-to-have", "preferred qualifications", "bonus points", "pluses"]),
+    (Block::Requirements, &["任职要求", "岗位要求", "任职资格", "职位要求", "我们希望你", "requirements", "qualifications", "what we're looking for", "what we are looking for", "must have", "must-have"]),
+    (Block::Responsibilities, &["岗位职责", "工作职责", "职责描述", "工作内容", "你将负责", "responsibilities", "what you'll do", "what you will do", "duties"]),
+];
+
+const NICE_MARKERS: &[&str] = &["优先", "加分", "preferred", "a plus", "bonus", "nice to have"];
+pub(super) const REQUIREMENT_MARKERS: &[&str] = &[
+    "熟悉", "掌握", "精通", "了解", "具备", "具有", "要求", "学历", "本科", "硕士", "经验", "以上",
+    "proficien", "familiar", "experience with", "years of", "degree", "knowledge of", "must", "strong", "solid",
+];
+const RESPONSIBILITY_MARKERS: &[&str] = &[
+    "负责", "参与", "主导", "推动", "维护", "responsible", "own ", "build", "design", "develop", "maintain",
+];
+
+const LOCATION_LABELS: &[&str] = &["工作地点", "办公地点", "工作城市", "地点", "城市", "location", "base", "office"];
+const COMPANY_LABELS: &[&str] = &["公司名称", "公司", "企业", "company", "employer"];
+const COMPANY_SUFFIXES: &[&str] = &["有限公司", "公司", "集团", "inc.", "inc", "ltd.", "ltd", "corp.", "corporation", "gmbh"];
+const CITIES: &[&str] = &[
+    "北京", "上海", "深圳", "广州", "杭州", "成都", "南京", "武汉", "西安", "苏州", "天津", "重庆", "厦门", "长沙", "合肥", "青岛", "香港", "远程",
+    "beijing", "shanghai", "shenzhen", "guangzhou", "hangzhou", "chengdu", "singapore", "london", "new york", "san francisco", "remote",
+];
+
+/// Structured fields extracted from a job description
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StructuredJobDescription {
+    pub responsibilities: Vec<String>,
+    pub must_have: Vec<String>,
+    pub nice_to_have: Vec<String>,
+    pub tech_stack: Vec<String>,
+    pub seniority: Option<Seniority>,
+    pub years_experience: Option<f32>,
+    pub location: Option<String>,
+    pub company: Option<String>,
+    pub refined: bool,                   // Reviewed by the LLM on top of the rules
+}
+
+impl StructuredJobDescription {
+    /// True when nothing was extracted
+    pub fn is_empty(&self) -> bool {
+        self.responsibilities.is_empty()
+            && self.must_have.is_empty()
+            && self.nice_to_have.is_empty()
+            && self.tech_stack.is_empty()
+            && self.seniority.is_none()
+            && self.years_experience.is_none()
+    }
+
+    /// Requirement sentences, must-haves first
+    pub fn requirements(&self) -> Vec<String> {
+        self.must_have.iter().chain(&self.nice_to_have).cloned().collect()
+    }
+
+    /// Keywords an answer is expected to cover: tech stack plus must-have terms
+    pub fn keywords(&self) -> Vec<String> {
+        let mut keywords: Vec<String> = self.tech_stack.clone();
+        for keyword in Segmenter::global().keywords(&self.must_have.join("\n")) {
+            if !keywords.iter().any(|k| k.eq_ignore_ascii_case(&keyword)) {
+                keywords.push(keyword);
+            }
+        }
+        keywords
+    }
+
+    /// Prompt paragraph summarizing the JD for question generation and interviewers
+    pub fn prompt_summary(&self) -> Option<String> {
+        if self.is_empty() {
+            return None;
+        }
+        let mut parts = Vec::new();
+        if let Some(company) = &self.company {
+            parts.push(format!("公司：{}", company));
+        }
+        if let Some(seniority) = self.seniority {
+            parts.push(format!("职级：{}", seniority.label()));
+        }
+        if let Some(years) = self.years_experience {
+            parts.push(format!("经验要求：{}年以上", years));
+        }
+        if !self.responsibilities.is_empty() {
+            parts.push(format!("核心职责：{}", self.responsibilities.join("；")));
+        }
+        if !self.must_have.is_empty() {
+            parts.push(format!("必备要求：{}", self.must_have.join("；")));
+        }
+        if !self.nice_to_have.is_empty() {
+            parts.push(format!("加分项：{}", self.nice_to_have.join("；")));
+        }
+        if !self.tech_stack.is_empty() {
+            parts.push(format!("技术栈：{}", self.tech_stack.join("、")));
+        }
+        Some(format!("岗位要点：\n{}", parts.join("\n")))
+    }
+}
+
+/// Rule-based JD parser with optional LLM refinement
+pub struct JobDescriptionParser;
+
+impl JobDescriptionParser {
+    /// Extract structured fields with rules only
+    pub fn parse(text: &str) -> StructuredJobDescription {
+        let mut jd = StructuredJobDescription::default();
+        let mut block: Option<Block> = None;
+
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            let (label, value) = Self::split_label(line);
+            if let Some(value) = value {
+                let label_lower = label.to_lowercase();
+                if LOCATION_LABELS.contains(&label_lower.as_str()) {
+                    jd.location = Some(value.to_string());
+                    continue;
+                }
+                if COMPANY_LABELS.contains(&label_lower.as_str()) {
+                    jd.company = Some(value.to_string());
+                    continue;
+                }
+            }
+
+            if let Some(heading) = Self::heading(label) {
+                block = Some(heading);
+                if let Some(value) = value {
+                    Self::push(&mut jd, block, value);
+                }
+                continue;
+            }
+
+            let content = SemanticMatcher::strip_bullet(line);
+            if content.is_empty() {
+                continue;
+            }
+            if block.is_none() && jd.company.is_none() && Self::looks_like_company(content) {
+                jd.company = Some(content.to_string());
+                continue;
+            }
+            Self::push(&mut jd, block, content);
+        }
+
+        if jd.location.is_none() {
+            jd.location = Self::find_city(text);
+        }
+        jd.tech_stack = SkillGapAnalyzer::extract_skills(text)
+            .into_iter()
+            .map(|s| s.value.0)
+            .collect();
+        jd.years_experience = SkillGapAnalyzer::extract_years(text).map(|s| s.value);
+        jd.seniority = SkillGapAnalyzer::extract_seniority(text).map(|s| s.value);
+        jd
+    }
+
+    /// System prompt asking the LLM to correct the rule-based record
+    pub fn refinement_prompt(language: Language) -> String {
+        format!(
+            "你是一位招聘专家，负责把岗位描述整理为结构化信息。你会收到岗位描述原文和规则提取的初步结果，请修正错误、补充遗漏。\
+             seniority 只能是 intern、junior、mid、senior、lead 之一或 null；years_experience 为数字或 null。\
+             你必须只返回有效的JSON对象，格式：{{\"responsibilities\": [...], \"must_have\": [...], \"nice_to_have\": [...], \
+             \"tech_stack\": [...], \"seniority\": \"senior\", \"years_experience\": 3, \"location\": \"上海\", \"company\": \"...\"}}。{}",
+            language.prompt_instruction()
+        )
+    }
+
+    /// Merge an LLM refinement into the rule-based record
+    ///
+    /// Non-empty LLM fields win; tech stack entries are merged so rule hits are never lost.
+    pub fn refine(rules: StructuredJobDescription, response: &str) -> Result<StructuredJobDescription> {
+        let refined: StructuredJobDescription = match (response.find('{'), response.rfind('}')) {
+            (Some(start), Some(end)) if start < end => serde_json::from_str(&response[start..=end])
+                .map_err(|e| anyhow!("Invalid JD refinement JSON: {}", e))?,
+            _ => return Err(anyhow!("No JSON object in JD refinement response")),
+        };
+
+        let pick = |llm: Vec<String>, fallback: Vec<String>| {
+            let cleaned: Vec<String> = llm.into_iter().map(|s| s.trim().to_string()).filter(|s| !s.is_empty()).collect();
+            if cleaned.is_empty() { fallback } else { cleaned }
+        };
+        let mut tech_stack = rules.tech_stack;
+        for item in refined.tech_stack.into_iter().map(|s| s.trim().to_string()) {
+            if !item.is_empty() && !tech_stack.iter().any(|t| t.eq_ignore_ascii_case(&item)) {
+                tech_stack.push(item);
+            }
+        }
+        let non_empty = |value: Option<String>| value.map(|v| v.trim().to_string()).filter(|v| !v.is_empty());
+
+        Ok(StructuredJobDescription {
+            responsibilities: pick(refined.responsibilities, rules.responsibilities),
+            must_have: pick(refined.must_have, rules.must_have),
+            nice_to_have: pick(refined.nice_to_have, rules.nice_to_have),
+            tech_stack,
+            seniority: refined.seniority.or(rules.seniority),
+            years_experience: refined
+                .years_experience
+                .filter(|y| y.is_finite() && *y > 0.0)
+                .or(rules.years_experience),
+            location: non_empty(refined.location).or(rules.location),
+            company: non_empty(refined.company).or(rules.company),
+            refined: true,
+        })
+    }
+
+    fn push(jd: &mut StructuredJobDescription, block: Option<Block>, content: &str) {
+        let has = |markers: &[&str]| {
+            let lower = content.to_lowercase();
+            markers.iter().any(|m| lower.contains(m))
+        };
+        let is_nice = has(NICE_MARKERS) && !content.contains("优先级");
+        let target = match block {
+            Some(Block::Responsibilities) => &mut jd.responsibilities,
+            Some(Block::NiceToHave) => &mut jd.nice_to_have,
+            Some(Block::Requirements) if is_nice => &mut jd.nice_to_have,
+            Some(Block::Requirements) => &mut jd.must_have,
+            // No heading yet: classify the line by its wording, skip titles and blurbs
+            None if is_nice => &mut jd.nice_to_have,
+            None if has(REQUIREMENT_MARKERS) => &mut jd.must_have,
+            None if has(RESPONSIBILITY_MARKERS) => &mut jd.responsibilities,
+            None => return,
+        };
+        target.push(content.to_string());
+    }
+
+    /// Split "label: value" lines; the value is `None` when empty or the label is too long
+    fn split_label(line: &str) -> (&str, Option<&str>) {
+        let Some(index) = line.find([':', '：']) else {
+            return (line, None);
+        };
+        let label = line[..index].trim();
+        if label.chars().count() > MAX_LABEL_CHARS {
+            return (line, None);
+        }
+        let separator_len = line[index..].chars().next().map_or(1, char::len_utf8);
+        let value = line[index + separator_len..].trim();
+        (label, (!value.is_empty()).then_some(value))
+    }
+
+    fn heading(label: &str) -> Option<Block> {
+        let title = label
+            .trim_start_matches(|c: char| c.is_ascii_digit() || "一二三四五六七八九十.、#*-•【[ ".contains(c))
+            .trim_end_matches(['】', ']', ':', '：', ' '])
+            .to_lowercase();
+        let title_chars = title.chars().count();
+        if title_chars == 0 || title_chars > MAX_LABEL_CHARS {
+            return None;
+        }
+        HEADINGS.iter().find_map(|(block, keywords)| {
+            keywords
+                .iter()
+                .any(|k| title == *k || (title.contains(k) && title_chars <= k.chars().count() + 6))
+                .then_some(*block)
+        })
+    }
+
+    fn looks_like_company(line: &str) -> bool {
+        let lower = line.to_lowercase();
+        line.chars().count() <= MAX_COMPANY_CHARS && COMPANY_SUFFIXES.iter().any(|s| lower.ends_with(s))
+    }
+
+    /// Earliest known city mentioned anywhere in the text
+    fn find_city(text: &str) -> Option<String> {
+        let lower = text.to_lowercase();
+        CITIES
+            .iter()
+            .filter_map(|city| lower.find(city).map(|index| (index, *city)))
+            .min_by_key(|(index, _)| *index)
+            .map(|(index, city)| text.get(index..index + city.len()).unwrap_or(city).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_chinese_jd() {
+        let text = "高级后端工程师\n星辰科技有限公司\n工作地点：杭州\n岗位职责：\n1. 负责订单系统的设计与开发\n2. 参与性能优化\n任职要求：\n1. 5年以上Go或Java开发经验\n2. 熟悉Kafka、Redis\n3. 有Kubernetes经验者优先";
+        let jd = JobDescriptionParser::parse(text);
+
+        assert_eq!(jd.company.as_deref(), Some("星辰科技有限公司"));
+        assert_eq!(jd.location.as_deref(), Some("杭州"));
+        assert_eq!(jd.responsibilities, vec!["负责订单系统的设计与开发", "参与性能优化"]);
+        assert_eq!(jd.must_have, vec!["5年以上Go或Java开发经验", "熟悉Kafka、Redis"]);
+        assert_eq!(jd.nice_to_have, vec!["有Kubernetes经验者优先"]);
+        assert_eq!(jd.years_experience, Some(5.0));
+        assert_eq!(jd.seniority, Some(Seniority::Senior));
+        assert!(jd.tech_stack.contains(&"Kafka".to_string()));
+        assert!(jd.tech_stack.contains(&"Kubernetes".to_string()));
+    }
+
+    #[test]
+    fn test_parse_without_headings_uses_wording() {
+        let text = "We are hiring in Shanghai.\n- Build and maintain data pipelines\n- 3+ years of Python experience\n- Spark experience is a plus";
+        let jd = JobDescriptionParser::parse(text);
+
+        assert_eq!(jd.location.as_deref(), Some("Shanghai"));
+        assert_eq!(jd.responsibilities, vec!["Build and maintain data pipelines"]);
+        assert_eq!(jd.must_have, vec!["3+ years of Python experience"]);
+        assert_eq!(jd.nice_to_have, vec!["Spark experience is a plus"]);
+        assert_eq!(jd.years_experience, Some(3.0));
+    }
+
+    #[test]
+    fn test_refine_keeps_rule_results_for_missing_fields() {
+        let rules = JobDescriptionParser::parse("岗位职责：负责支付系统开发\n任职要求：熟悉Redis");
+        let refined = JobDescriptionParser::refine(
+            rules,
+            "```json\n{\"must_have\": [\"熟悉Redis缓存设计\"], \"tech_stack\": [\"redis\", \"Terraform\"], \"seniority\": \"mid\", \"company\": \" \"}\n```",
+        )
+        .unwrap();
+
+        assert!(refined.refined);
+        assert_eq!(refined.responsibilities, vec!["负责支付系统开发"]);
+        assert_eq!(refined.must_have, vec!["熟悉Redis缓存设计"]);
+        assert_eq!(refined.tech_stack, vec!["Redis", "Terraform"]);
+        assert_eq!(refined.seniority, Some(Seniority::Mid));
+        assert_eq!(refined.company, None);
+        assert!(JobDescriptionParser::refine(StructuredJobDescription::default(), "no json").is_err());
+    }
+}
@@ -0,0 +1,416 @@
+//! LLM judge scoring calibrated against the heuristic scores
+//!
+//! The judge rates logic, job match and overall quality on the same 1-10
+//! scale as `ScoringEngine`. Both are blended with configurable weights and
+//! every judgement is recorded so heuristic-vs-judge agreement can be tracked.
+
+use super::trace::{ComponentTrace, ScoreTrace, OVERRIDE_RULES};
+use crate::db::JudgeScore;
+use crate::language::Language;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `user_config` key holding the blend weights as JSON
+pub const BLEND_WEIGHTS_CONFIG_KEY: &str = "judge_blend_weights";
+
+/// Weights of heuristic and judge scores in the blended score
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct BlendWeights {
+    pub heuristic: f32,
+    pub judge: f32,
+}
+
+impl Default for BlendWeights {
+    fn default() -> Self {
+        Self {
+            heuristic: 0.5,
+            judge: 0.5,
+        }
+    }
+}
+
+impl BlendWeights {
+    /// Check that weights are non-negative and not both zero
+    pub fn validate(&self) -> Result<()> {
+        if self.heuristic < 0.0 || self.judge < 0.0 {
+            return Err(anyhow!("Blend weights must not be negative"));
+        }
+        if self.heuristic + self.judge <= 0.0 {
+            return Err(anyhow!("At least one blend weight must be positive"));
+        }
+        Ok(())
+    }
+
+    /// Weights scaled to sum to 1
+    pub fn normalized(&self) -> (f32, f32) {
+        let total = self.heuristic + self.judge;
+        (self.heuristic / total, self.judge / total)
+    }
+
+    fn blend(&self, heuristic: f32, judge: f32) -> f32 {
+        let (wh, wj) = self.normalized();
+        (heuristic * wh + judge * wj).clamp(1.0, 10.0)
+    }
+}
+
+/// Numeric dimension scores from the LLM judge
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JudgeScores {
+    pub logic: f32,
+    pub job_match: f32,
+    pub overall: f32,
+    #[serde(default)]
+    pub rationale: String,
+}
+
+/// Heuristic dimension scores, before any judge blend
+#[derive(Debug, Clone, Copy)]
+pub struct HeuristicScores {
+    pub logic: f32,
+    pub job_match: f32,
+    pub overall: f32,
+}
+
+impl HeuristicScores {
+    /// Recover the heuristic scores from a stored score trace, ignoring override entries
+    pub fn from_trace(trace: &ScoreTrace) -> Option<Self> {
+        Some(Self {
+            logic: trace.component("logic")?.heuristic_score(),
+            job_match: trace.component("job_match")?.heuristic_score(),
+            overall: trace.component("overall")?.heuristic_score(),
+        })
+    }
+}
+
+/// Agreement between heuristic and judge for one day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AgreementPoint {
+    pub date: String,
+    pub samples: usize,
+    pub overall_mae: f32,
+    pub overall_bias: f32,
+}
+
+/// Heuristic-vs-judge agreement over all recorded judgements
+///
+/// Bias is judge minus heuristic: positive means the heuristics score too low.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JudgeAgreement {
+    pub samples: usize,
+    pub logic_mae: f32,
+    pub job_match_mae: f32,
+    pub overall_mae: f32,
+    pub logic_bias: f32,
+    pub job_match_bias: f32,
+    pub overall_bias: f32,
+    pub within_one_point: f32,           // 0-100: share of answers where overall scores differ by at most 1
+    pub trend: Vec<AgreementPoint>,
+}
+
+/// LLM judge prompt, parsing, blending and calibration
+pub struct JudgeScorer;
+
+impl JudgeScorer {
+    /// System prompt for the judge
+    pub fn system_prompt(language: Language) -> String {
+        format!(
+            "你是一位严格且前后一致的面试评分员。请根据问题、岗位描述和候选人回答打分，所有分数为1-10分，可保留一位小数：\
+             logic（逻辑与结构）、job_match（与岗位要求的匹配度）、overall（整体质量）。\
+             你必须只返回有效的JSON对象，格式：{{\"logic\": 7.0, \"job_match\": 6.5, \"overall\": 6.8, \"rationale\": \"...\"}}。{}",
+            language.prompt_instruction()
+        )
+    }
+
+    /// Parse the judge response, clamping scores to 1-10
+    pub fn parse(response: &str) -> Result<JudgeScores> {
+        let mut scores: JudgeScores = match (response.find('{'), response.rfind('}')) {
+            (Some(start), Some(end)) if start < end => serde_json::from_str(&response[start..=end])
+                .map_err(|e| anyhow!("Invalid judge response JSON: {}", e))?,
+            _ => return Err(anyhow!("No JSON object in judge response")),
+        };
+
+        for score in [&mut scores.logic, &mut scores.job_match, &mut scores.overall] {
+            if !score.is_finite() {
+                return Err(anyhow!("Judge returned a non-numeric score"));
+            }
+            *score = score.clamp(1.0, 10.0);
+        }
+
+        Ok(scores)
+    }
+
+    /// Blend heuristic and judge scores into a record ready to store
+    pub fn blend(
+        answer_id: i64,
+        heuristic: HeuristicScores,
+        judge: &JudgeScores,
+        weights: BlendWeights,
+    ) -> JudgeScore {
+        JudgeScore {
+            id: None,
+            answer_id,
+            heuristic_logic: heuristic.logic,
+            heuristic_job_match: heuristic.job_match,
+            heuristic_overall: heuristic.overall,
+            judge_logic: judge.logic,
+            judge_job_match: judge.job_match,
+            judge_overall: judge.overall,
+            blended_overall: weights.blend(heuristic.overall, judge.overall),
+            heuristic_weight: weights.heuristic,
+            judge_weight: weights.judge,
+            rationale: judge.rationale.clone(),
+            created_at: String::new(),
+        }
+    }
+
+    /// Blended logic and job match scores of a record
+    pub fn blended_dimensions(record: &JudgeScore) -> (f32, f32) {
+        let weights = BlendWeights {
+            heuristic: record.heuristic_weight,
+            judge: record.judge_weight,
+        };
+        (
+            weights.blend(record.heuristic_logic, record.judge_logic),
+            weights.blend(record.heuristic_job_match, record.judge_job_match),
+        )
+    }
+
+    /// Record the blended logic and job match scores as judge override steps of the score trace
+    ///
+    /// The overall score is settled by `settle_overall`
+    pub fn trace_blend(trace: &mut ScoreTrace, record: &JudgeScore) {
+        let (logic, job_match) = Self::blended_dimensions(record);
+        trace.override_score("logic", "judge_blend", Self::blend_detail(record, record.judge_logic), logic);
+        trace.override_score("job_match", "judge_blend", Self::blend_detail(record, record.judge_job_match), job_match);
+    }
+
+    /// Settle the stored overall score: judge blend first, then the median over blended samples
+    ///
+    /// Every LLM sample, the judge's overall and each consistency run kept in
+    /// the trace, is blended with the heuristic overall. Without consistency
+    /// runs the judge blend is the overall score; with them the median of the
+    /// blended samples is. A judged answer uses the judge record's weights,
+    /// otherwise `weights` apply. `stored_overall` stands in for the heuristic
+    /// score of answers analyzed before score traces existed.
+    pub fn settle_overall(
+        trace: &mut ScoreTrace,
+        stored_overall: f32,
+        judge: Option<&JudgeScore>,
+        weights: BlendWeights,
+    ) -> f32 {
+        let position = trace.components.iter().position(|c| c.component == "overall");
+        let mut overall = match position {
+            Some(index) => trace.components.remove(index),
+            None => ComponentTrace::new("overall"),
+        };
+        overall.entries.retain(|e| !OVERRIDE_RULES.contains(&e.rule.as_str()));
+        if overall.entries.is_empty() {
+            overall.add("stored", "score recorded before tracing", stored_overall, None);
+        }
+        let heuristic = judge.map(|r| r.heuristic_overall).unwrap_or_else(|| overall.heuristic_score());
+        overall.score = overall.heuristic_score();
+        let weights = judge
+            .map(|r| BlendWeights { heuristic: r.heuristic_weight, judge: r.judge_weight })
+            .unwrap_or(weights);
+
+        if let Some(record) = judge {
+            let blended = weights.blend(heuristic, record.judge_overall);
+            overall.override_score("judge_blend", Self::blend_detail(record, record.judge_overall), blended);
+        }
+        if !trace.consistency_samples.is_empty() {
+            let mut blended: Vec<f32> = judge
+                .map(|r| r.judge_overall)
+                .into_iter()
+                .chain(trace.consistency_samples.iter().copied())
+                .map(|sample| weights.blend(heuristic, sample))
+                .collect();
+            blended.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+            let middle = blended.len() / 2;
+            let median = if blended.len() % 2 == 1 {
+                blended[middle]
+            } else {
+                (blended[middle - 1] + blended[middle]) / 2.0
+            };
+            overall.override_score(
+                "consistency_median",
+                format!("median of {} blended samples", blended.len()),
+                median,
+            );
+        }
+
+        let score = overall.score;
+        trace.components.insert(position.unwrap_or(trace.components.len()), overall);
+        score
+    }
+
+    fn blend_detail(record: &JudgeScore, judge: f32) -> String {
+        format!("judge {:.1}, weights {}:{}", judge, record.heuristic_weight, record.judge_weight)
+    }
+
+    /// Measure how closely the heuristics track the judge, overall and per day
+    pub fn agreement(records: &[JudgeScore]) -> JudgeAgreement {
+        let mean = |values: Vec<f32>| {
+            if values.is_empty() {
+                0.0
+            } else {
+                values.iter().sum::<f32>() / values.len() as f32
+            }
+        };
+        // Judge minus heuristic per record
+        let logic: Vec<f32> = records.iter().map(|r| r.judge_logic - r.heuristic_logic).collect();
+        let job_match: Vec<f32> = records.iter().map(|r| r.judge_job_match - r.heuristic_job_match).collect();
+        let overall: Vec<f32> = records.iter().map(|r| r.judge_overall - r.heuristic_overall).collect();
+
+        let within_one = overall.iter().filter(|d| d.abs() <= 1.0).count();
+        let within_one_point = if overall.is_empty() {
+            0.0
+        } else {
+            within_one as f32 / overall.len() as f32 * 100.0
+        };
+
+        let mut by_date: BTreeMap<&str, Vec<f32>> = BTreeMap::new();
+        for (record, diff) in records.iter().zip(&overall) {
+            let date = record.created_at.get(..10).unwrap_or(&record.created_at);
+            by_date.entry(date).or_default().push(*diff);
+        }
+        let trend = by_date
+            .into_iter()
+            .map(|(date, diffs)| AgreementPoint {
+                date: date.to_string(),
+                samples: diffs.len(),
+                overall_mae: mean(diffs.iter().map(|d| d.abs()).collect()),
+                overall_bias: mean(diffs),
+            })
+            .collect();
+
+        JudgeAgreement {
+            samples: records.len(),
+            logic_mae: mean(logic.iter().map(|d| d.abs()).collect()),
+            job_match_mae: mean(job_match.iter().map(|d| d.abs()).collect()),
+            overall_mae: mean(overall.iter().map(|d| d.abs()).collect()),
+            logic_bias: mean(logic),
+            job_match_bias: mean(job_match),
+            overall_bias: mean(overall),
+            within_one_point,
+            trend,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(heuristic: f32, judge: f32, created_at: &str) -> JudgeScore {
+        let mut record = JudgeScorer::blend(
+            1,
+            HeuristicScores { logic: heuristic, job_match: heuristic, overall: heuristic },
+            &JudgeScores { logic: judge, job_match: judge, overall: judge, rationale: String::new() },
+            BlendWeights::default(),
+        );
+        record.created_at = created_at.to_string();
+        record
+    }
+
+    #[test]
+    fn test_parse_clamps_scores() {
+        let scores = JudgeScorer::parse("```json\n{\"logic\": 12, \"job_match\": 6.5, \"overall\": 0}\n```").unwrap();
+        assert_eq!(scores.logic, 10.0);
+        assert_eq!(scores.job_match, 6.5);
+        assert_eq!(scores.overall, 1.0);
+        assert!(JudgeScorer::parse("no scores").is_err());
+    }
+
+    #[test]
+    fn test_blend_uses_normalized_weights() {
+        let record = JudgeScorer::blend(
+            7,
+            HeuristicScores { logic: 4.0, job_match: 6.0, overall: 5.0 },
+            &JudgeScores { logic: 8.0, job_match: 6.0, overall: 9.0, rationale: "ok".to_string() },
+            BlendWeights { heuristic: 3.0, judge: 1.0 },
+        );
+        assert!((record.blended_overall - 6.0).abs() < 1e-6);
+        assert_eq!(JudgeScorer::blended_dimensions(&record), (5.0, 6.0));
+        assert!(BlendWeights { heuristic: 0.0, judge: 0.0 }.validate().is_err());
+    }
+
+    #[test]
+    fn test_agreement_trend() {
+        let records = vec![
+            record(5.0, 7.0, "2026-03-01T10:00:00Z"),
+            record(6.0, 6.5, "2026-03-01T11:00:00Z"),
+            record(8.0, 7.0, "2026-03-02T09:00:00Z"),
+        ];
+        let agreement = JudgeScorer::agreement(&records);
+
+        assert_eq!(agreement.samples, 3);
+        assert!((agreement.overall_mae - 3.5 / 3.0).abs() < 1e-5);
+        assert!((agreement.overall_bias - 1.5 / 3.0).abs() < 1e-5);
+        assert!((agreement.within_one_point - 200.0 / 3.0).abs() < 1e-3);
+        assert_eq!(agreement.trend.len(), 2);
+        assert_eq!(agreement.trend[0].samples, 2);
+        assert!((agreement.trend[1].overall_bias + 1.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_rejudging_blends_against_traced_heuristics() {
+        let mut trace = ScoreTrace::default();
+        for (component, score) in [("logic", 4.0), ("job_match", 6.0), ("overall", 5.0)] {
+            let mut component = crate::analysis::trace::ComponentTrace::new(component);
+            component.add("base", "", score, None);
+            trace.set(component.finish(1.0, 10.0));
+        }
+
+        for judge in [9.0, 7.0] {
+            let heuristic = HeuristicScores::from_trace(&trace).unwrap();
+            assert_eq!((heuristic.logic, heuristic.job_match, heuristic.overall), (4.0, 6.0, 5.0));
+            let record = JudgeScorer::blend(
+                1,
+                heuristic,
+                &JudgeScores { logic: judge, job_match: judge, overall: judge, rationale: String::new() },
+                BlendWeights::default(),
+            );
+            JudgeScorer::trace_blend(&mut trace, &record);
+            JudgeScorer::settle_overall(&mut trace, 0.0, Some(&record), BlendWeights::default());
+        }
+
+        let overall = trace.component("overall").unwrap();
+        assert!((overall.score - 6.0).abs() < 1e-6);
+        assert_eq!(overall.entries.iter().filter(|e| e.rule == "judge_blend").count(), 1);
+        let total: f32 = overall.entries.iter().map(|e| e.points).sum();
+        assert!((total - overall.score).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_settle_overall_is_independent_of_order() {
+        let mut heuristic = ComponentTrace::new("overall");
+        heuristic.add("base", "", 5.0, None);
+        let base = ScoreTrace { components: vec![heuristic.finish(1.0, 10.0)], ..Default::default() };
+        let judged = record(5.0, 9.0, "");
+
+        // Judge blend alone
+        let mut trace = base.clone();
+        assert!((JudgeScorer::settle_overall(&mut trace, 0.0, Some(&judged), BlendWeights::default()) - 7.0).abs() < 1e-6);
+
+        // Consistency runs stored before and after judging settle to the same median of blended samples
+        let mut consistency_first = base.clone();
+        consistency_first.consistency_samples = vec![6.0, 8.0];
+        JudgeScorer::settle_overall(&mut consistency_first, 0.0, None, BlendWeights::default());
+        let first = JudgeScorer::settle_overall(&mut consistency_first, 0.0, Some(&judged), BlendWeights::default());
+
+        let mut judge_first = base;
+        JudgeScorer::settle_overall(&mut judge_first, 0.0, Some(&judged), BlendWeights::default());
+        judge_first.consistency_samples = vec![6.0, 8.0];
+        let second = JudgeScorer::settle_overall(&mut judge_first, 0.0, Some(&judged), BlendWeights::default());
+
+        // Blended samples 7.0 (judge), 5.5 and 6.5
+        assert!((first - 6.5).abs() < 1e-6);
+        assert!((second - first).abs() < 1e-6);
+        let overall = judge_first.component("overall").unwrap();
+        assert_eq!(overall.heuristic_score(), 5.0);
+        assert_eq!(overall.entries.last().unwrap().rule, "consistency_median");
+        let total: f32 = overall.entries.iter().map(|e| e.points).sum();
+        assert!((total - second).abs() < 1e-5);
+    }
+}
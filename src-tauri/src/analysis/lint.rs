@@ -0,0 +1,568 @@
+//! Answer quality linter
+//!
+//! Offline, rule-based checks over answer text. Every finding points at a
+//! byte span of the answer so the UI can underline the exact phrase, and new
+//! checks plug in by implementing `LintRule`. Messages and suggestions are
+//! written in the interview language.
+
+use super::scoring::{RESULT_KEYWORDS_EN, RESULT_KEYWORDS_ZH};
+use crate::db::{LintFinding, LintSeverity};
+use crate::language::Language;
+use std::collections::HashMap;
+
+/// Vague qualifiers that weaken claims
+///
+/// "比较" is only vague as a degree adverb, so it is listed with the adjectives
+/// it usually softens; "比较了两种方案" is a real comparison
+const VAGUE_QUALIFIERS: &[&str] = &[
+    "大概", "一些", "差不多", "左右", "很多", "有点", "某些",
+    "比较好", "比较多", "比较少", "比较快", "比较慢", "比较大", "比较小", "比较高", "比较低", "比较明显",
+    "some", "a few", "a lot", "kind of", "sort of", "various", "several", "stuff",
+];
+
+/// Hedges that undercut the candidate's confidence
+const HEDGES: &[&str] = &[
+    "我觉得可能", "应该是", "也许", "好像", "不太确定", "我猜",
+    "maybe", "i guess", "i think maybe", "probably", "i'm not sure", "not sure",
+];
+
+/// Phrases that introduce an explanation of a term
+const EXPLANATION_MARKERS: &[&str] = &["(", "（", "即", "也就是", "指的是", "i.e.", "which is", "stands for", "means"];
+
+/// Acronyms common enough to need no explanation
+const WELL_KNOWN_ACRONYMS: &[&str] = &[
+    "AI", "API", "APP", "CPU", "CSS", "GPU", "HR", "HTML", "HTTP", "HTTPS", "ID", "IT",
+    "JSON", "OK", "PM", "PPT", "SQL", "UI", "URL", "UX",
+];
+
+/// Sentences longer than this many characters count as run-on
+const RUN_ON_CHARS: usize = 120;
+
+/// Sentences with more clauses than this count as run-on
+const RUN_ON_CLAUSES: usize = 6;
+
+/// Words per phrase and repetitions before a phrase counts as repeated
+const REPEATED_PHRASE_UNITS: usize = 4;
+const REPEATED_PHRASE_MIN_COUNT: usize = 3;
+
+/// Minimum "我们" mentions, and how many times more than "我", before ownership is ambiguous
+const OWNERSHIP_MIN_WE: usize = 3;
+const OWNERSHIP_RATIO: usize = 2;
+
+/// Answer text shared by all rules
+pub struct LintContext<'a> {
+    pub text: &'a str,
+    pub language: Language,              // Language of messages and suggestions
+    lower: String,
+    sentences: Vec<(usize, usize)>,
+}
+
+impl<'a> LintContext<'a> {
+    pub fn new(text: &'a str, language: Language) -> Self {
+        Self {
+            text,
+            language,
+            // ASCII lowercasing keeps byte offsets identical to `text`
+            lower: text.to_ascii_lowercase(),
+            sentences: split_sentences(text),
+        }
+    }
+
+    /// Byte spans of trimmed sentences
+    pub fn sentences(&self) -> impl Iterator<Item = (usize, usize, &'a str)> + '_ {
+        self.sentences.iter().map(|&(start, end)| (start, end, &self.text[start..end]))
+    }
+
+    /// Byte spans of every case-insensitive occurrence of `phrase`
+    ///
+    /// Latin phrases only match on word boundaries
+    pub fn find_all(&self, phrase: &str) -> Vec<(usize, usize)> {
+        let needle = phrase.to_ascii_lowercase();
+        let latin = needle.starts_with(|c: char| c.is_ascii_alphanumeric());
+        let is_word_byte = |b: Option<&u8>| b.is_some_and(|b| b.is_ascii_alphanumeric());
+
+        self.lower
+            .match_indices(&needle)
+            .map(|(start, m)| (start, start + m.len()))
+            .filter(|&(start, end)| {
+                !latin
+                    || (!is_word_byte(start.checked_sub(1).and_then(|i| self.lower.as_bytes().get(i)))
+                        && !is_word_byte(self.lower.as_bytes().get(end)))
+            })
+            .collect()
+    }
+
+    /// Spans of any of `phrases`, longest match first where phrases overlap
+    ///
+    /// "i think maybe" is reported once, not again as "maybe"
+    pub fn find_longest<'p>(&self, phrases: &[&'p str]) -> Vec<((usize, usize), &'p str)> {
+        let mut matches: Vec<((usize, usize), &'p str)> = phrases
+            .iter()
+            .flat_map(|phrase| self.find_all(phrase).into_iter().map(move |span| (span, *phrase)))
+            .collect();
+        matches.sort_by_key(|&((start, end), _)| (start, std::cmp::Reverse(end)));
+
+        let mut kept: Vec<((usize, usize), &'p str)> = Vec::new();
+        for candidate in matches {
+            if kept.iter().all(|((start, end), _)| candidate.0 .0 >= *end || candidate.0 .1 <= *start) {
+                kept.push(candidate);
+            }
+        }
+        kept
+    }
+}
+
+/// A single lint check
+pub trait LintRule: Send + Sync {
+    /// Stable rule identifier, e.g. "vague-qualifier"
+    fn id(&self) -> &'static str;
+
+    /// Findings for the answer
+    fn check(&self, context: &LintContext) -> Vec<LintFinding>;
+}
+
+/// Runs a set of lint rules over an answer
+pub struct LintEngine {
+    rules: Vec<Box<dyn LintRule>>,
+}
+
+impl Default for LintEngine {
+    /// Engine with all built-in rules
+    fn default() -> Self {
+        Self::empty()
+            .with_rule(VagueQualifierRule)
+            .with_rule(ResultWithoutNumbersRule)
+            .with_rule(OwnershipAmbiguityRule)
+            .with_rule(HedgingRule)
+            .with_rule(RunOnSentenceRule)
+            .with_rule(RepeatedPhraseRule)
+            .with_rule(UnexplainedJargonRule)
+    }
+}
+
+impl LintEngine {
+    /// Engine without rules
+    pub fn empty() -> Self {
+        Self { rules: Vec::new() }
+    }
+
+    /// Add a rule
+    pub fn with_rule(mut self, rule: impl LintRule + 'static) -> Self {
+        self.rules.push(Box::new(rule));
+        self
+    }
+
+    /// Run every rule, findings ordered by position and written in `language`
+    pub fn lint(&self, answer: &str, language: Language) -> Vec<LintFinding> {
+        let context = LintContext::new(answer, language);
+        let mut findings: Vec<LintFinding> = self.rules.iter().flat_map(|r| r.check(&context)).collect();
+        findings.sort_by_key(|f| (f.start, f.end));
+        findings
+    }
+}
+
+fn finding(rule_id: &str, severity: LintSeverity, span: (usize, usize), message: String, suggestion: String) -> LintFinding {
+    LintFinding {
+        rule_id: rule_id.to_string(),
+        severity,
+        start: span.0,
+        end: span.1,
+        message,
+        suggestion,
+    }
+}
+
+/// Flags vague qualifiers such as "大概" or "一些"
+pub struct VagueQualifierRule;
+
+impl LintRule for VagueQualifierRule {
+    fn id(&self) -> &'static str {
+        "vague-qualifier"
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+        context
+            .find_longest(VAGUE_QUALIFIERS)
+            .into_iter()
+            .map(|(span, word)| {
+                finding(
+                    self.id(),
+                    LintSeverity::Info,
+                    span,
+                    context.language.text(&format!("模糊表述“{}”", word), &format!("Vague wording \"{}\"", word)),
+                    context.language.text(
+                        "用具体的数字、范围或例子代替模糊词",
+                        "Replace vague words with concrete numbers, ranges or examples",
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags result statements without any number
+pub struct ResultWithoutNumbersRule;
+
+impl LintRule for ResultWithoutNumbersRule {
+    fn id(&self) -> &'static str {
+        "result-without-numbers"
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+        context
+            .sentences()
+            .filter(|(_, _, sentence)| {
+                let lower = sentence.to_lowercase();
+                let is_result = RESULT_KEYWORDS_ZH.iter().any(|k| lower.contains(k))
+                    || RESULT_KEYWORDS_EN.iter().any(|k| lower.split(|c: char| !c.is_alphanumeric()).any(|w| w == *k));
+                let has_number = sentence.chars().any(|c| c.is_ascii_digit() || "一二三四五六七八九十百千万倍半".contains(c));
+                is_result && !has_number
+            })
+            .map(|(start, end, _)| {
+                finding(
+                    self.id(),
+                    LintSeverity::Warning,
+                    (start, end),
+                    context.language.text("结果描述缺少数据", "Result stated without numbers"),
+                    context.language.text(
+                        "用数字量化结果，例如提升了多少百分比、节省了多少时间",
+                        "Quantify the result, e.g. by how many percent it improved or how much time it saved",
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags answers where "我们" dominates "我" and personal contribution is unclear
+pub struct OwnershipAmbiguityRule;
+
+impl LintRule for OwnershipAmbiguityRule {
+    fn id(&self) -> &'static str {
+        "ownership-ambiguity"
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+        let we_zh = context.find_all("我们");
+        // "我" also matches inside every "我们"
+        let i_count = context.find_all("我").len() - we_zh.len() + context.find_all("i").len();
+        let we: Vec<(usize, usize)> = we_zh.into_iter().chain(context.find_all("we")).collect();
+
+        if we.len() < OWNERSHIP_MIN_WE || we.len() <= i_count * OWNERSHIP_RATIO {
+            return Vec::new();
+        }
+
+        let message = context.language.text(
+            &format!("“我们”出现{}次，“我”仅{}次，个人贡献不清晰", we.len(), i_count),
+            &format!("\"we\" appears {} times and \"I\" only {}, your own contribution is unclear", we.len(), i_count),
+        );
+        let suggestion = context.language.text(
+            "说明你本人做了什么，把关键动作改为“我……”",
+            "Say what you did yourself and phrase the key actions as \"I ...\"",
+        );
+        we.into_iter()
+            .map(|span| {
+                finding(
+                    self.id(),
+                    LintSeverity::Warning,
+                    span,
+                    message.clone(),
+                    suggestion.clone(),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags hedging phrases such as "也许" or "maybe"
+pub struct HedgingRule;
+
+impl LintRule for HedgingRule {
+    fn id(&self) -> &'static str {
+        "hedging"
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+        context
+            .find_longest(HEDGES)
+            .into_iter()
+            .map(|(span, hedge)| {
+                finding(
+                    self.id(),
+                    LintSeverity::Warning,
+                    span,
+                    context.language.text(&format!("不确定的表述“{}”", hedge), &format!("Hedging \"{}\"", hedge)),
+                    context.language.text(
+                        "用肯定的语气陈述事实，不确定的地方说明依据",
+                        "State facts with confidence and give your reasoning where you are unsure",
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags sentences that are too long or chain too many clauses
+pub struct RunOnSentenceRule;
+
+impl LintRule for RunOnSentenceRule {
+    fn id(&self) -> &'static str {
+        "run-on-sentence"
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+        context
+            .sentences()
+            .filter(|(_, _, sentence)| {
+                let clauses = sentence.matches([',', '，', '、']).count() + 1;
+                sentence.chars().count() > RUN_ON_CHARS || clauses > RUN_ON_CLAUSES
+            })
+            .map(|(start, end, _)| {
+                finding(
+                    self.id(),
+                    LintSeverity::Info,
+                    (start, end),
+                    context.language.text("句子过长", "Sentence too long"),
+                    context.language.text(
+                        "拆成几个短句，每句只讲一个要点",
+                        "Split it into short sentences with one point each",
+                    ),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags phrases repeated several times
+pub struct RepeatedPhraseRule;
+
+impl LintRule for RepeatedPhraseRule {
+    fn id(&self) -> &'static str {
+        "repeated-phrase"
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+        let units = word_units(&context.lower);
+        if units.len() < REPEATED_PHRASE_UNITS {
+            return Vec::new();
+        }
+
+        let mut occurrences: HashMap<Vec<&str>, Vec<(usize, usize)>> = HashMap::new();
+        for window in units.windows(REPEATED_PHRASE_UNITS) {
+            let key: Vec<&str> = window.iter().map(|&(s, e)| &context.lower[s..e]).collect();
+            occurrences.entry(key).or_default().push((window[0].0, window[REPEATED_PHRASE_UNITS - 1].1));
+        }
+
+        let mut repeated: Vec<(usize, usize)> = occurrences
+            .into_values()
+            .filter(|spans| spans.len() >= REPEATED_PHRASE_MIN_COUNT)
+            // The first use is fine, later repetitions are flagged
+            .flat_map(|spans| spans.into_iter().skip(1))
+            .collect();
+        repeated.sort();
+
+        // Merge overlapping windows of longer repeated phrases
+        let mut merged: Vec<(usize, usize)> = Vec::new();
+        for (start, end) in repeated {
+            match merged.last_mut() {
+                Some(last) if start <= last.1 => last.1 = last.1.max(end),
+                _ => merged.push((start, end)),
+            }
+        }
+
+        merged
+            .into_iter()
+            .map(|span| {
+                finding(
+                    self.id(),
+                    LintSeverity::Info,
+                    span,
+                    context.language.text(
+                        &format!("重复表述“{}”", &context.text[span.0..span.1]),
+                        &format!("Repeated phrase \"{}\"", &context.text[span.0..span.1]),
+                    ),
+                    context.language.text("换一种说法或删去重复的内容", "Rephrase or drop the repetition"),
+                )
+            })
+            .collect()
+    }
+}
+
+/// Flags acronyms used without an explanation
+pub struct UnexplainedJargonRule;
+
+impl LintRule for UnexplainedJargonRule {
+    fn id(&self) -> &'static str {
+        "unexplained-jargon"
+    }
+
+    fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+        let mut seen = Vec::new();
+        let mut findings = Vec::new();
+
+        for (start, end) in word_units(context.text) {
+            let word = &context.text[start..end];
+            let is_acronym = (2..=6).contains(&word.len())
+                && word.chars().all(|c| c.is_ascii_uppercase() || c.is_ascii_digit())
+                && word.chars().filter(|c| c.is_ascii_uppercase()).count() >= 2;
+            if !is_acronym || WELL_KNOWN_ACRONYMS.contains(&word) || seen.contains(&word) {
+                continue;
+            }
+            seen.push(word);
+
+            // Only the first use needs an explanation, right after the term
+            let following: String = context.text[end..].chars().take(12).collect::<String>().to_lowercase();
+            let explained = EXPLANATION_MARKERS.iter().any(|m| following.trim_start().starts_with(m));
+            if !explained {
+                findings.push(finding(
+                    self.id(),
+                    LintSeverity::Info,
+                    (start, end),
+                    context.language.text(&format!("术语“{}”没有解释", word), &format!("Unexplained term \"{}\"", word)),
+                    context.language.text(
+                        "第一次出现时用一句话解释术语，面试官未必熟悉",
+                        "Explain the term in one sentence the first time, the interviewer may not know it",
+                    ),
+                ));
+            }
+        }
+
+        findings
+    }
+}
+
+/// Byte spans of trimmed sentences
+fn split_sentences(text: &str) -> Vec<(usize, usize)> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut push = |start: usize, end: usize| {
+        let raw = &text[start..end];
+        let trimmed = raw.trim();
+        if !trimmed.is_empty() {
+            let leading = raw.len() - raw.trim_start().len();
+            sentences.push((start + leading, start + leading + trimmed.len()));
+        }
+    };
+
+    let mut chars = text.char_indices().peekable();
+    while let Some((i, c)) = chars.next() {
+        let next = chars.peek().map(|(_, n)| *n);
+        let is_end = matches!(c, '。' | '！' | '？' | '!' | '?' | '；' | ';' | '\n')
+            || (c == '.' && next.is_none_or(char::is_whitespace));
+        if is_end {
+            push(start, i + c.len_utf8());
+            start = i + c.len_utf8();
+        }
+    }
+    push(start, text.len());
+
+    sentences
+}
+
+/// Byte spans of Han characters and Latin words
+fn word_units(text: &str) -> Vec<(usize, usize)> {
+    let mut units = Vec::new();
+    let mut word_start: Option<usize> = None;
+
+    for (i, c) in text.char_indices() {
+        if c.is_ascii_alphanumeric() || (word_start.is_some() && matches!(c, '+' | '#' | '-' | '_')) {
+            word_start.get_or_insert(i);
+            continue;
+        }
+        if let Some(start) = word_start.take() {
+            units.push((start, i));
+        }
+        if c.is_alphabetic() && !c.is_ascii() {
+            units.push((i, i + c.len_utf8()));
+        }
+    }
+    if let Some(start) = word_start {
+        units.push((start, text.len()));
+    }
+
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule_ids(findings: &[LintFinding]) -> Vec<&str> {
+        findings.iter().map(|f| f.rule_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_findings_carry_byte_spans() {
+        let answer = "我们大概做了一些优化。最终性能提升明显。我们用了CQRS架构，我们也许还能更快。";
+        let findings = LintEngine::default().lint(answer, Language::Zh);
+        let ids = rule_ids(&findings);
+
+        assert!(ids.contains(&"vague-qualifier"));
+        assert!(ids.contains(&"result-without-numbers"));
+        assert!(ids.contains(&"ownership-ambiguity"));
+        assert!(ids.contains(&"hedging"));
+        assert!(ids.contains(&"unexplained-jargon"));
+
+        for finding in &findings {
+            assert!(answer.is_char_boundary(finding.start) && answer.is_char_boundary(finding.end));
+        }
+        let vague = findings.iter().find(|f| f.rule_id == "vague-qualifier").unwrap();
+        assert_eq!(&answer[vague.start..vague.end], "大概");
+        let result = findings.iter().find(|f| f.rule_id == "result-without-numbers").unwrap();
+        assert_eq!(&answer[result.start..result.end], "最终性能提升明显。");
+    }
+
+    #[test]
+    fn test_clean_answer_and_explained_jargon() {
+        let answer = "I led the migration to CQRS (command query responsibility segregation). As a result, p99 latency dropped by 40%.";
+        assert!(LintEngine::default().lint(answer, Language::En).is_empty());
+    }
+
+    #[test]
+    fn test_messages_follow_language() {
+        let answer = "We maybe shipped it.";
+        let english = LintEngine::empty().with_rule(HedgingRule).lint(answer, Language::En);
+        assert_eq!(english[0].message, "Hedging \"maybe\"");
+        assert!(english[0].suggestion.starts_with("State facts"));
+
+        let chinese = LintEngine::empty().with_rule(HedgingRule).lint(answer, Language::Zh);
+        assert_eq!(chinese[0].message, "不确定的表述“maybe”");
+    }
+
+    #[test]
+    fn test_overlapping_phrases_report_longest_match() {
+        let answer = "I think maybe it works, I'm not sure. 我们比较了两种方案，结果比较好。";
+        let findings = LintEngine::empty().with_rule(HedgingRule).with_rule(VagueQualifierRule).lint(answer, Language::Zh);
+        let spans: Vec<(&str, &str)> = findings.iter().map(|f| (f.rule_id.as_str(), &answer[f.start..f.end])).collect();
+
+        assert_eq!(
+            spans,
+            vec![("hedging", "I think maybe"), ("hedging", "I'm not sure"), ("vague-qualifier", "比较好")]
+        );
+    }
+
+    #[test]
+    fn test_repeated_phrase_and_custom_rule() {
+        struct NoExclamation;
+        impl LintRule for NoExclamation {
+            fn id(&self) -> &'static str {
+                "no-exclamation"
+            }
+            fn check(&self, context: &LintContext) -> Vec<LintFinding> {
+                context
+                    .find_all("!")
+                    .into_iter()
+                    .map(|span| finding(self.id(), LintSeverity::Info, span, String::new(), String::new()))
+                    .collect()
+            }
+        }
+
+        let answer = "on the other hand it works! On the other hand it fails, on the other hand it depends";
+        let engine = LintEngine::empty().with_rule(RepeatedPhraseRule).with_rule(NoExclamation);
+        let findings = engine.lint(answer, Language::Zh);
+
+        assert_eq!(rule_ids(&findings), vec!["no-exclamation", "repeated-phrase", "repeated-phrase"]);
+        // Overlapping repeated windows merge into one span
+        assert_eq!(&answer[findings[1].start..findings[1].end], "On the other hand it");
+    }
+}
@@ -6,12 +6,16 @@
 //! - Overall scoring and feedback generation
 
 pub mod content;
+pub mod segmentation;
+pub mod star_segmentation;
 pub mod scoring;
 pub mod report;
 pub mod export;
 pub mod analytics;
 pub mod dashboard;
 pub mod trends;
+pub mod goals;
+pub mod spaced_repetition;
 pub mod backup;
 pub mod cache;
 pub mod cache_manager;
@@ -19,11 +23,28 @@ pub mod profile;
 pub mod recommendation;
 pub mod best_practices;
 pub mod industry;
+pub mod multi_agent_report;
+pub mod answer_comparison;
+pub mod rubric;
+pub mod semantic_match;
+pub mod judge;
+pub mod consistency;
+pub mod lint;
+pub mod skill_gap;
+pub mod resume_parser;
+pub mod jd_parser;
+pub mod taxonomy;
+pub mod question_classifier;
+pub mod trace;
+#[cfg(test)]
+mod test_support;
 
 pub use content::ContentAnalyzer;
 // STAR types are part of the public API and used by frontend via Tauri commands
 #[allow(unused_imports)]
 pub use scoring::{ScoringEngine, STARScoringEngine, STARScoringResult, STARScoreBreakdown};
+#[allow(unused_imports)]
+pub use star_segmentation::{STARSegmenter, STARSegmentation, STARSpan, STARComponent};
 // Report types are part of the public API
 #[allow(unused_imports)]
 pub use report::ReportGenerator;
@@ -34,6 +55,11 @@ pub use export::ReportExporter;
 #[allow(unused_imports)]
 pub use analytics::{AnalyticsEngine, TrendAnalytics, TrendDataPoint, PerformanceStatistics};
 pub use dashboard::{DashboardService, DashboardData};
+#[allow(unused_imports)]
+pub use trends::{TrendsAnalyzer, TrendsReport, TrendStatus, DimensionTrend};
+#[allow(unused_imports)]
+pub use goals::{GoalTracker, GoalType, GoalStatus, GoalProgress, GoalCheck};
+pub use spaced_repetition::{ReviewScheduler, DueReview};
 // Backup types are part of the public API
 #[allow(unused_imports)]
 pub use backup::{BackupManager, BackupData};
@@ -45,4 +71,21 @@ pub use cache_manager::{GenericCache, CacheStats};
 pub use profile::ProfileGenerator;
 pub use recommendation::RecommendationEngine;
 pub use best_practices::BestPracticesExtractor;
-pub use industry::IndustryComparisonGenerator;
+pub use industry::{IndustryComparisonGenerator, BenchmarkImporter};
+pub use multi_agent_report::MultiAgentReportGenerator;
+pub use answer_comparison::AnswerComparisonTracker;
+pub use rubric::{RubricEngine, RubricEvaluation};
+#[allow(unused_imports)]
+pub use semantic_match::{SemanticMatcher, SemanticMatchResult};
+pub use judge::{BlendWeights, JudgeScorer};
+pub use consistency::{ConsistencyAnalyzer, ConsistencyReport, NoiseReport};
+#[allow(unused_imports)]
+pub use lint::{LintEngine, LintRule, LintContext};
+pub use skill_gap::{SkillGapAnalyzer, SkillGapReport};
+pub use resume_parser::ResumeParser;
+pub use jd_parser::{JobDescriptionParser, StructuredJobDescription};
+pub use taxonomy::{SkillTaxonomy, SkillDefinition};
+#[allow(unused_imports)]
+pub use question_classifier::{QuestionClassifier, QuestionType, QuestionDimension, Difficulty};
+#[allow(unused_imports)]
+pub use trace::{ScoreTrace, ComponentTrace, TraceEntry};
@@ -0,0 +1,772 @@
+//! End-of-interview report for multi-agent sessions
+//!
+//! Aggregates conversation turns by phase and interviewer role, derives a
+//! hire/no-hire recommendation and persists the result as a `SessionReport`
+//! so export, dashboard and trend analytics treat it like any other session.
+
+use crate::analysis::ReviewScheduler;
+use crate::db::{Repository, SessionReport, now};
+use crate::language::Language;
+use crate::rig_adapter::{ConversationTurn, InterviewContext, InterviewPhase, InterviewStateMachine};
+use crate::rig_adapter::agents::{CandidateQuestionEvaluation, InterviewerRole, ReverseTurn};
+use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Number of answers listed in the strongest/weakest sections
+const HIGHLIGHT_COUNT: usize = 3;
+
+/// Role average below which a single interviewer vetoes a hire
+const ROLE_VETO_SCORE: f32 = 5.0;
+
+/// Hire recommendation levels
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HireRecommendation {
+    StrongHire,
+    Hire,
+    NoHire,
+    StrongNoHire,
+}
+
+impl HireRecommendation {
+    /// Map an overall score (1-10) to a recommendation level
+    fn from_score(score: f32) -> Self {
+        match score {
+            s if s >= 8.0 => Self::StrongHire,
+            s if s >= 6.5 => Self::Hire,
+            s if s >= 5.0 => Self::NoHire,
+            _ => Self::StrongNoHire,
+        }
+    }
+
+    /// Get display label in the session language
+    pub fn label(&self, language: Language) -> String {
+        match self {
+            Self::StrongHire => language.text("强烈推荐录用", "Strong hire"),
+            Self::Hire => language.text("推荐录用", "Hire"),
+            Self::NoHire => language.text("暂不推荐", "No hire"),
+            Self::StrongNoHire => language.text("不推荐录用", "Strong no hire"),
+        }
+    }
+}
+
+/// Score given by one interviewer role
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoleScore {
+    pub role: InterviewerRole,
+    pub role_name: String,
+    pub average_score: f32,
+    pub answered_count: usize,
+}
+
+/// Per-phase breakdown of turns and scores
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseBreakdown {
+    pub phase: InterviewPhase,
+    pub average_score: Option<f32>,
+    pub question_count: usize,
+    pub answered_count: usize,
+    pub turns: Vec<ConversationTurn>,
+}
+
+/// A single answer highlighted in the report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerHighlight {
+    pub question: String,
+    pub answer: String,
+    pub score: f32,
+    pub role_name: String,
+    pub phase: InterviewPhase,
+    pub summary: String,
+}
+
+/// Time spent in one phase against its budget
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PhaseTiming {
+    pub phase: InterviewPhase,
+    pub elapsed_secs: f32,
+    pub budget_secs: Option<u64>,
+    pub over_budget_secs: f32,
+}
+
+/// Interview duration and answer latency statistics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimingStats {
+    pub total_duration_secs: f32,
+    pub total_budget_secs: u64,
+    pub total_over_budget_secs: f32,
+    pub average_latency_secs: f32,
+    pub median_latency_secs: f32,
+    pub max_latency_secs: f32,
+    pub overtime_answers: usize,
+    pub total_overtime_penalty: f32,
+    pub phases: Vec<PhaseTiming>,
+}
+
+/// Complete multi-agent interview report
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MultiAgentReport {
+    pub overall_score: f32,
+    pub recommendation: HireRecommendation,
+    pub rationale: String,
+    pub role_scores: Vec<RoleScore>,
+    pub phases: Vec<PhaseBreakdown>,
+    pub strongest_answers: Vec<AnswerHighlight>,
+    pub weakest_answers: Vec<AnswerHighlight>,
+    pub improvements: Vec<String>,
+    pub key_takeaways: Vec<String>,
+    pub reverse_turns: Vec<ReverseTurn>,
+    pub question_evaluation: Option<CandidateQuestionEvaluation>,
+    pub timing: Option<TimingStats>,
+}
+
+/// Report generator for multi-agent interviews
+pub struct MultiAgentReportGenerator;
+
+impl MultiAgentReportGenerator {
+    /// Build report from conversation history
+    ///
+    /// Returns `None` when no turn has been answered and analyzed yet,
+    /// `language` selects the language of the rationale
+    pub fn build_report(turns: &[ConversationTurn], language: Language) -> Option<MultiAgentReport> {
+        let scored: Vec<&ConversationTurn> = turns
+            .iter()
+            .filter(|t| t.answer.is_some() && t.analysis.is_some())
+            .collect();
+
+        if scored.is_empty() {
+            return None;
+        }
+
+        let overall_score = Self::average(scored.iter().map(|t| Self::turn_score(t)));
+        let role_scores = Self::score_roles(&scored);
+        let phases = Self::breakdown_phases(turns);
+
+        let (recommendation, rationale) = Self::recommend(overall_score, &role_scores, language);
+
+        let mut highlights: Vec<AnswerHighlight> = scored.iter().map(|t| Self::highlight(t)).collect();
+        highlights.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap_or(std::cmp::Ordering::Equal));
+
+        let strongest_answers: Vec<AnswerHighlight> = highlights
+            .iter()
+            .take(HIGHLIGHT_COUNT)
+            .cloned()
+            .collect();
+        // Weakest answers must not repeat the strongest ones in short interviews
+        let weakest_answers: Vec<AnswerHighlight> = highlights
+            .iter()
+            .rev()
+            .take(HIGHLIGHT_COUNT.min(highlights.len().saturating_sub(strongest_answers.len())))
+            .cloned()
+            .collect();
+
+        // Improvements come from the weakest answers first, takeaways from the strongest
+        let mut by_score = scored.clone();
+        by_score.sort_by(|a, b| Self::turn_score(a).partial_cmp(&Self::turn_score(b)).unwrap_or(std::cmp::Ordering::Equal));
+        let improvements = Self::collect_points(
+            by_score.iter().filter_map(|t| t.analysis.as_ref()).map(|a| &a.improvements),
+        );
+        let key_takeaways = Self::collect_points(
+            by_score.iter().rev().filter_map(|t| t.analysis.as_ref()).map(|a| &a.strengths),
+        );
+
+        Some(MultiAgentReport {
+            overall_score,
+            recommendation,
+            rationale,
+            role_scores,
+            phases,
+            strongest_answers,
+            weakest_answers,
+            improvements,
+            key_takeaways,
+            reverse_turns: Vec::new(),
+            question_evaluation: None,
+            timing: None,
+        })
+    }
+
+    /// Build report and persist it as interview session + session report
+    ///
+    /// # Arguments
+    /// * `db` - Database repository
+    /// * `context` - Interview context of the multi-agent session
+    /// * `question_evaluation` - Evaluation of the candidate's reverse-interview questions
+    /// * `state_machine` - State machine holding the time budgets
+    ///
+    /// The reverse interview is saved on its own as well, so its evaluation
+    /// survives a session in which no answer was scored.
+    ///
+    /// # Returns
+    /// * `Ok(Some(SessionReport))` - Saved report
+    /// * `Ok(None)` - Nothing was answered, no report generated
+    /// * `Err` - Error if persisting fails
+    pub fn generate_and_save(
+        db: &Repository,
+        context: &InterviewContext,
+        question_evaluation: Option<&CandidateQuestionEvaluation>,
+        state_machine: &InterviewStateMachine,
+    ) -> Result<Option<SessionReport>> {
+        let turns = &context.conversation_history;
+        let mut report = match Self::build_report(turns, context.language) {
+            Some(report) => report,
+            None => {
+                Self::save_reverse_evaluation(db, None, &context.reverse_turns, question_evaluation)?;
+                return Ok(None);
+            }
+        };
+        report.reverse_turns = context.reverse_turns.clone();
+        report.question_evaluation = question_evaluation.cloned();
+        report.timing = Self::timing_stats(turns, state_machine);
+
+        // Persist the session so the existing history, export and trend views can find it
+        let questions: Vec<String> = turns.iter().map(|t| t.question.clone()).collect();
+        let session_id = db
+            .create_interview_session(None, None, questions)
+            .context("Failed to create session for multi-agent report")?;
+
+        for (index, turn) in turns.iter().enumerate() {
+            if let Some(answer) = &turn.answer {
+                let feedback = turn
+                    .analysis
+                    .as_ref()
+                    .map(|a| a.summary.clone())
+                    .unwrap_or_default();
+                let answer_id = db.save_answer(
+                    session_id,
+                    index as i32,
+                    turn.question.clone(),
+                    answer.clone(),
+                    feedback,
+                    Some(turn.asked_at.clone()),
+                    turn.answered_at.clone(),
+                    turn.question_id,
+                )
+                    .context("Failed to save multi-agent answer")?;
+
+                // Scored turns on bank questions count as reviews of them
+                if let (Some(question_id), Some(analysis)) = (turn.question_id, &turn.analysis) {
+                    if let Err(e) = ReviewScheduler::record_review(db, question_id, Some(answer_id), analysis.score) {
+                        log::warn!("Review scheduling failed: {}", e);
+                    }
+                }
+            }
+        }
+
+        let content_analysis = serde_json::to_string(&Self::content_analysis(&report, context.language))?;
+        let summary = Self::summary(&report, context.language);
+        let improvements_json = serde_json::to_string(&report.improvements)?;
+        let key_takeaways_json = serde_json::to_string(&report.key_takeaways)?;
+
+        let report_id = db
+            .save_session_report(
+                session_id,
+                report.overall_score,
+                content_analysis.clone(),
+                None,
+                summary.clone(),
+                improvements_json.clone(),
+                key_takeaways_json.clone(),
+                None,
+                None,
+            )
+            .context("Failed to save multi-agent report")?;
+        Self::save_reverse_evaluation(db, Some(session_id), &context.reverse_turns, question_evaluation)?;
+
+        Ok(Some(SessionReport {
+            id: Some(report_id),
+            session_id,
+            overall_score: report.overall_score,
+            content_analysis,
+            expression_analysis: None,
+            summary,
+            improvements: improvements_json,
+            key_takeaways: key_takeaways_json,
+            reference_answers: None,
+            generated_at: now(),
+            api_response_time: None,
+        }))
+    }
+
+    /// Save reverse-interview turns and their evaluation, if the candidate asked anything
+    fn save_reverse_evaluation(
+        db: &Repository,
+        session_id: Option<i64>,
+        reverse_turns: &[ReverseTurn],
+        question_evaluation: Option<&CandidateQuestionEvaluation>,
+    ) -> Result<()> {
+        if reverse_turns.is_empty() && question_evaluation.is_none() {
+            return Ok(());
+        }
+        let evaluation = question_evaluation.map(serde_json::to_string).transpose()?;
+        db.save_reverse_evaluation(session_id, &serde_json::to_string(reverse_turns)?, evaluation.as_deref())
+            .context("Failed to save reverse-interview evaluation")?;
+        Ok(())
+    }
+
+    /// Build content_analysis JSON, including the dimension scores read by trend analytics
+    fn content_analysis(report: &MultiAgentReport, language: Language) -> serde_json::Value {
+        let role_avg = |role: InterviewerRole| {
+            report
+                .role_scores
+                .iter()
+                .find(|r| r.role == role)
+                .map(|r| r.average_score)
+                .unwrap_or(report.overall_score)
+        };
+
+        serde_json::json!({
+            "source": "multi_agent",
+            "recommendation": report.recommendation,
+            "recommendation_label": report.recommendation.label(language),
+            "language": language,
+            "rationale": report.rationale,
+            "role_scores": report.role_scores,
+            "phases": report.phases,
+            "strongest_answers": report.strongest_answers,
+            "weakest_answers": report.weakest_answers,
+            "reverse_turns": report.reverse_turns,
+            "question_evaluation": report.question_evaluation,
+            "timing": report.timing,
+            "technical_depth_score": role_avg(InterviewerRole::Technical),
+            "communication_score": role_avg(InterviewerRole::HR),
+            "problem_solving_score": role_avg(InterviewerRole::Business),
+            "presentation_score": report.overall_score,
+        })
+    }
+
+    /// Build human-readable summary used by exports
+    fn summary(report: &MultiAgentReport, language: Language) -> String {
+        let roles = report
+            .role_scores
+            .iter()
+            .map(|r| format!("{} {:.1}", r.role_name, r.average_score))
+            .collect::<Vec<_>>()
+            .join(", ");
+
+        let mut summary = language.text(
+            &format!(
+                "多面试官综合评估：{}（综合 {:.1}/10）。{}各面试官评分：{}。",
+                report.recommendation.label(Language::Zh),
+                report.overall_score,
+                report.rationale,
+                roles
+            ),
+            &format!(
+                "Panel assessment: {} (overall {:.1}/10). Rationale: {} Interviewer scores: {}.",
+                report.recommendation.label(Language::En),
+                report.overall_score,
+                report.rationale,
+                roles
+            ),
+        );
+
+        if let Some(evaluation) = &report.question_evaluation {
+            summary.push_str(&language.text(
+                &format!(
+                    "反问环节 {:.1}分（洞察力 {:.1}，准备程度 {:.1}，相关性 {:.1}）：",
+                    evaluation.score, evaluation.insightfulness, evaluation.preparation, evaluation.relevance
+                ),
+                &format!(
+                    " Reverse interview {:.1} (insight {:.1}, preparation {:.1}, relevance {:.1}): ",
+                    evaluation.score, evaluation.insightfulness, evaluation.preparation, evaluation.relevance
+                ),
+            ));
+            summary.push_str(&evaluation.summary);
+        }
+
+        if let Some(timing) = report.timing.as_ref().filter(|t| t.overtime_answers > 0) {
+            summary.push_str(&language.text(
+                &format!(
+                    "共{}道题回答超时，平均用时 {:.0} 秒，累计扣{:.1}分。",
+                    timing.overtime_answers, timing.average_latency_secs, timing.total_overtime_penalty
+                ),
+                &format!(
+                    " {} answers ran over time, averaging {:.0}s, costing {:.1} points in total.",
+                    timing.overtime_answers, timing.average_latency_secs, timing.total_overtime_penalty
+                ),
+            ));
+        }
+
+        summary
+    }
+
+    /// Derive recommendation and rationale from overall and per-role scores
+    fn recommend(overall_score: f32, role_scores: &[RoleScore], language: Language) -> (HireRecommendation, String) {
+        let mut recommendation = HireRecommendation::from_score(overall_score);
+        let mut reasons = vec![language.text(
+            &format!("综合得分 {:.1}/10", overall_score),
+            &format!("overall score {:.1}/10", overall_score),
+        )];
+
+        if let Some(best) = role_scores
+            .iter()
+            .max_by(|a, b| a.average_score.partial_cmp(&b.average_score).unwrap_or(std::cmp::Ordering::Equal))
+        {
+            reasons.push(language.text(
+                &format!("{}评价最高（{:.1}分）", best.role_name, best.average_score),
+                &format!("highest rating from {} ({:.1})", best.role_name, best.average_score),
+            ));
+        }
+
+        let vetoes: Vec<&RoleScore> = role_scores
+            .iter()
+            .filter(|r| r.average_score < ROLE_VETO_SCORE)
+            .collect();
+
+        if !vetoes.is_empty() {
+            // A failing interviewer caps the decision at no-hire
+            if matches!(recommendation, HireRecommendation::StrongHire | HireRecommendation::Hire) {
+                recommendation = HireRecommendation::NoHire;
+            }
+            for veto in vetoes {
+                reasons.push(language.text(
+                    &format!("{}评分偏低（{:.1}分），存在明显短板", veto.role_name, veto.average_score),
+                    &format!("low rating from {} ({:.1}), a clear weakness", veto.role_name, veto.average_score),
+                ));
+            }
+        }
+
+        let rationale = match language {
+            Language::En => format!("{}.", reasons.join("; ")),
+            _ => format!("{}。", reasons.join("；")),
+        };
+        (recommendation, rationale)
+    }
+
+    /// Average score per interviewer role, in first-seen order
+    fn score_roles(scored: &[&ConversationTurn]) -> Vec<RoleScore> {
+        let mut roles: Vec<(InterviewerRole, String, Vec<f32>)> = Vec::new();
+
+        for turn in scored {
+            match roles.iter_mut().find(|(role, _, _)| *role == turn.role) {
+                Some((_, _, scores)) => scores.push(Self::turn_score(turn)),
+                None => roles.push((turn.role, turn.role_name.clone(), vec![Self::turn_score(turn)])),
+            }
+        }
+
+        roles
+            .into_iter()
+            .map(|(role, role_name, scores)| RoleScore {
+                role,
+                role_name,
+                average_score: Self::average(scores.iter().copied()),
+                answered_count: scores.len(),
+            })
+            .collect()
+    }
+
+    /// Group turns by phase, in interview order
+    fn breakdown_phases(turns: &[ConversationTurn]) -> Vec<PhaseBreakdown> {
+        let mut phases: Vec<PhaseBreakdown> = Vec::new();
+
+        for turn in turns {
+            let index = match phases.iter().position(|p| p.phase == turn.phase) {
+                Some(index) => index,
+                None => {
+                    phases.push(PhaseBreakdown {
+                        phase: turn.phase,
+                        average_score: None,
+                        question_count: 0,
+                        answered_count: 0,
+                        turns: Vec::new(),
+                    });
+                    phases.len() - 1
+                }
+            };
+            let phase = &mut phases[index];
+            phase.question_count += 1;
+            if turn.answer.is_some() {
+                phase.answered_count += 1;
+            }
+            phase.turns.push(turn.clone());
+        }
+
+        for phase in phases.iter_mut() {
+            let scores: Vec<f32> = phase
+                .turns
+                .iter()
+                .filter_map(|t| t.analysis.as_ref().map(|a| a.score))
+                .collect();
+            if !scores.is_empty() {
+                phase.average_score = Some(Self::average(scores.into_iter()));
+            }
+        }
+
+        phases
+    }
+
+    /// Collect up to five unique points, keeping iteration order
+    fn collect_points<'a>(all_points: impl Iterator<Item = &'a Vec<String>>) -> Vec<String> {
+        let mut points: Vec<String> = Vec::new();
+
+        for list in all_points {
+            for point in list {
+                let point = point.trim();
+                if !point.is_empty() && !points.iter().any(|p| p == point) {
+                    points.push(point.to_string());
+                }
+            }
+            if points.len() >= 5 {
+                break;
+            }
+        }
+
+        points.truncate(5);
+        points
+    }
+
+    fn highlight(turn: &ConversationTurn) -> AnswerHighlight {
+        AnswerHighlight {
+            question: turn.question.clone(),
+            answer: turn.answer.clone().unwrap_or_default(),
+            score: Self::turn_score(turn),
+            role_name: turn.role_name.clone(),
+            phase: turn.phase,
+            summary: turn.analysis.as_ref().map(|a| a.summary.clone()).unwrap_or_default(),
+        }
+    }
+
+    /// Compute duration, per-phase budget usage and answer latency statistics
+    ///
+    /// Returns `None` when no turn carries a parseable timestamp
+    pub fn timing_stats(turns: &[ConversationTurn], state_machine: &InterviewStateMachine) -> Option<TimingStats> {
+        let parse = |ts: &str| DateTime::parse_from_rfc3339(ts).ok().map(|t| t.with_timezone(&Utc));
+        let span = |turns: &[&ConversationTurn]| -> Option<f32> {
+            let start = turns.iter().filter_map(|t| parse(&t.asked_at)).min()?;
+            let end = turns
+                .iter()
+                .filter_map(|t| t.answered_at.as_deref().and_then(parse).or_else(|| parse(&t.asked_at)))
+                .max()?;
+            Some((end - start).num_milliseconds().max(0) as f32 / 1000.0)
+        };
+
+        let all: Vec<&ConversationTurn> = turns.iter().collect();
+        let total_duration_secs = span(&all)?;
+        let timing = state_machine.timing();
+
+        let mut phases: Vec<PhaseTiming> = Vec::new();
+        for turn in turns {
+            if phases.iter().any(|p| p.phase == turn.phase) {
+                continue;
+            }
+            let phase_turns: Vec<&ConversationTurn> = turns.iter().filter(|t| t.phase == turn.phase).collect();
+            let elapsed_secs = span(&phase_turns).unwrap_or(0.0);
+            let budget_secs = state_machine.phase_budget_secs(turn.phase);
+            phases.push(PhaseTiming {
+                phase: turn.phase,
+                elapsed_secs,
+                budget_secs,
+                over_budget_secs: budget_secs.map(|b| (elapsed_secs - b as f32).max(0.0)).unwrap_or(0.0),
+            });
+        }
+
+        let mut latencies: Vec<f32> = turns.iter().filter_map(|t| t.latency_secs).collect();
+        latencies.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let median_latency_secs = match latencies.len() {
+            0 => 0.0,
+            n if n % 2 == 0 => (latencies[n / 2 - 1] + latencies[n / 2]) / 2.0,
+            n => latencies[n / 2],
+        };
+
+        Some(TimingStats {
+            total_duration_secs,
+            total_budget_secs: timing.overall_budget_secs,
+            total_over_budget_secs: (total_duration_secs - timing.overall_budget_secs as f32).max(0.0),
+            average_latency_secs: Self::average(latencies.iter().copied()),
+            median_latency_secs,
+            max_latency_secs: latencies.last().copied().unwrap_or(0.0),
+            overtime_answers: latencies.iter().filter(|l| **l > timing.answer_budget_secs as f32).count(),
+            total_overtime_penalty: turns.iter().map(|t| t.overtime_penalty).sum(),
+            phases,
+        })
+    }
+
+    fn turn_score(turn: &ConversationTurn) -> f32 {
+        turn.analysis.as_ref().map(|a| a.score).unwrap_or(0.0)
+    }
+
+    fn average(scores: impl Iterator<Item = f32>) -> f32 {
+        let (sum, count) = scores.fold((0.0, 0), |(sum, count), s| (sum + s, count + 1));
+        if count == 0 {
+            0.0
+        } else {
+            sum / count as f32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::rig_adapter::AnalysisResult;
+
+    fn turn(role: InterviewerRole, phase: InterviewPhase, score: Option<f32>) -> ConversationTurn {
+        ConversationTurn {
+            role,
+            role_name: format!("{:?}", role),
+            phase,
+            question: format!("question {:?}", score),
+            question_id: None,
+            answer: score.map(|_| "answer".to_string()),
+            analysis: score.map(|score| AnalysisResult {
+                score,
+                strengths: vec![format!("strength {}", score)],
+                improvements: vec![format!("improvement {}", score)],
+                summary: "summary".to_string(),
+                criterion_scores: Vec::new(),
+            }),
+            asked_at: "2024-01-01T10:00:00+00:00".to_string(),
+            answered_at: score.map(|_| "2024-01-01T10:04:00+00:00".to_string()),
+            latency_secs: score.map(|_| 240.0),
+            overtime_penalty: 0.0,
+        }
+    }
+
+    #[test]
+    fn test_no_report_without_answers() {
+        let turns = vec![turn(InterviewerRole::Technical, InterviewPhase::WarmUp, None)];
+        assert!(MultiAgentReportGenerator::build_report(&turns, Language::Zh).is_none());
+    }
+
+    #[test]
+    fn test_report_aggregates_phases_and_roles() {
+        let turns = vec![
+            turn(InterviewerRole::Technical, InterviewPhase::WarmUp, Some(8.0)),
+            turn(InterviewerRole::Technical, InterviewPhase::Technical, Some(9.0)),
+            turn(InterviewerRole::HR, InterviewPhase::Behavioral, Some(7.0)),
+            turn(InterviewerRole::Business, InterviewPhase::Business, None),
+        ];
+        let report = MultiAgentReportGenerator::build_report(&turns, Language::Zh).unwrap();
+
+        assert!((report.overall_score - 8.0).abs() < 0.01);
+        assert_eq!(report.recommendation, HireRecommendation::StrongHire);
+        assert_eq!(report.role_scores.len(), 2);
+        assert_eq!(report.phases.len(), 4);
+        assert_eq!(report.phases[3].answered_count, 0);
+        assert!(report.phases[3].average_score.is_none());
+        assert_eq!(report.strongest_answers[0].score, 9.0);
+        assert_eq!(report.improvements[0], "improvement 7");
+    }
+
+    #[test]
+    fn test_low_role_score_vetoes_hire() {
+        let turns = vec![
+            turn(InterviewerRole::Technical, InterviewPhase::Technical, Some(10.0)),
+            turn(InterviewerRole::Technical, InterviewPhase::Technical, Some(10.0)),
+            turn(InterviewerRole::HR, InterviewPhase::Behavioral, Some(4.0)),
+        ];
+        let report = MultiAgentReportGenerator::build_report(&turns, Language::Zh).unwrap();
+
+        assert_eq!(report.recommendation, HireRecommendation::NoHire);
+        assert!(report.rationale.contains("HR"));
+    }
+
+    #[test]
+    fn test_english_rationale_and_label() {
+        let turns = vec![turn(InterviewerRole::HR, InterviewPhase::Behavioral, Some(4.0))];
+        let report = MultiAgentReportGenerator::build_report(&turns, Language::En).unwrap();
+
+        assert!(report.rationale.starts_with("overall score 4.0/10"));
+        assert!(report.rationale.ends_with('.'));
+        assert_eq!(report.recommendation.label(Language::En), "Strong no hire");
+
+        let summary = MultiAgentReportGenerator::summary(&report, Language::En);
+        assert!(summary.starts_with("Panel assessment: Strong no hire"));
+        assert!(summary.contains(&report.rationale));
+        assert_eq!(InterviewerRole::HR.display_name(Language::En), "HR interviewer");
+    }
+
+    #[test]
+    fn test_reverse_evaluation_saved_without_answers() {
+        let db = Repository::new(crate::db::init_database(":memory:".into()).unwrap());
+        let context = InterviewContext {
+            resume: String::new(),
+            job_description: String::new(),
+            structured_jd: Default::default(),
+            company_notes: String::new(),
+            conversation_history: vec![turn(InterviewerRole::HR, InterviewPhase::WarmUp, None)],
+            reverse_turns: vec![ReverseTurn {
+                role: InterviewerRole::Business,
+                role_name: "业务面试官".to_string(),
+                candidate_question: "团队今年的业务重点是什么？".to_string(),
+                answer: "主要是海外市场".to_string(),
+            }],
+            current_phase: InterviewPhase::Questions,
+            language: Language::Zh,
+            rubrics: Vec::new(),
+        };
+        let evaluation = CandidateQuestionEvaluation {
+            score: 8.0,
+            insightfulness: 8.0,
+            preparation: 7.5,
+            relevance: 8.5,
+            strengths: Vec::new(),
+            improvements: Vec::new(),
+            summary: "有针对性".to_string(),
+        };
+
+        let report = MultiAgentReportGenerator::generate_and_save(
+            &db,
+            &context,
+            Some(&evaluation),
+            &InterviewStateMachine::new(),
+        )
+        .unwrap();
+        assert!(report.is_none());
+
+        let saved = db.get_reverse_evaluations(10).unwrap();
+        assert_eq!(saved.len(), 1);
+        assert!(saved[0].session_id.is_none());
+        assert!(saved[0].turns.contains("海外市场"));
+        let stored: CandidateQuestionEvaluation = serde_json::from_str(saved[0].evaluation.as_deref().unwrap()).unwrap();
+        assert_eq!(stored.score, 8.0);
+    }
+
+    #[test]
+    fn test_bank_turns_saved_with_id_and_reviewed() {
+        let db = Repository::new(crate::db::init_database(":memory:".into()).unwrap());
+        let question_id = db.add_to_question_bank("介绍一个项目".to_string(), None, None, None).unwrap();
+        let mut linked = turn(InterviewerRole::Technical, InterviewPhase::Technical, Some(8.0));
+        linked.question_id = Some(question_id);
+        let context = InterviewContext {
+            resume: String::new(),
+            job_description: String::new(),
+            structured_jd: Default::default(),
+            company_notes: String::new(),
+            conversation_history: vec![linked, turn(InterviewerRole::HR, InterviewPhase::Behavioral, Some(6.0))],
+            reverse_turns: Vec::new(),
+            current_phase: InterviewPhase::Completed,
+            language: Language::Zh,
+            rubrics: Vec::new(),
+        };
+
+        let report = MultiAgentReportGenerator::generate_and_save(&db, &context, None, &InterviewStateMachine::new())
+            .unwrap()
+            .unwrap();
+
+        let answers = db.get_answers_by_session(report.session_id).unwrap();
+        assert_eq!(answers.len(), 2);
+        assert_eq!(answers.iter().filter(|a| a.question_id == Some(question_id)).count(), 1);
+        let review = db.get_question_review(question_id).unwrap().unwrap();
+        assert_eq!(review.interval_days, 1);
+        assert_eq!(db.get_question_history(question_id).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_timing_stats() {
+        let turns = vec![
+            turn(InterviewerRole::HR, InterviewPhase::WarmUp, Some(7.0)),
+            turn(InterviewerRole::Technical, InterviewPhase::Technical, None),
+        ];
+        let state_machine = InterviewStateMachine::new();
+        let timing = MultiAgentReportGenerator::timing_stats(&turns, &state_machine).unwrap();
+
+        assert_eq!(timing.total_duration_secs, 240.0);
+        assert_eq!(timing.median_latency_secs, 240.0);
+        assert_eq!(timing.overtime_answers, 1);
+        assert_eq!(timing.phases.len(), 2);
+        assert_eq!(timing.phases[1].elapsed_secs, 0.0);
+    }
+}
@@ -10,11 +10,13 @@ use anyhow::Result;
 pub struct ProfileGenerator;
 
 impl ProfileGenerator {
-    /// Generate interview profile based on user's session history
+    /// Generate interview profile based on the active user's session history
+    /// 
+    /// The repository scopes sessions to the active user, whose ID the
+    /// profile carries.
     /// 
     /// # Arguments
     /// * `repository` - Database repository
-    /// * `user_id` - User identifier (default to "default_user")
     /// * `session_limit` - Maximum number of recent sessions to analyze (None for all)
     /// 
     /// # Returns
@@ -22,14 +24,15 @@ impl ProfileGenerator {
     /// * `Err` - Error if profile generation fails
     pub fn generate_profile(
         repository: &Repository,
-        user_id: &str,
         session_limit: Option<usize>,
     ) -> Result<InterviewProfile> {
+        let user_id = repository.get_current_user_id()?.to_string();
+        
         // Get all sessions
         let sessions = repository.get_interview_sessions()?;
         
         if sessions.is_empty() {
-            return Self::create_empty_profile(user_id);
+            return Self::create_empty_profile(&user_id);
         }
         
         // Limit to recent sessions if specified
@@ -119,7 +122,7 @@ impl ProfileGenerator {
         let suggestions = Self::generate_suggestions(&dimension_scores, &weakest);
         
         Ok(InterviewProfile {
-            user_id: user_id.to_string(),
+            user_id,
             dimensions: dimension_scores,
             total_sessions,
             average_score,
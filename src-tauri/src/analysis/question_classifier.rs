@@ -0,0 +1,491 @@
+//! Question bank classification
+//!
+//! Assigns each question a type, a target profile dimension, a difficulty and
+//! the skills it touches. Type and dimension come from the local embedding
+//! model: the question is compared with the centroid of a few labeled
+//! prototype questions per label, with keyword cues as a small prior. Without
+//! the model the keyword cues decide alone. An optional LLM pass can override
+//! the result.
+
+use super::semantic_match::SemanticMatcher;
+use super::taxonomy::SkillTaxonomy;
+use crate::db::QuestionClassification;
+use crate::language::Language;
+use crate::rag::RagService;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use tokio::sync::OnceCell;
+
+/// Similarity bonus per keyword cue matching a label
+const KEYWORD_BONUS: f32 = 0.05;
+
+/// Kind of interview question
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionType {
+    Behavioral,
+    Technical,
+    SystemDesign,
+    Situational,
+    Motivation,
+}
+
+/// Profile dimension a question exercises; stored names match `ProfileDimension` fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum QuestionDimension {
+    TechnicalDepth,
+    Communication,
+    ProblemSolving,
+    DomainKnowledge,
+    Adaptability,
+}
+
+/// Expected difficulty
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+/// How a classification was produced
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ClassificationMethod {
+    Embedding,
+    Keywords,
+    Llm,
+}
+
+/// Serialized name of a unit-like enum value, as stored in the database
+fn label<T: Serialize>(value: T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+/// Enum value from its stored name
+fn parse_label<T: for<'de> Deserialize<'de>>(value: &str) -> Option<T> {
+    serde_json::from_value(serde_json::Value::String(value.trim().to_lowercase().replace([' ', '-'], "_"))).ok()
+}
+
+/// Labeled prototype questions per type
+const TYPE_PROTOTYPES: &[(QuestionType, &[&str])] = &[
+    (QuestionType::Behavioral, &[
+        "请讲一个你和同事发生分歧并最终解决的经历",
+        "描述一次你在压力下按时完成任务的经历",
+        "举例说明你如何带领团队完成一个困难的项目",
+        "Tell me about a time you failed and what you learned",
+        "Describe a situation where you had to persuade a stakeholder",
+    ]),
+    (QuestionType::Technical, &[
+        "Redis 的持久化机制有哪些，各自的优缺点是什么",
+        "解释一下 Java 垃圾回收的原理",
+        "TCP 三次握手的过程是怎样的",
+        "What is the difference between a process and a thread",
+        "How does a hash map handle collisions",
+    ]),
+    (QuestionType::SystemDesign, &[
+        "设计一个短链接生成系统",
+        "如何设计一个支持千万级用户的消息推送系统",
+        "设计一个高可用的秒杀系统架构",
+        "Design a distributed rate limiter",
+        "How would you design the architecture of a news feed at scale",
+    ]),
+    (QuestionType::Situational, &[
+        "如果上线后发现严重故障，你会怎么处理",
+        "假如产品经理临时变更需求，而交付时间不变，你会怎么做",
+        "如果你和领导的技术方案意见不一致，你会怎么办",
+        "What would you do if a teammate kept missing deadlines",
+        "Suppose production traffic doubles overnight, how would you respond",
+    ]),
+    (QuestionType::Motivation, &[
+        "你为什么想加入我们公司",
+        "你未来三到五年的职业规划是什么",
+        "你为什么考虑离开现在的公司",
+        "Why are you interested in this role",
+        "What motivates you in your work",
+    ]),
+];
+
+/// Labeled prototype questions per dimension
+const DIMENSION_PROTOTYPES: &[(QuestionDimension, &[&str])] = &[
+    (QuestionDimension::TechnicalDepth, &[
+        "讲讲数据库索引的底层实现原理",
+        "说说你对操作系统内存管理的理解",
+        "Explain how garbage collection works under the hood",
+    ]),
+    (QuestionDimension::Communication, &[
+        "请做一个简短的自我介绍",
+        "你如何向非技术人员解释一个复杂的技术方案",
+        "How do you keep stakeholders informed during a project",
+    ]),
+    (QuestionDimension::ProblemSolving, &[
+        "线上接口突然变慢，你会如何排查定位问题",
+        "遇到一个难以复现的 bug，你的解决思路是什么",
+        "How would you debug a memory leak in production",
+    ]),
+    (QuestionDimension::DomainKnowledge, &[
+        "你在上一个项目中负责的业务是什么，核心指标有哪些",
+        "你如何理解我们所在行业的发展趋势",
+        "What business metrics did your last project move",
+    ]),
+    (QuestionDimension::Adaptability, &[
+        "你是如何快速学习一门新技术的",
+        "需求频繁变化时你如何调整自己的工作方式",
+        "Tell me how you adapted when your team changed direction",
+    ]),
+];
+
+/// Keyword cues per type
+const TYPE_KEYWORDS: &[(QuestionType, &[&str])] = &[
+    (QuestionType::Behavioral, &["讲一个", "讲讲你", "举例", "描述一次", "经历", "tell me about a time", "describe a time", "give an example"]),
+    (QuestionType::Technical, &["原理", "区别", "什么是", "如何实现", "底层", "机制", "explain", "difference between", "how does", "what is"]),
+    (QuestionType::SystemDesign, &["设计一个", "设计一套", "系统设计", "如何设计", "架构设计", "design a", "design an", "architecture", "at scale"]),
+    (QuestionType::Situational, &["如果", "假如", "假设", "遇到", "what would you do", "if you", "suppose", "how would you handle"]),
+    (QuestionType::Motivation, &["为什么选择", "为什么想", "为什么加入", "职业规划", "动机", "离职", "why do you want", "why are you interested", "career", "motivat"]),
+];
+
+/// Keyword cues per dimension
+const DIMENSION_KEYWORDS: &[(QuestionDimension, &[&str])] = &[
+    (QuestionDimension::TechnicalDepth, &["原理", "底层", "实现", "架构", "性能", "算法", "数据结构", "internals", "under the hood"]),
+    (QuestionDimension::Communication, &["介绍", "描述", "解释", "沟通", "表达", "团队", "communicat", "explain to"]),
+    (QuestionDimension::ProblemSolving, &["解决", "问题", "调试", "排查", "优化", "方案", "debug", "troubleshoot", "solve"]),
+    (QuestionDimension::DomainKnowledge, &["业务", "行业", "领域", "经验", "项目", "business", "industry", "domain"]),
+    (QuestionDimension::Adaptability, &["变化", "学习", "新技术", "挑战", "压力", "adapt", "learn", "change"]),
+];
+
+const HARD_MARKERS: &[&str] = &[
+    "原理", "底层", "源码", "权衡", "取舍", "高并发", "分布式", "一致性", "千万", "亿级",
+    "trade-off", "tradeoff", "internals", "under the hood", "at scale", "consistency",
+];
+const EASY_MARKERS: &[&str] = &["介绍一下", "自我介绍", "什么是", "简单", "基本", "what is", "introduce", "basic"];
+
+/// Centroid embeddings of the labeled prototypes
+pub struct PrototypeEmbeddings {
+    types: Vec<(QuestionType, Vec<f32>)>,
+    dimensions: Vec<(QuestionDimension, Vec<f32>)>,
+}
+
+/// Embedding, keyword and LLM question classification
+pub struct QuestionClassifier;
+
+impl QuestionClassifier {
+    /// Centroids of the labeled prototypes, embedded once per process
+    ///
+    /// The prototype tables and the local model are fixed at build time; a
+    /// failed attempt is not cached, so a later call can retry.
+    pub async fn embed_prototypes(rag: &RagService) -> Result<&'static PrototypeEmbeddings> {
+        static PROTOTYPES: OnceCell<PrototypeEmbeddings> = OnceCell::const_new();
+        PROTOTYPES
+            .get_or_try_init(|| async {
+                Ok(PrototypeEmbeddings {
+                    types: Self::centroids(rag, TYPE_PROTOTYPES).await?,
+                    dimensions: Self::centroids(rag, DIMENSION_PROTOTYPES).await?,
+                })
+            })
+            .await
+    }
+
+    /// Classify questions with the embedding model, falling back to keyword cues if it is unavailable
+    pub async fn classify_batch(rag: &RagService, questions: &[(i64, String)]) -> Vec<QuestionClassification> {
+        if questions.is_empty() {
+            return Vec::new();
+        }
+        let texts: Vec<String> = questions.iter().map(|(_, q)| q.clone()).collect();
+        let embedded = match Self::embed_prototypes(rag).await {
+            Ok(prototypes) => rag.embed_texts(&texts).await.map(|embeddings| (prototypes, embeddings)),
+            Err(e) => Err(e),
+        };
+        match embedded {
+            Ok((prototypes, embeddings)) if embeddings.len() == questions.len() => questions
+                .iter()
+                .zip(&embeddings)
+                .map(|((id, question), embedding)| Self::classify_embedding(*id, question, embedding, prototypes))
+                .collect(),
+            Ok(_) => questions.iter().map(|(id, q)| Self::classify_keywords(*id, q)).collect(),
+            Err(e) => {
+                log::warn!("Question embedding unavailable, classifying by keywords: {}", e);
+                questions.iter().map(|(id, q)| Self::classify_keywords(*id, q)).collect()
+            }
+        }
+    }
+
+    /// Classify a question from its embedding
+    ///
+    /// Confidence reflects the margin between the best and second-best type.
+    pub fn classify_embedding(
+        question_id: i64,
+        question: &str,
+        embedding: &[f32],
+        prototypes: &PrototypeEmbeddings,
+    ) -> QuestionClassification {
+        let lower = question.to_lowercase();
+        let type_scores: Vec<(QuestionType, f32)> = prototypes
+            .types
+            .iter()
+            .map(|(label, centroid)| {
+                let similarity = SemanticMatcher::cosine_similarity(embedding, centroid);
+                (*label, similarity + Self::cue_count(&lower, TYPE_KEYWORDS, label) as f32 * KEYWORD_BONUS)
+            })
+            .collect();
+        let dimension_scores: Vec<(QuestionDimension, f32)> = prototypes
+            .dimensions
+            .iter()
+            .map(|(label, centroid)| {
+                let similarity = SemanticMatcher::cosine_similarity(embedding, centroid);
+                (*label, similarity + Self::cue_count(&lower, DIMENSION_KEYWORDS, label) as f32 * KEYWORD_BONUS)
+            })
+            .collect();
+
+        let (question_type, confidence) = Self::best(&type_scores);
+        let (dimension, _) = Self::best(&dimension_scores);
+        Self::build(question_id, question, question_type, dimension, confidence, ClassificationMethod::Embedding)
+    }
+
+    /// Classify from keyword cues only
+    pub fn classify_keywords(question_id: i64, question: &str) -> QuestionClassification {
+        let lower = question.to_lowercase();
+        let skills = Self::skills(question);
+
+        let mut type_scores: Vec<(QuestionType, f32)> = TYPE_KEYWORDS
+            .iter()
+            .map(|(label, _)| (*label, Self::cue_count(&lower, TYPE_KEYWORDS, label) as f32))
+            .collect();
+        // A named technology without other cues suggests a technical question
+        if !skills.is_empty() {
+            if let Some(technical) = type_scores.iter_mut().find(|(label, _)| *label == QuestionType::Technical) {
+                technical.1 += 0.5;
+            }
+        }
+        let dimension_scores: Vec<(QuestionDimension, f32)> = DIMENSION_KEYWORDS
+            .iter()
+            .map(|(label, _)| (*label, Self::cue_count(&lower, DIMENSION_KEYWORDS, label) as f32))
+            .collect();
+
+        let (question_type, margin) = Self::best(&type_scores);
+        let question_type = if type_scores.iter().all(|(_, s)| *s == 0.0) { QuestionType::Behavioral } else { question_type };
+        let dimension = if dimension_scores.iter().all(|(_, s)| *s == 0.0) {
+            Self::default_dimension(question_type)
+        } else {
+            Self::best(&dimension_scores).0
+        };
+        // Keyword cues are coarse: cap confidence below embedding results
+        let confidence = (margin * 0.3).min(0.6);
+        Self::build(question_id, question, question_type, dimension, confidence, ClassificationMethod::Keywords)
+    }
+
+    /// System prompt for the optional LLM pass
+    pub fn llm_prompt(language: Language) -> String {
+        format!(
+            "你是一位资深面试官，负责给面试题分类。question_type 只能是 behavioral、technical、system_design、situational、motivation 之一；\
+             dimension 只能是 technical_depth、communication、problem_solving、domain_knowledge、adaptability 之一；\
+             difficulty 只能是 easy、medium、hard 之一；skills 为题目涉及的技能名称列表。\
+             你必须只返回有效的JSON对象，格式：{{\"question_type\": \"technical\", \"dimension\": \"technical_depth\", \"difficulty\": \"medium\", \"skills\": [\"Redis\"]}}。{}",
+            language.prompt_instruction()
+        )
+    }
+
+    /// Apply an LLM classification on top of a local one; invalid labels keep the local value
+    pub fn refine(local: QuestionClassification, response: &str) -> Result<QuestionClassification> {
+        #[derive(Deserialize)]
+        struct LlmClassification {
+            #[serde(default)]
+            question_type: String,
+            #[serde(default)]
+            dimension: String,
+            #[serde(default)]
+            difficulty: String,
+            #[serde(default)]
+            skills: Vec<String>,
+        }
+
+        let parsed: LlmClassification = match (response.find('{'), response.rfind('}')) {
+            (Some(start), Some(end)) if start < end => serde_json::from_str(&response[start..=end])
+                .map_err(|e| anyhow!("Invalid classification JSON: {}", e))?,
+            _ => return Err(anyhow!("No JSON object in classification response")),
+        };
+
+        let question_type = parse_label::<QuestionType>(&parsed.question_type).map(label);
+        let dimension = parse_label::<QuestionDimension>(&parsed.dimension).map(label);
+        let difficulty = parse_label::<Difficulty>(&parsed.difficulty).map(label);
+        if question_type.is_none() && dimension.is_none() && difficulty.is_none() {
+            return Err(anyhow!("Classification response has no valid labels"));
+        }
+
+        let mut skills: Vec<String> = serde_json::from_str(&local.skills).unwrap_or_default();
+        skills.extend(parsed.skills.into_iter().filter(|s| !s.trim().is_empty()));
+        let skills = SkillTaxonomy::global().dedup_terms(skills);
+
+        Ok(QuestionClassification {
+            question_type: question_type.unwrap_or(local.question_type),
+            dimension: dimension.unwrap_or(local.dimension),
+            difficulty: difficulty.unwrap_or(local.difficulty),
+            skills: serde_json::to_string(&skills).unwrap_or_else(|_| "[]".to_string()),
+            method: label(ClassificationMethod::Llm),
+            confidence: 1.0,
+            ..local
+        })
+    }
+
+    /// Difficulty from type, depth markers and the number of skills involved
+    pub fn difficulty(question: &str, question_type: QuestionType, skill_count: usize) -> Difficulty {
+        let lower = question.to_lowercase();
+        let mut level: i32 = match question_type {
+            QuestionType::SystemDesign => 2,
+            QuestionType::Technical => 1,
+            _ => 0,
+        };
+        level += HARD_MARKERS.iter().filter(|m| lower.contains(*m)).count().min(2) as i32;
+        if EASY_MARKERS.iter().any(|m| lower.contains(m)) {
+            level -= 1;
+        }
+        if skill_count >= 3 {
+            level += 1;
+        }
+        match level {
+            i32::MIN..=0 => Difficulty::Easy,
+            1..=2 => Difficulty::Medium,
+            _ => Difficulty::Hard,
+        }
+    }
+
+    fn build(
+        question_id: i64,
+        question: &str,
+        question_type: QuestionType,
+        dimension: QuestionDimension,
+        confidence: f32,
+        method: ClassificationMethod,
+    ) -> QuestionClassification {
+        let skills = Self::skills(question);
+        QuestionClassification {
+            id: None,
+            question_id,
+            question_type: label(question_type),
+            dimension: label(dimension),
+            difficulty: label(Self::difficulty(question, question_type, skills.len())),
+            skills: serde_json::to_string(&skills).unwrap_or_else(|_| "[]".to_string()),
+            method: label(method),
+            confidence: confidence.clamp(0.0, 1.0),
+            created_at: String::new(),
+        }
+    }
+
+    /// Technical skills named in a question, excluding broad parent groups mentioned alongside them
+    fn skills(question: &str) -> Vec<String> {
+        let taxonomy = SkillTaxonomy::global();
+        let found = taxonomy.extract(question);
+        found
+            .iter()
+            .filter(|skill| !found.iter().any(|other| other.name != skill.name && taxonomy.is_a(&other.name, &skill.name)))
+            .map(|skill| skill.name.clone())
+            .collect()
+    }
+
+    fn default_dimension(question_type: QuestionType) -> QuestionDimension {
+        match question_type {
+            QuestionType::Technical => QuestionDimension::TechnicalDepth,
+            QuestionType::SystemDesign => QuestionDimension::ProblemSolving,
+            QuestionType::Behavioral => QuestionDimension::Communication,
+            QuestionType::Situational => QuestionDimension::Adaptability,
+            QuestionType::Motivation => QuestionDimension::DomainKnowledge,
+        }
+    }
+
+    fn cue_count<T: PartialEq>(lower: &str, table: &[(T, &[&str])], label: &T) -> usize {
+        table
+            .iter()
+            .filter(|(l, _)| l == label)
+            .flat_map(|(_, cues)| cues.iter())
+            .filter(|cue| lower.contains(*cue))
+            .count()
+    }
+
+    /// Highest-scoring label and its margin over the runner-up, scaled to 0-1
+    fn best<T: Copy>(scores: &[(T, f32)]) -> (T, f32) {
+        let mut sorted: Vec<&(T, f32)> = scores.iter().collect();
+        sorted.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        let best = sorted[0];
+        let margin = sorted.get(1).map(|second| best.1 - second.1).unwrap_or(best.1);
+        (best.0, (0.5 + margin * 5.0).clamp(0.0, 1.0))
+    }
+
+    async fn centroids<T: Copy>(rag: &RagService, table: &[(T, &[&str])]) -> Result<Vec<(T, Vec<f32>)>> {
+        let texts: Vec<String> = table.iter().flat_map(|(_, examples)| examples.iter().map(|e| e.to_string())).collect();
+        let embeddings = rag.embed_texts(&texts).await?;
+
+        let mut offset = 0;
+        let mut centroids = Vec::new();
+        for (label, examples) in table {
+            let group = embeddings.get(offset..offset + examples.len()).ok_or_else(|| anyhow!("Missing prototype embeddings"))?;
+            offset += examples.len();
+            let dims = group.first().map(Vec::len).unwrap_or(0);
+            let centroid: Vec<f32> = (0..dims)
+                .map(|d| group.iter().map(|v| v[d]).sum::<f32>() / group.len() as f32)
+                .collect();
+            centroids.push((*label, centroid));
+        }
+        Ok(centroids)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_keyword_classification() {
+        let design = QuestionClassifier::classify_keywords(1, "如何设计一个支持亿级用户的分布式消息系统？");
+        assert_eq!(design.question_type, "system_design");
+        assert_eq!(design.difficulty, "hard");
+
+        let technical = QuestionClassifier::classify_keywords(2, "Redis 的持久化机制原理是什么？");
+        assert_eq!(technical.question_type, "technical");
+        assert_eq!(technical.dimension, "technical_depth");
+        assert_eq!(technical.skills, r#"["Redis"]"#);
+        assert_eq!(technical.method, "keywords");
+
+        let motivation = QuestionClassifier::classify_keywords(3, "你为什么想加入我们公司？");
+        assert_eq!(motivation.question_type, "motivation");
+        assert_eq!(motivation.difficulty, "easy");
+    }
+
+    #[test]
+    fn test_embedding_classification_uses_nearest_centroid() {
+        let prototypes = PrototypeEmbeddings {
+            types: vec![
+                (QuestionType::Behavioral, vec![1.0, 0.0, 0.0]),
+                (QuestionType::Technical, vec![0.0, 1.0, 0.0]),
+            ],
+            dimensions: vec![
+                (QuestionDimension::Communication, vec![1.0, 0.0, 0.0]),
+                (QuestionDimension::TechnicalDepth, vec![0.0, 0.0, 1.0]),
+            ],
+        };
+        let result = QuestionClassifier::classify_embedding(4, "Kafka 如何保证消息不丢失", &[0.1, 0.9, 0.3], &prototypes);
+        assert_eq!(result.question_type, "technical");
+        assert_eq!(result.dimension, "technical_depth");
+        assert_eq!(result.method, "embedding");
+        assert!(result.confidence > 0.9);
+    }
+
+    #[test]
+    fn test_refine_keeps_local_labels_when_invalid() {
+        let local = QuestionClassifier::classify_keywords(5, "讲一个你解决团队冲突的经历");
+        let refined = QuestionClassifier::refine(
+            local.clone(),
+            r#"{"question_type": "Situational", "dimension": "nonsense", "difficulty": "hard", "skills": ["k8s"]}"#,
+        )
+        .unwrap();
+        assert_eq!(refined.question_type, "situational");
+        assert_eq!(refined.dimension, local.dimension);
+        assert_eq!(refined.difficulty, "hard");
+        assert_eq!(refined.skills, r#"["Kubernetes"]"#);
+        assert_eq!(refined.method, "llm");
+        assert!(QuestionClassifier::refine(local, r#"{"question_type": "poem"}"#).is_err());
+    }
+}
@@ -1,165 +1,630 @@
 //! Smart practice recommendation engine
 //!
-//! Analyzes user's weak areas and recommends questions for practice
+//! Ranks question bank entries and knowledge base questions for the active
+//! user by combining signals, each on a 0-1 scale:
+//!
+//! - `jd_match`: embedding similarity to the closest requirement of the target JD
+//! - `weakness`: how low the profile scores the question's dimension, raised for open
+//!   goals and for bank categories naming a weak dimension
+//! - `performance`: how poorly the question was answered before
+//! - `recency`: how long ago the question was last practiced
+//! - `due`: whether its spaced-repetition review is due
+//!
+//! Relevance is the weighted mean of the signals available for a question;
+//! without a target JD or the embedding model `jd_match` is left out. Picks
+//! are made by maximal marginal relevance, trading relevance against
+//! similarity to the questions already picked so one topic does not fill the
+//! list. Similarity uses embeddings when available and character bigrams
+//! otherwise. Every pick lists the signals that put it there.
 
-use crate::db::{Repository, PracticeRecommendation, RecommendationResult, ProfileDimension, now};
-use crate::analysis::ProfileGenerator;
+use crate::db::{
+    now, PracticeRecommendation, ProfileDimension, QuestionClassification, QuestionPracticeStats,
+    QuestionReview, RecommendationResult, RecommendationSignals, Repository,
+};
+use crate::analysis::{GoalStatus, GoalTracker, ProfileGenerator, QuestionClassifier, SemanticMatcher};
+use crate::rag::RagService;
+use crate::language::Language;
 use anyhow::Result;
+use chrono::{DateTime, Local, NaiveDate, Utc};
+use std::collections::{HashMap, HashSet};
+
+/// Signal weights of the relevance score
+const WEIGHT_JD_MATCH: f32 = 0.25;
+const WEIGHT_WEAKNESS: f32 = 0.25;
+const WEIGHT_PERFORMANCE: f32 = 0.2;
+const WEIGHT_RECENCY: f32 = 0.1;
+const WEIGHT_DUE: f32 = 0.2;
+
+/// Share of relevance against diversity in maximal marginal relevance
+const MMR_LAMBDA: f32 = 0.7;
+
+/// Profile score below which a dimension counts as weak
+const WEAK_THRESHOLD: f32 = 60.0;
+
+/// Weakness raise for questions serving an on-track goal
+const GOAL_BOOST: f32 = 0.15;
+
+/// Weakness raise for questions serving a goal that is at risk
+const AT_RISK_GOAL_BOOST: f32 = 0.3;
+
+/// Weakness raise for bank questions whose job category names a weak dimension
+const CATEGORY_BOOST: f32 = 0.15;
+
+/// Weakness of questions whose dimension the profile has no score for
+const UNKNOWN_WEAKNESS: f32 = 0.3;
+
+/// Performance signal of questions never answered
+const UNPRACTICED_PERFORMANCE: f32 = 0.5;
+
+/// Due signal of bank questions never reviewed
+const NEW_QUESTION_DUE: f32 = 0.4;
+
+/// Days after which a practiced question is half way back to fully fresh
+const RECENCY_HALF_LIFE_DAYS: f32 = 7.0;
+
+/// JD similarities mapped linearly onto 0-1 between these bounds
+const JD_SIMILARITY_FLOOR: f32 = 0.3;
+const JD_SIMILARITY_CEILING: f32 = 0.8;
+
+/// Bank questions embedded per request, best by the offline signals first
+const EMBED_CANDIDATES: usize = 60;
+
+/// Knowledge base questions kept per request, after dropping those already in the bank
+const KNOWLEDGE_CANDIDATES: usize = 20;
+
+/// Dimensions a bank category can name to target a weak area
+const PROFILE_DIMENSIONS: [&str; 5] = ["technical_depth", "communication", "problem_solving", "domain_knowledge", "adaptability"];
+
+/// Share of the remaining score gap one focused practice is expected to close
+const LEARNING_RATE: f32 = 0.15;
+
+/// Question considered for recommendation
+#[derive(Debug, Clone)]
+struct Candidate {
+    question_id: Option<i64>,
+    knowledge_id: Option<i64>,
+    question: String,
+    dimension: String,
+    category: Option<String>,            // Job category of bank questions
+    skills: Vec<String>,
+    stats: Option<QuestionPracticeStats>,
+    review: Option<QuestionReview>,
+    embedding: Option<Vec<f32>>,
+    jd_match: Option<(f32, String)>,     // Similarity and closest JD requirement
+}
+
+/// Practice stats of the active user, loaded once per request
+struct PracticeHistory {
+    by_question: HashMap<i64, QuestionPracticeStats>,
+    by_text: HashMap<String, QuestionPracticeStats>,     // Questions outside the bank
+}
+
+/// User state the signals are computed from
+struct RankingContext {
+    dimensions: ProfileDimension,
+    goals: HashMap<String, GoalStatus>,
+    today: NaiveDate,
+    now: DateTime<Utc>,
+    language: Language,
+}
+
+/// Candidate with its signals and relevance
+struct Scored {
+    candidate: Candidate,
+    signals: RecommendationSignals,
+    relevance: f32,
+    explanations: Vec<String>,
+}
 
 /// Recommendation engine for suggesting practice questions
 pub struct RecommendationEngine;
 
 impl RecommendationEngine {
-    /// Generate practice recommendations based on user's profile
+    /// Generate practice recommendations for the active user
     ///
     /// # Arguments
     /// * `repository` - Database repository
-    /// * `user_id` - User identifier
+    /// * `rag` - Embedding model and knowledge base; ranking falls back to
+    ///   offline signals when unavailable
+    /// * `requirements` - Requirements of the target JD, empty for none
     /// * `limit` - Maximum recommendations to return
+    /// * `language` - Language of the explanations
     ///
     /// # Returns
-    /// * `Ok(RecommendationResult)` - List of recommended questions
+    /// * `Ok(RecommendationResult)` - Recommended questions with explanations
     /// * `Err` - Error if generation fails
-    pub fn generate_recommendations(
+    pub async fn generate_recommendations(
         repository: &Repository,
-        user_id: &str,
+        rag: &RagService,
+        requirements: &[String],
         limit: usize,
+        language: Language,
     ) -> Result<RecommendationResult> {
         // Get user profile to identify weak areas
-        let profile = ProfileGenerator::generate_profile(repository, user_id, None)?;
-        
-        // Identify weak dimensions (score < 60)
+        let profile = ProfileGenerator::generate_profile(repository, None)?;
         let weak_dimensions = Self::identify_weak_dimensions(&profile.dimensions);
-        
-        // Get all questions from bank
-        let all_questions = repository.get_question_bank()?;
-        
-        if all_questions.is_empty() {
-            return Ok(RecommendationResult {
-                recommendations: vec![],
-                weak_dimensions,
-                total_available: 0,
-                generated_at: now(),
-            });
-        }
-        
-        // Score and rank questions
-        let mut scored_questions: Vec<(i64, String, f32, String, String)> = Vec::new();
-        
-        for question in all_questions.iter() {
-            if let Some(id) = question.id {
-                let (score, dimension, reason) = Self::score_question(
-                    &question.question,
-                    question.job_category.as_deref(),
-                    &weak_dimensions,
-                    &profile.dimensions,
-                );
-                scored_questions.push((id, question.question.clone(), score, dimension, reason));
-            }
-        }
-        
-        // Sort by score (higher is better)
-        scored_questions.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap());
-        
-        // Take top recommendations
-        let recommendations: Vec<PracticeRecommendation> = scored_questions
+        let context = RankingContext {
+            dimensions: profile.dimensions,
+            goals: GoalTracker::open_dimension_goals(repository)?,
+            today: Local::now().date_naive(),
+            now: Utc::now(),
+            language,
+        };
+
+        let mut history = Self::practice_history(repository)?;
+        let bank = Self::bank_candidates(repository, &mut history)?;
+        let bank_count = bank.len();
+        let bank_questions: HashSet<String> = bank.iter().map(|c| c.question.trim().to_string()).collect();
+
+        // Embed the best candidates by the offline signals and pull related
+        // questions from the knowledge base
+        let mut prerank: Vec<(f32, Candidate)> = bank
             .into_iter()
-            .take(limit)
-            .enumerate()
-            .map(|(_idx, (id, question, score, dimension, reason))| {
-                PracticeRecommendation {
-                    question_id: id,
-                    question,
-                    reason,
-                    priority: Self::score_to_priority(score),
-                    dimension,
-                    estimated_improvement: score * 0.1,
-                }
-            })
+            .map(|candidate| (Self::score(&candidate, &context).relevance, candidate))
+            .collect();
+        prerank.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let mut candidates: Vec<Candidate> = prerank
+            .into_iter()
+            .take(EMBED_CANDIDATES.max(limit * 4))
+            .map(|(_, candidate)| candidate)
             .collect();
-        
+        let query = if requirements.is_empty() {
+            weak_dimensions
+                .iter()
+                .map(|dim| Self::dimension_name(dim, language))
+                .collect::<Vec<_>>()
+                .join(" ")
+        } else {
+            requirements.join("\n")
+        };
+        let knowledge = Self::knowledge_candidates(rag, &query, &bank_questions, &mut history).await;
+        let knowledge_count = knowledge.len();
+        candidates.extend(knowledge);
+
+        let semantic_ranking = match Self::embed(rag, &mut candidates, requirements).await {
+            Ok(()) => true,
+            Err(e) => {
+                log::warn!("Embedding model unavailable, ranking recommendations offline: {}", e);
+                false
+            }
+        };
+
+        let recommendations = Self::rank(candidates, &context, limit);
+
         Ok(RecommendationResult {
             recommendations,
             weak_dimensions,
-            total_available: all_questions.len() as i32,
+            total_available: (bank_count + knowledge_count) as i32,
+            semantic_ranking,
             generated_at: now(),
         })
     }
-    
+
+    /// Bank questions with their dimension, practice history and review schedule
+    fn bank_candidates(repository: &Repository, history: &mut PracticeHistory) -> Result<Vec<Candidate>> {
+        // Stored classifications replace keyword guessing where available
+        let classifications: HashMap<i64, QuestionClassification> = repository
+            .get_question_classifications()?
+            .into_iter()
+            .map(|c| (c.question_id, c))
+            .collect();
+        let reviews: HashMap<i64, QuestionReview> = repository
+            .get_question_reviews()?
+            .into_iter()
+            .map(|review| (review.question_id, review))
+            .collect();
+
+        let candidates = repository
+            .get_question_bank()?
+            .into_iter()
+            .filter_map(|item| {
+                let id = item.id?;
+                let classification = classifications
+                    .get(&id)
+                    .cloned()
+                    .unwrap_or_else(|| QuestionClassifier::classify_keywords(id, &item.question));
+                Some(Candidate {
+                    question_id: Some(id),
+                    knowledge_id: None,
+                    stats: history.by_question.remove(&id),
+                    review: reviews.get(&id).cloned(),
+                    dimension: classification.dimension,
+                    category: item.job_category,
+                    skills: serde_json::from_str(&classification.skills).unwrap_or_default(),
+                    question: item.question,
+                    embedding: None,
+                    jd_match: None,
+                })
+            })
+            .collect();
+        Ok(candidates)
+    }
+
+    fn practice_history(repository: &Repository) -> Result<PracticeHistory> {
+        let mut history = PracticeHistory {
+            by_question: HashMap::new(),
+            by_text: HashMap::new(),
+        };
+        for stats in repository.get_question_practice_stats()? {
+            match stats.question_id {
+                Some(id) => history.by_question.insert(id, stats),
+                None => history.by_text.insert(stats.question.clone(), stats),
+            };
+        }
+        Ok(history)
+    }
+
+    /// Knowledge base questions similar to the query that are not in the bank
+    ///
+    /// Over-fetches by the bank size so questions dropped as already in the bank
+    /// do not shrink the result. Returns nothing when the knowledge base is
+    /// empty or unavailable.
+    async fn knowledge_candidates(
+        rag: &RagService,
+        query: &str,
+        bank_questions: &HashSet<String>,
+        history: &mut PracticeHistory,
+    ) -> Vec<Candidate> {
+        if query.trim().is_empty() || rag.is_empty() {
+            return Vec::new();
+        }
+        let top_k = KNOWLEDGE_CANDIDATES + bank_questions.len();
+        let results = match rag.retrieve_similar_questions(query, top_k).await {
+            Ok(results) => results,
+            Err(e) => {
+                log::warn!("Knowledge base questions unavailable for recommendations: {}", e);
+                return Vec::new();
+            }
+        };
+        let mut seen = bank_questions.clone();
+
+        results
+            .into_iter()
+            .filter(|result| seen.insert(result.content.trim().to_string()))
+            .take(KNOWLEDGE_CANDIDATES)
+            .map(|result| {
+                let classification = QuestionClassifier::classify_keywords(0, &result.content);
+                Candidate {
+                    question_id: None,
+                    knowledge_id: Some(result.id),
+                    stats: history.by_text.remove(result.content.trim()),
+                    review: None,
+                    dimension: classification.dimension,
+                    category: None,
+                    skills: serde_json::from_str(&classification.skills).unwrap_or_default(),
+                    question: result.content,
+                    embedding: None,
+                    jd_match: None,
+                }
+            })
+            .collect()
+    }
+
+    /// Embed candidates and JD requirements, and match each candidate to its
+    /// closest requirement
+    async fn embed(rag: &RagService, candidates: &mut [Candidate], requirements: &[String]) -> Result<()> {
+        if candidates.is_empty() {
+            return Ok(());
+        }
+        let texts: Vec<String> = candidates.iter().map(|c| c.question.clone()).collect();
+        let embeddings = rag.embed_texts(&texts).await?;
+        let requirement_embeddings = if requirements.is_empty() {
+            Vec::new()
+        } else {
+            rag.embed_texts(requirements).await?
+        };
+
+        for (candidate, embedding) in candidates.iter_mut().zip(embeddings) {
+            candidate.jd_match = requirements
+                .iter()
+                .zip(&requirement_embeddings)
+                .map(|(requirement, requirement_embedding)| {
+                    (SemanticMatcher::cosine_similarity(&embedding, requirement_embedding), requirement.clone())
+                })
+                .max_by(|a, b| a.0.total_cmp(&b.0));
+            candidate.embedding = Some(embedding);
+        }
+        Ok(())
+    }
+
+    /// Score candidates and pick `limit` of them by maximal marginal relevance
+    fn rank(candidates: Vec<Candidate>, context: &RankingContext, limit: usize) -> Vec<PracticeRecommendation> {
+        let mut remaining: Vec<Scored> = candidates.into_iter().map(|c| Self::score(&c, context)).collect();
+        let mut picked: Vec<Scored> = Vec::new();
+
+        while picked.len() < limit && !remaining.is_empty() {
+            let (index, redundancy, _) = remaining
+                .iter()
+                .enumerate()
+                .map(|(index, scored)| {
+                    let redundancy = picked
+                        .iter()
+                        .map(|p| Self::similarity(&scored.candidate, &p.candidate))
+                        .fold(0.0f32, f32::max);
+                    (index, redundancy, MMR_LAMBDA * scored.relevance - (1.0 - MMR_LAMBDA) * redundancy)
+                })
+                .max_by(|a, b| a.2.total_cmp(&b.2))
+                .expect("remaining is not empty");
+            let mut scored = remaining.swap_remove(index);
+            scored.signals.redundancy = redundancy;
+            picked.push(scored);
+        }
+
+        picked.into_iter().map(|scored| Self::to_recommendation(scored, context)).collect()
+    }
+
+    /// Signals, relevance and explanations of one candidate
+    fn score(candidate: &Candidate, context: &RankingContext) -> Scored {
+        let dimension = candidate.dimension.as_str();
+        let language = context.language;
+        let dimension_label = Self::dimension_name(dimension, language);
+        let dimension_score = Self::dimension_score(&context.dimensions, dimension);
+        // (weighted contribution, explanation)
+        let mut reasons: Vec<(f32, String)> = Vec::new();
+
+        let jd_match = candidate.jd_match.as_ref().map(|(similarity, requirement)| {
+            let value = ((similarity - JD_SIMILARITY_FLOOR) / (JD_SIMILARITY_CEILING - JD_SIMILARITY_FLOOR))
+                .clamp(0.0, 1.0);
+            if value >= 0.5 {
+                reasons.push((
+                    WEIGHT_JD_MATCH * value,
+                    language.text(
+                        &format!("与岗位要求「{}」相关（相似度 {:.2}）", requirement, similarity),
+                        &format!("Related to the requirement \"{}\" (similarity {:.2})", requirement, similarity),
+                    ),
+                ));
+            }
+            value
+        });
+
+        let mut weakness = dimension_score.map_or(UNKNOWN_WEAKNESS, |score| ((100.0 - score) / 100.0).clamp(0.0, 1.0));
+        match context.goals.get(dimension) {
+            Some(GoalStatus::AtRisk) => {
+                weakness = (weakness + AT_RISK_GOAL_BOOST).min(1.0);
+                reasons.push((
+                    WEIGHT_WEAKNESS * weakness,
+                    language.text(
+                        &format!("目标「{}」进度落后，优先练习", dimension_label),
+                        &format!("Goal \"{}\" is behind schedule, practice it first", dimension_label),
+                    ),
+                ));
+            }
+            Some(_) => {
+                weakness = (weakness + GOAL_BOOST).min(1.0);
+                reasons.push((
+                    WEIGHT_WEAKNESS * weakness,
+                    language.text(
+                        &format!("服务于目标「{}」", dimension_label),
+                        &format!("Works towards the goal \"{}\"", dimension_label),
+                    ),
+                ));
+            }
+            None => {
+                if let Some(score) = dimension_score.filter(|score| *score < WEAK_THRESHOLD) {
+                    let reason = if candidate.skills.is_empty() {
+                        language.text(
+                            &format!("针对薄弱维度「{}」的练习（当前 {:.0} 分）", dimension_label, score),
+                            &format!("Practice for the weak dimension \"{}\" (currently {:.0})", dimension_label, score),
+                        )
+                    } else {
+                        language.text(
+                            &format!("针对薄弱维度「{}」的练习（{}）", dimension_label, candidate.skills.join("、")),
+                            &format!("Practice for the weak dimension \"{}\" ({})", dimension_label, candidate.skills.join(", ")),
+                        )
+                    };
+                    reasons.push((WEIGHT_WEAKNESS * weakness, reason));
+                }
+            }
+        }
+        if let Some(weak) = candidate.category.as_deref().and_then(|category| Self::category_weakness(category, context)) {
+            weakness = (weakness + CATEGORY_BOOST).min(1.0);
+            let weak_label = Self::dimension_name(weak, language);
+            reasons.push((
+                WEIGHT_WEAKNESS * CATEGORY_BOOST,
+                language.text(
+                    &format!("分类匹配薄弱维度「{}」", weak_label),
+                    &format!("Category matches the weak dimension \"{}\"", weak_label),
+                ),
+            ));
+        }
+
+        let (performance, recency) = match &candidate.stats {
+            Some(stats) => {
+                let performance = (1.0 - (0.6 * stats.last_score + 0.4 * stats.average_score) / 10.0).clamp(0.0, 1.0);
+                if performance >= 0.4 {
+                    reasons.push((
+                        WEIGHT_PERFORMANCE * performance,
+                        language.text(
+                            &format!(
+                                "已练习 {} 次，上次 {:.1} 分、平均 {:.1} 分，还有提升空间",
+                                stats.attempts, stats.last_score, stats.average_score
+                            ),
+                            &format!(
+                                "Practiced {} times, last {:.1}, average {:.1}, room to improve",
+                                stats.attempts, stats.last_score, stats.average_score
+                            ),
+                        ),
+                    ));
+                }
+                let days = DateTime::parse_from_rfc3339(&stats.last_practiced_at)
+                    .map(|date| (context.now - date.with_timezone(&Utc)).num_hours() as f32 / 24.0)
+                    .unwrap_or(0.0)
+                    .max(0.0);
+                let recency = 1.0 - 0.5f32.powf(days / RECENCY_HALF_LIFE_DAYS);
+                if days >= 2.0 * RECENCY_HALF_LIFE_DAYS {
+                    reasons.push((
+                        WEIGHT_RECENCY * recency,
+                        language.text(
+                            &format!("已有 {:.0} 天未练习", days),
+                            &format!("Not practiced for {:.0} days", days),
+                        ),
+                    ));
+                }
+                (performance, recency)
+            }
+            None => {
+                reasons.push((WEIGHT_RECENCY, language.text("尚未练习过", "Not practiced yet")));
+                (UNPRACTICED_PERFORMANCE, 1.0)
+            }
+        };
+
+        let due = match &candidate.review {
+            Some(review) => {
+                let overdue = NaiveDate::parse_from_str(&review.due_date, "%Y-%m-%d")
+                    .map(|due| (context.today - due).num_days())
+                    .unwrap_or(0);
+                if overdue > 0 {
+                    let value = (0.7 + 0.05 * overdue as f32).min(1.0);
+                    reasons.push((
+                        WEIGHT_DUE * value,
+                        language.text(
+                            &format!("复习已逾期 {} 天", overdue),
+                            &format!("Review overdue by {} days", overdue),
+                        ),
+                    ));
+                    value
+                } else if overdue == 0 {
+                    reasons.push((WEIGHT_DUE * 0.7, language.text("今日到期复习", "Review due today")));
+                    0.7
+                } else {
+                    0.0
+                }
+            }
+            None if candidate.question_id.is_some() => NEW_QUESTION_DUE,
+            None => 0.0,
+        };
+
+        if candidate.knowledge_id.is_some() {
+            reasons.push((
+                0.0,
+                language.text(
+                    "来自知识库，题库中还没有这道题",
+                    "From the knowledge base, not in the question bank yet",
+                ),
+            ));
+        }
+
+        let mut weighted = vec![
+            (WEIGHT_WEAKNESS, weakness),
+            (WEIGHT_PERFORMANCE, performance),
+            (WEIGHT_RECENCY, recency),
+            (WEIGHT_DUE, due),
+        ];
+        if let Some(value) = jd_match {
+            weighted.push((WEIGHT_JD_MATCH, value));
+        }
+        let total_weight: f32 = weighted.iter().map(|(weight, _)| weight).sum();
+        let relevance = weighted.iter().map(|(weight, value)| weight * value).sum::<f32>() / total_weight;
+
+        reasons.sort_by(|a, b| b.0.total_cmp(&a.0));
+        let mut explanations: Vec<String> = reasons.into_iter().map(|(_, reason)| reason).collect();
+        if explanations.is_empty() {
+            explanations.push(language.text("基础练习题目", "Basic practice question"));
+        }
+
+        Scored {
+            candidate: candidate.clone(),
+            signals: RecommendationSignals {
+                jd_match,
+                weakness,
+                performance,
+                recency,
+                due,
+                redundancy: 0.0,
+            },
+            relevance,
+            explanations,
+        }
+    }
+
+    fn to_recommendation(scored: Scored, context: &RankingContext) -> PracticeRecommendation {
+        let Scored { candidate, signals, relevance, explanations } = scored;
+
+        // Expected gain: a share of the gap between the last result and a full score
+        let baseline = match &candidate.stats {
+            Some(stats) => stats.last_score,
+            None => Self::dimension_score(&context.dimensions, &candidate.dimension)
+                .filter(|score| *score > 0.0)
+                .map_or(5.0, |score| score / 10.0),
+        };
+
+        PracticeRecommendation {
+            question_id: candidate.question_id,
+            knowledge_id: candidate.knowledge_id,
+            source: if candidate.knowledge_id.is_some() { "knowledge" } else { "bank" }.to_string(),
+            question: candidate.question,
+            reason: explanations[0].clone(),
+            explanations,
+            signals,
+            score: relevance,
+            priority: Self::score_to_priority(relevance * 100.0),
+            dimension: candidate.dimension,
+            estimated_improvement: ((10.0 - baseline) * LEARNING_RATE).max(0.0),
+        }
+    }
+
+    /// Similarity of two questions: embeddings when both have one, character bigrams otherwise
+    fn similarity(a: &Candidate, b: &Candidate) -> f32 {
+        if let (Some(x), Some(y)) = (&a.embedding, &b.embedding) {
+            return SemanticMatcher::cosine_similarity(x, y).max(0.0);
+        }
+        let bigrams = |text: &str| -> HashSet<(char, char)> {
+            let chars: Vec<char> = text.to_lowercase().chars().filter(|c| !c.is_whitespace()).collect();
+            chars.windows(2).map(|pair| (pair[0], pair[1])).collect()
+        };
+        let (x, y) = (bigrams(&a.question), bigrams(&b.question));
+        let union = x.union(&y).count();
+        if union == 0 {
+            return 0.0;
+        }
+        x.intersection(&y).count() as f32 / union as f32
+    }
+
+    /// Profile score (0-100) of a dimension, `None` for dimensions the profile does not score
+    fn dimension_score(dimensions: &ProfileDimension, dimension: &str) -> Option<f32> {
+        match dimension {
+            "technical_depth" => Some(dimensions.technical_depth),
+            "communication" => Some(dimensions.communication),
+            "problem_solving" => Some(dimensions.problem_solving),
+            "domain_knowledge" => Some(dimensions.domain_knowledge),
+            "adaptability" => Some(dimensions.adaptability),
+            "job_intention" => Some(dimensions.job_intention),
+            _ => None,
+        }
+    }
+
+    /// Weak dimension named by a bank question's job category, if any
+    fn category_weakness(category: &str, context: &RankingContext) -> Option<&'static str> {
+        let category = category.to_lowercase();
+        PROFILE_DIMENSIONS.iter().copied().find(|dim| {
+            category.contains(dim)
+                && Self::dimension_score(&context.dimensions, dim).is_some_and(|score| score < WEAK_THRESHOLD)
+        })
+    }
+
     /// Identify dimensions with scores below threshold
     fn identify_weak_dimensions(dimensions: &ProfileDimension) -> Vec<String> {
-        let threshold = 60.0;
         let mut weak = Vec::new();
-        
-        if dimensions.technical_depth < threshold {
+
+        if dimensions.technical_depth < WEAK_THRESHOLD {
             weak.push("technical_depth".to_string());
         }
-        if dimensions.communication < threshold {
+        if dimensions.communication < WEAK_THRESHOLD {
             weak.push("communication".to_string());
         }
-        if dimensions.problem_solving < threshold {
+        if dimensions.problem_solving < WEAK_THRESHOLD {
             weak.push("problem_solving".to_string());
         }
-        if dimensions.domain_knowledge < threshold {
+        if dimensions.domain_knowledge < WEAK_THRESHOLD {
             weak.push("domain_knowledge".to_string());
         }
-        if dimensions.adaptability < threshold {
+        if dimensions.adaptability < WEAK_THRESHOLD {
             weak.push("adaptability".to_string());
         }
-        
+
         weak
     }
-    
-    /// Score a question based on relevance to weak areas
-    fn score_question(
-        question: &str,
-        category: Option<&str>,
-        weak_dimensions: &[String],
-        _dimensions: &ProfileDimension,
-    ) -> (f32, String, String) {
-        let q_lower = question.to_lowercase();
-        let mut score: f32 = 50.0; // Base score
-        let mut primary_dimension = "general".to_string();
-        let mut reason = "基础练习题目".to_string();
-        
-        // Keyword-based dimension detection
-        let dimension_keywords = vec![
-            ("technical_depth", vec!["原理", "底层", "实现", "架构", "性能", "算法", "数据结构"]),
-            ("communication", vec!["介绍", "描述", "解释", "沟通", "表达", "团队"]),
-            ("problem_solving", vec!["解决", "问题", "调试", "优化", "方案", "设计"]),
-            ("domain_knowledge", vec!["业务", "行业", "领域", "经验", "项目"]),
-            ("adaptability", vec!["变化", "学习", "新技术", "挑战", "压力"]),
-        ];
-        
-        for (dim, keywords) in dimension_keywords.iter() {
-            for kw in keywords {
-                if q_lower.contains(kw) {
-                    if weak_dimensions.contains(&dim.to_string()) {
-                        score += 20.0;
-                        primary_dimension = dim.to_string();
-                        reason = format!("针对薄弱维度「{}」的练习", Self::dimension_name(dim));
-                    } else {
-                        score += 5.0;
-                    }
-                    break;
-                }
-            }
-        }
-        
-        // Boost score for questions in weak dimensions
-        if let Some(cat) = category {
-            let cat_lower = cat.to_lowercase();
-            for weak in weak_dimensions {
-                if cat_lower.contains(weak) {
-                    score += 15.0;
-                    reason = format!("分类匹配薄弱维度: {}", weak);
-                }
-            }
-        }
-        
-        (score.min(100.0), primary_dimension, reason)
-    }
-    
+
     /// Convert score to priority level (1-5)
     fn score_to_priority(score: f32) -> u8 {
         match score as u32 {
@@ -170,16 +635,137 @@ impl RecommendationEngine {
             _ => 5,
         }
     }
-    
+
     /// Get dimension display name
-    fn dimension_name(dim: &str) -> &str {
-        match dim {
-            "technical_depth" => "技术深度",
-            "communication" => "沟通表达",
-            "problem_solving" => "问题解决",
-            "domain_knowledge" => "领域知识",
-            "adaptability" => "应变能力",
-            _ => "综合能力",
+    fn dimension_name(dim: &str, language: Language) -> String {
+        let (zh, en) = match dim {
+            "technical_depth" => ("技术深度", "Technical depth"),
+            "communication" => ("沟通表达", "Communication"),
+            "problem_solving" => ("问题解决", "Problem solving"),
+            "domain_knowledge" => ("领域知识", "Domain knowledge"),
+            "adaptability" => ("应变能力", "Adaptability"),
+            "job_intention" => ("求职意向", "Job intention"),
+            _ => ("综合能力", "Overall ability"),
+        };
+        language.text(zh, en)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::{dimensions, practice_stats, review};
+
+    fn context() -> RankingContext {
+        RankingContext {
+            dimensions: dimensions([45.0, 80.0, 70.0, 70.0, 70.0, 70.0]),
+            goals: HashMap::new(),
+            today: NaiveDate::from_ymd_opt(2026, 5, 20).unwrap(),
+            now: DateTime::parse_from_rfc3339("2026-05-20T12:00:00Z").unwrap().with_timezone(&Utc),
+            language: Language::Zh,
         }
     }
+
+    fn candidate(id: i64, question: &str, dimension: &str) -> Candidate {
+        Candidate {
+            question_id: Some(id),
+            knowledge_id: None,
+            question: question.to_string(),
+            dimension: dimension.to_string(),
+            category: None,
+            skills: Vec::new(),
+            stats: None,
+            review: None,
+            embedding: None,
+            jd_match: None,
+        }
+    }
+
+    #[test]
+    fn test_history_and_due_signals() {
+        let mut fumbled = candidate(1, "讲讲 Raft 的选主过程", "communication");
+        fumbled.stats = Some(practice_stats("讲讲 Raft 的选主过程", 4.0, 4.5, "2026-05-01T10:00:00Z"));
+        fumbled.review = Some(review(1, "2026-05-17"));
+        let mut mastered = candidate(2, "介绍一下你自己", "communication");
+        mastered.stats = Some(practice_stats("介绍一下你自己", 9.0, 8.8, "2026-05-20T09:00:00Z"));
+        mastered.review = Some(review(2, "2026-06-10"));
+
+        let picks = RecommendationEngine::rank(vec![mastered, fumbled], &context(), 2);
+        assert_eq!(picks[0].question_id, Some(1));
+        assert!(picks[0].score > picks[1].score);
+        assert!(picks[0].signals.due > 0.8 && picks[1].signals.due == 0.0);
+        assert!(picks[0].signals.recency > 0.8 && picks[1].signals.recency < 0.1);
+        assert!(picks[0].explanations.iter().any(|e| e.contains("逾期 3 天")));
+        assert!(picks[0].explanations.iter().any(|e| e.contains("上次 4.0 分")));
+        assert_eq!(picks[0].signals.jd_match, None);
+
+        // Weak dimensions and goals feed the weakness signal
+        let mut context = context();
+        let weak = RecommendationEngine::score(&candidate(3, "解释一下 B+ 树索引", "technical_depth"), &context);
+        assert!((weak.signals.weakness - 0.55).abs() < 1e-6);
+        assert!(weak.explanations.iter().any(|e| e.contains("薄弱维度「技术深度」")));
+        context.goals.insert("technical_depth".to_string(), GoalStatus::AtRisk);
+        let goal = RecommendationEngine::score(&candidate(3, "解释一下 B+ 树索引", "technical_depth"), &context);
+        assert!(goal.relevance > weak.relevance);
+        assert_eq!(goal.explanations[0], "目标「技术深度」进度落后，优先练习");
+
+        // A bank category naming a weak dimension raises the weakness too
+        let mut categorized = candidate(4, "如何与产品经理沟通需求变更", "communication");
+        categorized.category = Some("technical_depth".to_string());
+        let boosted = RecommendationEngine::score(&categorized, &context);
+        let plain = RecommendationEngine::score(&candidate(4, "如何与产品经理沟通需求变更", "communication"), &context);
+        assert!((boosted.signals.weakness - plain.signals.weakness - CATEGORY_BOOST).abs() < 1e-6);
+        assert!(boosted.explanations.iter().any(|e| e == "分类匹配薄弱维度「技术深度」"));
+
+        context.language = Language::En;
+        let english = RecommendationEngine::score(&candidate(3, "解释一下 B+ 树索引", "technical_depth"), &context);
+        assert_eq!(english.explanations[0], "Goal \"Technical depth\" is behind schedule, practice it first");
+        assert!(english.explanations.iter().any(|e| e == "Not practiced yet"));
+    }
+
+    #[test]
+    fn test_mmr_prefers_diverse_questions() {
+        let mut first = candidate(1, "Redis 持久化有哪些方式", "technical_depth");
+        let mut duplicate = candidate(2, "Redis 的持久化方式有哪些", "technical_depth");
+        let mut other = candidate(3, "如何带领团队完成一次重构", "technical_depth");
+        first.embedding = Some(vec![1.0, 0.0]);
+        duplicate.embedding = Some(vec![0.99, 0.05]);
+        other.embedding = Some(vec![0.0, 1.0]);
+        // The near duplicate is slightly more relevant than the other topic
+        duplicate.review = Some(review(2, "2026-05-20"));
+
+        let picks = RecommendationEngine::rank(vec![duplicate, first, other], &context(), 2);
+        let ids: Vec<Option<i64>> = picks.iter().map(|p| p.question_id).collect();
+        assert_eq!(ids, vec![Some(2), Some(3)]);
+        assert!(picks[1].signals.redundancy < 0.1);
+
+        // Without embeddings the bigram overlap still separates them
+        let a = candidate(1, "Redis 持久化有哪些方式", "technical_depth");
+        let b = candidate(2, "Redis 的持久化方式有哪些", "technical_depth");
+        let c = candidate(3, "如何带领团队完成一次重构", "technical_depth");
+        assert!(RecommendationEngine::similarity(&a, &b) > 0.4);
+        assert_eq!(RecommendationEngine::similarity(&a, &c), 0.0);
+    }
+
+    #[test]
+    fn test_jd_match_and_knowledge_questions() {
+        let mut related = candidate(1, "如何设计一个高并发秒杀系统", "problem_solving");
+        related.jd_match = Some((0.78, "有高并发系统设计经验".to_string()));
+        let mut knowledge = candidate(0, "Kafka 如何保证消息不丢失", "technical_depth");
+        knowledge.question_id = None;
+        knowledge.knowledge_id = Some(42);
+        knowledge.jd_match = Some((0.35, "有高并发系统设计经验".to_string()));
+
+        let picks = RecommendationEngine::rank(vec![knowledge, related], &context(), 5);
+        assert_eq!(picks.len(), 2);
+        let related = picks.iter().find(|p| p.question_id == Some(1)).unwrap();
+        assert!(related.signals.jd_match.unwrap() > 0.9);
+        assert!(related.explanations[0].contains("有高并发系统设计经验"));
+
+        let knowledge = picks.iter().find(|p| p.source == "knowledge").unwrap();
+        assert_eq!((knowledge.question_id, knowledge.knowledge_id), (None, Some(42)));
+        assert!(knowledge.explanations.iter().any(|e| e.contains("来自知识库")));
+        assert!(knowledge.signals.jd_match.unwrap() < 0.2);
+        assert!(knowledge.estimated_improvement > 0.0);
+    }
 }
@@ -2,6 +2,7 @@
 
 use crate::api::SiliconFlowClient;
 use crate::db::{Repository, SessionReport};
+use crate::language::Language;
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
@@ -28,15 +29,16 @@ impl ReportGenerator {
         api_client: &SiliconFlowClient,
         db: &Repository,
     ) -> Result<SessionReport> {
-        Self::generate_report_with_model(session_id, api_client, db, None).await
+        Self::generate_report_with_model(session_id, api_client, db, None, Language::default()).await
     }
 
-    /// Generate comprehensive report with optional model override
+    /// Generate comprehensive report with optional model override in the given language
     pub async fn generate_report_with_model(
         session_id: i64,
         api_client: &SiliconFlowClient,
         db: &Repository,
         model: Option<&str>,
+        language: Language,
     ) -> Result<SessionReport> {
         let start_time = Instant::now();
 
@@ -76,7 +78,7 @@ impl ReportGenerator {
         // Call API to generate report with timeout protection
         let api_response = match timeout(
             Duration::from_secs(90),
-            api_client.generate_session_report_with_model(&questions, &answer_texts, &job_description, model)
+            api_client.generate_session_report_with_model(&questions, &answer_texts, &job_description, model, language)
         )
         .await
         {
@@ -85,7 +87,7 @@ impl ReportGenerator {
         };
 
         // Parse response
-        let report = Self::parse_report_response(&api_response)?;
+        let report = Self::parse_report_response(&api_response, language)?;
 
         // Calculate response time
         let api_response_time = start_time.elapsed().as_millis() as i32;
@@ -132,10 +134,10 @@ impl ReportGenerator {
     }
 
     /// Parse API response to structured report
-    fn parse_report_response(response: &str) -> Result<ComprehensiveReport> {
+    fn parse_report_response(response: &str, language: Language) -> Result<ComprehensiveReport> {
         // Try to parse as JSON
         if let Ok(json_value) = serde_json::from_str::<Value>(response) {
-            return Self::extract_from_json(&json_value);
+            return Self::extract_from_json(&json_value, language);
         }
 
         // Try to find JSON in text
@@ -144,7 +146,7 @@ impl ReportGenerator {
                 if start < end {
                     let json_part = &response[start..=end];
                     if let Ok(json_value) = serde_json::from_str::<Value>(json_part) {
-                        return Self::extract_from_json(&json_value);
+                        return Self::extract_from_json(&json_value, language);
                     }
                 }
             }
@@ -155,19 +157,19 @@ impl ReportGenerator {
             summary: response.chars().take(500).collect(),
             overall_score: 7.0,
             improvements: vec![
-                "提高回答的结构性和清晰度".to_string(),
-                "多使用具体例子支撑观点".to_string(),
-                "加强对岗位要求的理解和匹配".to_string(),
+                language.text("提高回答的结构性和清晰度", "Make answers more structured and clear"),
+                language.text("多使用具体例子支撑观点", "Support points with concrete examples"),
+                language.text("加强对岗位要求的理解和匹配", "Align answers more closely with the job requirements"),
             ],
             key_takeaways: vec![
-                "继续保持良好的逻辑表达".to_string(),
-                "注意补充行业相关的专业术语".to_string(),
+                language.text("继续保持良好的逻辑表达", "Keep up the clear, logical delivery"),
+                language.text("注意补充行业相关的专业术语", "Add more industry-specific terminology"),
             ],
         })
     }
 
     /// Extract structured data from JSON value
-    fn extract_from_json(json: &Value) -> Result<ComprehensiveReport> {
+    fn extract_from_json(json: &Value, language: Language) -> Result<ComprehensiveReport> {
         let summary = json
             .get("summary")
             .and_then(|v| v.as_str())
@@ -190,9 +192,9 @@ impl ReportGenerator {
             })
             .unwrap_or_else(|| {
                 vec![
-                    "提高回答的结构性".to_string(),
-                    "补充具体案例".to_string(),
-                    "加强岗位匹配度".to_string(),
+                    language.text("提高回答的结构性", "Make answers more structured"),
+                    language.text("补充具体案例", "Add concrete examples"),
+                    language.text("加强岗位匹配度", "Improve the fit with the job"),
                 ]
             });
 
@@ -207,8 +209,8 @@ impl ReportGenerator {
             })
             .unwrap_or_else(|| {
                 vec![
-                    "保持良好的表达能力".to_string(),
-                    "继续完善专业知识".to_string(),
+                    language.text("保持良好的表达能力", "Keep up the good communication"),
+                    language.text("继续完善专业知识", "Keep deepening your domain knowledge"),
                 ]
             });
 
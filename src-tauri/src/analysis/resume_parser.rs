@@ -0,0 +1,353 @@
+//! Resume file import
+//!
+//! Extracts text from PDF and DOCX resumes locally and splits it into
+//! education, experience, projects and skills sections. Documents that yield
+//! little or garbled text (typically scanned PDFs) are imported with warnings.
+
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::io::{Cursor, Read};
+use std::path::Path;
+
+/// Fewer non-whitespace characters than this suggests a scanned document
+const MIN_TEXT_CHARS: usize = 50;
+
+/// Maximum length of a line treated as a section heading
+const MAX_HEADING_CHARS: usize = 20;
+
+/// Characters that may follow a heading keyword, e.g. "工作经历 (5年)" or "技能 / Skills"
+const HEADING_SEPARATORS: &[char] = &['(', '（', '/', '|', '-', '—', '&'];
+
+/// Largest resume file accepted for import
+const MAX_FILE_BYTES: u64 = 20 * 1024 * 1024;
+
+/// Largest uncompressed word/document.xml read from a DOCX archive
+const MAX_DOCX_XML_BYTES: u64 = 50 * 1024 * 1024;
+
+/// Section heading keywords; projects and skills are checked before the
+/// broader experience keywords so "project experience" lands in projects
+const SECTION_KEYWORDS: &[(ResumeSection, &[&str])] = &[
+    (ResumeSection::Education, &["教育背景", "教育经历", "学历", "education", "academic background"]),
+    (ResumeSection::Projects, &["项目经历", "项目经验", "项目", "projects", "project experience"]),
+    (ResumeSection::Skills, &["专业技能", "技能特长", "技术栈", "技能", "skills", "technical skills", "core competencies"]),
+    (ResumeSection::Experience, &["工作经历", "工作经验", "实习经历", "职业经历", "work experience", "professional experience", "employment", "internship", "experience"]),
+];
+
+/// Supported resume file formats
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ResumeFormat {
+    Pdf,
+    Docx,
+}
+
+/// Recognized resume sections
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ResumeSection {
+    Education,
+    Experience,
+    Projects,
+    Skills,
+}
+
+/// Resume lines grouped by section
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResumeSections {
+    pub education: Vec<String>,
+    pub experience: Vec<String>,
+    pub projects: Vec<String>,
+    pub skills: Vec<String>,      // Individual skills, split on list separators
+    pub other: Vec<String>,       // Header/contact lines and unrecognized sections
+}
+
+/// Structured representation stored on the resume record
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StructuredResume {
+    pub format: ResumeFormat,
+    pub sections: ResumeSections,
+    pub warnings: Vec<String>,
+}
+
+/// Result of parsing a resume file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParsedResume {
+    pub text: String,
+    pub structured: StructuredResume,
+}
+
+/// PDF/DOCX text extraction and section detection
+pub struct ResumeParser;
+
+impl ResumeParser {
+    /// Parse a resume file from disk
+    pub fn parse_file(path: &Path) -> Result<ParsedResume> {
+        let size = std::fs::metadata(path).map_err(|e| anyhow!("Failed to read file: {}", e))?.len();
+        if size > MAX_FILE_BYTES {
+            return Err(anyhow!("Resume file is too large ({} MB, limit {} MB)", size / 1024 / 1024, MAX_FILE_BYTES / 1024 / 1024));
+        }
+        let bytes = std::fs::read(path).map_err(|e| anyhow!("Failed to read file: {}", e))?;
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .map(|e| e.to_lowercase())
+            .unwrap_or_default();
+        Self::parse_bytes(&bytes, &extension)
+    }
+
+    /// Parse resume bytes; the format comes from the extension or the file signature
+    pub fn parse_bytes(bytes: &[u8], extension: &str) -> Result<ParsedResume> {
+        let format = match extension {
+            "pdf" => ResumeFormat::Pdf,
+            "docx" => ResumeFormat::Docx,
+            _ if bytes.starts_with(b"%PDF") => ResumeFormat::Pdf,
+            _ if bytes.starts_with(b"PK") => ResumeFormat::Docx,
+            _ => return Err(anyhow!("Unsupported resume format: {}", extension)),
+        };
+
+        let mut warnings = Vec::new();
+        let text = match format {
+            ResumeFormat::Pdf => Self::extract_pdf(bytes),
+            ResumeFormat::Docx => Self::extract_docx(bytes),
+        };
+        let text = match text {
+            Ok(text) => Self::normalize(&text),
+            Err(e) => {
+                warnings.push(format!("无法解析文档内容：{}", e));
+                String::new()
+            }
+        };
+
+        let visible = text.chars().filter(|c| !c.is_whitespace()).count();
+        let garbled = text.chars().filter(|c| *c == '\u{FFFD}' || (c.is_control() && !c.is_whitespace())).count();
+        if visible < MIN_TEXT_CHARS {
+            warnings.push("提取到的文本过少，文档可能是扫描件或图片，请使用可复制文字的版本或手动粘贴".to_string());
+        } else if garbled * 10 > visible {
+            warnings.push("提取的文本包含大量乱码，可能使用了不支持的字体编码，请检查导入结果".to_string());
+        }
+
+        let sections = Self::detect_sections(&text);
+        if visible >= MIN_TEXT_CHARS
+            && sections.education.is_empty()
+            && sections.experience.is_empty()
+            && sections.projects.is_empty()
+            && sections.skills.is_empty()
+        {
+            warnings.push("未识别到教育、经历、项目或技能等分节标题，全部内容已归入其他".to_string());
+        }
+
+        Ok(ParsedResume {
+            text,
+            structured: StructuredResume { format, sections, warnings },
+        })
+    }
+
+    /// Split resume text into sections by heading lines
+    pub fn detect_sections(text: &str) -> ResumeSections {
+        let mut sections = ResumeSections::default();
+        let mut current: Option<ResumeSection> = None;
+
+        for line in text.lines().map(str::trim).filter(|l| !l.is_empty()) {
+            if let Some((section, rest)) = Self::heading(line) {
+                current = Some(section);
+                // "技能：Rust、Go" keeps the content after the heading
+                if rest.is_empty() {
+                    continue;
+                }
+                Self::push_line(&mut sections, current, rest);
+                continue;
+            }
+            Self::push_line(&mut sections, current, line);
+        }
+
+        sections
+    }
+
+    fn push_line(sections: &mut ResumeSections, section: Option<ResumeSection>, line: &str) {
+        match section {
+            Some(ResumeSection::Education) => sections.education.push(line.to_string()),
+            Some(ResumeSection::Experience) => sections.experience.push(line.to_string()),
+            Some(ResumeSection::Projects) => sections.projects.push(line.to_string()),
+            Some(ResumeSection::Skills) => sections.skills.extend(
+                line.split([',', '，', '、', ';', '；', '|', '/', '•', '·'])
+                    .map(|s| s.trim().trim_start_matches(['-', '*']).trim())
+                    .filter(|s| !s.is_empty())
+                    .map(str::to_string),
+            ),
+            None => sections.other.push(line.to_string()),
+        }
+    }
+
+    /// Recognize a heading line, returning its section and any inline content
+    fn heading(line: &str) -> Option<(ResumeSection, &str)> {
+        let (title, rest) = match line.find([':', '：']) {
+            Some(index) => {
+                let separator_len = line[index..].chars().next().map_or(1, char::len_utf8);
+                (&line[..index], line[index + separator_len..].trim())
+            }
+            None => (line, ""),
+        };
+        let title = title
+            .trim_start_matches(|c: char| c.is_ascii_digit() || "一二三四五六七八九十.、#*-•■●【[ ".contains(c))
+            .trim_end_matches(['】', ']', ' '])
+            .to_lowercase();
+        let title_chars = title.chars().count();
+        if title_chars == 0 || title_chars > MAX_HEADING_CHARS {
+            return None;
+        }
+
+        // The keyword must be the whole title or be followed by a separator, so
+        // job titles such as "项目经理" or "Experience Designer" stay content
+        SECTION_KEYWORDS.iter().find_map(|(section, keywords)| {
+            keywords
+                .iter()
+                .any(|keyword| {
+                    title.strip_prefix(keyword).is_some_and(|after| {
+                        let after = after.trim_start();
+                        after.is_empty() || after.starts_with(HEADING_SEPARATORS)
+                    })
+                })
+                .then_some((*section, rest))
+        })
+    }
+
+    fn extract_pdf(bytes: &[u8]) -> Result<String> {
+        // pdf-extract panics on some malformed documents
+        match std::panic::catch_unwind(|| pdf_extract::extract_text_from_mem(bytes)) {
+            Ok(Ok(text)) => Ok(text),
+            Ok(Err(e)) => Err(anyhow!("PDF error: {}", e)),
+            Err(_) => Err(anyhow!("PDF parser failed on this document")),
+        }
+    }
+
+    fn extract_docx(bytes: &[u8]) -> Result<String> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(bytes)).map_err(|e| anyhow!("Invalid DOCX archive: {}", e))?;
+        let entry = archive
+            .by_name("word/document.xml")
+            .map_err(|_| anyhow!("DOCX has no word/document.xml"))?;
+        if entry.size() > MAX_DOCX_XML_BYTES {
+            return Err(anyhow!("DOCX document is too large to import"));
+        }
+
+        // The declared size can lie, so cap the decompressed stream as well
+        let mut xml = String::new();
+        entry.take(MAX_DOCX_XML_BYTES + 1).read_to_string(&mut xml)?;
+        if xml.len() as u64 > MAX_DOCX_XML_BYTES {
+            return Err(anyhow!("DOCX document is too large to import"));
+        }
+        Ok(Self::docx_xml_to_text(&xml))
+    }
+
+    /// Text of a WordprocessingML document, one paragraph per line
+    pub fn docx_xml_to_text(xml: &str) -> String {
+        let mut text = String::new();
+        let mut in_text = false;
+        let mut rest = xml;
+
+        while let Some(open) = rest.find('<') {
+            if in_text {
+                text.push_str(&Self::decode_entities(&rest[..open]));
+            }
+            let Some(close) = rest[open..].find('>') else { break };
+            let tag = &rest[open + 1..open + close];
+            rest = &rest[open + close + 1..];
+
+            let self_closing = tag.ends_with('/');
+            let name = tag.trim_end_matches('/').split_whitespace().next().unwrap_or("");
+            match name {
+                "w:t" => in_text = !self_closing,
+                "/w:t" => in_text = false,
+                "w:tab" => text.push('\t'),
+                "w:br" | "w:cr" | "/w:p" => text.push('\n'),
+                _ => {}
+            }
+        }
+
+        text
+    }
+
+    fn decode_entities(raw: &str) -> String {
+        let mut decoded = String::with_capacity(raw.len());
+        let mut rest = raw;
+        while let Some(amp) = rest.find('&') {
+            decoded.push_str(&rest[..amp]);
+            let Some(semi) = rest[amp..].find(';') else {
+                rest = &rest[amp..];
+                break;
+            };
+            let entity = &rest[amp + 1..amp + semi];
+            let replacement = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" => Some('\''),
+                _ => entity
+                    .strip_prefix("#x")
+                    .map(|hex| u32::from_str_radix(hex, 16))
+                    .or_else(|| entity.strip_prefix('#').map(str::parse))
+                    .and_then(|code| code.ok())
+                    .and_then(char::from_u32),
+            };
+            match replacement {
+                Some(c) => decoded.push(c),
+                None => decoded.push_str(&rest[amp..=amp + semi]),
+            }
+            rest = &rest[amp + semi + 1..];
+        }
+        decoded.push_str(rest);
+        decoded
+    }
+
+    /// Trim lines and collapse runs of blank lines left by the extractors
+    fn normalize(text: &str) -> String {
+        let mut lines: Vec<&str> = Vec::new();
+        for line in text.lines().map(str::trim_end) {
+            if line.trim().is_empty() && lines.last().is_none_or(|l| l.is_empty()) {
+                continue;
+            }
+            lines.push(if line.trim().is_empty() { "" } else { line });
+        }
+        lines.join("\n").trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_sections() {
+        let text = "张三\n电话：13800000000\n教育背景\n某某大学 计算机科学 本科\n工作经历：\n2020-2023 某公司 后端工程师\n项目经历\n订单系统重构\n专业技能：Rust、Go, Kubernetes\n- PostgreSQL";
+        let sections = ResumeParser::detect_sections(text);
+
+        assert_eq!(sections.other, vec!["张三", "电话：13800000000"]);
+        assert_eq!(sections.education, vec!["某某大学 计算机科学 本科"]);
+        assert_eq!(sections.experience, vec!["2020-2023 某公司 后端工程师"]);
+        assert_eq!(sections.projects, vec!["订单系统重构"]);
+        assert_eq!(sections.skills, vec!["Rust", "Go", "Kubernetes", "PostgreSQL"]);
+    }
+
+    #[test]
+    fn test_job_titles_are_not_headings() {
+        let text = "项目经历\n项目经理 2020-2023 某公司\n工作经历 (5年)\nExperience Designer at Acme\n技能 / Skills\nRust";
+        let sections = ResumeParser::detect_sections(text);
+
+        assert_eq!(sections.projects, vec!["项目经理 2020-2023 某公司"]);
+        assert_eq!(sections.experience, vec!["Experience Designer at Acme"]);
+        assert_eq!(sections.skills, vec!["Rust"]);
+    }
+
+    #[test]
+    fn test_docx_xml_to_text() {
+        let xml = r#"<w:document><w:body><w:p><w:r><w:t>Project Experience</w:t></w:r></w:p><w:p><w:r><w:t xml:space="preserve">R&amp;D </w:t></w:r><w:r><w:tab/><w:t>lead</w:t></w:r></w:p></w:body></w:document>"#;
+        assert_eq!(ResumeParser::docx_xml_to_text(xml), "Project Experience\nR&D \tlead\n");
+    }
+
+    #[test]
+    fn test_unparseable_pdf_reports_warning() {
+        let parsed = ResumeParser::parse_bytes(b"%PDF-1.4 garbage", "pdf").unwrap();
+        assert!(parsed.text.is_empty());
+        assert!(!parsed.structured.warnings.is_empty());
+        assert!(ResumeParser::parse_bytes(b"plain", "txt").is_err());
+    }
+}
@@ -0,0 +1,563 @@
+//! Rubric-based answer evaluation
+//!
+//! Rubrics define weighted criteria with level anchors per interviewer role
+//! and question type. Built-in defaults apply until a rubric is stored for the
+//! same role and question type. Criterion scores carry evidence quoted from
+//! the answer, so an overall score can be traced back to what was said.
+//!
+//! Rubrics are shared by all users: a stored rubric replaces the built-in
+//! default for everyone, unlike answers and sessions which are per user.
+
+use super::question_classifier::QuestionClassifier;
+use super::trace::{ComponentTrace, ScoreTrace};
+use crate::db::{CriterionScore, Repository, Rubric, RubricCriterion, RubricLevel, now};
+use crate::language::Language;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+/// Question type used when no specific rubric exists
+pub const GENERAL_QUESTION_TYPE: &str = "general";
+
+/// Role used when no rubric exists for the interviewer role
+pub const GENERAL_ROLE: &str = "general";
+
+/// Result of scoring an answer against a rubric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RubricEvaluation {
+    pub rubric_id: Option<i64>,
+    pub rubric_name: String,
+    pub overall_score: f32,
+    pub criterion_scores: Vec<CriterionScore>,
+    pub strengths: Vec<String>,
+    pub improvements: Vec<String>,
+    pub summary: String,
+}
+
+/// Raw model output, every field optional
+#[derive(Debug, Default, Deserialize)]
+struct RawEvaluation {
+    #[serde(default)]
+    score: Option<f32>,
+    #[serde(default)]
+    criterion_scores: Vec<CriterionScore>,
+    #[serde(default)]
+    strengths: Vec<String>,
+    #[serde(default)]
+    improvements: Vec<String>,
+    #[serde(default)]
+    summary: String,
+}
+
+/// Rubric lookup, prompt rendering and score aggregation
+pub struct RubricEngine;
+
+impl RubricEngine {
+    /// Built-in rubrics in `language`, one per interviewer role plus a general fallback
+    pub fn default_rubrics(language: Language) -> Vec<Rubric> {
+        let t = |zh: &str, en: &str| language.text(zh, en);
+        vec![
+            Self::rubric(t("技术面试评分标准", "Technical interview rubric"), "technical", vec![
+                Self::criterion(
+                    t("技术准确性", "Technical accuracy"),
+                    t("概念、原理和结论是否正确", "Whether concepts, principles and conclusions are correct"),
+                    0.35,
+                    [
+                        t("概念准确，能指出边界条件和常见误区", "Accurate concepts, points out edge cases and common pitfalls"),
+                        t("主要结论正确，个别细节模糊", "Main conclusions correct, some details vague"),
+                        t("存在明显的概念错误", "Clear conceptual errors"),
+                    ],
+                ),
+                Self::criterion(
+                    t("深度与原理", "Depth and principles"),
+                    t("是否深入到底层实现和设计权衡", "Whether it reaches the underlying implementation and design trade-offs"),
+                    0.25,
+                    [
+                        t("能解释底层机制并比较不同方案的取舍", "Explains the underlying mechanism and compares trade-offs of alternatives"),
+                        t("说明了做法，原理阐述有限", "Describes the approach with limited explanation of principles"),
+                        t("停留在表面描述", "Stays at a surface-level description"),
+                    ],
+                ),
+                Self::criterion(
+                    t("实践经验", "Practical experience"),
+                    t("是否有真实项目经验和可验证的细节支撑", "Whether real project experience and verifiable details back it up"),
+                    0.25,
+                    [
+                        t("结合具体项目给出数据和踩坑经历", "Cites concrete projects with data and lessons learned"),
+                        t("提到项目但细节不足", "Mentions projects without enough detail"),
+                        t("没有实践经验支撑", "No practical experience to back it up"),
+                    ],
+                ),
+                Self::criterion(
+                    t("表达逻辑", "Logical delivery"),
+                    t("回答是否条理清晰、重点突出", "Whether the answer is well organized and focused"),
+                    0.15,
+                    [
+                        t("结构清晰，先结论后展开", "Clear structure, conclusion first then details"),
+                        t("基本有条理，略有跳跃", "Mostly organized with some jumps"),
+                        t("杂乱无章，难以跟上", "Disorganized and hard to follow"),
+                    ],
+                ),
+            ]),
+            Self::rubric(t("行为面试评分标准", "Behavioral interview rubric"), "hr", vec![
+                Self::criterion(
+                    t("STAR结构", "STAR structure"),
+                    t("是否完整交代情境、任务、行动和结果", "Whether situation, task, action and result are all covered"),
+                    0.3,
+                    [
+                        t("四要素完整，结果可量化", "All four parts present with a measurable result"),
+                        t("缺少一到两个要素", "One or two parts missing"),
+                        t("没有结构，只有笼统描述", "No structure, only a vague description"),
+                    ],
+                ),
+                Self::criterion(
+                    t("真实具体", "Authentic and specific"),
+                    t("案例是否真实、具体、可追问", "Whether the example is real, specific and holds up to follow-up"),
+                    0.25,
+                    [
+                        t("细节丰富，体现个人贡献", "Rich detail showing personal contribution"),
+                        t("有案例但个人角色不清楚", "Has an example but the personal role is unclear"),
+                        t("泛泛而谈，没有具体案例", "Generic talk without a concrete example"),
+                    ],
+                ),
+                Self::criterion(
+                    t("沟通表达", "Communication"),
+                    t("表达是否清晰、得体", "Whether the delivery is clear and appropriate"),
+                    0.25,
+                    [
+                        t("表达流畅，用词准确", "Fluent delivery with precise wording"),
+                        t("基本清楚，偶有冗余", "Mostly clear with occasional redundancy"),
+                        t("表达混乱或过于简短", "Confused or too brief"),
+                    ],
+                ),
+                Self::criterion(
+                    t("文化匹配", "Culture fit"),
+                    t("价值观和工作方式是否契合岗位", "Whether values and working style fit the role"),
+                    0.2,
+                    [
+                        t("价值观与岗位高度契合", "Values closely match the role"),
+                        t("未体现明显冲突", "No obvious conflict"),
+                        t("体现出明显的不匹配", "Clear mismatch"),
+                    ],
+                ),
+            ]),
+            Self::rubric(t("业务面试评分标准", "Business interview rubric"), "business", vec![
+                Self::criterion(
+                    t("业务理解", "Business understanding"),
+                    t("对业务模式、用户和行业的理解", "Understanding of the business model, users and industry"),
+                    0.35,
+                    [
+                        t("准确把握业务核心和用户价值", "Grasps the core of the business and the user value"),
+                        t("理解基本业务，缺少洞察", "Understands the basics but lacks insight"),
+                        t("对业务理解有偏差", "Misunderstands the business"),
+                    ],
+                ),
+                Self::criterion(
+                    t("数据与指标", "Data and metrics"),
+                    t("是否用数据和关键指标支撑判断", "Whether judgments are backed by data and key metrics"),
+                    0.25,
+                    [
+                        t("给出明确指标和量化依据", "Gives explicit metrics and quantified evidence"),
+                        t("提到指标但不具体", "Mentions metrics without specifics"),
+                        t("没有任何数据支撑", "No data at all"),
+                    ],
+                ),
+                Self::criterion(
+                    t("方案可行性", "Feasibility"),
+                    t("提出的方案是否可落地、考虑成本和风险", "Whether the proposal is actionable and weighs cost and risk"),
+                    0.25,
+                    [
+                        t("方案可落地并考虑了风险和取舍", "Actionable proposal that weighs risks and trade-offs"),
+                        t("方案合理但缺少落地细节", "Reasonable proposal lacking execution detail"),
+                        t("方案空泛或不可行", "Vague or infeasible proposal"),
+                    ],
+                ),
+                Self::criterion(
+                    t("表达逻辑", "Logical delivery"),
+                    t("回答是否条理清晰、重点突出", "Whether the answer is well organized and focused"),
+                    0.15,
+                    [
+                        t("结构清晰，先结论后展开", "Clear structure, conclusion first then details"),
+                        t("基本有条理，略有跳跃", "Mostly organized with some jumps"),
+                        t("杂乱无章，难以跟上", "Disorganized and hard to follow"),
+                    ],
+                ),
+            ]),
+            Self::rubric(t("通用评分标准", "General rubric"), GENERAL_ROLE, vec![
+                Self::criterion(
+                    t("内容相关性", "Relevance"),
+                    t("是否切题并回应岗位要求", "Whether it stays on topic and addresses the role requirements"),
+                    0.35,
+                    [
+                        t("紧扣问题并结合岗位要求", "Directly answers the question and ties it to the role"),
+                        t("基本切题，部分内容偏离", "Mostly on topic, partly off track"),
+                        t("答非所问", "Does not answer the question"),
+                    ],
+                ),
+                Self::criterion(
+                    t("逻辑结构", "Logical structure"),
+                    t("回答是否有清晰的结构", "Whether the answer has a clear structure"),
+                    0.25,
+                    [
+                        t("层次分明，论证完整", "Well layered with a complete argument"),
+                        t("有一定结构，论证不充分", "Some structure, argument incomplete"),
+                        t("缺乏结构", "Lacks structure"),
+                    ],
+                ),
+                Self::criterion(
+                    t("具体案例", "Concrete examples"),
+                    t("是否有具体例子或数据支撑", "Whether concrete examples or data back it up"),
+                    0.25,
+                    [
+                        t("案例具体且有量化结果", "Specific examples with quantified results"),
+                        t("有例子但不够具体", "Examples that are not specific enough"),
+                        t("没有例子", "No examples"),
+                    ],
+                ),
+                Self::criterion(
+                    t("表达清晰", "Clarity"),
+                    t("语言是否简洁、准确", "Whether the language is concise and precise"),
+                    0.15,
+                    [
+                        t("简洁准确，重点突出", "Concise, precise and focused"),
+                        t("基本清楚，略显冗长", "Mostly clear, somewhat long-winded"),
+                        t("表达混乱", "Confused delivery"),
+                    ],
+                ),
+            ]),
+        ]
+    }
+
+    /// Find the rubric for a role and question type
+    ///
+    /// Stored rubrics win over built-in ones; lookup falls back from the exact
+    /// question type to the role's general rubric, then to the general rubric.
+    /// Built-in rubrics are written in `language`; stored ones as they were saved
+    pub fn resolve(stored: &[Rubric], role: &str, question_type: &str, language: Language) -> Rubric {
+        let defaults = Self::default_rubrics(language);
+        let candidates = [
+            (role, question_type),
+            (role, GENERAL_QUESTION_TYPE),
+            (GENERAL_ROLE, question_type),
+            (GENERAL_ROLE, GENERAL_QUESTION_TYPE),
+        ];
+
+        candidates
+            .iter()
+            .find_map(|(role, question_type)| {
+                stored
+                    .iter()
+                    .chain(defaults.iter())
+                    .find(|r| r.role == *role && r.question_type == *question_type)
+                    .cloned()
+            })
+            .unwrap_or_else(|| defaults[defaults.len() - 1].clone())
+    }
+
+    /// Rubric question type of a question, from its keyword classification
+    pub fn question_type(question: &str) -> String {
+        QuestionClassifier::classify_keywords(0, question).question_type
+    }
+
+    /// Stored rubrics plus the built-in defaults they do not override
+    pub fn merged(stored: Vec<Rubric>, language: Language) -> Vec<Rubric> {
+        let defaults: Vec<Rubric> = Self::default_rubrics(language)
+            .into_iter()
+            .filter(|d| !stored.iter().any(|s| s.role == d.role && s.question_type == d.question_type))
+            .collect();
+        stored.into_iter().chain(defaults).collect()
+    }
+
+    /// Validate an edited rubric before storing it
+    pub fn validate(rubric: &Rubric) -> Result<()> {
+        if rubric.name.trim().is_empty() || rubric.role.trim().is_empty() || rubric.question_type.trim().is_empty() {
+            return Err(anyhow!("Rubric name, role and question type are required"));
+        }
+        if rubric.criteria.is_empty() {
+            return Err(anyhow!("Rubric must contain at least one criterion"));
+        }
+        for (index, criterion) in rubric.criteria.iter().enumerate() {
+            if criterion.name.trim().is_empty() {
+                return Err(anyhow!("Criterion {} has no name", index + 1));
+            }
+            if criterion.weight <= 0.0 {
+                return Err(anyhow!("Criterion '{}' must have a positive weight", criterion.name));
+            }
+            if rubric.criteria[..index].iter().any(|c| c.name == criterion.name) {
+                return Err(anyhow!("Duplicate criterion '{}'", criterion.name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Render rubric as prompt instructions asking for `criterion_scores`
+    pub fn render_prompt(rubric: &Rubric, language: Language) -> String {
+        let criteria = rubric
+            .criteria
+            .iter()
+            .map(|c| {
+                let levels = c
+                    .levels
+                    .iter()
+                    .map(|l| format!("    {:.0}{}{}", l.score, language.prompt("分：", " points: "), l.description))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                let weight = format!("{:.0}%", c.weight * 100.0);
+                let header = match language {
+                    Language::En => format!("- {} (weight {}): {}", c.name, weight, c.description),
+                    _ => format!("- {}（权重 {}）：{}", c.name, weight, c.description),
+                };
+                format!("{}\n{}", header, levels)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        match language {
+            Language::En => format!(
+                "Rubric \"{}\":\n{}\n\nScore each criterion (1-10) and output a criterion_scores field in the JSON, for example: \
+                 [{{\"criterion\": \"criterion name\", \"score\": 7.5, \"evidence\": [\"quote from the answer\"], \"comment\": \"reason\"}}]. \
+                 criterion must match a criterion name above exactly, and evidence must quote sentences from the candidate's answer verbatim.",
+                rubric.name, criteria
+            ),
+            _ => format!(
+                "评分标准「{}」：\n{}\n\n请逐项评分（1-10分），在JSON中输出 criterion_scores 字段，例如：\
+                 [{{\"criterion\": \"标准名称\", \"score\": 7.5, \"evidence\": [\"回答原文引用\"], \"comment\": \"评分理由\"}}]。\
+                 criterion 必须与上面的标准名称完全一致，evidence 必须逐字引用候选人回答中的原句。",
+                rubric.name, criteria
+            ),
+        }
+    }
+
+    /// Keep scores of known criteria, attach weights and drop evidence not found in the answer
+    pub fn normalize(rubric: &Rubric, scores: Vec<CriterionScore>, answer: &str) -> Vec<CriterionScore> {
+        let answer_text = Self::squash(answer);
+
+        rubric
+            .criteria
+            .iter()
+            .filter_map(|criterion| {
+                let mut score = scores
+                    .iter()
+                    .find(|s| s.criterion.trim() == criterion.name)
+                    .cloned()?;
+                score.criterion = criterion.name.clone();
+                score.score = score.score.clamp(1.0, 10.0);
+                score.weight = criterion.weight;
+                score.evidence.retain(|quote| {
+                    let quote = Self::squash(quote.trim_matches(|c| "\"'“”‘’「」".contains(c)));
+                    !quote.is_empty() && answer_text.contains(&quote)
+                });
+                Some(score)
+            })
+            .collect()
+    }
+
+    /// Weighted average of criterion scores, `None` without scores
+    pub fn weighted_score(scores: &[CriterionScore]) -> Option<f32> {
+        let total_weight: f32 = scores.iter().map(|s| s.weight).sum();
+        if scores.is_empty() || total_weight <= 0.0 {
+            return None;
+        }
+        let weighted: f32 = scores.iter().map(|s| s.score * s.weight).sum();
+        Some(((weighted / total_weight) * 10.0).round() / 10.0)
+    }
+
+    /// Trace of a rubric breakdown: each criterion contributes its weighted share of the average
+    pub fn trace(scores: &[CriterionScore]) -> Option<ComponentTrace> {
+        let total_weight: f32 = scores.iter().map(|s| s.weight).sum();
+        if scores.is_empty() || total_weight <= 0.0 {
+            return None;
+        }
+        let mut trace = ComponentTrace::new("rubric");
+        for score in scores {
+            trace.add(
+                "criterion",
+                format!("{} {:.1} × {:.0}%", score.criterion, score.score, score.weight / total_weight * 100.0),
+                score.score * score.weight / total_weight,
+                score.evidence.first().cloned(),
+            );
+        }
+        Some(trace.finish(0.0, 10.0))
+    }
+
+    /// Attach criterion scores to an analyzed answer and record them in its score trace
+    ///
+    /// Returns `false` when the answer has not been analyzed yet
+    pub fn store(db: &Repository, answer_id: i64, rubric_id: Option<i64>, scores: &[CriterionScore]) -> Result<bool> {
+        if !db.save_criterion_scores(answer_id, rubric_id, scores)? {
+            return Ok(false);
+        }
+        let Some(analysis) = db.get_answer_analysis(answer_id)? else {
+            return Ok(false);
+        };
+
+        let mut trace: ScoreTrace = serde_json::from_str(&analysis.score_trace).unwrap_or_default();
+        match Self::trace(scores) {
+            Some(rubric) => trace.set(rubric),
+            None => {
+                trace.take("rubric");
+            }
+        }
+        db.save_score_trace(answer_id, &serde_json::to_string(&trace)?)?;
+        Ok(true)
+    }
+
+    /// Parse a model response scored against `rubric`
+    ///
+    /// The overall score is the weighted criterion average; the model's own
+    /// score is only used when no criterion could be matched
+    pub fn parse_evaluation(response: &str, rubric: &Rubric, answer: &str) -> Result<RubricEvaluation> {
+        let raw: RawEvaluation = match (response.find('{'), response.rfind('}')) {
+            (Some(start), Some(end)) if start < end => serde_json::from_str(&response[start..=end])
+                .map_err(|e| anyhow!("Invalid rubric evaluation JSON: {}", e))?,
+            _ => return Err(anyhow!("No JSON object in rubric evaluation response")),
+        };
+
+        let criterion_scores = Self::normalize(rubric, raw.criterion_scores, answer);
+        let overall_score = Self::weighted_score(&criterion_scores)
+            .or(raw.score.map(|s| s.clamp(1.0, 10.0)))
+            .ok_or_else(|| anyhow!("Rubric evaluation contains no scores"))?;
+
+        Ok(RubricEvaluation {
+            rubric_id: rubric.id,
+            rubric_name: rubric.name.clone(),
+            overall_score,
+            criterion_scores,
+            strengths: raw.strengths,
+            improvements: raw.improvements,
+            summary: raw.summary,
+        })
+    }
+
+    fn rubric(name: String, role: &str, criteria: Vec<RubricCriterion>) -> Rubric {
+        let timestamp = now();
+        Rubric {
+            id: None,
+            name,
+            role: role.to_string(),
+            question_type: GENERAL_QUESTION_TYPE.to_string(),
+            criteria,
+            created_at: timestamp.clone(),
+            updated_at: timestamp,
+        }
+    }
+
+    /// Criterion with high, medium and low anchors at 9, 6 and 3 points
+    fn criterion(name: String, description: String, weight: f32, anchors: [String; 3]) -> RubricCriterion {
+        RubricCriterion {
+            name,
+            description,
+            weight,
+            levels: [9.0, 6.0, 3.0]
+                .into_iter()
+                .zip(anchors)
+                .map(|(score, description)| RubricLevel { score, description })
+                .collect(),
+        }
+    }
+
+    /// Remove whitespace so quotes match across line breaks
+    fn squash(text: &str) -> String {
+        text.chars().filter(|c| !c.is_whitespace()).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(criterion: &str, score: f32, evidence: &[&str]) -> CriterionScore {
+        CriterionScore {
+            criterion: criterion.to_string(),
+            score,
+            weight: 0.0,
+            evidence: evidence.iter().map(|e| e.to_string()).collect(),
+            comment: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_resolve_prefers_stored_and_falls_back() {
+        let mut stored = RubricEngine::resolve(&[], "technical", GENERAL_QUESTION_TYPE, Language::Zh);
+        stored.id = Some(7);
+        stored.question_type = "system_design".to_string();
+
+        assert_eq!(RubricEngine::resolve(&[stored.clone()], "technical", "system_design", Language::Zh).id, Some(7));
+        assert_eq!(RubricEngine::resolve(&[stored], "technical", "coding", Language::Zh).name, "技术面试评分标准");
+        assert_eq!(RubricEngine::resolve(&[], "unknown", "coding", Language::Zh).role, GENERAL_ROLE);
+    }
+
+    #[test]
+    fn test_resolve_by_question_type_and_language() {
+        let mut design = RubricEngine::resolve(&[], "technical", GENERAL_QUESTION_TYPE, Language::Zh);
+        design.id = Some(3);
+        design.question_type = "system_design".to_string();
+
+        let question_type = RubricEngine::question_type("如何设计一个支持亿级用户的分布式消息系统？");
+        assert_eq!(question_type, "system_design");
+        assert_eq!(RubricEngine::resolve(&[design], "technical", &question_type, Language::Zh).id, Some(3));
+
+        let english = RubricEngine::resolve(&[], "technical", "technical", Language::En);
+        assert_eq!(english.name, "Technical interview rubric");
+        assert_eq!(english.criteria[0].name, "Technical accuracy");
+        assert!(RubricEngine::render_prompt(&english, Language::En).starts_with("Rubric \"Technical interview rubric\""));
+        assert!(RubricEngine::validate(&english).is_ok());
+    }
+
+    #[test]
+    fn test_normalize_and_weighted_score() {
+        let rubric = RubricEngine::resolve(&[], "technical", GENERAL_QUESTION_TYPE, Language::Zh);
+        let answer = "我们用一致性哈希做分片，\n扩容时只迁移少量数据。";
+        let scores = RubricEngine::normalize(
+            &rubric,
+            vec![
+                score("技术准确性", 8.0, &["用一致性哈希做分片，扩容时只迁移少量数据", "编造的引用"]),
+                score("深度与原理", 12.0, &[]),
+                score("不存在的标准", 1.0, &[]),
+            ],
+            answer,
+        );
+
+        assert_eq!(scores.len(), 2);
+        assert_eq!(scores[0].evidence.len(), 1);
+        assert_eq!(scores[1].score, 10.0);
+        // (8.0 * 0.35 + 10.0 * 0.25) / 0.6
+        assert_eq!(RubricEngine::weighted_score(&scores), Some(8.8));
+        assert_eq!(RubricEngine::weighted_score(&[]), None);
+    }
+
+    #[test]
+    fn test_parse_evaluation() {
+        let rubric = RubricEngine::resolve(&[], "hr", GENERAL_QUESTION_TYPE, Language::Zh);
+        let response = r#"结果如下：{"score": 5.0, "criterion_scores": [
+            {"criterion": "STAR结构", "score": 6.0, "evidence": ["我负责协调"]},
+            {"criterion": "沟通表达", "score": 8.0}
+        ], "summary": "尚可"}"#;
+
+        let evaluation = RubricEngine::parse_evaluation(response, &rubric, "当时我负责协调两个团队").unwrap();
+        assert_eq!(evaluation.criterion_scores.len(), 2);
+        // (6.0 * 0.3 + 8.0 * 0.25) / 0.55
+        assert_eq!(evaluation.overall_score, 6.9);
+        assert_eq!(evaluation.summary, "尚可");
+
+        assert!(RubricEngine::parse_evaluation("没有JSON", &rubric, "").is_err());
+        assert!(RubricEngine::validate(&rubric).is_ok());
+    }
+
+    #[test]
+    fn test_trace_adds_up_to_weighted_score() {
+        let rubric = RubricEngine::resolve(&[], "technical", GENERAL_QUESTION_TYPE, Language::Zh);
+        let scores = RubricEngine::normalize(
+            &rubric,
+            vec![score("技术准确性", 8.0, &["一致性哈希"]), score("深度与原理", 10.0, &[])],
+            "我们用一致性哈希做分片",
+        );
+
+        let trace = RubricEngine::trace(&scores).unwrap();
+        assert_eq!(trace.entries.len(), 2);
+        assert_eq!(trace.entries[0].evidence.as_deref(), Some("一致性哈希"));
+        let total: f32 = trace.entries.iter().map(|e| e.points).sum();
+        assert!((total - trace.score).abs() < 1e-4);
+        assert!((trace.score - RubricEngine::weighted_score(&scores).unwrap()).abs() < 0.05);
+        assert!(RubricEngine::trace(&[]).is_none());
+    }
+}
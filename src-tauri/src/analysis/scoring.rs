@@ -2,6 +2,24 @@
 //! Implements 1-10 scoring algorithm with weighted dimensions
 
 use super::content::ContentAnalysisResult;
+use super::segmentation::{SegmentedText, Segmenter};
+use super::star_segmentation::{STARSegmentation, STARSegmenter};
+use super::trace::{evidence, evidence_any, ComponentTrace, ScoreTrace};
+use crate::language::Language;
+
+/// Chinese STAR keywords
+pub(super) const SITUATION_KEYWORDS_ZH: &[&str] = &["背景", "情况", "当时", "项目中", "团队里", "环境", "场景", "之前"];
+pub(super) const TASK_KEYWORDS_ZH: &[&str] = &["任务", "目标", "需要", "负责", "职责", "要求", "期望", "挑战"];
+pub(super) const ACTION_KEYWORDS_ZH: &[&str] = &["采取", "实施", "执行", "通过", "使用", "做了", "进行", "设计", "开发"];
+pub(super) const RESULT_KEYWORDS_ZH: &[&str] = &["结果", "效果", "提升", "降低", "完成", "达成", "成功", "实现"];
+
+/// English STAR keywords, matched alongside the Chinese lists so mixed answers score fairly
+///
+/// Latin keywords match whole words, so common inflections are listed explicitly
+pub(super) const SITUATION_KEYWORDS_EN: &[&str] = &["background", "situation", "at the time", "context", "in my previous", "the project", "our team", "back when"];
+pub(super) const TASK_KEYWORDS_EN: &[&str] = &["task", "tasks", "goal", "goals", "objective", "responsible for", "needed to", "my role", "challenge", "challenges", "requirement", "requirements"];
+pub(super) const ACTION_KEYWORDS_EN: &[&str] = &["implemented", "executed", "designed", "developed", "built", "introduced", "i decided", "i led", "by using"];
+pub(super) const RESULT_KEYWORDS_EN: &[&str] = &["result", "results", "outcome", "improved", "reduced", "increased", "achieved", "delivered", "saved"];
 
 /// Scoring engine for interview evaluation
 pub struct ScoringEngine;
@@ -35,6 +53,7 @@ pub struct ScoringResult {
     pub expression_score: Option<f32>, // 1-10: Optional expression score
     pub score_breakdown: ScoreBreakdown,
     pub score_grade: String,         // A+, A, B+, B, C+, C, D, F
+    pub trace: ScoreTrace,           // Content traces plus the weighted overall
 }
 
 /// Detailed score breakdown
@@ -62,6 +81,10 @@ pub struct STARScoringResult {
     pub breakdown: STARScoreBreakdown,
     pub completeness: f32,         // 0-100: Percentage of STAR elements present
     pub suggestions: Vec<String>,  // Improvement suggestions for each dimension
+    #[serde(default)]
+    pub segmentation: STARSegmentation, // Sentence-level spans, proportions and ordering
+    #[serde(default)]
+    pub trace: ScoreTrace,         // Rules behind each STAR dimension and the overall
 }
 
 impl ScoringEngine {
@@ -75,14 +98,9 @@ impl ScoringEngine {
         // Normalize keyword coverage to 1-10 scale
         let keyword_score = (content_analysis.keyword_coverage / 10.0).min(10.0);
 
-        // Calculate content score (weighted average of logic, match, keywords)
-        let content_score = (content_analysis.logic_score * weights.logic_weight
-            + content_analysis.job_match_score * weights.job_match_weight
-            + keyword_score * weights.keyword_weight)
-            / (weights.logic_weight + weights.job_match_weight + weights.keyword_weight);
-
-        // Overall score (assuming no expression score for now)
-        let overall_score = content_score;
+        // Content score: weighted average of logic, match, keywords (no expression score for now)
+        let overall = Self::content_trace("overall", content_analysis, keyword_score, &weights, 1.0).finish(1.0, 10.0);
+        let overall_score = overall.score;
 
         let score_breakdown = ScoreBreakdown {
             logic: content_analysis.logic_score,
@@ -92,13 +110,16 @@ impl ScoringEngine {
         };
 
         let score_grade = Self::calculate_grade(overall_score);
+        let mut trace = content_analysis.trace.clone();
+        trace.set(overall);
 
         ScoringResult {
-            overall_score: overall_score.max(1.0).min(10.0),
-            content_score: content_score.max(1.0).min(10.0),
+            overall_score,
+            content_score: overall_score,
             expression_score: None,
             score_breakdown,
             score_grade,
+            trace,
         }
     }
 
@@ -114,14 +135,18 @@ impl ScoringEngine {
         let keyword_score = (content_analysis.keyword_coverage / 10.0).min(10.0);
 
         // Content score
-        let content_score = (content_analysis.logic_score * weights.logic_weight
-            + content_analysis.job_match_score * weights.job_match_weight
-            + keyword_score * weights.keyword_weight)
-            / (weights.logic_weight + weights.job_match_weight + weights.keyword_weight);
+        let content = Self::content_trace("content", content_analysis, keyword_score, &weights, 1.0).finish(1.0, 10.0);
 
         // Overall score with expression
-        let overall_score = content_score * (1.0 - weights.expression_weight)
-            + expression_score * weights.expression_weight;
+        let mut overall = Self::content_trace("overall", content_analysis, keyword_score, &weights, 1.0 - weights.expression_weight);
+        overall.add(
+            "expression",
+            format!("{:.1} × {:.2}", expression_score, weights.expression_weight),
+            expression_score * weights.expression_weight,
+            None,
+        );
+        let overall = overall.finish(1.0, 10.0);
+        let overall_score = overall.score;
 
         let score_breakdown = ScoreBreakdown {
             logic: content_analysis.logic_score,
@@ -131,14 +156,58 @@ impl ScoringEngine {
         };
 
         let score_grade = Self::calculate_grade(overall_score);
+        let content_score = content.score;
+        let mut trace = content_analysis.trace.clone();
+        trace.set(content);
+        trace.set(overall);
 
         ScoringResult {
-            overall_score: overall_score.max(1.0).min(10.0),
-            content_score: content_score.max(1.0).min(10.0),
+            overall_score,
+            content_score,
             expression_score: Some(expression_score),
             score_breakdown,
             score_grade,
+            trace,
+        }
+    }
+
+    /// Deduct a penalty from the overall score, recorded as a step of the overall trace
+    pub fn apply_penalty(result: &mut ScoringResult, rule: &str, detail: impl Into<String>, penalty: f32) {
+        if penalty <= 0.0 {
+            return;
+        }
+        let mut overall = result
+            .trace
+            .take("overall")
+            .unwrap_or_else(|| ComponentTrace::new("overall"));
+        overall.entries.retain(|entry| entry.rule != "clamp");
+        overall.add(rule, detail, -penalty, None);
+        let overall = overall.finish(1.0, 10.0);
+
+        result.overall_score = overall.score;
+        result.score_grade = Self::calculate_grade(overall.score);
+        result.trace.set(overall);
+    }
+
+    /// Weighted logic, job match and keyword contributions, scaled by `share`
+    fn content_trace(
+        component: &str,
+        content_analysis: &ContentAnalysisResult,
+        keyword_score: f32,
+        weights: &ScoringWeights,
+        share: f32,
+    ) -> ComponentTrace {
+        let total = weights.logic_weight + weights.job_match_weight + weights.keyword_weight;
+        let mut trace = ComponentTrace::new(component);
+        for (rule, score, weight) in [
+            ("logic", content_analysis.logic_score, weights.logic_weight),
+            ("job_match", content_analysis.job_match_score, weights.job_match_weight),
+            ("keyword_coverage", keyword_score, weights.keyword_weight),
+        ] {
+            let factor = weight / total * share;
+            trace.add(rule, format!("{:.1} × {:.2}", score, factor), score * factor, None);
         }
+        trace
     }
 
     /// Calculate letter grade from numerical score
@@ -160,37 +229,45 @@ impl ScoringEngine {
     /// Get improvement suggestions based on scores
     pub fn get_improvement_suggestions(
         score_breakdown: &ScoreBreakdown,
+        language: Language,
     ) -> Vec<String> {
         let mut suggestions = Vec::new();
 
         if score_breakdown.logic < 5.0 {
-            suggestions.push(
-                "建议多使用逻辑连词，如'首先、其次、最后'等，使答案结构更清晰".to_string()
-            );
+            suggestions.push(language.text(
+                "建议多使用逻辑连词，如'首先、其次、最后'等，使答案结构更清晰",
+                "Use connectives such as 'first', 'then' and 'finally' to give the answer a clearer structure",
+            ));
         }
 
         if score_breakdown.job_match < 5.0 {
-            suggestions.push(
-                "建议更多地引用职位描述中的关键词和要求，提高岗位匹配度".to_string()
-            );
+            suggestions.push(language.text(
+                "建议更多地引用职位描述中的关键词和要求，提高岗位匹配度",
+                "Refer to keywords and requirements from the job description to improve job fit",
+            ));
         }
 
         if score_breakdown.keyword_coverage < 50.0 {
-            suggestions.push(
-                "建议补充岗位相关的专业术语和技能关键词".to_string()
-            );
+            suggestions.push(language.text(
+                "建议补充岗位相关的专业术语和技能关键词",
+                "Add role-specific technical terms and skill keywords",
+            ));
         }
 
         if let Some(expr) = score_breakdown.expression {
             if expr < 5.0 {
-                suggestions.push(
-                    "建议改进表达方式，使用更清晰、更专业的语言".to_string()
-                );
+                suggestions.push(language.text(
+                    "建议改进表达方式，使用更清晰、更专业的语言",
+                    "Express yourself more clearly and professionally",
+                ));
             }
         }
 
         if suggestions.is_empty() {
-            suggestions.push("保持目前的水平，继续完善细节".to_string());
+            suggestions.push(language.text(
+                "保持目前的水平，继续完善细节",
+                "Keep up the current level and keep polishing the details",
+            ));
         }
 
         suggestions
@@ -202,13 +279,22 @@ pub struct STARScoringEngine;
 
 impl STARScoringEngine {
     /// Calculate STAR score from answer text
-    pub fn calculate_star_score(answer: &str) -> STARScoringResult {
-        let situation_score = Self::evaluate_situation(answer);
-        let task_score = Self::evaluate_task(answer);
-        let action_score = Self::evaluate_action(answer);
-        let result_score = Self::evaluate_result(answer);
-
-        let overall_score = (situation_score + task_score + action_score + result_score) / 4.0;
+    ///
+    /// Chinese and English keywords are both matched; `language` selects the feedback language
+    pub fn calculate_star_score(answer: &str, language: Language) -> STARScoringResult {
+        let text = Segmenter::global().analyze(answer);
+        let situation = Self::evaluate_situation(answer, &text);
+        let task = Self::evaluate_task(answer, &text);
+        let action = Self::evaluate_action(answer, &text);
+        let result = Self::evaluate_result(answer, &text);
+        let (situation_score, task_score, action_score, result_score) = (situation.score, task.score, action.score, result.score);
+
+        let mut overall = ComponentTrace::new("overall");
+        for dimension in [&situation, &task, &action, &result] {
+            overall.add(&dimension.component, format!("{:.1} ÷ 4", dimension.score), dimension.score / 4.0, None);
+        }
+        let overall = overall.finish(0.0, 10.0);
+        let overall_score = overall.score;
         let completeness = Self::calculate_completeness(situation_score, task_score, action_score, result_score);
 
         let breakdown = STARScoreBreakdown {
@@ -218,99 +304,88 @@ impl STARScoringEngine {
             result: result_score,
         };
 
-        let suggestions = Self::generate_star_suggestions(&breakdown);
+        // Span-specific suggestions first, they point at the exact weak part of the story
+        let segmentation = STARSegmenter::segment(answer, language);
+        let mut suggestions = segmentation.suggestions.clone();
+        suggestions.extend(Self::generate_star_suggestions(&breakdown, language));
 
         STARScoringResult {
             overall_score,
             breakdown,
             completeness,
             suggestions,
+            segmentation,
+            trace: ScoreTrace { components: vec![situation, task, action, result, overall], ..Default::default() },
         }
     }
 
     /// Evaluate Situation dimension
-    fn evaluate_situation(answer: &str) -> f32 {
-        let answer_lower = answer.to_lowercase();
-        let situation_keywords = ["背景", "情况", "当时", "项目中", "团队里", "环境", "场景", "之前"];
-        
-        let mut score: f32 = 3.0; // Base score
-        
-        for keyword in situation_keywords.iter() {
-            if answer_lower.contains(keyword) {
-                score += 1.0;
-            }
-        }
+    fn evaluate_situation(answer: &str, text: &SegmentedText) -> ComponentTrace {
+        let mut trace = Self::keyword_trace("situation", answer, text, SITUATION_KEYWORDS_ZH.iter().chain(SITUATION_KEYWORDS_EN.iter()), 1.0);
         
         // Bonus for context richness
-        if answer.len() > 100 && answer.contains("在") {
-            score += 1.5;
+        if text.word_count() > 30 && (text.contains_term("在") || text.contains_term("when")) {
+            trace.add("context_richness", format!("{} words", text.word_count()), 1.5, evidence_any(answer, ["when", "在"]));
         }
         
-        score.min(10.0)
+        trace.finish(0.0, 10.0)
     }
 
     /// Evaluate Task dimension
-    fn evaluate_task(answer: &str) -> f32 {
-        let answer_lower = answer.to_lowercase();
-        let task_keywords = ["任务", "目标", "需要", "负责", "职责", "要求", "期望", "挑战"];
-        
-        let mut score: f32 = 3.0;
-        
-        for keyword in task_keywords.iter() {
-            if answer_lower.contains(keyword) {
-                score += 1.0;
-            }
-        }
+    fn evaluate_task(answer: &str, text: &SegmentedText) -> ComponentTrace {
+        let mut trace = Self::keyword_trace("task", answer, text, TASK_KEYWORDS_ZH.iter().chain(TASK_KEYWORDS_EN.iter()), 1.0);
         
         // Bonus for clear task definition
-        if answer_lower.contains("目标是") || answer_lower.contains("任务是") {
-            score += 1.5;
+        if let Some(marker) = ["目标是", "任务是", "goal was", "task was"].iter().find(|k| text.contains_term(k)) {
+            trace.add("clear_task", *marker, 1.5, evidence(answer, marker));
         }
         
-        score.min(10.0)
+        trace.finish(0.0, 10.0)
     }
 
     /// Evaluate Action dimension
-    fn evaluate_action(answer: &str) -> f32 {
-        let answer_lower = answer.to_lowercase();
-        let action_keywords = ["采取", "实施", "执行", "通过", "使用", "做了", "进行", "设计", "开发"];
-        
-        let mut score: f32 = 3.0;
-        
-        for keyword in action_keywords.iter() {
-            if answer_lower.contains(keyword) {
-                score += 0.8;
-            }
-        }
+    fn evaluate_action(answer: &str, text: &SegmentedText) -> ComponentTrace {
+        let mut trace = Self::keyword_trace("action", answer, text, ACTION_KEYWORDS_ZH.iter().chain(ACTION_KEYWORDS_EN.iter()), 0.8);
         
         // Bonus for detailed action steps
-        if answer.contains("首先") || answer.contains("然后") || answer.contains("最后") {
-            score += 2.0;
+        if let Some(marker) = ["首先", "然后", "最后", "first", "then", "finally"].iter().find(|k| text.contains_term(k)) {
+            trace.add("action_steps", *marker, 2.0, evidence(answer, marker));
         }
         
-        score.min(10.0)
+        trace.finish(0.0, 10.0)
     }
 
     /// Evaluate Result dimension
-    fn evaluate_result(answer: &str) -> f32 {
-        let answer_lower = answer.to_lowercase();
-        let result_keywords = ["结果", "效果", "提升", "降低", "完成", "达成", "成功", "实现"];
+    fn evaluate_result(answer: &str, text: &SegmentedText) -> ComponentTrace {
+        let mut trace = Self::keyword_trace("result", answer, text, RESULT_KEYWORDS_ZH.iter().chain(RESULT_KEYWORDS_EN.iter()), 1.0);
         
-        let mut score: f32 = 3.0;
-        
-        for keyword in result_keywords.iter() {
-            if answer_lower.contains(keyword) {
-                score += 1.0;
+        // Bonus for quantified results
+        let number = text.tokens.iter().find(|t| t.chars().any(|c| c.is_numeric()));
+        if let Some(number) = number {
+            if text.normalized.contains('%') || ["倍", "次", "times", "percent"].iter().any(|k| text.contains_term(k)) {
+                trace.add("quantified_result", number.as_str(), 2.5, evidence(answer, number));
             }
         }
         
-        // Bonus for quantified results
-        let has_numbers = answer.chars().any(|c| c.is_numeric());
-        if has_numbers && (answer.contains("%") || answer.contains("倍") || answer.contains("次")) {
-            score += 2.5;
+        trace.finish(0.0, 10.0)
+    }
+
+    /// Base score of 3 plus `points` per matched STAR keyword
+    fn keyword_trace<'a>(
+        component: &str,
+        answer: &str,
+        text: &SegmentedText,
+        keywords: impl Iterator<Item = &'a &'a str>,
+        points: f32,
+    ) -> ComponentTrace {
+        let mut trace = ComponentTrace::new(component);
+        trace.add("base", "", 3.0, None);
+        for keyword in keywords {
+            if text.contains_term(keyword) {
+                trace.add("keyword", *keyword, points, evidence(answer, keyword));
+            }
         }
-        
-        score.min(10.0)
+        trace
     }
 
     /// Calculate completeness percentage
@@ -327,29 +402,74 @@ impl STARScoringEngine {
     }
 
     /// Generate improvement suggestions
-    fn generate_star_suggestions(breakdown: &STARScoreBreakdown) -> Vec<String> {
+    fn generate_star_suggestions(breakdown: &STARScoreBreakdown, language: Language) -> Vec<String> {
         let mut suggestions = Vec::new();
         
         if breakdown.situation < 6.0 {
-            suggestions.push("建议补充情境背景，说明当时的环境、团队情况或项目背景".to_string());
+            suggestions.push(language.text(
+                "建议补充情境背景，说明当时的环境、团队情况或项目背景",
+                "Add the situation: describe the environment, team or project background at the time",
+            ));
         }
         
         if breakdown.task < 6.0 {
-            suggestions.push("建议明确描述任务目标，说明你需要完成什么、面临什么挑战".to_string());
+            suggestions.push(language.text(
+                "建议明确描述任务目标，说明你需要完成什么、面临什么挑战",
+                "State the task clearly: what you had to achieve and which challenges you faced",
+            ));
         }
         
         if breakdown.action < 6.0 {
-            suggestions.push("建议详细说明行动步骤，使用'首先、然后、最后'等连词展示执行过程".to_string());
+            suggestions.push(language.text(
+                "建议详细说明行动步骤，使用'首先、然后、最后'等连词展示执行过程",
+                "Detail your actions step by step, using 'first', 'then' and 'finally' to show how you executed",
+            ));
         }
         
         if breakdown.result < 6.0 {
-            suggestions.push("建议量化结果，用数据（百分比、倍数等）说明效果和成果".to_string());
+            suggestions.push(language.text(
+                "建议量化结果，用数据（百分比、倍数等）说明效果和成果",
+                "Quantify the result with data such as percentages or multiples",
+            ));
         }
         
         if suggestions.is_empty() {
-            suggestions.push("STAR结构完整，继续保持这种回答方式".to_string());
+            suggestions.push(language.text(
+                "STAR结构完整，继续保持这种回答方式",
+                "The STAR structure is complete, keep answering this way",
+            ));
         }
         
         suggestions
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::ContentAnalyzer;
+
+    fn overall_total(result: &ScoringResult) -> f32 {
+        let overall = result.trace.components.iter().find(|c| c.component == "overall").unwrap();
+        overall.entries.iter().map(|e| e.points).sum()
+    }
+
+    #[test]
+    fn test_penalty_is_traced_and_floored() {
+        let answer = "首先，我负责把订单服务迁移到 Kubernetes，其次引入 Redis 缓存，最后接口延迟降低了 40%";
+        let analysis = ContentAnalyzer::analyze(answer, "", "熟悉 K8s 和 Redis", Language::default()).unwrap();
+        let mut result = ScoringEngine::calculate_score(&analysis, None);
+        let before = result.overall_score;
+
+        ScoringEngine::apply_penalty(&mut result, "overtime", "240s / 180s", 0.5);
+        assert!((result.overall_score - (before - 0.5).max(1.0)).abs() < 1e-4);
+        assert!((overall_total(&result) - result.overall_score).abs() < 1e-4);
+        assert!((result.content_score - before).abs() < 1e-4);
+
+        // Large penalties stop at the lower bound, the clamp keeps the trace adding up
+        ScoringEngine::apply_penalty(&mut result, "overtime", "", 20.0);
+        assert_eq!(result.overall_score, 1.0);
+        assert!((overall_total(&result) - 1.0).abs() < 1e-4);
+        assert_eq!(result.score_grade, "F");
+    }
+}
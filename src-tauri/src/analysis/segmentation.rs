@@ -0,0 +1,350 @@
+//! Word segmentation for Chinese and mixed Chinese/English text
+//!
+//! Han runs are split by bidirectional maximum matching against a built-in
+//! dictionary, and characters the dictionary does not know are paired into
+//! bigrams; Latin words, numbers and identifiers such as `c++` or `node.js`
+//! stay whole. Text is normalized first: full-width ASCII becomes
+//! half-width and Latin letters are lowercased.
+
+use std::collections::HashSet;
+use std::sync::OnceLock;
+
+/// Built-in dictionary: interview, engineering and business vocabulary
+const DEFAULT_WORDS: &[&str] = &[
+    // Structure and STAR vocabulary
+    "首先", "其次", "然后", "最后", "此外", "然而", "因此", "所以", "但是", "因为", "如果",
+    "例如", "比如", "具体", "背景", "情况", "当时", "环境", "场景", "之前", "项目中", "团队里",
+    "任务", "目标", "需要", "负责", "职责", "要求", "期望", "挑战", "采取", "实施", "执行",
+    "通过", "使用", "做了", "进行", "设计", "开发", "结果", "效果", "提升", "降低", "完成",
+    "达成", "成功", "实现", "目标是", "任务是",
+    // Function words
+    "我们", "他们", "你们", "自己", "一个", "这个", "那个", "没有", "可以", "能够", "已经",
+    "非常", "以及", "或者", "并且", "同时", "及其", "其中", "之后", "以上", "以下",
+    // Engineering
+    "分布式", "存储", "系统", "架构", "微服务", "高并发", "并发", "数据库", "缓存", "性能",
+    "优化", "前端", "后端", "全栈", "测试", "运维", "部署", "容器", "云原生", "服务", "接口",
+    "协议", "网络", "安全", "稳定性", "可用性", "高可用", "可靠性", "监控", "日志", "消息队列",
+    "中间件", "框架", "语言", "编程", "代码", "质量", "重构", "上线", "发布", "迭代", "敏捷",
+    "算法", "数据结构", "设计模式", "面向对象", "操作系统", "计算机", "线程", "进程", "内存",
+    "异步", "同步", "编译", "运行时", "所有权", "生命周期", "一致性", "事务", "索引", "查询",
+    "集群", "负载均衡", "调度", "实时", "离线", "计算", "大数据", "数据仓库", "数据分析",
+    "可视化", "报表", "人工智能", "机器学习", "深度学习", "模型", "训练", "推理", "移动端",
+    "客户端", "服务端", "小程序", "单元测试", "自动化", "持续集成", "版本控制", "开源", "社区",
+    "文档", "评审", "平台", "应用", "技术", "方案", "问题", "解决", "方法", "工程师", "瓶颈",
+    "扩容", "分片", "延迟", "吞吐", "吞吐量", "故障", "排查", "压测",
+    // Business and soft skills
+    "产品", "用户", "业务", "需求", "分析", "数据", "指标", "增长", "转化率", "留存", "营收",
+    "成本", "效率", "客户", "市场", "竞品", "战略", "规划", "方向", "领导", "推动", "落地",
+    "协调", "跨部门", "沟通", "协作", "管理", "团队", "项目", "经验", "能力", "学习能力",
+    "责任心", "抗压", "主动", "岗位", "职位", "公司", "行业", "工作", "专业",
+    // Job description filler
+    "熟悉", "掌握", "精通", "了解", "熟练", "相关", "优先", "加分", "本科", "硕士", "学历",
+    "年以上", "以上学历",
+];
+
+/// Stop words excluded from keyword extraction
+const STOP_WORDS: &[&str] = &[
+    "的", "了", "是", "在", "我", "有", "和", "就", "不", "人", "都", "一", "一个", "上", "也",
+    "很", "到", "说", "要", "去", "你", "会", "着", "没有", "看", "好", "自己", "这", "那", "这个",
+    "那个", "我们", "他们", "你们", "以及", "及", "与", "或", "或者", "等", "并", "并且", "对",
+    "能", "可以", "能够", "已经", "非常", "为", "以", "个", "之", "其", "及其", "中", "把", "被",
+    "让", "从", "而", "但", "但是", "因为", "所以", "如果", "还", "又", "具有", "相关", "优先",
+    "熟悉", "了解", "掌握", "精通", "熟练", "进行", "其中", "之后", "以上", "以下", "加分",
+    "年以上",
+    "the", "a", "an", "and", "or", "is", "are", "be", "to", "in", "on", "at", "of", "for",
+    "with", "that", "this", "it", "by", "from", "as", "was", "were", "will", "would", "can",
+    "could", "should", "i", "we", "you", "he", "she", "they", "our", "your", "their", "my",
+    "me", "us", "have", "has", "had", "do", "does", "did", "not", "but", "so", "if", "than",
+    "then", "there", "these", "those", "which", "who", "what", "when", "where", "how", "also",
+    "etc", "about", "into", "over", "under", "such", "any", "all", "more", "most", "other",
+    "some", "very", "just", "years", "year", "plus",
+];
+
+/// Characters kept inside Latin tokens, e.g. `c++`, `c#`, `node.js`, `real-time`
+const LATIN_INNER: &[char] = &['+', '#', '.', '_', '-'];
+
+/// Dictionary-based segmenter
+pub struct Segmenter {
+    dictionary: HashSet<String>,
+    max_word_chars: usize,
+}
+
+/// Normalized text with its tokens, for repeated term lookups
+pub struct SegmentedText {
+    pub normalized: String,
+    pub tokens: Vec<String>,
+    boundaries: HashSet<usize>, // Byte offsets in `normalized` where a Han term may start or end
+}
+
+impl Segmenter {
+    /// Segmenter with the built-in dictionary
+    pub fn global() -> &'static Segmenter {
+        static SEGMENTER: OnceLock<Segmenter> = OnceLock::new();
+        SEGMENTER.get_or_init(|| Segmenter::with_words(std::iter::empty::<String>()))
+    }
+
+    /// Built-in dictionary extended with domain words
+    pub fn with_words<I, S>(words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let dictionary: HashSet<String> = DEFAULT_WORDS
+            .iter()
+            .map(|w| w.to_string())
+            .chain(words.into_iter().map(|w| Self::normalize(w.as_ref())))
+            .filter(|w| !w.is_empty())
+            .collect();
+        let max_word_chars = dictionary.iter().map(|w| w.chars().count()).max().unwrap_or(1);
+
+        Self { dictionary, max_word_chars }
+    }
+
+    /// Convert full-width ASCII to half-width and lowercase Latin letters
+    pub fn normalize(text: &str) -> String {
+        text.chars()
+            .map(|c| match c {
+                '\u{3000}' => ' ',
+                '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+                _ => c,
+            })
+            .flat_map(|c| c.to_lowercase())
+            .collect()
+    }
+
+    /// Whether a word is a stop word (expects normalized input)
+    pub fn is_stop_word(word: &str) -> bool {
+        STOP_WORDS.contains(&word)
+    }
+
+    /// Split text into normalized words, dropping whitespace and punctuation
+    pub fn segment(&self, text: &str) -> Vec<String> {
+        self.tokenize(&Self::normalize(text)).0
+    }
+
+    /// Segment text once for word counts and repeated term lookups
+    pub fn analyze(&self, text: &str) -> SegmentedText {
+        let normalized = Self::normalize(text);
+        let (tokens, boundaries) = self.tokenize(&normalized);
+        SegmentedText { normalized, tokens, boundaries }
+    }
+
+    /// Tokens of normalized text with the byte offsets of word boundaries
+    ///
+    /// Inside unknown Han runs every character edge is a boundary, since the
+    /// bigram split there is only a guess
+    fn tokenize(&self, normalized: &str) -> (Vec<String>, HashSet<usize>) {
+        let chars: Vec<(usize, char)> = normalized.char_indices().collect();
+        let offset = |i: usize| chars.get(i).map(|(offset, _)| *offset).unwrap_or(normalized.len());
+        let mut tokens = Vec::new();
+        let mut boundaries = HashSet::new();
+        let mut i = 0;
+
+        while i < chars.len() {
+            let c = chars[i].1;
+            if is_han(c) {
+                let start = i;
+                while i < chars.len() && is_han(chars[i].1) {
+                    i += 1;
+                }
+                let run: Vec<char> = chars[start..i].iter().map(|(_, c)| *c).collect();
+                let mut position = offset(start);
+                let mut unknown = Vec::new();
+                for word in self.segment_han(&run) {
+                    boundaries.insert(position);
+                    position += word.len();
+                    if word.chars().count() == 1 && !Self::is_stop_word(&word) {
+                        unknown.push(word);
+                    } else {
+                        tokens.extend(Self::bigrams(std::mem::take(&mut unknown)));
+                        tokens.push(word);
+                    }
+                }
+                boundaries.insert(position);
+                tokens.extend(Self::bigrams(unknown));
+            } else if c.is_alphanumeric() {
+                let start = i;
+                while i < chars.len()
+                    && !is_han(chars[i].1)
+                    && (chars[i].1.is_alphanumeric() || LATIN_INNER.contains(&chars[i].1))
+                {
+                    i += 1;
+                }
+                // Sentence punctuation such as a trailing '.' is not part of the word
+                let word = normalized[offset(start)..offset(i)].trim_end_matches(['.', '-', '_']);
+                if !word.is_empty() {
+                    boundaries.insert(offset(start));
+                    boundaries.insert(offset(start) + word.len());
+                    tokens.push(word.to_string());
+                }
+            } else {
+                i += 1;
+            }
+        }
+
+        (tokens, boundaries)
+    }
+
+    /// Pair consecutive unknown characters into words, a trailing odd one stays single
+    fn bigrams(chars: Vec<String>) -> Vec<String> {
+        chars.chunks(2).map(|pair| pair.concat()).collect()
+    }
+
+    /// Unique keywords in order of appearance, without stop words, numbers or single Han characters
+    pub fn keywords(&self, text: &str) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.segment(text)
+            .into_iter()
+            .filter(|w| Self::is_keyword(w))
+            .filter(|w| seen.insert(w.clone()))
+            .collect()
+    }
+
+    /// Number of words, counting each segmented Chinese word and each Latin word once
+    pub fn word_count(&self, text: &str) -> usize {
+        self.segment(text).len()
+    }
+
+    fn is_keyword(word: &str) -> bool {
+        if Self::is_stop_word(word) || word.chars().all(|c| c.is_numeric() || c == '.') {
+            return false;
+        }
+        word.chars().count() >= 2
+    }
+
+    /// Bidirectional maximum matching over a run of Han characters
+    fn segment_han(&self, chars: &[char]) -> Vec<String> {
+        let forward = self.forward_match(chars);
+        let backward = self.backward_match(chars);
+
+        let singles = |tokens: &[String]| tokens.iter().filter(|t| t.chars().count() == 1).count();
+        // Fewer words wins, then fewer single characters; ties favor backward matching
+        if forward.len() < backward.len()
+            || (forward.len() == backward.len() && singles(&forward) < singles(&backward))
+        {
+            forward
+        } else {
+            backward
+        }
+    }
+
+    fn forward_match(&self, chars: &[char]) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let mut len = self.max_word_chars.min(chars.len() - i);
+            while len > 1 && !self.dictionary.contains(&chars[i..i + len].iter().collect::<String>()) {
+                len -= 1;
+            }
+            tokens.push(chars[i..i + len].iter().collect());
+            i += len;
+        }
+        tokens
+    }
+
+    fn backward_match(&self, chars: &[char]) -> Vec<String> {
+        let mut tokens = Vec::new();
+        let mut end = chars.len();
+        while end > 0 {
+            let mut len = self.max_word_chars.min(end);
+            while len > 1 && !self.dictionary.contains(&chars[end - len..end].iter().collect::<String>()) {
+                len -= 1;
+            }
+            tokens.push(chars[end - len..end].iter().collect());
+            end -= len;
+        }
+        tokens.reverse();
+        tokens
+    }
+}
+
+impl SegmentedText {
+    /// Whether the text contains a term
+    ///
+    /// Han terms must start and end on word boundaries, so `数据` does not
+    /// match inside `数据库`; Latin terms, including multi-word phrases, must
+    /// match whole words
+    pub fn contains_term(&self, term: &str) -> bool {
+        let term = Segmenter::normalize(term);
+        let term = term.trim();
+        if term.is_empty() {
+            return false;
+        }
+        if term.chars().any(is_han) {
+            return self
+                .normalized
+                .match_indices(term)
+                .any(|(start, _)| self.boundaries.contains(&start) && self.boundaries.contains(&(start + term.len())));
+        }
+
+        let words: Vec<&str> = term.split_whitespace().collect();
+        self.tokens
+            .windows(words.len())
+            .any(|window| window.iter().zip(&words).all(|(token, word)| token == word))
+    }
+
+    /// Number of words in the text
+    pub fn word_count(&self) -> usize {
+        self.tokens.len()
+    }
+}
+
+/// CJK unified ideographs, including extension A
+fn is_han(c: char) -> bool {
+    matches!(c, '\u{4E00}'..='\u{9FFF}' | '\u{3400}'..='\u{4DBF}')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_full_width() {
+        assert_eq!(Segmenter::normalize("ＲＵＳＴ　１２３，Ｃ＋＋"), "rust 123,c++");
+    }
+
+    #[test]
+    fn test_segment_mixed_text() {
+        let tokens = Segmenter::global().segment("负责分布式存储系统开发，熟悉Rust和C++；使用node.js。");
+        assert_eq!(
+            tokens,
+            vec!["负责", "分布式", "存储", "系统", "开发", "熟悉", "rust", "和", "c++", "使用", "node.js"]
+        );
+    }
+
+    #[test]
+    fn test_keywords_skip_stop_words() {
+        let keywords = Segmenter::global().keywords("熟悉 Rust 的分布式系统，有 5 年以上 Rust 经验 and Go");
+        assert_eq!(keywords, vec!["rust", "分布式", "系统", "经验", "go"]);
+    }
+
+    #[test]
+    fn test_contains_term_respects_word_boundaries() {
+        let text = Segmenter::global().analyze("Then I led the migration to Kubernetes，项目中延迟降低了30%");
+        assert!(text.contains_term("i led"));
+        assert!(text.contains_term("kubernetes"));
+        assert!(text.contains_term("延迟"));
+        assert!(!text.contains_term("go"));
+        assert!(!text.contains_term("the project"));
+    }
+
+    #[test]
+    fn test_custom_words() {
+        let segmenter = Segmenter::with_words(["向量检索"]);
+        assert_eq!(segmenter.segment("向量检索"), vec!["向量检索"]);
+        assert_eq!(Segmenter::global().word_count("向量检索"), 2);
+    }
+
+    #[test]
+    fn test_unknown_han_runs_become_bigrams() {
+        let segmenter = Segmenter::global();
+        assert_eq!(segmenter.segment("使用向量检索的方案"), vec!["使用", "向量", "检索", "的", "方案"]);
+        assert_eq!(segmenter.segment("鲁棒"), vec!["鲁棒"]);
+
+        let text = segmenter.analyze("熟悉数据库和向量检索");
+        assert!(text.contains_term("数据库"));
+        assert!(!text.contains_term("数据"));
+        assert!(text.contains_term("检索"));
+        assert!(text.contains_term("向量检索"));
+    }
+}
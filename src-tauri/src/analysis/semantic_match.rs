@@ -0,0 +1,212 @@
+//! Semantic job-match scoring with the local embedding model
+//!
+//! Splits the job description into requirement sentences and the answer into
+//! sentences, embeds both with the bundled `bge-small-zh` model and scores
+//! each requirement by its most similar answer sentence. Runs fully offline.
+
+use crate::rag::RagService;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+
+/// Similarity at or above which a requirement counts as addressed
+const COVERED_SIMILARITY: f32 = 0.65;
+
+/// Similarity at or below which a requirement counts as not touched at all
+const UNRELATED_SIMILARITY: f32 = 0.4;
+
+/// Minimum characters for a requirement or answer sentence
+const MIN_SENTENCE_CHARS: usize = 4;
+
+/// Sentence delimiters for JD requirements and answers
+const SENTENCE_DELIMITERS: &[char] = &['\n', '。', '；', ';', '！', '？', '!', '?'];
+
+/// Coverage of one JD requirement by the answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequirementCoverage {
+    pub requirement: String,
+    pub similarity: f32,
+    pub best_sentence: Option<String>,
+    pub addressed: bool,
+}
+
+/// Per-requirement coverage and the resulting semantic job-match score
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SemanticMatchResult {
+    pub score: f32,                      // 1-10
+    pub coverage: Vec<RequirementCoverage>,
+    pub addressed: Vec<String>,
+    pub ignored: Vec<String>,
+}
+
+/// Embedding-based matcher between JD requirements and answer sentences
+pub struct SemanticMatcher;
+
+impl SemanticMatcher {
+    /// Embed JD requirements and answer sentences and match them
+    ///
+    /// Requirements come from the structured JD or `split_requirements`.
+    /// Fails when the embedding model is unavailable or either side has no usable sentence
+    pub async fn analyze(rag: &RagService, answer: &str, requirements: Vec<String>) -> Result<SemanticMatchResult> {
+        let sentences = Self::split_sentences(answer);
+        if requirements.is_empty() || sentences.is_empty() {
+            return Err(anyhow!("Nothing to match: JD or answer has no sentences"));
+        }
+
+        let requirement_embeddings = rag.embed_texts(&requirements).await?;
+        let sentence_embeddings = rag.embed_texts(&sentences).await?;
+
+        Ok(Self::match_embeddings(
+            &requirements,
+            &requirement_embeddings,
+            &sentences,
+            &sentence_embeddings,
+        ))
+    }
+
+    /// Score each requirement by its most similar answer sentence
+    pub fn match_embeddings(
+        requirements: &[String],
+        requirement_embeddings: &[Vec<f32>],
+        sentences: &[String],
+        sentence_embeddings: &[Vec<f32>],
+    ) -> SemanticMatchResult {
+        let coverage: Vec<RequirementCoverage> = requirements
+            .iter()
+            .zip(requirement_embeddings)
+            .map(|(requirement, requirement_embedding)| {
+                let best = sentences
+                    .iter()
+                    .zip(sentence_embeddings)
+                    .map(|(sentence, embedding)| (sentence, Self::cosine_similarity(requirement_embedding, embedding)))
+                    .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+
+                let similarity = best.map(|(_, s)| s).unwrap_or(0.0);
+                RequirementCoverage {
+                    requirement: requirement.clone(),
+                    similarity,
+                    best_sentence: best.map(|(sentence, _)| sentence.clone()),
+                    addressed: similarity >= COVERED_SIMILARITY,
+                }
+            })
+            .collect();
+
+        // Linear credit between "unrelated" and "covered" similarity
+        let credit = |similarity: f32| {
+            ((similarity - UNRELATED_SIMILARITY) / (COVERED_SIMILARITY - UNRELATED_SIMILARITY)).clamp(0.0, 1.0)
+        };
+        let mean_credit = if coverage.is_empty() {
+            0.0
+        } else {
+            coverage.iter().map(|c| credit(c.similarity)).sum::<f32>() / coverage.len() as f32
+        };
+
+        let (addressed, ignored): (Vec<&RequirementCoverage>, Vec<&RequirementCoverage>) =
+            coverage.iter().partition(|c| c.addressed);
+
+        SemanticMatchResult {
+            score: mean_credit * 8.0 + 2.0,
+            addressed: addressed.into_iter().map(|c| c.requirement.clone()).collect(),
+            ignored: ignored.into_iter().map(|c| c.requirement.clone()).collect(),
+            coverage,
+        }
+    }
+
+    /// Split a JD into requirement sentences, dropping bullets, numbering and headings
+    pub fn split_requirements(job_description: &str) -> Vec<String> {
+        Self::split(job_description)
+            .into_iter()
+            .filter(|s| !s.ends_with(':') && !s.ends_with('：'))
+            .collect()
+    }
+
+    /// Split an answer into sentences
+    pub fn split_sentences(answer: &str) -> Vec<String> {
+        Self::split(answer)
+    }
+
+    /// Cosine similarity of two vectors, 0 for mismatched or zero vectors
+    pub fn cosine_similarity(a: &[f32], b: &[f32]) -> f32 {
+        if a.len() != b.len() || a.is_empty() {
+            return 0.0;
+        }
+        let dot: f32 = a.iter().zip(b).map(|(x, y)| x * y).sum();
+        let norm_a = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+        let norm_b = b.iter().map(|x| x * x).sum::<f32>().sqrt();
+        if norm_a == 0.0 || norm_b == 0.0 {
+            0.0
+        } else {
+            dot / (norm_a * norm_b)
+        }
+    }
+
+    fn split(text: &str) -> Vec<String> {
+        text.split(SENTENCE_DELIMITERS)
+            .flat_map(|s| s.split(". "))
+            .map(Self::strip_bullet)
+            .filter(|s| s.chars().count() >= MIN_SENTENCE_CHARS)
+            .map(|s| s.to_string())
+            .collect()
+    }
+
+    /// Remove list markers such as "1.", "2、", "(3)", "-", "•"
+    pub(super) fn strip_bullet(line: &str) -> &str {
+        let line = line.trim().trim_start_matches(['-', '*', '•', '·']).trim_start();
+        let marker_end = line
+            .char_indices()
+            .find(|(_, c)| !(c.is_ascii_digit() || matches!(c, '(' | ')' | '（' | '）')))
+            .map(|(i, _)| i)
+            .unwrap_or(line.len());
+        if marker_end > 0 {
+            // "3.5年经验" is a number, not a list marker
+            if let Some(rest) = line[marker_end..].strip_prefix(['.', '、', ')', '）']) {
+                if !rest.starts_with(|c: char| c.is_ascii_digit()) {
+                    return rest.trim();
+                }
+            }
+            if line[..marker_end].ends_with([')', '）']) {
+                return line[marker_end..].trim();
+            }
+        }
+        line.trim_end_matches(['.', ','])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_requirements() {
+        let jd = "岗位要求：\n1. 熟悉Rust或Go语言\n2、有分布式存储开发经验；(3) 良好的沟通能力\n- Experience with Kubernetes.";
+        assert_eq!(
+            SemanticMatcher::split_requirements(jd),
+            vec!["熟悉Rust或Go语言", "有分布式存储开发经验", "良好的沟通能力", "Experience with Kubernetes"]
+        );
+    }
+
+    #[test]
+    fn test_match_embeddings() {
+        let requirements = vec!["Rust".to_string(), "沟通".to_string()];
+        let sentences = vec!["我用Rust写过存储引擎".to_string()];
+
+        let result = SemanticMatcher::match_embeddings(
+            &requirements,
+            &[vec![1.0, 0.0], vec![0.0, 1.0]],
+            &sentences,
+            &[vec![0.9, 0.1]],
+        );
+
+        assert_eq!(result.addressed, vec!["Rust"]);
+        assert_eq!(result.ignored, vec!["沟通"]);
+        assert_eq!(result.coverage[0].best_sentence.as_deref(), Some("我用Rust写过存储引擎"));
+        // One requirement fully covered, one unrelated
+        assert!((result.score - 6.0).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_cosine_similarity_edge_cases() {
+        assert_eq!(SemanticMatcher::cosine_similarity(&[1.0, 0.0], &[1.0]), 0.0);
+        assert_eq!(SemanticMatcher::cosine_similarity(&[0.0, 0.0], &[1.0, 0.0]), 0.0);
+        assert!((SemanticMatcher::cosine_similarity(&[1.0, 1.0], &[2.0, 2.0]) - 1.0).abs() < 1e-6);
+    }
+}
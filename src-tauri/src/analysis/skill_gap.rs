@@ -0,0 +1,528 @@
+//! Resume vs job description skill-gap analysis
+//!
+//! Extracts skills, tools, years of experience and seniority signals from both
+//! texts, normalizes synonyms through the skill taxonomy ("k8s" = "Kubernetes",
+//! "golang" = "Go") and reports what the resume covers, misses or exceeds, each
+//! with the line it was found on. A more specific resume skill satisfies a
+//! broader requirement ("Redis" covers "缓存").
+
+use super::jd_parser::{StructuredJobDescription, REQUIREMENT_MARKERS};
+use super::segmentation::Segmenter;
+use super::taxonomy::{SkillCategory, SkillTaxonomy};
+use chrono::Datelike;
+use serde::{Deserialize, Serialize};
+
+/// Candidate years at least this far above the requirement count as overqualified
+const OVERQUALIFIED_YEARS: f32 = 5.0;
+
+/// Upper bound for a plausible "N years" statement
+const MAX_PLAUSIBLE_YEARS: f32 = 40.0;
+
+/// What a gap item describes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GapCategory {
+    Skill,
+    Tool,
+    Experience,
+    Seniority,
+}
+
+impl GapCategory {
+    /// Gap category of a taxonomy skill: concrete technologies are tools
+    fn of(category: SkillCategory) -> Self {
+        match category {
+            SkillCategory::Framework | SkillCategory::Database | SkillCategory::Tool | SkillCategory::Platform => Self::Tool,
+            SkillCategory::Language | SkillCategory::Concept | SkillCategory::Soft => Self::Skill,
+        }
+    }
+}
+
+/// Seniority levels, lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Seniority {
+    Intern,
+    Junior,
+    Mid,
+    Senior,
+    Lead,
+}
+
+impl Seniority {
+    const SIGNALS: &'static [(Seniority, &'static [&'static str])] = &[
+        (Seniority::Intern, &["实习", "intern", "internship"]),
+        (Seniority::Junior, &["初级", "应届", "junior", "entry level", "graduate"]),
+        (Seniority::Mid, &["中级", "mid-level", "intermediate"]),
+        (Seniority::Senior, &["高级", "资深", "senior"]),
+        // "lead" and "staff" alone are too often a verb or a noun ("I lead the sync", "support staff")
+        (Seniority::Lead, &[
+            "专家", "架构师", "技术负责人", "技术经理", "主管", "总监", "tech lead", "team lead", "lead engineer",
+            "lead developer", "staff engineer", "staff software engineer", "principal", "architect", "head of",
+        ]),
+    ];
+
+    /// Level implied by years of experience when no title says otherwise
+    fn from_years(years: f32) -> Self {
+        match years {
+            y if y >= 8.0 => Self::Lead,
+            y if y >= 5.0 => Self::Senior,
+            y if y >= 2.0 => Self::Mid,
+            y if y >= 1.0 => Self::Junior,
+            _ => Self::Intern,
+        }
+    }
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            Self::Intern => "实习",
+            Self::Junior => "初级",
+            Self::Mid => "中级",
+            Self::Senior => "高级",
+            Self::Lead => "专家/负责人",
+        }
+    }
+}
+
+/// One compared item with the lines it was found on
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GapItem {
+    pub category: GapCategory,
+    pub name: String,
+    pub required: Option<String>,        // Requirement for experience and seniority items
+    pub candidate: Option<String>,       // Candidate value for experience and seniority items
+    pub jd_evidence: Option<String>,
+    pub resume_evidence: Option<String>,
+    #[serde(default)]
+    pub optional: bool,                  // Only asked for as a nice-to-have
+}
+
+/// Structured comparison of a resume against a job description
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SkillGapReport {
+    pub matched: Vec<GapItem>,
+    pub missing: Vec<GapItem>,
+    pub overqualified: Vec<GapItem>,
+    pub additional: Vec<GapItem>,        // Resume skills and tools the JD does not ask for
+    pub match_rate: f32,                 // 0-100: JD skills and tools found in the resume
+}
+
+impl SkillGapReport {
+    /// Prompt paragraph steering question generation towards the gaps
+    pub fn question_focus(&self) -> Option<String> {
+        if self.missing.is_empty() {
+            return None;
+        }
+        // Must-have gaps first, nice-to-haves last
+        let mut missing: Vec<&GapItem> = self.missing.iter().collect();
+        missing.sort_by_key(|item| item.optional);
+        let gaps: Vec<String> = missing
+            .into_iter()
+            .map(|item| match (&item.required, &item.candidate) {
+                (Some(required), Some(candidate)) => format!("{}（要求{}，简历{}）", item.name, required, candidate),
+                (Some(required), None) => format!("{}（要求{}，简历未体现）", item.name, required),
+                _ if item.optional => format!("{}（加分项）", item.name),
+                _ => item.name.clone(),
+            })
+            .collect();
+
+        Some(format!(
+            "简历与岗位要求的差距：{}。请优先针对这些差距提问，考察候选人是否具备相关能力或可迁移的经验。",
+            gaps.join("、")
+        ))
+    }
+}
+
+/// A skill, tool, experience or seniority signal found in one text
+#[derive(Debug, Clone)]
+pub(super) struct Signal<T> {
+    pub(super) value: T,
+    pub(super) evidence: String,
+}
+
+/// Extracts and compares skills, experience and seniority
+pub struct SkillGapAnalyzer;
+
+impl SkillGapAnalyzer {
+    /// Compare a resume against the structured fields of a job description
+    ///
+    /// Required skills, years and seniority come from `structured`, which may be
+    /// LLM-refined; the JD text only supplies evidence lines. Skills mentioned
+    /// only in nice-to-have requirements are marked optional.
+    pub fn analyze(
+        resume: &str,
+        job_description: &str,
+        structured: &StructuredJobDescription,
+    ) -> SkillGapReport {
+        let segmenter = Segmenter::global();
+        let taxonomy = SkillTaxonomy::global();
+        let resume_skills = Self::extract_skills(resume);
+        let mut report = SkillGapReport::default();
+        let mut required_names: Vec<String> = Vec::new();
+
+        for skill in &structured.tech_stack {
+            // Known skills match by any alias, refined additions by their own name
+            let (name, category, aliases) = match taxonomy.resolve(skill) {
+                Some(known) => (
+                    known.name.clone(),
+                    GapCategory::of(known.category),
+                    std::iter::once(known.name.clone()).chain(known.aliases.iter().cloned()).collect(),
+                ),
+                None => (skill.trim().to_string(), GapCategory::Tool, vec![skill.trim().to_string()]),
+            };
+            if name.is_empty() || required_names.contains(&name) {
+                continue;
+            }
+            let mentions = |line: &str| {
+                let analyzed = segmenter.analyze(line);
+                aliases.iter().any(|alias| analyzed.contains_term(alias))
+            };
+
+            let resume_evidence = match resume_skills
+                .iter()
+                .find(|s| s.value.0 == name || taxonomy.is_a(&s.value.0, &name))
+            {
+                Some(found) => Some(found.evidence.clone()),
+                None => Self::lines(resume).find(|line| mentions(line)).map(str::to_string),
+            };
+            let item = GapItem {
+                category,
+                name: name.clone(),
+                required: None,
+                candidate: None,
+                jd_evidence: Self::lines(job_description).find(|line| mentions(line)).map(str::to_string),
+                resume_evidence,
+                optional: structured.nice_to_have.iter().any(|line| mentions(line))
+                    && !structured.must_have.iter().any(|line| mentions(line)),
+            };
+            if item.resume_evidence.is_some() {
+                report.matched.push(item);
+            } else {
+                report.missing.push(item);
+            }
+            required_names.push(name);
+        }
+
+        report.additional = resume_skills
+            .iter()
+            .filter(|s| !required_names.iter().any(|name| *name == s.value.0 || taxonomy.is_a(&s.value.0, name)))
+            .map(|s| GapItem {
+                category: s.value.1,
+                name: s.value.0.clone(),
+                required: None,
+                candidate: None,
+                jd_evidence: None,
+                resume_evidence: Some(s.evidence.clone()),
+                optional: false,
+            })
+            .collect();
+
+        report.match_rate = if required_names.is_empty() {
+            0.0
+        } else {
+            report.matched.len() as f32 / required_names.len() as f32 * 100.0
+        };
+
+        let required_years = structured.years_experience.map(|value| Signal {
+            value,
+            evidence: Self::extract_years(job_description)
+                .filter(|s| s.value == value)
+                .map(|s| s.evidence)
+                .unwrap_or_default(),
+        });
+        let candidate_years = Self::extract_years(resume).or_else(|| Self::years_from_dates(resume));
+        Self::compare_experience(&mut report, required_years.as_ref(), candidate_years.as_ref());
+
+        let required_level = structured.seniority.map(|value| Signal {
+            value,
+            evidence: Self::extract_seniority(job_description)
+                .filter(|s| s.value == value)
+                .map(|s| s.evidence)
+                .unwrap_or_default(),
+        });
+        let candidate_level = Self::extract_seniority(resume).or_else(|| {
+            candidate_years.as_ref().map(|y| Signal {
+                value: Seniority::from_years(y.value),
+                evidence: y.evidence.clone(),
+            })
+        });
+        Self::compare_seniority(&mut report, required_level.as_ref(), candidate_level.as_ref());
+
+        report
+    }
+
+    fn compare_experience(report: &mut SkillGapReport, required: Option<&Signal<f32>>, candidate: Option<&Signal<f32>>) {
+        let Some(required) = required else {
+            return;
+        };
+        let item = GapItem {
+            category: GapCategory::Experience,
+            name: "工作年限".to_string(),
+            required: Some(format!("{}年以上", required.value)),
+            candidate: candidate.map(|c| format!("{}年", c.value)),
+            jd_evidence: (!required.evidence.is_empty()).then(|| required.evidence.clone()),
+            resume_evidence: candidate.map(|c| c.evidence.clone()),
+            optional: false,
+        };
+        match candidate {
+            Some(c) if c.value >= required.value + OVERQUALIFIED_YEARS => report.overqualified.push(item),
+            Some(c) if c.value >= required.value => report.matched.push(item),
+            _ => report.missing.push(item),
+        }
+    }
+
+    fn compare_seniority(
+        report: &mut SkillGapReport,
+        required: Option<&Signal<Seniority>>,
+        candidate: Option<&Signal<Seniority>>,
+    ) {
+        let (Some(required), Some(candidate)) = (required, candidate) else {
+            return;
+        };
+        let item = GapItem {
+            category: GapCategory::Seniority,
+            name: "职级".to_string(),
+            required: Some(required.value.label().to_string()),
+            candidate: Some(candidate.value.label().to_string()),
+            jd_evidence: (!required.evidence.is_empty()).then(|| required.evidence.clone()),
+            resume_evidence: Some(candidate.evidence.clone()),
+            optional: false,
+        };
+        match candidate.value.cmp(&required.value) {
+            std::cmp::Ordering::Greater => report.overqualified.push(item),
+            std::cmp::Ordering::Equal => report.matched.push(item),
+            std::cmp::Ordering::Less => report.missing.push(item),
+        }
+    }
+
+    /// Taxonomy skills in order of first appearance
+    pub(super) fn extract_skills(text: &str) -> Vec<Signal<(String, GapCategory)>> {
+        let segmenter = Segmenter::global();
+        let taxonomy = SkillTaxonomy::global();
+        let lines: Vec<(&str, _)> = Self::lines(text).map(|line| (line, segmenter.analyze(line))).collect();
+
+        let mut found: Vec<(usize, Signal<(String, GapCategory)>)> = taxonomy
+            .skills()
+            .iter()
+            .filter_map(|skill| {
+                lines
+                    .iter()
+                    .position(|(_, analyzed)| taxonomy.mentions(analyzed, skill))
+                    .map(|index| {
+                        (index, Signal {
+                            value: (skill.name.clone(), GapCategory::of(skill.category)),
+                            evidence: lines[index].0.to_string(),
+                        })
+                    })
+            })
+            .collect();
+        found.sort_by_key(|(index, _)| *index);
+        found.into_iter().map(|(_, signal)| signal).collect()
+    }
+
+    /// Largest explicit "N年" / "N years" statement
+    ///
+    /// For ranges such as "3-5年" the lower bound is the requirement
+    pub(super) fn extract_years(text: &str) -> Option<Signal<f32>> {
+        let mut best: Option<Signal<f32>> = None;
+
+        for line in Self::lines(text) {
+            let lower = line.to_lowercase();
+            let chars: Vec<char> = lower.chars().collect();
+            let mut i = 0;
+            while i < chars.len() {
+                let Some((value, end)) = Self::number_at(&chars, i) else {
+                    i += 1;
+                    continue;
+                };
+                let mut after = end;
+                while after < chars.len() && matches!(chars[after], ' ' | '+' | '＋') {
+                    after += 1;
+                }
+                let rest: String = chars[after..].iter().take(10).collect();
+                let is_years = rest.starts_with('年') && !rest.starts_with("年前") && !rest.starts_with("年内")
+                    || rest.starts_with("year")
+                    || rest.starts_with("yrs");
+
+                // "3-5年": the number before the dash is the one that counts
+                let range_start = (i >= 2 && matches!(chars[i - 1], '-' | '~' | '～' | '至' | '到'))
+                    .then(|| Self::number_ending_at(&chars, i - 1))
+                    .flatten();
+
+                if is_years && value > 0.0 && value <= MAX_PLAUSIBLE_YEARS {
+                    let value = range_start.unwrap_or(value);
+                    if best.as_ref().is_none_or(|b| value > b.value) {
+                        best = Some(Signal { value, evidence: line.to_string() });
+                    }
+                }
+                i = end;
+            }
+        }
+
+        best
+    }
+
+    /// Years between the earliest and latest year of date ranges such as "2018.03 - 至今"
+    fn years_from_dates(text: &str) -> Option<Signal<f32>> {
+        let current_year = chrono::Local::now().year();
+        let mut earliest: Option<(i32, String)> = None;
+        let mut latest = i32::MIN;
+
+        for line in Self::lines(text) {
+            let lower = line.to_lowercase();
+            let is_range = ["-", "–", "~", "～", "至", "到"].iter().any(|m| lower.contains(m));
+            if !is_range {
+                continue;
+            }
+            let chars: Vec<char> = lower.chars().collect();
+            let years: Vec<i32> = (0..chars.len())
+                .filter_map(|i| {
+                    let (value, end) = Self::number_at(&chars, i)?;
+                    (end - i == 4 && (i == 0 || !chars[i - 1].is_ascii_digit())).then_some(value as i32)
+                })
+                .filter(|y| (1980..=current_year).contains(y))
+                .collect();
+            let Some(&first) = years.iter().min() else {
+                continue;
+            };
+
+            let mut last = years.iter().copied().max().unwrap_or(first);
+            if ["至今", "今", "present", "now"].iter().any(|m| lower.contains(m)) {
+                last = current_year;
+            }
+            latest = latest.max(last);
+            if earliest.as_ref().is_none_or(|(e, _)| first < *e) {
+                earliest = Some((first, line.to_string()));
+            }
+        }
+
+        let (first, evidence) = earliest?;
+        let years = (latest - first) as f32;
+        (years > 0.0).then_some(Signal { value: years, evidence })
+    }
+
+    /// Seniority signal, preferring the title line, then requirement lines
+    ///
+    /// A JD for a senior engineer that mentions reporting to a director is
+    /// still a senior role; the highest level wins only within the same tier
+    pub(super) fn extract_seniority(text: &str) -> Option<Signal<Seniority>> {
+        let segmenter = Segmenter::global();
+        // (tier, signal): 0 = title line, 1 = requirement line, 2 = anything else
+        let mut best: Option<(usize, Signal<Seniority>)> = None;
+
+        for (index, line) in Self::lines(text).enumerate() {
+            let analyzed = segmenter.analyze(line);
+            let lower = line.to_lowercase();
+            let tier = match index {
+                0 => 0,
+                _ if REQUIREMENT_MARKERS.iter().any(|m| lower.contains(m)) => 1,
+                _ => 2,
+            };
+            for (level, signals) in Seniority::SIGNALS {
+                let better = best
+                    .as_ref()
+                    .is_none_or(|(best_tier, b)| tier < *best_tier || (tier == *best_tier && *level > b.value));
+                if better && signals.iter().any(|s| analyzed.contains_term(s)) {
+                    best = Some((tier, Signal { value: *level, evidence: line.to_string() }));
+                }
+            }
+        }
+
+        best.map(|(_, signal)| signal)
+    }
+
+    /// Integer starting at `i` (digits or a single Chinese numeral), with its end index
+    fn number_at(chars: &[char], i: usize) -> Option<(f32, usize)> {
+        const CHINESE: &str = "一二三四五六七八九十";
+        if chars[i].is_ascii_digit() {
+            if i > 0 && chars[i - 1].is_ascii_digit() {
+                return None;
+            }
+            let end = (i..chars.len()).find(|&j| !chars[j].is_ascii_digit()).unwrap_or(chars.len());
+            let value: String = chars[i..end].iter().collect();
+            return value.parse().ok().map(|v| (v, end));
+        }
+        CHINESE
+            .chars()
+            .position(|c| c == chars[i])
+            .map(|index| ((index + 1) as f32, i + 1))
+    }
+
+    /// Integer ending right before `end`
+    fn number_ending_at(chars: &[char], end: usize) -> Option<f32> {
+        let start = (0..end).rev().take_while(|&j| chars[j].is_ascii_digit()).last()?;
+        chars[start..end].iter().collect::<String>().parse().ok()
+    }
+
+    fn lines(text: &str) -> impl Iterator<Item = &str> {
+        text.split(['\n', '。', '；', ';'])
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::jd_parser::JobDescriptionParser;
+
+    const JD: &str = "高级后端工程师\n要求：3-5年后端开发经验\n熟悉Golang或Rust，熟悉K8s和Docker\n有分布式系统经验者优先";
+
+    #[test]
+    fn test_skills_with_synonyms_and_evidence() {
+        let resume = "2016-2019 某公司 Go开发\n使用 kubernetes 部署微服务，维护 MySQL 集群";
+        let report = SkillGapAnalyzer::analyze(resume, JD, &JobDescriptionParser::parse(JD));
+
+        let names = |items: &[GapItem]| items.iter().map(|i| i.name.clone()).collect::<Vec<_>>();
+        assert!(names(&report.matched).contains(&"Go".to_string()));
+        assert!(names(&report.matched).contains(&"Kubernetes".to_string()));
+        assert!(names(&report.missing).contains(&"Docker".to_string()));
+        assert!(names(&report.missing).contains(&"分布式系统".to_string()));
+        assert!(names(&report.additional).contains(&"MySQL".to_string()));
+        assert!(report.missing.iter().find(|i| i.name == "分布式系统").unwrap().optional);
+        assert!(!report.missing.iter().find(|i| i.name == "Docker").unwrap().optional);
+
+        let kubernetes = report.matched.iter().find(|i| i.name == "Kubernetes").unwrap();
+        assert_eq!(kubernetes.jd_evidence.as_deref(), Some("熟悉Golang或Rust，熟悉K8s和Docker"));
+        assert_eq!(kubernetes.resume_evidence.as_deref(), Some("使用 kubernetes 部署微服务，维护 MySQL 集群"));
+    }
+
+    #[test]
+    fn test_experience_and_seniority() {
+        let junior = SkillGapAnalyzer::analyze("初级工程师，2年Rust开发经验", JD, &JobDescriptionParser::parse(JD));
+        let experience = junior.missing.iter().find(|i| i.category == GapCategory::Experience).unwrap();
+        assert_eq!(experience.required.as_deref(), Some("3年以上"));
+        assert_eq!(experience.candidate.as_deref(), Some("2年"));
+        assert!(junior.missing.iter().any(|i| i.category == GapCategory::Seniority));
+
+        let veteran = SkillGapAnalyzer::analyze("技术总监，12 years of experience with Rust", JD, &JobDescriptionParser::parse(JD));
+        assert!(veteran.overqualified.iter().any(|i| i.category == GapCategory::Experience));
+        assert!(veteran.overqualified.iter().any(|i| i.category == GapCategory::Seniority));
+
+        let focus = junior.question_focus().unwrap();
+        assert!(focus.contains("工作年限（要求3年以上，简历2年）"));
+        assert!(focus.contains("Docker"));
+    }
+
+    #[test]
+    fn test_short_aliases_and_seniority_match_whole_words() {
+        let skills = SkillGapAnalyzer::extract_skills("用 Google 文档整理 tsconfig 和 JSON，写过 HTML 页面\nI lead the weekly sync with support staff");
+        assert!(skills.is_empty(), "{:?}", skills.iter().map(|s| &s.value.0).collect::<Vec<_>>());
+        assert!(SkillGapAnalyzer::extract_seniority("I lead the weekly sync with support staff").is_none());
+
+        let skills = SkillGapAnalyzer::extract_skills("熟悉go和TS，了解 ML");
+        let names: Vec<&str> = skills.iter().map(|s| s.value.0.as_str()).collect();
+        assert_eq!(names, vec!["Go", "TypeScript", "机器学习"]);
+    }
+
+    #[test]
+    fn test_seniority_prefers_title_and_requirements() {
+        let jd = "高级后端工程师\n团队介绍：向技术总监汇报\n要求：3年以上经验，初级同学可以实习转正";
+        let seniority = SkillGapAnalyzer::extract_seniority(jd).unwrap();
+        assert_eq!(seniority.value, Seniority::Senior);
+        assert_eq!(seniority.evidence, "高级后端工程师");
+
+        let seniority = SkillGapAnalyzer::extract_seniority("后端工程师\n向技术总监汇报\n要求：资深后端经验").unwrap();
+        assert_eq!(seniority.value, Seniority::Senior);
+    }
+}
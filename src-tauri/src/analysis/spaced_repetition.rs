@@ -0,0 +1,331 @@
+//! Spaced-repetition review scheduling for the question bank
+//!
+//! Follows SM-2. Every analyzed practice attempt of a bank question is a
+//! review, graded 0-5 from the answer's stored overall score (0-10):
+//!
+//! | Score  | Grade |
+//! |--------|-------|
+//! | 9-10   | 5     |
+//! | 8-9    | 4     |
+//! | 6-8    | 3     |
+//! | 4-6    | 2     |
+//! | 2-4    | 1     |
+//! | 0-2    | 0     |
+//!
+//! A grade of 3 or more is a successful recall: the interval goes to 1 day,
+//! then 6 days, then grows by the ease factor. Lower grades are lapses that
+//! reset the interval to one day. The ease factor moves with every grade and
+//! never drops below 1.3. Passing a question again before it is due keeps its
+//! schedule, so a burst of same-day drills does not push it months out.
+//!
+//! The stored overall score can change after the review was recorded, when the
+//! LLM judge blends in or a consistency median replaces it. The question's
+//! history is then replayed with the new score so the schedule follows.
+
+use crate::db::{now, QuestionBankItem, QuestionReview, Repository};
+use anyhow::Result;
+use chrono::{DateTime, Duration, Local, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Ease factor of a question that was never reviewed
+const INITIAL_EASE: f32 = 2.5;
+
+/// Lowest ease factor, keeps hard questions from being shown every day forever
+const MIN_EASE: f32 = 1.3;
+
+/// Lowest grade that counts as a successful recall
+const PASSING_GRADE: i64 = 3;
+
+/// Never-reviewed questions added to the daily queue after the due ones
+const NEW_QUESTIONS_PER_DAY: usize = 5;
+
+/// Question in the review queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DueReview {
+    pub question: QuestionBankItem,
+    pub review: Option<QuestionReview>,  // None for questions never practiced
+    pub overdue_days: i64,
+}
+
+/// SM-2 scheduler over question bank entries
+pub struct ReviewScheduler;
+
+impl ReviewScheduler {
+    /// Record an analyzed answer as a review of the bank question it practised
+    ///
+    /// Answers saved without a bank question id fall back to the bank entry with
+    /// the same text. An answer that was already recorded is re-graded instead of
+    /// reviewed twice.
+    ///
+    /// # Returns
+    /// * `Ok(Some(QuestionReview))` - Updated schedule of the bank question
+    /// * `Ok(None)` - The answer is not analyzed or its question is not in the bank
+    pub fn record_answer(repository: &Repository, answer_id: i64) -> Result<Option<QuestionReview>> {
+        let Some(analysis) = repository.get_answer_analysis(answer_id)? else {
+            return Ok(None);
+        };
+        if let Some(question_id) = repository.update_review_score(answer_id, analysis.overall_score)? {
+            return Self::replay(repository, question_id);
+        }
+
+        let Some(answer) = repository.get_answer(answer_id)? else {
+            return Ok(None);
+        };
+        let question_id = match answer.question_id {
+            Some(question_id) => Some(question_id),
+            None => repository.find_bank_question(&answer.question)?,
+        };
+        match question_id {
+            Some(question_id) => {
+                Self::record_review(repository, question_id, Some(answer_id), analysis.overall_score).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Grade an attempt of a bank question and store its next review
+    ///
+    /// For attempts scored outside `answer_analysis`, such as multi-agent turns.
+    pub fn record_review(
+        repository: &Repository,
+        question_id: i64,
+        answer_id: Option<i64>,
+        score: f32,
+    ) -> Result<QuestionReview> {
+        let previous = repository.get_question_review(question_id)?;
+        let grade = Self::grade(score);
+        let review = Self::schedule(previous.as_ref(), question_id, grade, Local::now().date_naive(), &now());
+        repository.save_question_review(&review, answer_id, score, grade)?;
+        Ok(review)
+    }
+
+    /// Re-grade a question's whole history from its stored scores, oldest first
+    fn replay(repository: &Repository, question_id: i64) -> Result<Option<QuestionReview>> {
+        let mut records = repository.get_question_history(question_id)?;
+        let mut review: Option<QuestionReview> = None;
+        for record in records.iter_mut() {
+            let reviewed_on = DateTime::parse_from_rfc3339(&record.reviewed_at)
+                .map(|reviewed_at| reviewed_at.with_timezone(&Local).date_naive())
+                .unwrap_or_else(|_| Local::now().date_naive());
+            record.grade = Self::grade(record.score);
+            let next = Self::schedule(review.as_ref(), question_id, record.grade, reviewed_on, &record.reviewed_at);
+            record.ease = next.ease;
+            record.interval_days = next.interval_days;
+            record.due_date = next.due_date.clone();
+            review = Some(next);
+        }
+        if let Some(review) = &review {
+            repository.rewrite_question_reviews(review, &records)?;
+        }
+        Ok(review)
+    }
+
+    /// Questions due today or earlier, most overdue and hardest first, followed
+    /// by a few questions that were never practiced
+    pub fn due_queue(repository: &Repository, limit: usize) -> Result<Vec<DueReview>> {
+        let today = Local::now().date_naive();
+        let reviews = repository.get_question_reviews()?;
+        let mut bank = repository.get_question_bank()?;
+        bank.sort_by_key(|item| item.id);
+
+        let mut queue: Vec<DueReview> = Vec::new();
+        for review in reviews.iter() {
+            let Ok(due) = NaiveDate::parse_from_str(&review.due_date, "%Y-%m-%d") else {
+                continue;
+            };
+            if due > today {
+                continue;
+            }
+            if let Some(item) = bank.iter().find(|item| item.id == Some(review.question_id)) {
+                queue.push(DueReview {
+                    question: item.clone(),
+                    review: Some(review.clone()),
+                    overdue_days: (today - due).num_days(),
+                });
+            }
+        }
+        queue.sort_by(|a, b| {
+            b.overdue_days.cmp(&a.overdue_days).then_with(|| {
+                let ease = |due: &DueReview| due.review.as_ref().map_or(INITIAL_EASE, |r| r.ease);
+                ease(a).total_cmp(&ease(b))
+            })
+        });
+
+        let reviewed: HashSet<i64> = reviews.iter().map(|review| review.question_id).collect();
+        queue.extend(
+            bank.into_iter()
+                .filter(|item| item.id.is_some_and(|id| !reviewed.contains(&id)))
+                .take(NEW_QUESTIONS_PER_DAY)
+                .map(|item| DueReview {
+                    question: item,
+                    review: None,
+                    overdue_days: 0,
+                }),
+        );
+
+        queue.truncate(limit);
+        Ok(queue)
+    }
+
+    /// SM-2 recall grade (0-5) of an overall score (0-10)
+    pub fn grade(score: f32) -> i64 {
+        match score {
+            s if s >= 9.0 => 5,
+            s if s >= 8.0 => 4,
+            s if s >= 6.0 => 3,
+            s if s >= 4.0 => 2,
+            s if s >= 2.0 => 1,
+            _ => 0,
+        }
+    }
+
+    /// Next schedule after a review graded `grade` on `today`
+    fn schedule(
+        previous: Option<&QuestionReview>,
+        question_id: i64,
+        grade: i64,
+        today: NaiveDate,
+        reviewed_at: &str,
+    ) -> QuestionReview {
+        let mut review = previous.cloned().unwrap_or(QuestionReview {
+            question_id,
+            ease: INITIAL_EASE,
+            interval_days: 0,
+            repetitions: 0,
+            lapses: 0,
+            due_date: today.format("%Y-%m-%d").to_string(),
+            last_reviewed_at: None,
+        });
+        review.last_reviewed_at = Some(reviewed_at.to_string());
+
+        let due = NaiveDate::parse_from_str(&review.due_date, "%Y-%m-%d").unwrap_or(today);
+        if previous.is_some() && grade >= PASSING_GRADE && today < due {
+            return review;
+        }
+
+        if grade >= PASSING_GRADE {
+            review.interval_days = match review.repetitions {
+                0 => 1,
+                1 => 6,
+                _ => ((review.interval_days as f32 * review.ease).round() as i64).max(review.interval_days + 1),
+            };
+            review.repetitions += 1;
+        } else {
+            review.interval_days = 1;
+            review.repetitions = 0;
+            review.lapses += 1;
+        }
+
+        let miss = (5 - grade) as f32;
+        review.ease = (review.ease + 0.1 - miss * (0.08 + miss * 0.02)).max(MIN_EASE);
+        review.due_date = (today + Duration::days(review.interval_days))
+            .format("%Y-%m-%d")
+            .to_string();
+        review
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_database;
+
+    fn day(date: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap()
+    }
+
+    #[test]
+    fn test_grades_and_intervals() {
+        assert_eq!(ReviewScheduler::grade(9.5), 5);
+        assert_eq!(ReviewScheduler::grade(6.0), 3);
+        assert_eq!(ReviewScheduler::grade(5.9), 2);
+        assert_eq!(ReviewScheduler::grade(0.5), 0);
+
+        // Successful recalls: 1 day, 6 days, then interval times ease
+        let first = ReviewScheduler::schedule(None, 7, 4, day("2026-03-01"), "t1");
+        assert_eq!((first.interval_days, first.repetitions, first.due_date.as_str()), (1, 1, "2026-03-02"));
+        assert!((first.ease - 2.5).abs() < 1e-6);
+        let second = ReviewScheduler::schedule(Some(&first), 7, 5, day("2026-03-02"), "t2");
+        assert_eq!((second.interval_days, second.due_date.as_str()), (6, "2026-03-08"));
+        assert!((second.ease - 2.6).abs() < 1e-6);
+        let third = ReviewScheduler::schedule(Some(&second), 7, 3, day("2026-03-08"), "t3");
+        assert_eq!(third.interval_days, 16);
+        assert!(third.ease < second.ease);
+    }
+
+    #[test]
+    fn test_lapse_and_early_review() {
+        let first = ReviewScheduler::schedule(None, 7, 5, day("2026-03-01"), "t1");
+        let second = ReviewScheduler::schedule(Some(&first), 7, 5, day("2026-03-02"), "t2");
+
+        // Passing again before the due date keeps the schedule
+        let early = ReviewScheduler::schedule(Some(&second), 7, 5, day("2026-03-03"), "t3");
+        assert_eq!((early.interval_days, early.due_date.as_str()), (6, "2026-03-08"));
+        assert_eq!(early.last_reviewed_at.as_deref(), Some("t3"));
+
+        // Fumbling it resets the interval whenever it happens
+        let lapse = ReviewScheduler::schedule(Some(&early), 7, 1, day("2026-03-03"), "t4");
+        assert_eq!((lapse.interval_days, lapse.repetitions, lapse.lapses), (1, 0, 1));
+        assert_eq!(lapse.due_date, "2026-03-04");
+        assert!(lapse.ease < early.ease);
+
+        let mut hard = lapse;
+        for _ in 0..10 {
+            hard = ReviewScheduler::schedule(Some(&hard), 7, 0, day("2026-03-04"), "t5");
+        }
+        assert!((hard.ease - MIN_EASE).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_attempts_update_queue_and_history() {
+        let repo = Repository::new(init_database(":memory:".into()).unwrap());
+        let known = repo.add_to_question_bank("介绍一个项目".to_string(), None, None, None).unwrap();
+        let fumbled = repo.add_to_question_bank("讲讲 Raft 的选主过程".to_string(), None, None, None).unwrap();
+
+        // Never-practiced questions are queued as new
+        let queue = ReviewScheduler::due_queue(&repo, 10).unwrap();
+        assert_eq!(queue.len(), 2);
+        assert!(queue.iter().all(|due| due.review.is_none()));
+
+        let review = ReviewScheduler::record_review(&repo, known, None, 9.0).unwrap();
+        assert_eq!((review.question_id, review.interval_days), (known, 1));
+        ReviewScheduler::record_review(&repo, fumbled, None, 3.0).unwrap();
+
+        // Both were reviewed today, so neither is due until tomorrow
+        assert!(ReviewScheduler::due_queue(&repo, 10).unwrap().is_empty());
+        let history = repo.get_review_history(Some(fumbled), 10).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!((history[0].grade, history[0].interval_days), (1, 1));
+        assert_eq!(repo.get_review_history(None, 10).unwrap().len(), 2);
+        assert_eq!(repo.get_question_review(fumbled).unwrap().unwrap().lapses, 1);
+    }
+
+    #[test]
+    fn test_answers_link_by_id_and_regrade() {
+        let repo = Repository::new(init_database(":memory:".into()).unwrap());
+        let question_id = repo.add_to_question_bank("介绍一个项目".to_string(), None, None, None).unwrap();
+        let session_id = repo.create_interview_session(None, None, vec!["请介绍一个你做过的项目".to_string()]).unwrap();
+        let answer_id = repo
+            .save_answer(session_id, 0, "请介绍一个你做过的项目".to_string(), "我负责存储引擎".to_string(), String::new(), None, None, Some(question_id))
+            .unwrap();
+
+        // Unanalyzed answers are not reviews yet
+        assert!(ReviewScheduler::record_answer(&repo, answer_id).unwrap().is_none());
+
+        // The bank id links the answer even though its text differs
+        repo.save_answer_analysis(answer_id, 9.0, 9.0, 9.0, 9.0, None, 9.0, "[]".into(), "[]".into(), "[]".into()).unwrap();
+        let review = ReviewScheduler::record_answer(&repo, answer_id).unwrap().unwrap();
+        assert_eq!((review.question_id, review.interval_days, review.lapses), (question_id, 1, 0));
+
+        // A lower stored score re-grades the same review instead of adding one
+        repo.update_answer_scores(answer_id, 3.0, 3.0, 3.0, 3.0).unwrap();
+        let regraded = ReviewScheduler::record_answer(&repo, answer_id).unwrap().unwrap();
+        assert_eq!((regraded.repetitions, regraded.lapses), (0, 1));
+        let history = repo.get_review_history(Some(question_id), 10).unwrap();
+        assert_eq!(history.len(), 1);
+        assert_eq!((history[0].grade, history[0].answer_id), (1, Some(answer_id)));
+        assert!((history[0].score - 3.0).abs() < 1e-6);
+        assert_eq!(repo.get_question_review(question_id).unwrap().unwrap().lapses, 1);
+    }
+}
@@ -0,0 +1,396 @@
+//! Sentence-level STAR segmentation
+//!
+//! Classifies every sentence of an answer as Situation, Task, Action, Result
+//! or filler and returns its span, so the UI can highlight exactly which part
+//! of the story is missing, thin or told out of order.
+
+use super::scoring::{
+    ACTION_KEYWORDS_EN, ACTION_KEYWORDS_ZH, RESULT_KEYWORDS_EN, RESULT_KEYWORDS_ZH,
+    SITUATION_KEYWORDS_EN, SITUATION_KEYWORDS_ZH, TASK_KEYWORDS_EN, TASK_KEYWORDS_ZH,
+};
+use super::segmentation::{SegmentedText, Segmenter};
+use crate::language::Language;
+use serde::{Deserialize, Serialize};
+
+/// Extra sentence-level cues on top of the STAR scoring keywords
+const SITUATION_CUES: &[&str] = &["公司", "项目", "那时", "当时", "when", "while", "company"];
+const TASK_CUES: &[&str] = &["目标是", "任务是", "我的职责", "要解决", "goal was", "task was", "had to"];
+const ACTION_CUES: &[&str] = &["首先", "然后", "接着", "随后", "我用", "first", "then", "next"];
+const RESULT_CUES: &[&str] = &["最终", "最后", "上线后", "倍", "in the end", "finally", "as a result"];
+
+/// Hedges and closing lines that carry no story content
+const FILLER_PHRASES: &[&str] = &[
+    "嗯", "呃", "那个", "就是说", "怎么说呢", "谢谢", "以上就是", "大概就是这样",
+    "um", "uh", "you know", "i mean", "basically", "thank you", "that's all",
+];
+
+/// Sentences shorter than this (in characters) count as filler
+const MIN_CONTENT_CHARS: usize = 4;
+
+/// Part of a STAR story a sentence belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum STARComponent {
+    Situation,
+    Task,
+    Action,
+    Result,
+    Filler,
+}
+
+impl STARComponent {
+    /// Story components in their expected order
+    pub const STORY: [STARComponent; 4] = [Self::Situation, Self::Task, Self::Action, Self::Result];
+
+    /// Minimum share of the answer (0-100) before a component counts as thin
+    ///
+    /// Action carries the story, so it is expected to take the largest share
+    pub fn min_share(&self) -> f32 {
+        match self {
+            Self::Action => 30.0,
+            Self::Filler => 0.0,
+            _ => 10.0,
+        }
+    }
+
+    /// Localized display name
+    pub fn label(&self, language: Language) -> String {
+        match self {
+            Self::Situation => language.text("情境（Situation）", "Situation"),
+            Self::Task => language.text("任务（Task）", "Task"),
+            Self::Action => language.text("行动（Action）", "Action"),
+            Self::Result => language.text("结果（Result）", "Result"),
+            Self::Filler => language.text("无关内容", "Filler"),
+        }
+    }
+
+    fn keywords(&self) -> [&'static [&'static str]; 3] {
+        match self {
+            Self::Situation => [SITUATION_KEYWORDS_ZH, SITUATION_KEYWORDS_EN, SITUATION_CUES],
+            Self::Task => [TASK_KEYWORDS_ZH, TASK_KEYWORDS_EN, TASK_CUES],
+            Self::Action => [ACTION_KEYWORDS_ZH, ACTION_KEYWORDS_EN, ACTION_CUES],
+            Self::Result => [RESULT_KEYWORDS_ZH, RESULT_KEYWORDS_EN, RESULT_CUES],
+            Self::Filler => [&[], &[], FILLER_PHRASES],
+        }
+    }
+
+    fn story_index(&self) -> usize {
+        Self::STORY.iter().position(|c| c == self).unwrap_or(Self::STORY.len())
+    }
+}
+
+/// One classified sentence
+///
+/// `start` and `end` are UTF-16 offsets into the original answer, so the
+/// frontend can `slice` the answer string directly.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct STARSpan {
+    pub component: STARComponent,
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+/// Share of the answer (0-100, by characters) spent on each component
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct STARProportions {
+    pub situation: f32,
+    pub task: f32,
+    pub action: f32,
+    pub result: f32,
+    pub filler: f32,
+}
+
+impl STARProportions {
+    pub fn get(&self, component: STARComponent) -> f32 {
+        match component {
+            STARComponent::Situation => self.situation,
+            STARComponent::Task => self.task,
+            STARComponent::Action => self.action,
+            STARComponent::Result => self.result,
+            STARComponent::Filler => self.filler,
+        }
+    }
+}
+
+/// A component that first appears before one that should precede it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct STAROrderIssue {
+    pub component: STARComponent,
+    pub appears_before: STARComponent,
+}
+
+/// Sentence-level STAR breakdown of an answer
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct STARSegmentation {
+    pub spans: Vec<STARSpan>,
+    pub proportions: STARProportions,
+    pub missing: Vec<STARComponent>,
+    pub thin: Vec<STARComponent>,
+    pub out_of_order: Vec<STAROrderIssue>,
+    pub suggestions: Vec<String>,
+}
+
+/// Sentence classifier for STAR stories
+pub struct STARSegmenter;
+
+impl STARSegmenter {
+    /// Classify each sentence and report missing, thin and out-of-order components
+    pub fn segment(answer: &str, language: Language) -> STARSegmentation {
+        let segmenter = Segmenter::global();
+        let mut spans: Vec<STARSpan> = Vec::new();
+        let mut previous: Option<STARComponent> = None;
+
+        for (start, end, sentence) in Self::split_sentences(answer) {
+            let text = segmenter.analyze(sentence);
+            let component = Self::classify(sentence, &text, previous)
+                // Sentences without cues continue the part of the story they follow
+                .or(previous)
+                .unwrap_or(STARComponent::Filler);
+            if component != STARComponent::Filler {
+                previous = Some(component);
+            }
+            spans.push(STARSpan {
+                component,
+                start,
+                end,
+                text: sentence.to_string(),
+            });
+        }
+
+        let proportions = Self::proportions(&spans);
+        let first_start = |component: STARComponent| {
+            spans.iter().find(|s| s.component == component).map(|s| s.start)
+        };
+
+        let missing: Vec<STARComponent> = STARComponent::STORY
+            .into_iter()
+            .filter(|c| first_start(*c).is_none())
+            .collect();
+        let thin: Vec<STARComponent> = STARComponent::STORY
+            .into_iter()
+            .filter(|c| !missing.contains(c) && proportions.get(*c) < c.min_share())
+            .collect();
+
+        let mut out_of_order = Vec::new();
+        for (i, earlier) in STARComponent::STORY.iter().enumerate() {
+            for later in &STARComponent::STORY[i + 1..] {
+                if let (Some(a), Some(b)) = (first_start(*earlier), first_start(*later)) {
+                    if b < a {
+                        out_of_order.push(STAROrderIssue {
+                            component: *later,
+                            appears_before: *earlier,
+                        });
+                    }
+                }
+            }
+        }
+
+        let suggestions = Self::suggestions(&missing, &thin, &out_of_order, &proportions, language);
+
+        STARSegmentation {
+            spans,
+            proportions,
+            missing,
+            thin,
+            out_of_order,
+            suggestions,
+        }
+    }
+
+    /// Best matching component, `None` when the sentence has no cue at all
+    ///
+    /// Ties prefer components that keep the story moving forward from `previous`
+    fn classify(sentence: &str, text: &SegmentedText, previous: Option<STARComponent>) -> Option<STARComponent> {
+        let hits = |component: STARComponent| {
+            component
+                .keywords()
+                .iter()
+                .flat_map(|list| list.iter())
+                .filter(|keyword| text.contains_term(keyword))
+                .count()
+        };
+
+        if sentence.chars().count() < MIN_CONTENT_CHARS {
+            return Some(STARComponent::Filler);
+        }
+
+        let mut best: Option<(STARComponent, usize)> = None;
+        let floor = previous.map(|p| p.story_index()).unwrap_or(0);
+        for component in STARComponent::STORY {
+            let mut count = hits(component);
+            // Quantified outcomes are the strongest result signal
+            if component == STARComponent::Result {
+                count += usize::from(sentence.chars().any(|c| c.is_ascii_digit()));
+                count += usize::from(sentence.contains('%') || sentence.contains('％'));
+            }
+            if count == 0 {
+                continue;
+            }
+            let better = match best {
+                None => true,
+                Some((current, current_count)) => {
+                    count > current_count
+                        || (count == current_count
+                            && current.story_index() < floor
+                            && component.story_index() >= floor)
+                }
+            };
+            if better {
+                best = Some((component, count));
+            }
+        }
+
+        match best {
+            Some((component, _)) => Some(component),
+            None if hits(STARComponent::Filler) > 0 => Some(STARComponent::Filler),
+            None => None,
+        }
+    }
+
+    /// Split into trimmed sentences with UTF-16 start and end offsets
+    fn split_sentences(answer: &str) -> Vec<(usize, usize, &str)> {
+        let mut sentences = Vec::new();
+        let mut start_byte = 0;
+        let mut chars = answer.char_indices().peekable();
+
+        while let Some((i, c)) = chars.next() {
+            let next = chars.peek().map(|(_, n)| *n);
+            let is_end = matches!(c, '。' | '！' | '？' | '!' | '?' | '；' | ';' | '\n')
+                || (c == '.' && next.is_none_or(char::is_whitespace));
+            if is_end {
+                let end_byte = i + c.len_utf8();
+                Self::push_sentence(answer, start_byte, end_byte, &mut sentences);
+                start_byte = end_byte;
+            }
+        }
+        Self::push_sentence(answer, start_byte, answer.len(), &mut sentences);
+
+        sentences
+    }
+
+    fn push_sentence<'a>(answer: &'a str, start_byte: usize, end_byte: usize, out: &mut Vec<(usize, usize, &'a str)>) {
+        let raw = &answer[start_byte..end_byte];
+        let sentence = raw.trim();
+        if sentence.is_empty() {
+            return;
+        }
+        let leading = raw.len() - raw.trim_start().len();
+        let trimmed_start = start_byte + leading;
+        let utf16 = |byte: usize| answer[..byte].encode_utf16().count();
+        let start = utf16(trimmed_start);
+        out.push((start, start + sentence.encode_utf16().count(), sentence));
+    }
+
+    fn proportions(spans: &[STARSpan]) -> STARProportions {
+        let total: usize = spans.iter().map(|s| s.text.chars().count()).sum();
+        if total == 0 {
+            return STARProportions::default();
+        }
+        let share = |component: STARComponent| {
+            let chars: usize = spans
+                .iter()
+                .filter(|s| s.component == component)
+                .map(|s| s.text.chars().count())
+                .sum();
+            chars as f32 / total as f32 * 100.0
+        };
+
+        STARProportions {
+            situation: share(STARComponent::Situation),
+            task: share(STARComponent::Task),
+            action: share(STARComponent::Action),
+            result: share(STARComponent::Result),
+            filler: share(STARComponent::Filler),
+        }
+    }
+
+    fn suggestions(
+        missing: &[STARComponent],
+        thin: &[STARComponent],
+        out_of_order: &[STAROrderIssue],
+        proportions: &STARProportions,
+        language: Language,
+    ) -> Vec<String> {
+        let mut suggestions = Vec::new();
+
+        for component in missing {
+            let label = component.label(language);
+            suggestions.push(match language {
+                Language::En => format!("The {} part is missing from your story", label),
+                _ => format!("回答中缺少{}部分", label),
+            });
+        }
+
+        for component in thin {
+            let label = component.label(language);
+            let share = proportions.get(*component);
+            suggestions.push(match language {
+                Language::En => format!("{} takes only {:.0}% of the answer, expand it", label, share),
+                _ => format!("{}部分只占回答的{:.0}%，建议展开", label, share),
+            });
+        }
+
+        for issue in out_of_order {
+            let component = issue.component.label(language);
+            let before = issue.appears_before.label(language);
+            suggestions.push(match language {
+                Language::En => format!("{} comes before {}, follow the STAR order", component, before),
+                _ => format!("{}出现在{}之前，建议按STAR顺序讲述", component, before),
+            });
+        }
+
+        if proportions.filler > 20.0 {
+            suggestions.push(match language {
+                Language::En => format!("{:.0}% of the answer is filler, cut it down", proportions.filler),
+                _ => format!("{:.0}%的内容与故事无关，建议精简", proportions.filler),
+            });
+        }
+
+        suggestions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_classifies_sentences_with_offsets() {
+        let answer = "当时项目背景是订单系统经常超时。我的任务是把延迟降下来。首先我设计了缓存方案，然后进行压测。最终延迟降低了60%。谢谢。";
+        let result = STARSegmenter::segment(answer, Language::Zh);
+
+        let components: Vec<STARComponent> = result.spans.iter().map(|s| s.component).collect();
+        assert_eq!(
+            components,
+            vec![
+                STARComponent::Situation,
+                STARComponent::Task,
+                STARComponent::Action,
+                STARComponent::Result,
+                STARComponent::Filler,
+            ]
+        );
+
+        let utf16: Vec<u16> = answer.encode_utf16().collect();
+        for span in &result.spans {
+            assert_eq!(String::from_utf16(&utf16[span.start..span.end]).unwrap(), span.text);
+        }
+        assert!(result.missing.is_empty());
+        assert!(result.out_of_order.is_empty());
+    }
+
+    #[test]
+    fn test_segment_reports_missing_and_out_of_order() {
+        let answer = "The result was that latency dropped by 40%. I implemented a cache layer and then tuned the queries.";
+        let result = STARSegmenter::segment(answer, Language::En);
+
+        assert_eq!(result.spans[0].component, STARComponent::Result);
+        assert_eq!(result.spans[1].component, STARComponent::Action);
+        assert_eq!(result.missing, vec![STARComponent::Situation, STARComponent::Task]);
+        assert_eq!(result.out_of_order.len(), 1);
+        assert_eq!(result.out_of_order[0].component, STARComponent::Result);
+        assert_eq!(result.out_of_order[0].appears_before, STARComponent::Action);
+        assert!((result.proportions.result + result.proportions.action - 100.0).abs() < 0.01);
+    }
+}
@@ -0,0 +1,276 @@
+//! Normalized skill taxonomy
+//!
+//! Canonical skill names with Chinese and English aliases, a category and an
+//! optional parent skill ("Redis" is a "缓存", "Vue" is a "前端框架"). The
+//! bundled taxonomy ships in `resources/skill_taxonomy.json`; user-defined
+//! skills are merged on top. Content analysis, gap analysis, recommendations
+//! and tagging all resolve terms through the same installed instance.
+
+use super::segmentation::{Segmenter, SegmentedText};
+use crate::db::CustomSkill;
+use anyhow::{Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Kind of skill
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillCategory {
+    Language,
+    Framework,
+    Database,
+    Tool,
+    Platform,
+    Concept,
+    Soft,
+}
+
+impl SkillCategory {
+    /// Parse a stored category name
+    pub fn parse(value: &str) -> Option<Self> {
+        serde_json::from_value(serde_json::Value::String(value.trim().to_lowercase())).ok()
+    }
+}
+
+/// One skill with its aliases and place in the hierarchy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillDefinition {
+    pub name: String,
+    pub category: SkillCategory,
+    #[serde(default)]
+    pub aliases: Vec<String>,
+    #[serde(default)]
+    pub parent: Option<String>,
+}
+
+/// Installed taxonomy: bundled skills plus user additions
+pub struct SkillTaxonomy {
+    skills: Vec<SkillDefinition>,
+    index: HashMap<String, usize>,       // Compacted alias or name -> skill position
+}
+
+fn installed() -> &'static RwLock<Arc<SkillTaxonomy>> {
+    static TAXONOMY: OnceLock<RwLock<Arc<SkillTaxonomy>>> = OnceLock::new();
+    TAXONOMY.get_or_init(|| RwLock::new(Arc::new(SkillTaxonomy::bundled())))
+}
+
+impl SkillTaxonomy {
+    /// Currently installed taxonomy
+    pub fn global() -> Arc<SkillTaxonomy> {
+        installed().read().unwrap_or_else(|e| e.into_inner()).clone()
+    }
+
+    /// Replace the installed taxonomy with the bundled one plus `custom` skills
+    pub fn install(custom: &[CustomSkill]) {
+        let taxonomy = Self::bundled().with_custom(custom);
+        *installed().write().unwrap_or_else(|e| e.into_inner()) = Arc::new(taxonomy);
+    }
+
+    /// Taxonomy bundled with the app
+    pub fn bundled() -> Self {
+        let json = include_str!("../../resources/skill_taxonomy.json");
+        let skills: Vec<SkillDefinition> = serde_json::from_str(json).expect("bundled skill taxonomy is valid JSON");
+        Self::from_skills(skills)
+    }
+
+    /// Build a taxonomy from definitions; later aliases never steal earlier ones
+    pub fn from_skills(skills: Vec<SkillDefinition>) -> Self {
+        let mut index = HashMap::new();
+        for (position, skill) in skills.iter().enumerate() {
+            for term in std::iter::once(&skill.name).chain(&skill.aliases) {
+                let key = Self::compact(term);
+                if !key.is_empty() {
+                    index.entry(key).or_insert(position);
+                }
+            }
+        }
+        Self { skills, index }
+    }
+
+    /// Merge user skills: a known name gains aliases and may move category or parent
+    ///
+    /// An empty category keeps a bundled skill's own category; new skills default to a concept.
+    pub fn with_custom(self, custom: &[CustomSkill]) -> Self {
+        let mut skills = self.skills;
+        for entry in custom {
+            let aliases: Vec<String> = serde_json::from_str(&entry.aliases).unwrap_or_default();
+            let category = SkillCategory::parse(&entry.category);
+            let parent = entry.parent.clone().filter(|p| !p.trim().is_empty());
+            match skills.iter_mut().find(|s| s.name.eq_ignore_ascii_case(&entry.name)) {
+                Some(existing) => {
+                    for alias in aliases {
+                        if !existing.aliases.iter().any(|a| a.eq_ignore_ascii_case(&alias)) {
+                            existing.aliases.push(alias);
+                        }
+                    }
+                    if let Some(category) = category {
+                        existing.category = category;
+                    }
+                    if parent.is_some() {
+                        existing.parent = parent;
+                    }
+                }
+                None => skills.push(SkillDefinition {
+                    name: entry.name.clone(),
+                    category: category.unwrap_or(SkillCategory::Concept),
+                    aliases,
+                    parent,
+                }),
+            }
+        }
+        Self::from_skills(skills)
+    }
+
+    /// All skills in definition order
+    pub fn skills(&self) -> &[SkillDefinition] {
+        &self.skills
+    }
+
+    /// Skill a term refers to, by name or alias ("ReactJS", "React.js" and "react" all resolve to React)
+    pub fn resolve(&self, term: &str) -> Option<&SkillDefinition> {
+        self.index.get(&Self::compact(term)).map(|&position| &self.skills[position])
+    }
+
+    /// Canonical name of a term, or the trimmed term when it is not a known skill
+    pub fn canonical(&self, term: &str) -> String {
+        self.resolve(term)
+            .map(|skill| skill.name.clone())
+            .unwrap_or_else(|| term.trim().to_string())
+    }
+
+    /// Canonicalize terms and drop duplicates, keeping first-appearance order
+    pub fn dedup_terms(&self, terms: Vec<String>) -> Vec<String> {
+        let mut result: Vec<String> = Vec::new();
+        for term in terms {
+            let canonical = self.canonical(&term);
+            if !result.iter().any(|t| t.eq_ignore_ascii_case(&canonical)) {
+                result.push(canonical);
+            }
+        }
+        result
+    }
+
+    /// Whether analyzed text mentions a skill by any of its names
+    pub fn mentions(&self, text: &SegmentedText, skill: &SkillDefinition) -> bool {
+        std::iter::once(&skill.name)
+            .chain(&skill.aliases)
+            .any(|term| text.contains_term(term))
+    }
+
+    /// Whether analyzed text covers a term, directly or through any alias of its skill
+    pub fn covers(&self, text: &SegmentedText, term: &str) -> bool {
+        text.contains_term(term) || self.resolve(term).is_some_and(|skill| self.mentions(text, skill))
+    }
+
+    /// Skills mentioned in a text, in taxonomy order
+    pub fn extract(&self, text: &str) -> Vec<&SkillDefinition> {
+        let analyzed = Segmenter::global().analyze(text);
+        self.skills.iter().filter(|skill| self.mentions(&analyzed, skill)).collect()
+    }
+
+    /// Parent chain of a skill, nearest first
+    pub fn ancestors(&self, name: &str) -> Vec<&SkillDefinition> {
+        let mut chain: Vec<&SkillDefinition> = Vec::new();
+        let mut current = self.resolve(name).and_then(|s| s.parent.as_deref());
+        while let Some(parent) = current.and_then(|p| self.resolve(p)) {
+            // Guard against cycles introduced by user edits
+            if chain.iter().any(|s| s.name == parent.name) {
+                break;
+            }
+            chain.push(parent);
+            current = parent.parent.as_deref();
+        }
+        chain
+    }
+
+    /// Whether `name` is `ancestor` or one of its descendants
+    pub fn is_a(&self, name: &str, ancestor: &str) -> bool {
+        let Some(target) = self.resolve(ancestor) else {
+            return false;
+        };
+        self.resolve(name).is_some_and(|s| s.name == target.name)
+            || self.ancestors(name).iter().any(|s| s.name == target.name)
+    }
+
+    /// Check a user skill before storing it: known category if given, existing parent, no cycle
+    pub fn validate_custom(&self, name: &str, category: Option<&str>, parent: Option<&str>) -> Result<()> {
+        if name.trim().is_empty() {
+            return Err(anyhow!("Skill name must not be empty"));
+        }
+        if let Some(category) = category.filter(|c| !c.trim().is_empty()) {
+            if SkillCategory::parse(category).is_none() {
+                return Err(anyhow!("Unknown skill category: {}", category));
+            }
+        }
+        if let Some(parent) = parent.filter(|p| !p.trim().is_empty()) {
+            let Some(parent_skill) = self.resolve(parent) else {
+                return Err(anyhow!("Unknown parent skill: {}", parent));
+            };
+            if parent_skill.name.eq_ignore_ascii_case(name.trim()) || self.is_a(parent, name) {
+                return Err(anyhow!("Skill {} cannot be its own ancestor", name));
+            }
+        }
+        Ok(())
+    }
+
+    /// Lookup key: normalized, without spaces, dots, dashes and underscores
+    fn compact(term: &str) -> String {
+        Segmenter::normalize(term)
+            .chars()
+            .filter(|c| !c.is_whitespace() && !matches!(c, '.' | '-' | '_'))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resolve_aliases_and_hierarchy() {
+        let taxonomy = SkillTaxonomy::bundled();
+        for term in ["React", "ReactJS", "React.js", "react js"] {
+            assert_eq!(taxonomy.resolve(term).unwrap().name, "React");
+        }
+        assert_eq!(taxonomy.canonical("K8s"), "Kubernetes");
+        assert_eq!(taxonomy.canonical("Terraform"), "Terraform");
+        assert!(taxonomy.is_a("redis", "缓存"));
+        assert!(taxonomy.is_a("Vue", "前端开发"));
+        assert!(!taxonomy.is_a("缓存", "Redis"));
+        assert_eq!(taxonomy.dedup_terms(vec!["golang".into(), "Go".into(), "k8s".into()]), vec!["Go", "Kubernetes"]);
+
+        let analyzed = Segmenter::global().analyze("用 reactjs 和 k8s 做过项目");
+        assert!(taxonomy.covers(&analyzed, "React"));
+        assert!(taxonomy.covers(&analyzed, "Kubernetes"));
+    }
+
+    #[test]
+    fn test_custom_skills_extend_and_validate() {
+        let custom = |name: &str, aliases: &str, category: &str, parent: Option<&str>| CustomSkill {
+            id: None,
+            name: name.to_string(),
+            aliases: aliases.to_string(),
+            category: category.to_string(),
+            parent: parent.map(str::to_string),
+            created_at: String::new(),
+        };
+        let taxonomy = SkillTaxonomy::bundled().with_custom(&[
+            custom("Terraform", r#"["tf"]"#, "tool", Some("云平台")),
+            custom("Redis", r#"["redis cluster"]"#, "database", None),
+            custom("Vue", r#"["vue3"]"#, "", None),
+        ]);
+
+        assert_eq!(taxonomy.canonical("tf"), "Terraform");
+        assert!(taxonomy.is_a("Terraform", "云平台"));
+        assert_eq!(taxonomy.canonical("Redis Cluster"), "Redis");
+        assert!(taxonomy.is_a("Redis", "缓存"));
+        assert_eq!(taxonomy.resolve("vue3").unwrap().category, SkillCategory::Framework);
+
+        assert!(taxonomy.validate_custom("Pulumi", Some("tool"), Some("云平台")).is_ok());
+        assert!(taxonomy.validate_custom("Vue", None, None).is_ok());
+        assert!(taxonomy.validate_custom("Pulumi", Some("gadget"), None).is_err());
+        assert!(taxonomy.validate_custom("Pulumi", Some("tool"), Some("不存在")).is_err());
+        assert!(taxonomy.validate_custom("缓存", Some("concept"), Some("Redis")).is_err());
+    }
+}
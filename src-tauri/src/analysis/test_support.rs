@@ -0,0 +1,63 @@
+//! Fixtures shared by the analysis tests
+
+use crate::db::{ProfileDimension, QuestionPracticeStats, QuestionReview, SessionScoreAverages};
+
+/// One session per day with the given overall scores (0-10)
+///
+/// Logic alternates by 0.1 so its trend is not a perfectly flat line.
+pub fn session_averages(overall: &[f32]) -> Vec<SessionScoreAverages> {
+    overall
+        .iter()
+        .enumerate()
+        .map(|(i, score)| SessionScoreAverages {
+            session_id: i as i64 + 1,
+            created_at: format!("2026-01-{:02}T10:00:00Z", i + 1),
+            answer_count: 3,
+            overall_score: *score,
+            content_score: 6.0,
+            expression_score: None,
+            logic_score: 6.0 + (i % 2) as f32 * 0.1,
+            job_match_score: 6.0,
+            keyword_coverage: 50.0,
+        })
+        .collect()
+}
+
+/// Profile dimensions in declaration order: technical depth, communication,
+/// problem solving, domain knowledge, adaptability, job intention
+pub fn dimensions(scores: [f32; 6]) -> ProfileDimension {
+    let [technical_depth, communication, problem_solving, domain_knowledge, adaptability, job_intention] = scores;
+    ProfileDimension {
+        technical_depth,
+        communication,
+        problem_solving,
+        domain_knowledge,
+        adaptability,
+        job_intention,
+    }
+}
+
+/// Three attempts at a question
+pub fn practice_stats(question: &str, last_score: f32, average_score: f32, practiced_at: &str) -> QuestionPracticeStats {
+    QuestionPracticeStats {
+        question_id: None,
+        question: question.to_string(),
+        attempts: 3,
+        average_score,
+        last_score,
+        last_practiced_at: practiced_at.to_string(),
+    }
+}
+
+/// Review state after one lapse, due on `due_date`
+pub fn review(question_id: i64, due_date: &str) -> QuestionReview {
+    QuestionReview {
+        question_id,
+        ease: 2.0,
+        interval_days: 3,
+        repetitions: 1,
+        lapses: 1,
+        due_date: due_date.to_string(),
+        last_reviewed_at: None,
+    }
+}
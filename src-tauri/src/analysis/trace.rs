@@ -0,0 +1,178 @@
+//! Score traces for the heuristic scorers
+//!
+//! Every heuristic score is rebuilt from the rules that fired: a base value,
+//! one entry per matched keyword or bonus, and a final clamp entry when the
+//! score hit its bounds. The points of a component's entries always add up to
+//! its score, so a 5.8 can be read back rule by rule. Later adjustments such
+//! as the LLM judge blend are recorded as override entries on top of the
+//! heuristic rules, so the heuristic score stays recoverable. The overall
+//! score is always settled in the same order, judge blend first and then the
+//! median over blended samples (see `JudgeScorer::settle_overall`). A rubric
+//! breakdown from the LLM is traced as its own `rubric` component, one entry
+//! per criterion.
+
+use serde::{Deserialize, Serialize};
+
+/// Characters of surrounding text kept on each side of an evidence match
+const EVIDENCE_CONTEXT_CHARS: usize = 12;
+
+/// Rules that move a stored score after the heuristic pass
+pub const OVERRIDE_RULES: &[&str] = &["judge_blend", "consistency_median"];
+
+/// One rule that fired and what it contributed
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceEntry {
+    pub rule: String,                    // Stable rule id, e.g. "connective" or "length_in_range"
+    pub detail: String,                  // What matched: the keyword, JD term or measured value
+    pub points: f32,                     // Contribution to the component score (negative for penalties)
+    pub evidence: Option<String>,        // Answer excerpt around the match
+}
+
+/// Rules behind one score; entry points sum to `score`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComponentTrace {
+    pub component: String,               // logic, job_match, keyword_coverage, overall, situation, ...
+    pub score: f32,
+    pub entries: Vec<TraceEntry>,
+}
+
+/// Traces of all components of an analysis
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ScoreTrace {
+    pub components: Vec<ComponentTrace>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub consistency_samples: Vec<f32>,   // Overall scores of the stored consistency runs
+}
+
+impl ComponentTrace {
+    pub fn new(component: &str) -> Self {
+        Self {
+            component: component.to_string(),
+            score: 0.0,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Record a rule contribution
+    pub fn add(&mut self, rule: &str, detail: impl Into<String>, points: f32, evidence: Option<String>) {
+        self.entries.push(TraceEntry {
+            rule: rule.to_string(),
+            detail: detail.into(),
+            points,
+            evidence,
+        });
+    }
+
+    /// Sum the entries into the score, recording a clamp entry if the bounds cut it
+    pub fn finish(mut self, min: f32, max: f32) -> Self {
+        let raw: f32 = self.entries.iter().map(|e| e.points).sum();
+        let score = raw.clamp(min, max);
+        if (score - raw).abs() > f32::EPSILON {
+            self.add("clamp", format!("{}..{}", min, max), score - raw, None);
+        }
+        self.score = score;
+        self
+    }
+
+    /// Score from the heuristic rules alone, without override entries
+    pub fn heuristic_score(&self) -> f32 {
+        self.entries
+            .iter()
+            .filter(|e| !OVERRIDE_RULES.contains(&e.rule.as_str()))
+            .map(|e| e.points)
+            .sum()
+    }
+
+    /// Move the score to `score` with one `rule` entry, replacing an earlier entry of that rule
+    pub fn override_score(&mut self, rule: &str, detail: impl Into<String>, score: f32) {
+        self.entries.retain(|e| e.rule != rule);
+        let current: f32 = self.entries.iter().map(|e| e.points).sum();
+        self.add(rule, detail, score - current, None);
+        self.score = score;
+    }
+}
+
+impl ScoreTrace {
+    /// Add a component trace, replacing an earlier one with the same name
+    pub fn set(&mut self, trace: ComponentTrace) {
+        match self.components.iter_mut().find(|c| c.component == trace.component) {
+            Some(existing) => *existing = trace,
+            None => self.components.push(trace),
+        }
+    }
+
+    /// Trace of a component
+    pub fn component(&self, component: &str) -> Option<&ComponentTrace> {
+        self.components.iter().find(|c| c.component == component)
+    }
+
+    /// Override a component score, starting an empty trace for untraced components
+    pub fn override_score(&mut self, component: &str, rule: &str, detail: impl Into<String>, score: f32) {
+        match self.components.iter_mut().find(|c| c.component == component) {
+            Some(existing) => existing.override_score(rule, detail, score),
+            None => {
+                let mut trace = ComponentTrace::new(component);
+                trace.override_score(rule, detail, score);
+                self.components.push(trace);
+            }
+        }
+    }
+
+    /// Remove and return a component trace
+    pub fn take(&mut self, component: &str) -> Option<ComponentTrace> {
+        let position = self.components.iter().position(|c| c.component == component)?;
+        Some(self.components.remove(position))
+    }
+}
+
+/// Excerpt of `text` around the first case-insensitive occurrence of `term`
+pub fn evidence(text: &str, term: &str) -> Option<String> {
+    let needle: Vec<char> = term.chars().flat_map(char::to_lowercase).collect();
+    if needle.is_empty() {
+        return None;
+    }
+    let chars: Vec<char> = text.chars().collect();
+    let lowered: Vec<char> = chars.iter().map(|c| c.to_lowercase().next().unwrap_or(*c)).collect();
+    let start = lowered.windows(needle.len()).position(|window| window == needle.as_slice())?;
+
+    let from = start.saturating_sub(EVIDENCE_CONTEXT_CHARS);
+    let to = (start + needle.len() + EVIDENCE_CONTEXT_CHARS).min(chars.len());
+    let excerpt: String = chars[from..to].iter().collect();
+    Some(excerpt.split_whitespace().collect::<Vec<_>>().join(" "))
+}
+
+/// Evidence for the first of several spellings found in `text`
+pub fn evidence_any<'a>(text: &str, terms: impl IntoIterator<Item = &'a str>) -> Option<String> {
+    terms.into_iter().find_map(|term| evidence(text, term))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_finish_records_clamp() {
+        let mut trace = ComponentTrace::new("logic");
+        trace.add("base", "", 5.0, None);
+        for _ in 0..8 {
+            trace.add("connective", "首先", 0.8, None);
+        }
+        let trace = trace.finish(1.0, 10.0);
+        assert_eq!(trace.score, 10.0);
+        let clamp = trace.entries.last().unwrap();
+        assert_eq!(clamp.rule, "clamp");
+        assert!((clamp.points + 1.4).abs() < 1e-4);
+        let total: f32 = trace.entries.iter().map(|e| e.points).sum();
+        assert!((total - trace.score).abs() < 1e-4);
+    }
+
+    #[test]
+    fn test_evidence_excerpt() {
+        let text = "In my previous role we migrated everything to Kubernetes within two quarters";
+        let excerpt = evidence(text, "kubernetes").unwrap();
+        assert!(excerpt.contains("to Kubernetes within"));
+        assert!(!excerpt.contains("In my previous"));
+        assert_eq!(evidence("首先我们分析了问题", "首先").as_deref(), Some("首先我们分析了问题"));
+        assert!(evidence(text, "Redis").is_none());
+    }
+}
@@ -1,3 +1,479 @@
-//! Trends analysis module (placeholder for Phase 5.3)
-#[allow(dead_code)]
+//! Trend analysis over practice sessions
+//!
+//! Every dimension is a series of per-session analysis averages on a 0-100
+//! scale, indexed by session order. The series is smoothed with an
+//! exponential moving average, and a least squares line plus a Theil-Sen line
+//! are fitted over the most recent sessions. Each dimension is classified as:
+//!
+//! - `declining` / `improving`: the least squares slope is significant at 95%
+//!   and the Theil-Sen slope agrees in sign
+//! - `regressing`: the last sessions score significantly lower than the ones
+//!   before them (Welch t-test) by at least `MIN_EFFECT` points
+//! - `plateau`: no significant slope and the fitted change is under `MIN_EFFECT`
+//! - `fluctuating`: none of the above
+//!
+//! The forecast extends the least squares line a few sessions with a 95%
+//! prediction interval.
+
+use crate::db::{now, Repository, SessionScoreAverages};
+use crate::language::Language;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+/// Smoothing factor of the exponential moving average
+const EMA_ALPHA: f32 = 0.3;
+
+/// Sessions needed before a series is classified
+const MIN_POINTS: usize = 4;
+
+/// Most recent sessions the regression lines are fitted on
+const FIT_WINDOW: usize = 10;
+
+/// Sessions compared against the baseline for regression detection
+const RECENT_WINDOW: usize = 3;
+
+/// Sessions before the recent window that form the baseline
+const BASELINE_WINDOW: usize = 5;
+
+/// Smallest change in points (0-100) that counts as a real movement
+const MIN_EFFECT: f32 = 3.0;
+
+/// Sessions forecast ahead
+const FORECAST_HORIZON: usize = 3;
+
+/// Bound for t statistics of noise-free series, which would be infinite
+const MAX_T_STATISTIC: f32 = 99.9;
+
+/// Dimensions with the same score mapping as the interview profile
+const DIMENSIONS: [(&str, &str, &str); 6] = [
+    ("overall", "综合得分", "Overall score"),
+    ("technical_depth", "技术深度", "Technical depth"),
+    ("communication", "沟通表达", "Communication"),
+    ("problem_solving", "问题解决", "Problem solving"),
+    ("domain_knowledge", "领域知识", "Domain knowledge"),
+    ("adaptability", "应变能力", "Adaptability"),
+];
+
+/// Direction of a dimension over recent sessions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TrendStatus {
+    Improving,
+    Declining,
+    Regressing,
+    Plateau,
+    Fluctuating,
+    InsufficientData,
+}
+
+/// One session of a dimension series
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendPoint {
+    pub session_id: i64,
+    pub created_at: String,
+    pub value: f32,                      // Session average, 0-100
+    pub ema: f32,                        // Exponential moving average up to this session
+}
+
+/// Regression lines over the fit window, slopes in points per session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RegressionFit {
+    pub slope: f32,                      // Least squares
+    pub intercept: f32,
+    pub robust_slope: f32,               // Theil-Sen, median of pairwise slopes
+    pub r_squared: f32,
+    pub t_statistic: f32,                // Slope over its standard error
+    pub significant: bool,               // 95% two-sided, robust slope agrees in sign
+    pub window: usize,                   // Sessions the lines were fitted on
+}
+
+/// Forecast of a future session with its 95% prediction interval
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForecastPoint {
+    pub sessions_ahead: usize,
+    pub value: f32,
+    pub lower: f32,
+    pub upper: f32,
+}
+
+/// Trend of one dimension
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DimensionTrend {
+    pub dimension: String,
+    pub label: String,
+    pub points: Vec<TrendPoint>,
+    pub fit: Option<RegressionFit>,
+    pub recent_change: Option<f32>,      // Recent window mean minus baseline mean
+    pub status: TrendStatus,
+    pub forecast: Vec<ForecastPoint>,
+}
+
+/// Trends of all dimensions with a one-line verdict
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrendsReport {
+    pub session_count: usize,
+    pub dimensions: Vec<DimensionTrend>,
+    pub overall_status: TrendStatus,
+    pub summary: String,
+    pub generated_at: String,
+}
+
+/// Change of the recent window against the baseline before it
+struct RecentChange {
+    change: f32,
+    significant: bool,
+}
+
+/// Trends analyzer for per-dimension progress
 pub struct TrendsAnalyzer;
+
+impl TrendsAnalyzer {
+    /// Analyze the trends of the active user's analyzed sessions
+    pub fn analyze(repository: &Repository, language: Language) -> Result<TrendsReport> {
+        let sessions = repository.get_session_score_averages()?;
+        Ok(Self::from_sessions(&sessions, language))
+    }
+
+    /// Build the report from per-session averages, oldest first
+    pub fn from_sessions(sessions: &[SessionScoreAverages], language: Language) -> TrendsReport {
+        let dimensions: Vec<DimensionTrend> = DIMENSIONS
+            .iter()
+            .map(|(dimension, zh, en)| {
+                let samples = sessions
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, session)| {
+                        Self::dimension_value(session, dimension).map(|value| (index, session, value))
+                    })
+                    .collect::<Vec<_>>();
+                Self::dimension_trend(dimension, &language.text(zh, en), &samples, sessions.len())
+            })
+            .collect();
+
+        let overall_status = dimensions[0].status;
+        let summary = Self::summary(&dimensions, language);
+
+        TrendsReport {
+            session_count: sessions.len(),
+            dimensions,
+            overall_status,
+            summary,
+            generated_at: now(),
+        }
+    }
+
+    /// Session value of a dimension on a 0-100 scale, `None` when not measured
+    fn dimension_value(session: &SessionScoreAverages, dimension: &str) -> Option<f32> {
+        match dimension {
+            "overall" => Some(session.overall_score * 10.0),
+            "technical_depth" => Some(session.content_score * 10.0),
+            "communication" => session.expression_score.map(|score| score * 10.0),
+            "problem_solving" => Some(session.logic_score * 10.0),
+            "domain_knowledge" => Some(session.job_match_score * 10.0),
+            "adaptability" => Some(session.keyword_coverage),
+            _ => None,
+        }
+    }
+
+    /// Trend of one series; `samples` are (session position, session, value)
+    fn dimension_trend(
+        dimension: &str,
+        label: &str,
+        samples: &[(usize, &SessionScoreAverages, f32)],
+        session_count: usize,
+    ) -> DimensionTrend {
+        let values: Vec<f32> = samples.iter().map(|(_, _, value)| *value).collect();
+        let points = samples
+            .iter()
+            .zip(Self::ema(&values))
+            .map(|((_, session, value), ema)| TrendPoint {
+                session_id: session.session_id,
+                created_at: session.created_at.clone(),
+                value: *value,
+                ema,
+            })
+            .collect();
+
+        let window = &samples[samples.len().saturating_sub(FIT_WINDOW)..];
+        let xs: Vec<f32> = window.iter().map(|(index, _, _)| *index as f32).collect();
+        let ys: Vec<f32> = window.iter().map(|(_, _, value)| *value).collect();
+        let (fit, forecast) = match Self::fit(&xs, &ys, session_count.saturating_sub(1) as f32) {
+            Some((fit, forecast)) => (Some(fit), forecast),
+            None => (None, Vec::new()),
+        };
+        let recent = Self::recent_change(&values);
+        let status = Self::classify(fit.as_ref(), recent.as_ref(), &xs);
+
+        DimensionTrend {
+            dimension: dimension.to_string(),
+            label: label.to_string(),
+            points,
+            fit,
+            recent_change: recent.map(|r| r.change),
+            status,
+            forecast,
+        }
+    }
+
+    fn classify(fit: Option<&RegressionFit>, recent: Option<&RecentChange>, xs: &[f32]) -> TrendStatus {
+        let Some(fit) = fit else {
+            return TrendStatus::InsufficientData;
+        };
+        if fit.significant && fit.slope < 0.0 {
+            return TrendStatus::Declining;
+        }
+        if recent.is_some_and(|r| r.significant && r.change <= -MIN_EFFECT) {
+            return TrendStatus::Regressing;
+        }
+        if fit.significant {
+            return TrendStatus::Improving;
+        }
+        let span = xs.last().unwrap_or(&0.0) - xs.first().unwrap_or(&0.0);
+        if (fit.slope * span).abs() < MIN_EFFECT {
+            TrendStatus::Plateau
+        } else {
+            TrendStatus::Fluctuating
+        }
+    }
+
+    /// Exponential moving average, seeded with the first value
+    fn ema(values: &[f32]) -> Vec<f32> {
+        let mut smoothed = Vec::with_capacity(values.len());
+        for value in values {
+            let next = match smoothed.last() {
+                Some(previous) => EMA_ALPHA * value + (1.0 - EMA_ALPHA) * previous,
+                None => *value,
+            };
+            smoothed.push(next);
+        }
+        smoothed
+    }
+
+    /// Least squares and Theil-Sen fit, with a forecast past `last_x`
+    fn fit(xs: &[f32], ys: &[f32], last_x: f32) -> Option<(RegressionFit, Vec<ForecastPoint>)> {
+        let n = xs.len();
+        if n < MIN_POINTS {
+            return None;
+        }
+        let mean_x = xs.iter().sum::<f32>() / n as f32;
+        let mean_y = ys.iter().sum::<f32>() / n as f32;
+        let sxx: f32 = xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+        let sxy: f32 = xs.iter().zip(ys).map(|(x, y)| (x - mean_x) * (y - mean_y)).sum();
+        let syy: f32 = ys.iter().map(|y| (y - mean_y).powi(2)).sum();
+        if sxx <= f32::EPSILON {
+            return None;
+        }
+
+        let slope = sxy / sxx;
+        let intercept = mean_y - slope * mean_x;
+        let sse: f32 = xs.iter().zip(ys).map(|(x, y)| (y - intercept - slope * x).powi(2)).sum();
+        let df = n - 2;
+        let variance = sse / df as f32;
+        let se_slope = (variance / sxx).sqrt();
+        let t_statistic = if se_slope > f32::EPSILON {
+            (slope / se_slope).clamp(-MAX_T_STATISTIC, MAX_T_STATISTIC)
+        } else if slope.abs() > f32::EPSILON {
+            MAX_T_STATISTIC.copysign(slope)
+        } else {
+            0.0
+        };
+        let robust_slope = Self::theil_sen(xs, ys);
+        let t_critical = t_critical(df);
+        let significant = t_statistic.abs() >= t_critical && robust_slope * slope > 0.0;
+        let r_squared = if syy > f32::EPSILON { (1.0 - sse / syy).max(0.0) } else { 1.0 };
+
+        let forecast = (1..=FORECAST_HORIZON)
+            .map(|ahead| {
+                let x = last_x + ahead as f32;
+                let value = intercept + slope * x;
+                let se = (variance * (1.0 + 1.0 / n as f32 + (x - mean_x).powi(2) / sxx)).sqrt();
+                ForecastPoint {
+                    sessions_ahead: ahead,
+                    value: value.clamp(0.0, 100.0),
+                    lower: (value - t_critical * se).clamp(0.0, 100.0),
+                    upper: (value + t_critical * se).clamp(0.0, 100.0),
+                }
+            })
+            .collect();
+
+        let fit = RegressionFit {
+            slope,
+            intercept,
+            robust_slope,
+            r_squared,
+            t_statistic,
+            significant,
+            window: n,
+        };
+        Some((fit, forecast))
+    }
+
+    /// Median of the slopes between all pairs of points
+    fn theil_sen(xs: &[f32], ys: &[f32]) -> f32 {
+        let mut slopes = Vec::new();
+        for i in 0..xs.len() {
+            for j in i + 1..xs.len() {
+                let dx = xs[j] - xs[i];
+                if dx.abs() > f32::EPSILON {
+                    slopes.push((ys[j] - ys[i]) / dx);
+                }
+            }
+        }
+        if slopes.is_empty() {
+            return 0.0;
+        }
+        slopes.sort_by(|a, b| a.total_cmp(b));
+        let mid = slopes.len() / 2;
+        if slopes.len() % 2 == 0 {
+            (slopes[mid - 1] + slopes[mid]) / 2.0
+        } else {
+            slopes[mid]
+        }
+    }
+
+    /// Welch t-test of the recent window against the baseline before it
+    fn recent_change(values: &[f32]) -> Option<RecentChange> {
+        if values.len() < RECENT_WINDOW + 3 {
+            return None;
+        }
+        let (earlier, recent) = values.split_at(values.len() - RECENT_WINDOW);
+        let baseline = &earlier[earlier.len().saturating_sub(BASELINE_WINDOW)..];
+        let (recent_mean, recent_var) = mean_variance(recent);
+        let (baseline_mean, baseline_var) = mean_variance(baseline);
+        let change = recent_mean - baseline_mean;
+        let recent_term = recent_var / recent.len() as f32;
+        let baseline_term = baseline_var / baseline.len() as f32;
+        let se = (recent_term + baseline_term).sqrt();
+        let t_statistic = if se > f32::EPSILON { change / se } else { MAX_T_STATISTIC.copysign(change) };
+        // Welch-Satterthwaite degrees of freedom, rounded down
+        let df = if se > f32::EPSILON {
+            let denominator = recent_term.powi(2) / (recent.len() - 1) as f32
+                + baseline_term.powi(2) / (baseline.len() - 1) as f32;
+            ((recent_term + baseline_term).powi(2) / denominator).floor().max(1.0) as usize
+        } else {
+            recent.len() + baseline.len() - 2
+        };
+        Some(RecentChange {
+            change,
+            significant: change.abs() > f32::EPSILON && t_statistic.abs() >= t_critical(df),
+        })
+    }
+
+    fn summary(dimensions: &[DimensionTrend], language: Language) -> String {
+        let overall = &dimensions[0];
+        let slope = overall.fit.as_ref().map(|fit| fit.slope).unwrap_or(0.0);
+        let mut summary = match overall.status {
+            TrendStatus::Improving => language.text(
+                &format!("综合得分显著提升，每场约 +{:.1} 分", slope),
+                &format!("Overall score is improving significantly, about +{:.1} points per session", slope),
+            ),
+            TrendStatus::Declining => language.text(
+                &format!("综合得分显著下降，每场约 {:.1} 分", slope),
+                &format!("Overall score is declining significantly, about {:.1} points per session", slope),
+            ),
+            TrendStatus::Regressing => {
+                let change = overall.recent_change.unwrap_or(0.0);
+                language.text(
+                    &format!("最近 {} 场综合得分明显回落 {:.1} 分", RECENT_WINDOW, change.abs()),
+                    &format!("Overall score dropped {:.1} points over the last {} sessions", change.abs(), RECENT_WINDOW),
+                )
+            }
+            TrendStatus::Plateau => language.text(
+                "综合得分进入平台期，近期没有明显变化",
+                "Overall score has plateaued with no meaningful change recently",
+            ),
+            TrendStatus::Fluctuating => language.text(
+                "综合得分波动较大，暂无显著趋势",
+                "Overall score fluctuates without a significant trend",
+            ),
+            TrendStatus::InsufficientData => language.text(
+                &format!("至少完成 {} 场已分析的面试后才能判断趋势", MIN_POINTS),
+                &format!("Complete at least {} analyzed sessions to see a trend", MIN_POINTS),
+            ),
+        };
+
+        let slipping: Vec<&str> = dimensions[1..]
+            .iter()
+            .filter(|d| matches!(d.status, TrendStatus::Declining | TrendStatus::Regressing))
+            .map(|d| d.label.as_str())
+            .collect();
+        if !slipping.is_empty() {
+            summary.push_str(&language.text(
+                &format!("；下滑维度：{}", slipping.join("、")),
+                &format!("; slipping: {}", slipping.join(", ")),
+            ));
+        }
+        summary
+    }
+}
+
+fn mean_variance(values: &[f32]) -> (f32, f32) {
+    let n = values.len() as f32;
+    let mean = values.iter().sum::<f32>() / n;
+    let variance = if values.len() > 1 {
+        values.iter().map(|v| (v - mean).powi(2)).sum::<f32>() / (n - 1.0)
+    } else {
+        0.0
+    };
+    (mean, variance)
+}
+
+/// Two-sided 95% critical value of Student's t distribution
+fn t_critical(df: usize) -> f32 {
+    const TABLE: [f32; 10] = [12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228];
+    match df {
+        0 => f32::INFINITY,
+        1..=10 => TABLE[df - 1],
+        11..=15 => 2.131,
+        16..=20 => 2.086,
+        21..=30 => 2.042,
+        _ => 1.96,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::session_averages;
+
+    #[test]
+    fn test_improving_with_forecast_band() {
+        let report = TrendsAnalyzer::from_sessions(&session_averages(&[5.0, 5.4, 5.5, 6.1, 6.3, 6.8, 7.0]), Language::En);
+        let overall = &report.dimensions[0];
+        assert_eq!(report.overall_status, TrendStatus::Improving);
+        let fit = overall.fit.as_ref().unwrap();
+        assert!(fit.significant && fit.slope > 2.5 && fit.robust_slope > 2.5);
+        assert_eq!(overall.forecast.len(), FORECAST_HORIZON);
+        let next = &overall.forecast[0];
+        assert!(next.lower < next.value && next.value < next.upper && next.value > 70.0);
+        assert!(overall.forecast[2].upper - overall.forecast[2].lower > next.upper - next.lower);
+        // Points and EMA stay aligned with the sessions
+        assert_eq!(overall.points.len(), 7);
+        assert!(overall.points[6].ema < overall.points[6].value);
+        // Communication was never measured
+        assert_eq!(report.dimensions[2].status, TrendStatus::InsufficientData);
+        assert!(report.summary.contains("improving"));
+    }
+
+    #[test]
+    fn test_plateau_and_regression() {
+        let report = TrendsAnalyzer::from_sessions(&session_averages(&[6.0, 6.1, 5.9, 6.0, 6.1, 6.0, 5.9, 6.0]), Language::Zh);
+        assert_eq!(report.overall_status, TrendStatus::Plateau);
+        assert_eq!(report.dimensions[4].status, TrendStatus::Plateau);
+
+        let report = TrendsAnalyzer::from_sessions(&session_averages(&[5.0, 6.0, 7.0, 7.6, 7.9, 8.0, 6.2, 6.0, 6.1]), Language::Zh);
+        assert_eq!(report.overall_status, TrendStatus::Regressing);
+        assert!(report.dimensions[0].recent_change.unwrap() < -MIN_EFFECT);
+        assert!(report.summary.contains("回落"));
+    }
+
+    #[test]
+    fn test_insufficient_and_noisy_series() {
+        let report = TrendsAnalyzer::from_sessions(&session_averages(&[6.0, 7.0, 6.5]), Language::Zh);
+        assert_eq!(report.overall_status, TrendStatus::InsufficientData);
+        assert!(report.dimensions[0].forecast.is_empty());
+
+        let report = TrendsAnalyzer::from_sessions(&session_averages(&[4.0, 8.0, 5.0, 9.0, 4.5, 8.5]), Language::Zh);
+        assert_eq!(report.overall_status, TrendStatus::Fluctuating);
+    }
+}
@@ -5,6 +5,10 @@ use std::env;
 use std::collections::HashMap;
 use std::sync::OnceLock;
 use super::retry::RetryPolicy;
+use crate::analysis::judge::JudgeScorer;
+use crate::analysis::jd_parser::JobDescriptionParser;
+use crate::analysis::question_classifier::QuestionClassifier;
+use crate::language::Language;
 use futures::StreamExt;
 use eventsource_stream::Eventsource;
 
@@ -330,10 +334,11 @@ impl SiliconFlowClient {
         count: u32,
         persona: &str,
     ) -> Result<Vec<String>> {
-        self.generate_questions_with_context(resume, job_description, count, persona, None).await
+        self.generate_questions_with_context(resume, job_description, count, persona, None, None, Language::default()).await
     }
 
-    /// Generate questions with optional RAG context
+    /// Generate questions with optional RAG context in the given language
+    #[allow(clippy::too_many_arguments)]
     pub async fn generate_questions_with_context(
         &self,
         resume: &str,
@@ -341,6 +346,8 @@ impl SiliconFlowClient {
         count: u32,
         persona: &str,
         context: Option<&str>,
+        focus: Option<&str>,
+        language: Language,
     ) -> Result<Vec<String>> {
         let base_prompt = Self::get_persona_prompt(persona);
         let system_prompt = format!("{} 你必须只返回有效的JSON数组，不要任何额外的文字或解释。", base_prompt);
@@ -351,9 +358,11 @@ impl SiliconFlowClient {
             String::new()
         };
         
+        let focus_section = focus.map(|f| format!("\n\n{}", f)).unwrap_or_default();
+        
         let user_prompt = format!(
-            "根据以下简历和岗位描述，生成 {} 个相关的面试问题。所有问题必须使用中文。{}\n\n简历：\n{}\n\n岗位描述：\n{}\n\n重要提示：只返回JSON数组，不要任何解释说明。格式：[\"问题1\", \"问题2\", ...]",
-            count, context_section, resume, job_description
+            "根据以下简历和岗位描述，生成 {} 个相关的面试问题。{}{}{}\n\n简历：\n{}\n\n岗位描述：\n{}\n\n重要提示：只返回JSON数组，不要任何解释说明。格式：[\"问题1\", \"问题2\", ...]",
+            count, language.prompt_instruction(), context_section, focus_section, resume, job_description
         );
 
         let messages = vec![
@@ -384,19 +393,26 @@ impl SiliconFlowClient {
         Ok(questions)
     }
 
-    /// Analyze user's answer and provide feedback
+    /// Analyze user's answer against a rubric and return the raw JSON evaluation
+    #[allow(clippy::too_many_arguments)]
     pub async fn analyze_answer(
         &self,
         question: &str,
         answer: &str,
         job_description: &str,
         persona: &str,
+        rubric_prompt: &str,
+        language: Language,
+        temperature: Option<f32>,
     ) -> Result<String> {
-        let system_prompt = Self::get_persona_prompt(persona);
+        let system_prompt = format!(
+            "{} 你必须只返回有效的JSON对象，格式：{{\"score\": 7.5, \"strengths\": [...], \"improvements\": [...], \"summary\": \"...\", \"criterion_scores\": [...]}}",
+            Self::get_persona_prompt(persona)
+        );
         
         let user_prompt = format!(
-            "Question: {}\n\nCandidate's Answer: {}\n\nJob Description: {}\n\nPlease analyze this answer and provide:\n1. Strengths\n2. Areas for improvement\n3. Suggestions for better response\n4. Relevance to job requirements",
-            question, answer, job_description
+            "Question: {}\n\nCandidate's Answer: {}\n\nJob Description: {}\n\n{}\n\nPlease evaluate this answer against the rubric and list strengths, areas for improvement and a short summary including relevance to job requirements.\n\n{}",
+            question, answer, job_description, rubric_prompt, language.prompt_instruction()
         );
 
         let messages = vec![
@@ -410,7 +426,77 @@ impl SiliconFlowClient {
             },
         ];
 
-        self.chat_completion(messages, Some(0.7), Some(1500)).await
+        self.chat_completion(messages, temperature, Some(1500)).await
+    }
+
+    /// Score an answer with the LLM judge (numeric dimension scores as JSON)
+    pub async fn judge_answer(
+        &self,
+        question: &str,
+        answer: &str,
+        job_description: &str,
+        language: Language,
+    ) -> Result<String> {
+        let user_prompt = format!(
+            "Question: {}\n\nCandidate's Answer: {}\n\nJob Description: {}\n\nScore this answer.",
+            question, answer, job_description
+        );
+
+        let messages = vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: JudgeScorer::system_prompt(language),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: user_prompt,
+            },
+        ];
+
+        // Low temperature keeps judge scores comparable across answers
+        self.chat_completion(messages, Some(0.2), Some(500)).await
+    }
+
+    /// Refine a rule-parsed job description (structured fields as JSON)
+    pub async fn refine_job_description(
+        &self,
+        job_description: &str,
+        rules_json: &str,
+        language: Language,
+    ) -> Result<String> {
+        let user_prompt = format!(
+            "岗位描述原文：\n{}\n\n规则提取结果：\n{}\n\n请输出修正后的结构化JSON。",
+            job_description, rules_json
+        );
+
+        let messages = vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: JobDescriptionParser::refinement_prompt(language),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: user_prompt,
+            },
+        ];
+
+        self.chat_completion(messages, Some(0.2), Some(1500)).await
+    }
+
+    /// Classify a question bank entry (type, dimension, difficulty, skills as JSON)
+    pub async fn classify_question(&self, question: &str, language: Language) -> Result<String> {
+        let messages = vec![
+            ChatMessage {
+                role: "system".to_string(),
+                content: QuestionClassifier::llm_prompt(language),
+            },
+            ChatMessage {
+                role: "user".to_string(),
+                content: format!("面试题：{}\n\n请给出分类JSON。", question),
+            },
+        ];
+
+        self.chat_completion(messages, Some(0.2), Some(300)).await
     }
 
     /// Generate comprehensive interview report
@@ -421,7 +507,7 @@ impl SiliconFlowClient {
         answers: &[String],
         job_description: &str,
     ) -> Result<String> {
-        self.generate_session_report_with_model(questions, answers, job_description, None).await
+        self.generate_session_report_with_model(questions, answers, job_description, None, Language::default()).await
     }
 
     /// Generate comprehensive interview report with optional model override
@@ -431,9 +517,13 @@ impl SiliconFlowClient {
         answers: &[String],
         job_description: &str,
         model: Option<&str>,
+        language: Language,
     ) -> Result<String> {
         let model_to_use = model.unwrap_or(&self.model);
-        let system_prompt = "你是一位资深面试评估专家。请用中文生成一份全面的面试复盘报告，输出JSON格式：{\"summary\": \"总结...\", \"overall_score\": 8.5, \"improvements\": [...], \"key_takeaways\": [...]}";
+        let system_prompt = format!(
+            "你是一位资深面试评估专家。请生成一份全面的面试复盘报告，输出JSON格式：{{\"summary\": \"总结...\", \"overall_score\": 8.5, \"improvements\": [...], \"key_takeaways\": [...]}}。{}",
+            language.prompt_instruction()
+        );
         
         let qa_pairs = questions
             .iter()
@@ -444,14 +534,14 @@ impl SiliconFlowClient {
             .join("\n\n");
         
         let user_prompt = format!(
-            "岗位描述：\n{}\n\n面试问答：\n{}\n\n请用中文生成一份全面的面试复盘报告，包含：\n1. 整体表现总结（150-200字）\n2. 综合评分（1-10分）\n3. 3-5条具体改进建议\n4. 2-3条核心要点\n\n请只返回有效的JSON格式，不要其他任何文字。",
-            job_description, qa_pairs
+            "岗位描述：\n{}\n\n面试问答：\n{}\n\n请生成一份全面的面试复盘报告，包含：\n1. 整体表现总结（150-200字）\n2. 综合评分（1-10分）\n3. 3-5条具体改进建议\n4. 2-3条核心要点\n\n请只返回有效的JSON格式，不要其他任何文字。{}",
+            job_description, qa_pairs, language.prompt_instruction()
         );
 
         let messages = vec![
             ChatMessage {
                 role: "system".to_string(),
-                content: system_prompt.to_string(),
+                content: system_prompt,
             },
             ChatMessage {
                 role: "user".to_string(),
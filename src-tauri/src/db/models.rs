@@ -1,6 +1,6 @@
 //! Data models for database entities
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 /// User entity
@@ -20,6 +20,8 @@ pub struct Resume {
     pub user_id: i64,
     pub title: String,
     pub content: String,
+    #[serde(default)]
+    pub structured: String,              // JSON of the parsed sections for imported files
     pub created_at: String,
     pub updated_at: String,
 }
@@ -31,6 +33,8 @@ pub struct JobDescription {
     pub user_id: i64,
     pub title: String,
     pub content: String,
+    #[serde(default)]
+    pub structured: String,              // JSON of StructuredJobDescription
     pub created_at: String,
     pub updated_at: String,
 }
@@ -55,9 +59,22 @@ pub struct InterviewAnswer {
     pub question: String,
     pub answer: String,
     pub feedback: String,
+    pub question_issued_at: Option<String>,
+    pub answer_submitted_at: Option<String>,
+    #[serde(default)]
+    pub question_id: Option<i64>,        // Question bank entry being practised
     pub created_at: String,
 }
 
+impl InterviewAnswer {
+    /// Seconds from issuing the question to submitting the answer, if both were recorded
+    pub fn latency_secs(&self) -> Option<f32> {
+        let issued = DateTime::parse_from_rfc3339(self.question_issued_at.as_deref()?).ok()?;
+        let submitted = DateTime::parse_from_rfc3339(self.answer_submitted_at.as_deref()?).ok()?;
+        Some((submitted - issued).num_milliseconds().max(0) as f32 / 1000.0)
+    }
+}
+
 /// Question bank item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestionBankItem {
@@ -85,6 +102,11 @@ pub struct AnswerAnalysis {
     pub strengths: String,               // JSON array of strength points
     pub weaknesses: String,              // JSON array of weakness points
     pub suggestions: String,             // JSON array of suggestions
+    pub rubric_id: Option<i64>,          // Stored rubric used for criterion scores
+    pub criterion_scores: String,        // JSON array of per-criterion scores
+    pub lint_findings: String,           // JSON array of lint findings
+    #[serde(default)]
+    pub score_trace: String,             // JSON score trace: rules, points and evidence per component
     pub created_at: String,
 }
 
@@ -156,23 +178,51 @@ pub struct InterviewProfile {
 /// Practice recommendation item
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PracticeRecommendation {
-    pub question_id: i64,
+    pub question_id: Option<i64>,   // Question bank entry; None for knowledge base questions
+    pub knowledge_id: Option<i64>,  // Knowledge base entry for knowledge base questions
+    pub source: String,             // "bank" or "knowledge"
     pub question: String,
-    pub reason: String,
+    pub reason: String,             // Strongest explanation
+    pub explanations: Vec<String>,  // Why the question was picked, strongest first
+    pub signals: RecommendationSignals,
+    pub score: f32,                 // Relevance (0-1) before the diversity trade-off
     pub priority: u8,              // 1-5, higher is more important
     pub dimension: String,          // Which dimension this targets
     pub estimated_improvement: f32, // Expected score improvement
 }
 
+/// Ranking signals of a recommendation, each 0-1
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecommendationSignals {
+    pub jd_match: Option<f32>,      // None without a target JD or embedding model
+    pub weakness: f32,
+    pub performance: f32,
+    pub recency: f32,
+    pub due: f32,
+    pub redundancy: f32,            // Similarity to questions picked before it
+}
+
 /// Recommendation result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecommendationResult {
     pub recommendations: Vec<PracticeRecommendation>,
     pub weak_dimensions: Vec<String>,
     pub total_available: i32,
+    pub semantic_ranking: bool,     // Embeddings were available for JD match and diversity
     pub generated_at: String,
 }
 
+/// Practice history of one question for the active user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionPracticeStats {
+    pub question_id: Option<i64>,   // Question bank entry, None for questions outside the bank
+    pub question: String,           // Trimmed question text
+    pub attempts: i64,
+    pub average_score: f32,         // Overall score (0-10)
+    pub last_score: f32,
+    pub last_practiced_at: String,
+}
+
 /// Best practice extracted from high-scoring answers
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BestPractice {
@@ -217,8 +267,10 @@ pub struct IndustryBenchmark {
     pub dimension: String,
     pub user_score: f32,
     pub industry_avg: f32,
-    pub industry_top: f32,
+    pub industry_top: f32,      // 90th percentile of the benchmark distribution
     pub percentile: f32,        // User's percentile rank (0-100)
+    #[serde(default)]
+    pub sample_size: i32,       // Benchmark scores behind this dimension (0 for the built-in estimate)
 }
 
 /// Industry comparison result
@@ -228,9 +280,66 @@ pub struct IndustryComparisonResult {
     pub overall_percentile: f32,
     pub user_level: String,      // "Beginner", "Intermediate", "Advanced", "Expert"
     pub comparison_count: i32,   // Number of sessions used for comparison
+    #[serde(default)]
+    pub data_source: String,     // Dataset names, or "builtin" when no dataset covers the segment
+    #[serde(default)]
+    pub segment: BenchmarkSegment, // Segment the benchmarks were drawn from after fallbacks
     pub generated_at: String,
 }
 
+/// Role / seniority / region filter for benchmark data; `None` matches any
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct BenchmarkSegment {
+    pub role: Option<String>,
+    pub seniority: Option<String>,
+    pub region: Option<String>,
+}
+
+/// Imported or locally built benchmark dataset
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkDataset {
+    pub id: Option<i64>,
+    pub name: String,
+    pub source: String,          // "import" or "local"
+    pub sample_count: i64,
+    pub created_at: String,
+}
+
+/// Per-user averages of stored answer analyses, without the user's identity
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UserScoreAverages {
+    pub answer_count: i64,
+    pub content_score: f32,
+    pub expression_score: Option<f32>,
+    pub logic_score: f32,
+    pub job_match_score: f32,
+    pub keyword_coverage: f32,
+}
+
+/// Average analysis scores of one session, for trend analysis
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionScoreAverages {
+    pub session_id: i64,
+    pub created_at: String,
+    pub answer_count: i64,
+    pub overall_score: f32,
+    pub content_score: f32,
+    pub expression_score: Option<f32>,
+    pub logic_score: f32,
+    pub job_match_score: f32,
+    pub keyword_coverage: f32,
+}
+
+/// One benchmark score; empty segment fields apply to every segment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BenchmarkSample {
+    pub role: String,
+    pub seniority: String,
+    pub region: String,
+    pub dimension: String,       // Profile dimension, e.g. technical_depth
+    pub score: f32,              // 0-100
+}
+
 /// AI-generated best answer for a question
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QuestionBestAnswer {
@@ -246,6 +355,226 @@ pub struct QuestionBestAnswer {
     pub updated_at: String,
 }
 
+/// Point-by-point comparison of an answer against the best answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnswerComparison {
+    pub id: Option<i64>,
+    pub answer_id: Option<i64>,
+    pub question_hash: String,
+    pub question: String,
+    pub user_answer: String,
+    pub best_answer_version: i32,
+    pub overall_match: f32,
+    pub points: Vec<ComparisonPoint>,
+    pub missing_points: Vec<String>,
+    pub extra_points: Vec<String>,
+    pub created_at: String,
+}
+
+/// Single compared aspect of an answer comparison
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonPoint {
+    pub aspect: String,
+    pub best_answer_point: String,
+    pub user_answer_point: String,
+    pub match_status: String,    // "matched", "partial", "missing"
+    pub suggestion: String,
+}
+
+/// Missing point tracked as an improvement item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImprovementItem {
+    pub id: Option<i64>,
+    pub question_hash: String,
+    pub question: String,
+    pub point: String,
+    pub status: String,          // "open", "resolved", "dismissed"
+    pub occurrences: i32,
+    pub source_comparison_id: Option<i64>,
+    pub resolved_comparison_id: Option<i64>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// One attempt in the comparison history of a question
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonAttempt {
+    pub comparison_id: i64,
+    pub created_at: String,
+    pub overall_match: f32,
+    pub match_delta: Option<f32>,
+    pub matched_count: i32,
+    pub partial_count: i32,
+    pub missing_count: i32,
+    pub newly_covered: Vec<String>,
+}
+
+/// How the match against the best answer evolves across attempts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ComparisonEvolution {
+    pub question: String,
+    pub attempts: Vec<ComparisonAttempt>,
+    pub best_match: f32,
+    pub total_improvement: f32,
+    pub open_items: Vec<ImprovementItem>,
+    pub resolved_items: Vec<ImprovementItem>,
+}
+
+/// Scoring rubric for an interviewer role and question type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rubric {
+    pub id: Option<i64>,
+    pub name: String,
+    pub role: String,            // "technical", "hr", "business", "general"
+    pub question_type: String,   // "general", "technical", "behavioral", "system_design", ...
+    pub criteria: Vec<RubricCriterion>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Weighted criterion of a rubric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RubricCriterion {
+    pub name: String,
+    pub description: String,
+    pub weight: f32,
+    pub levels: Vec<RubricLevel>,
+}
+
+/// Anchor describing what an answer at a given score looks like
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RubricLevel {
+    pub score: f32,
+    pub description: String,
+}
+
+/// Score for one rubric criterion with evidence quoted from the answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CriterionScore {
+    pub criterion: String,
+    pub score: f32,
+    #[serde(default)]
+    pub weight: f32,
+    #[serde(default)]
+    pub evidence: Vec<String>,
+    #[serde(default)]
+    pub comment: String,
+}
+
+/// Severity of a lint finding
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Info,
+    Warning,
+    Error,
+}
+
+/// Rule violation found in an answer, `start..end` is a byte span of the answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LintFinding {
+    pub rule_id: String,
+    pub severity: LintSeverity,
+    pub start: usize,
+    pub end: usize,
+    pub message: String,
+    pub suggestion: String,
+}
+
+/// LLM judge scores recorded next to the heuristic scores for one answer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JudgeScore {
+    pub id: Option<i64>,
+    pub answer_id: i64,
+    pub heuristic_logic: f32,            // Heuristic logic score before blending (1-10)
+    pub heuristic_job_match: f32,        // Heuristic job match score before blending (1-10)
+    pub heuristic_overall: f32,          // Heuristic overall score before blending (1-10)
+    pub judge_logic: f32,                // LLM judge logic score (1-10)
+    pub judge_job_match: f32,            // LLM judge job match score (1-10)
+    pub judge_overall: f32,              // LLM judge overall score (1-10)
+    pub blended_overall: f32,            // Overall score written to answer_analysis
+    pub heuristic_weight: f32,
+    pub judge_weight: f32,
+    pub rationale: String,
+    pub created_at: String,
+}
+
+/// User-defined skill merged into the bundled taxonomy
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CustomSkill {
+    pub id: Option<i64>,
+    pub name: String,
+    pub aliases: String,                 // JSON array of alias strings
+    pub category: String,                // language, framework, database, tool, platform, concept or soft
+    pub parent: Option<String>,
+    pub created_at: String,
+}
+
+/// Stored classification of a question bank entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionClassification {
+    pub id: Option<i64>,
+    pub question_id: i64,
+    pub question_type: String,           // behavioral, technical, system_design, situational or motivation
+    pub dimension: String,               // Profile dimension, e.g. technical_depth
+    pub difficulty: String,              // easy, medium or hard
+    pub skills: String,                  // JSON array of canonical skill names
+    pub method: String,                  // embedding, keywords or llm
+    pub confidence: f32,                 // 0-1
+    pub created_at: String,
+}
+
+/// Practice goal of the active user
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Goal {
+    pub id: Option<i64>,
+    pub goal_type: String,               // dimension_score or session_count
+    pub dimension: Option<String>,       // Profile dimension or "overall" for score goals
+    pub target_value: f32,               // Score (0-100) or number of sessions
+    pub baseline_value: f32,             // Score when the goal was set; 0 for session goals
+    pub deadline: String,                // YYYY-MM-DD, inclusive
+    pub status: String,                  // active, at_risk, achieved or missed
+    pub achieved_at: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// Reverse-interview questions of a multi-agent session and their evaluation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReverseEvaluation {
+    pub id: Option<i64>,
+    pub session_id: Option<i64>,         // Saved session, None when nothing was answered
+    pub turns: String,                   // JSON array of reverse turns
+    pub evaluation: Option<String>,      // JSON evaluation, None when not evaluated
+    pub created_at: String,
+}
+
+/// Spaced-repetition schedule of a question bank entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuestionReview {
+    pub question_id: i64,
+    pub ease: f32,                       // SM-2 ease factor, at least 1.3
+    pub interval_days: i64,
+    pub repetitions: i64,                // Successful reviews in a row
+    pub lapses: i64,                     // Failed reviews in total
+    pub due_date: String,                // YYYY-MM-DD
+    pub last_reviewed_at: Option<String>,
+}
+
+/// Graded practice attempt with the schedule it produced
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewRecord {
+    pub id: Option<i64>,
+    pub question_id: i64,
+    pub answer_id: Option<i64>,
+    pub score: f32,                      // answer_analysis.overall_score (0-10)
+    pub grade: i64,                      // SM-2 recall grade (0-5)
+    pub ease: f32,
+    pub interval_days: i64,
+    pub due_date: String,
+    pub reviewed_at: String,
+}
+
 /// Helper function to get current timestamp as ISO 8601 string
 pub fn now() -> String {
     Utc::now().to_rfc3339()
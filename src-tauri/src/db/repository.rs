@@ -50,6 +50,61 @@ impl Repository {
         Ok(result)
     }
 
+    // ===== User Scoping =====
+
+    /// Owner of knowledge shared by all users, such as the bootstrapped question and answer set
+    ///
+    /// Knowledge reads include these rows next to the active user's own;
+    /// deletes only ever touch the active user's rows.
+    pub(crate) const SHARED_KNOWLEDGE_OWNER: i64 = 0;
+
+    /// ID of the active user, read on an already locked connection
+    ///
+    /// Every read and write below is scoped to this user. It cannot go
+    /// through `get_current_user_id`, which would lock the mutex again.
+    /// The vector store scopes its own connection with it as well.
+    pub(crate) fn current_user(conn: &Connection) -> i64 {
+        conn.query_row(
+            "SELECT value FROM user_config WHERE key = 'current_user_id'",
+            [],
+            |row| row.get::<_, String>(0),
+        )
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(1)
+    }
+
+    /// Whether a session belongs to the active user
+    fn owns_session(conn: &Connection, session_id: i64) -> Result<bool> {
+        let owned = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM interview_sessions WHERE id = ?1 AND user_id = ?2)",
+            params![session_id, Self::current_user(conn)],
+            |row| row.get(0),
+        )?;
+        Ok(owned)
+    }
+
+    /// Whether an answer belongs to a session of the active user
+    fn owns_answer(conn: &Connection, answer_id: i64) -> Result<bool> {
+        let owned = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM interview_answers ia JOIN interview_sessions s ON s.id = ia.session_id \
+             WHERE ia.id = ?1 AND s.user_id = ?2)",
+            params![answer_id, Self::current_user(conn)],
+            |row| row.get(0),
+        )?;
+        Ok(owned)
+    }
+
+    /// Whether a question bank entry belongs to the active user
+    fn owns_question(conn: &Connection, question_id: i64) -> Result<bool> {
+        let owned = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM question_bank WHERE id = ?1 AND user_id = ?2)",
+            params![question_id, Self::current_user(conn)],
+            |row| row.get(0),
+        )?;
+        Ok(owned)
+    }
+
     // ===== Resume Operations =====
 
     /// Save a new resume
@@ -58,8 +113,22 @@ impl Repository {
             let timestamp = now();
             
             conn.execute(
-                "INSERT INTO resumes (title, content, created_at, updated_at) VALUES (?1, ?2, ?3, ?4)",
-                params![title, content, timestamp, timestamp],
+                "INSERT INTO resumes (user_id, title, content, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![Self::current_user(&conn), title, content, timestamp, timestamp],
+            )?;
+            
+            Ok(conn.last_insert_rowid())
+        })
+    }
+
+    /// Save a resume imported from a file with its structured JSON
+    pub fn save_imported_resume(&self, title: String, content: String, structured: String) -> Result<i64> {
+        with_conn!(self, |conn| {
+            let timestamp = now();
+            
+            conn.execute(
+                "INSERT INTO resumes (user_id, title, content, structured, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![Self::current_user(&conn), title, content, structured, timestamp, timestamp],
             )?;
             
             Ok(conn.last_insert_rowid())
@@ -70,18 +139,19 @@ impl Repository {
     pub fn get_resumes(&self) -> Result<Vec<Resume>> {
         with_conn!(self, |conn| {
             let mut stmt = conn.prepare(
-                "SELECT id, user_id, title, content, created_at, updated_at FROM resumes ORDER BY updated_at DESC"
+                "SELECT id, user_id, title, content, structured, created_at, updated_at FROM resumes WHERE user_id = ?1 ORDER BY updated_at DESC"
             )?;
             
             let resumes = stmt
-                .query_map([], |row| {
+                .query_map(params![Self::current_user(&conn)], |row| {
                     Ok(Resume {
                         id: Some(row.get(0)?),
                         user_id: row.get(1)?,
                         title: row.get(2)?,
                         content: row.get(3)?,
-                        created_at: row.get(4)?,
-                        updated_at: row.get(5)?,
+                        structured: row.get(4)?,
+                        created_at: row.get(5)?,
+                        updated_at: row.get(6)?,
                     })
                 })?
                 .collect::<Result<Vec<_>, _>>()?;
@@ -93,7 +163,7 @@ impl Repository {
     /// Delete a resume by ID
     pub fn delete_resume(&self, id: i64) -> Result<()> {
         with_conn!(self, |conn| {
-            conn.execute("DELETE FROM resumes WHERE id = ?1", params![id])?;
+            conn.execute("DELETE FROM resumes WHERE id = ?1 AND user_id = ?2", params![id, Self::current_user(&conn)])?;
             Ok(())
         })
     }
@@ -106,8 +176,8 @@ impl Repository {
             let timestamp = now();
             
             conn.execute(
-                "INSERT INTO job_descriptions (title, content, created_at, updated_at) VALUES (?1, ?2, ?3, ?4)",
-                params![title, content, timestamp, timestamp],
+                "INSERT INTO job_descriptions (user_id, title, content, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+                params![Self::current_user(&conn), title, content, timestamp, timestamp],
             )?;
             
             Ok(conn.last_insert_rowid())
@@ -118,30 +188,73 @@ impl Repository {
     pub fn get_job_descriptions(&self) -> Result<Vec<JobDescription>> {
         with_conn!(self, |conn| {
             let mut stmt = conn.prepare(
-                "SELECT id, user_id, title, content, created_at, updated_at FROM job_descriptions ORDER BY updated_at DESC"
+                "SELECT id, user_id, title, content, structured, created_at, updated_at FROM job_descriptions WHERE user_id = ?1 ORDER BY updated_at DESC"
             )?;
             
             let jds = stmt
-                .query_map([], |row| {
-                    Ok(JobDescription {
-                        id: Some(row.get(0)?),
-                        user_id: row.get(1)?,
-                        title: row.get(2)?,
-                        content: row.get(3)?,
-                        created_at: row.get(4)?,
-                        updated_at: row.get(5)?,
-                    })
-                })?
+                .query_map(params![Self::current_user(&conn)], Self::row_to_job_description)?
                 .collect::<Result<Vec<_>, _>>()?;
             
             Ok(jds)
         })
     }
 
+    /// Get a job description by ID
+    pub fn get_job_description(&self, id: i64) -> Result<Option<JobDescription>> {
+        with_conn!(self, |conn| {
+            let jd = conn
+                .query_row(
+                    "SELECT id, user_id, title, content, structured, created_at, updated_at FROM job_descriptions WHERE id = ?1 AND user_id = ?2",
+                    params![id, Self::current_user(&conn)],
+                    Self::row_to_job_description,
+                )
+                .optional()?;
+            Ok(jd)
+        })
+    }
+
+    /// Store the structured fields of a job description
+    pub fn update_job_description_structured(&self, id: i64, structured: &str) -> Result<()> {
+        with_conn!(self, |conn| {
+            conn.execute(
+                "UPDATE job_descriptions SET structured = ?1, updated_at = ?2 WHERE id = ?3 AND user_id = ?4",
+                params![structured, now(), id, Self::current_user(&conn)],
+            )?;
+            Ok(())
+        })
+    }
+
+    /// Structured fields stored for a JD with exactly this content, if any
+    pub fn find_job_description_structured(&self, content: &str) -> Result<Option<String>> {
+        with_conn!(self, |conn| {
+            let structured = conn
+                .query_row(
+                    "SELECT structured FROM job_descriptions WHERE content = ?1 AND user_id = ?2 AND structured != '{}' \
+                     ORDER BY updated_at DESC LIMIT 1",
+                    params![content, Self::current_user(&conn)],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            Ok(structured)
+        })
+    }
+
+    fn row_to_job_description(row: &rusqlite::Row) -> rusqlite::Result<JobDescription> {
+        Ok(JobDescription {
+            id: Some(row.get(0)?),
+            user_id: row.get(1)?,
+            title: row.get(2)?,
+            content: row.get(3)?,
+            structured: row.get(4)?,
+            created_at: row.get(5)?,
+            updated_at: row.get(6)?,
+        })
+    }
+
     /// Delete a job description by ID
     pub fn delete_job_description(&self, id: i64) -> Result<()> {
         with_conn!(self, |conn| {
-            conn.execute("DELETE FROM job_descriptions WHERE id = ?1", params![id])?;
+            conn.execute("DELETE FROM job_descriptions WHERE id = ?1 AND user_id = ?2", params![id, Self::current_user(&conn)])?;
             Ok(())
         })
     }
@@ -160,8 +273,8 @@ impl Repository {
         let questions_json = serde_json::to_string(&questions)?;
         
         conn.execute(
-            "INSERT INTO interview_sessions (resume_id, job_description_id, questions, created_at) VALUES (?1, ?2, ?3, ?4)",
-            params![resume_id, job_description_id, questions_json, timestamp],
+            "INSERT INTO interview_sessions (user_id, resume_id, job_description_id, questions, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![Self::current_user(&conn), resume_id, job_description_id, questions_json, timestamp],
         )?;
         
         Ok(conn.last_insert_rowid())
@@ -171,11 +284,11 @@ impl Repository {
     pub fn get_interview_sessions(&self) -> Result<Vec<InterviewSession>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, user_id, resume_id, job_description_id, questions, created_at FROM interview_sessions ORDER BY created_at DESC"
+            "SELECT id, user_id, resume_id, job_description_id, questions, created_at FROM interview_sessions WHERE user_id = ?1 ORDER BY created_at DESC"
         )?;
         
         let sessions = stmt
-            .query_map([], |row| {
+            .query_map(params![Self::current_user(&conn)], |row| {
                 let questions_json: String = row.get(4)?;
                 let questions: Vec<String> = serde_json::from_str(&questions_json)
                     .unwrap_or_default();
@@ -203,12 +316,13 @@ impl Repository {
                     COUNT(a.id) as answer_count
              FROM interview_sessions s
              LEFT JOIN interview_answers a ON s.id = a.session_id
+             WHERE s.user_id = ?1
              GROUP BY s.id
              ORDER BY s.created_at DESC"
         )?;
         
         let results = stmt
-            .query_map([], |row| {
+            .query_map(params![Self::current_user(&conn)], |row| {
                 let questions_json: String = row.get(4)?;
                 let questions: Vec<String> = serde_json::from_str(&questions_json)
                     .unwrap_or_default();
@@ -235,11 +349,11 @@ impl Repository {
     pub fn get_session_by_id(&self, session_id: i64) -> Result<Option<InterviewSession>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, user_id, resume_id, job_description_id, questions, created_at FROM interview_sessions WHERE id = ?1"
+            "SELECT id, user_id, resume_id, job_description_id, questions, created_at FROM interview_sessions WHERE id = ?1 AND user_id = ?2"
         )?;
         
         let session = stmt
-            .query_row(params![session_id], |row| {
+            .query_row(params![session_id, Self::current_user(&conn)], |row| {
                 let questions_json: String = row.get(4)?;
                 let questions: Vec<String> = serde_json::from_str(&questions_json)
                     .unwrap_or_default();
@@ -261,6 +375,9 @@ impl Repository {
     // ===== Interview Answer Operations =====
 
     /// Save an interview answer
+    ///
+    /// `answer_submitted_at` defaults to the time of saving
+    #[allow(clippy::too_many_arguments)]
     pub fn save_answer(
         &self,
         session_id: i64,
@@ -268,13 +385,25 @@ impl Repository {
         question: String,
         answer: String,
         feedback: String,
+        question_issued_at: Option<String>,
+        answer_submitted_at: Option<String>,
+        question_id: Option<i64>,
     ) -> Result<i64> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_session(&conn, session_id)? {
+            anyhow::bail!("Session {} not found", session_id);
+        }
+        if let Some(question_id) = question_id {
+            if !Self::owns_question(&conn, question_id)? {
+                anyhow::bail!("Question {} not found", question_id);
+            }
+        }
         let timestamp = now();
+        let answer_submitted_at = answer_submitted_at.unwrap_or_else(|| timestamp.clone());
         
         conn.execute(
-            "INSERT INTO interview_answers (session_id, question_index, question, answer, feedback, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![session_id, question_index, question, answer, feedback, timestamp],
+            "INSERT INTO interview_answers (session_id, question_index, question, answer, feedback, question_issued_at, answer_submitted_at, question_id, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![session_id, question_index, question, answer, feedback, question_issued_at, answer_submitted_at, question_id, timestamp],
         )?;
         
         Ok(conn.last_insert_rowid())
@@ -283,8 +412,11 @@ impl Repository {
     /// Get all answers for a session
     pub fn get_answers_by_session(&self, session_id: i64) -> Result<Vec<InterviewAnswer>> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_session(&conn, session_id)? {
+            return Ok(Vec::new());
+        }
         let mut stmt = conn.prepare(
-            "SELECT id, session_id, question_index, question, answer, feedback, created_at FROM interview_answers WHERE session_id = ?1 ORDER BY question_index"
+            "SELECT id, session_id, question_index, question, answer, feedback, question_issued_at, answer_submitted_at, created_at, question_id FROM interview_answers WHERE session_id = ?1 ORDER BY question_index"
         )?;
         
         let answers = stmt
@@ -296,7 +428,10 @@ impl Repository {
                     question: row.get(3)?,
                     answer: row.get(4)?,
                     feedback: row.get(5)?,
-                    created_at: row.get(6)?,
+                    question_issued_at: row.get(6)?,
+                    answer_submitted_at: row.get(7)?,
+                    created_at: row.get(8)?,
+                    question_id: row.get(9)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -304,6 +439,72 @@ impl Repository {
         Ok(answers)
     }
 
+    /// Get an answer of the active user by id
+    pub fn get_answer(&self, answer_id: i64) -> Result<Option<InterviewAnswer>> {
+        let conn = self.conn.lock().unwrap();
+        let answer = conn
+            .query_row(
+                "SELECT ia.id, ia.session_id, ia.question_index, ia.question, ia.answer, ia.feedback, \
+                 ia.question_issued_at, ia.answer_submitted_at, ia.created_at, ia.question_id \
+                 FROM interview_answers ia \
+                 JOIN interview_sessions s ON ia.session_id = s.id \
+                 WHERE ia.id = ?1 AND s.user_id = ?2",
+                params![answer_id, Self::current_user(&conn)],
+                |row| {
+                    Ok(InterviewAnswer {
+                        id: Some(row.get(0)?),
+                        session_id: row.get(1)?,
+                        question_index: row.get(2)?,
+                        question: row.get(3)?,
+                        answer: row.get(4)?,
+                        feedback: row.get(5)?,
+                        question_issued_at: row.get(6)?,
+                        answer_submitted_at: row.get(7)?,
+                        created_at: row.get(8)?,
+                        question_id: row.get(9)?,
+                    })
+                },
+            )
+            .optional()?;
+        Ok(answer)
+    }
+
+    /// Random sample of non-empty answers with the job description of their session
+    pub fn sample_answers_with_jd(&self, limit: usize) -> Result<Vec<(InterviewAnswer, String)>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT ia.id, ia.session_id, ia.question_index, ia.question, ia.answer, ia.feedback, \
+             ia.question_issued_at, ia.answer_submitted_at, ia.created_at, ia.question_id, COALESCE(jd.content, '') \
+             FROM interview_answers ia \
+             JOIN interview_sessions s ON ia.session_id = s.id \
+             LEFT JOIN job_descriptions jd ON s.job_description_id = jd.id \
+             WHERE s.user_id = ?1 AND TRIM(ia.answer) != '' \
+             ORDER BY RANDOM() LIMIT ?2"
+        )?;
+        
+        let answers = stmt
+            .query_map(params![Self::current_user(&conn), limit as i64], |row| {
+                Ok((
+                    InterviewAnswer {
+                        id: Some(row.get(0)?),
+                        session_id: row.get(1)?,
+                        question_index: row.get(2)?,
+                        question: row.get(3)?,
+                        answer: row.get(4)?,
+                        feedback: row.get(5)?,
+                        question_issued_at: row.get(6)?,
+                        answer_submitted_at: row.get(7)?,
+                        created_at: row.get(8)?,
+                        question_id: row.get(9)?,
+                    },
+                    row.get::<_, String>(10)?,
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(answers)
+    }
+
     // ===== Question Bank Operations =====
 
     /// Add question to bank
@@ -318,8 +519,8 @@ impl Repository {
         let timestamp = now();
         
         conn.execute(
-            "INSERT INTO question_bank (question, best_answer, notes, job_category, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-            params![question, best_answer, notes, job_category, timestamp, timestamp],
+            "INSERT INTO question_bank (user_id, question, best_answer, notes, job_category, created_at, updated_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            params![Self::current_user(&conn), question, best_answer, notes, job_category, timestamp, timestamp],
         )?;
         
         Ok(conn.last_insert_rowid())
@@ -329,11 +530,11 @@ impl Repository {
     pub fn get_question_bank(&self) -> Result<Vec<QuestionBankItem>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, user_id, question, best_answer, notes, job_category, created_at, updated_at FROM question_bank ORDER BY updated_at DESC"
+            "SELECT id, user_id, question, best_answer, notes, job_category, created_at, updated_at FROM question_bank WHERE user_id = ?1 ORDER BY updated_at DESC"
         )?;
         
         let items = stmt
-            .query_map([], |row| {
+            .query_map(params![Self::current_user(&conn)], |row| {
                 Ok(QuestionBankItem {
                     id: Some(row.get(0)?),
                     user_id: row.get(1)?,
@@ -361,8 +562,8 @@ impl Repository {
         let timestamp = now();
         
         conn.execute(
-            "UPDATE question_bank SET best_answer = ?1, notes = ?2, updated_at = ?3 WHERE id = ?4",
-            params![best_answer, notes, timestamp, id],
+            "UPDATE question_bank SET best_answer = ?1, notes = ?2, updated_at = ?3 WHERE id = ?4 AND user_id = ?5",
+            params![best_answer, notes, timestamp, id, Self::current_user(&conn)],
         )?;
         
         Ok(())
@@ -371,7 +572,7 @@ impl Repository {
     /// Delete question from bank
     pub fn delete_from_question_bank(&self, id: i64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
-        conn.execute("DELETE FROM question_bank WHERE id = ?1", params![id])?;
+        conn.execute("DELETE FROM question_bank WHERE id = ?1 AND user_id = ?2", params![id, Self::current_user(&conn)])?;
         Ok(())
     }
 
@@ -392,6 +593,9 @@ impl Repository {
         suggestions: String,
     ) -> Result<i64> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, answer_id)? {
+            anyhow::bail!("Answer {} not found", answer_id);
+        }
         let timestamp = now();
         
         conn.execute(
@@ -408,8 +612,11 @@ impl Repository {
     /// Get analysis for an answer
     pub fn get_answer_analysis(&self, answer_id: i64) -> Result<Option<AnswerAnalysis>> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, answer_id)? {
+            return Ok(None);
+        }
         let mut stmt = conn.prepare(
-            "SELECT id, answer_id, content_score, logic_score, job_match_score, keyword_coverage, expression_score, overall_score, strengths, weaknesses, suggestions, created_at FROM answer_analysis WHERE answer_id = ?1"
+            "SELECT id, answer_id, content_score, logic_score, job_match_score, keyword_coverage, expression_score, overall_score, strengths, weaknesses, suggestions, rubric_id, criterion_scores, lint_findings, score_trace, created_at FROM answer_analysis WHERE answer_id = ?1"
         )?;
         
         let analysis = stmt
@@ -426,7 +633,11 @@ impl Repository {
                     strengths: row.get(8)?,
                     weaknesses: row.get(9)?,
                     suggestions: row.get(10)?,
-                    created_at: row.get(11)?,
+                    rubric_id: row.get(11)?,
+                    criterion_scores: row.get(12)?,
+                    lint_findings: row.get(13)?,
+                    score_trace: row.get(14)?,
+                    created_at: row.get(15)?,
                 })
             })
             .optional()?;
@@ -434,6 +645,259 @@ impl Repository {
         Ok(analysis)
     }
 
+    /// Attach a rubric breakdown to an existing answer analysis
+    ///
+    /// Returns `false` when the answer has not been analyzed yet
+    pub fn save_criterion_scores(
+        &self,
+        answer_id: i64,
+        rubric_id: Option<i64>,
+        scores: &[CriterionScore],
+    ) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, answer_id)? {
+            return Ok(false);
+        }
+        let scores_json = serde_json::to_string(scores)?;
+        
+        let updated = conn.execute(
+            "UPDATE answer_analysis SET rubric_id = ?1, criterion_scores = ?2 WHERE answer_id = ?3",
+            params![rubric_id, scores_json, answer_id],
+        )?;
+        
+        Ok(updated > 0)
+    }
+
+    /// Attach lint findings to an existing answer analysis
+    ///
+    /// Returns `false` when the answer has not been analyzed yet
+    pub fn save_lint_findings(&self, answer_id: i64, findings: &[LintFinding]) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, answer_id)? {
+            return Ok(false);
+        }
+        let findings_json = serde_json::to_string(findings)?;
+        
+        let updated = conn.execute(
+            "UPDATE answer_analysis SET lint_findings = ?1 WHERE answer_id = ?2",
+            params![findings_json, answer_id],
+        )?;
+        
+        Ok(updated > 0)
+    }
+
+    /// Attach a score trace (JSON) to an existing answer analysis
+    ///
+    /// Returns `false` when the answer has not been analyzed yet
+    pub fn save_score_trace(&self, answer_id: i64, trace_json: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, answer_id)? {
+            return Ok(false);
+        }
+        
+        let updated = conn.execute(
+            "UPDATE answer_analysis SET score_trace = ?1 WHERE answer_id = ?2",
+            params![trace_json, answer_id],
+        )?;
+        
+        Ok(updated > 0)
+    }
+
+    /// Overwrite the scores of an existing answer analysis
+    ///
+    /// Returns `false` when the answer has not been analyzed yet
+    pub fn update_answer_scores(
+        &self,
+        answer_id: i64,
+        content_score: f32,
+        logic_score: f32,
+        job_match_score: f32,
+        overall_score: f32,
+    ) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, answer_id)? {
+            return Ok(false);
+        }
+        
+        let updated = conn.execute(
+            "UPDATE answer_analysis SET content_score = ?1, logic_score = ?2, job_match_score = ?3, overall_score = ?4 WHERE answer_id = ?5",
+            params![content_score, logic_score, job_match_score, overall_score, answer_id],
+        )?;
+        
+        Ok(updated > 0)
+    }
+
+    // ===== Judge Score Operations =====
+
+    /// Save judge scores for an answer, replacing any previous judgement
+    pub fn save_judge_score(&self, score: &JudgeScore) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, score.answer_id)? {
+            anyhow::bail!("Answer {} not found", score.answer_id);
+        }
+        let timestamp = now();
+        
+        conn.execute(
+            "INSERT INTO judge_scores (answer_id, heuristic_logic, heuristic_job_match, heuristic_overall, \
+             judge_logic, judge_job_match, judge_overall, blended_overall, heuristic_weight, judge_weight, rationale, created_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12) \
+             ON CONFLICT(answer_id) DO UPDATE SET \
+                judge_logic = excluded.judge_logic, judge_job_match = excluded.judge_job_match, \
+                judge_overall = excluded.judge_overall, blended_overall = excluded.blended_overall, \
+                heuristic_weight = excluded.heuristic_weight, judge_weight = excluded.judge_weight, \
+                rationale = excluded.rationale, created_at = excluded.created_at",
+            params![
+                score.answer_id, score.heuristic_logic, score.heuristic_job_match, score.heuristic_overall,
+                score.judge_logic, score.judge_job_match, score.judge_overall, score.blended_overall,
+                score.heuristic_weight, score.judge_weight, score.rationale, timestamp
+            ],
+        )?;
+        
+        let id = conn.query_row(
+            "SELECT id FROM judge_scores WHERE answer_id = ?1",
+            params![score.answer_id],
+            |row| row.get(0),
+        )?;
+        
+        Ok(id)
+    }
+
+    /// Get judge scores for an answer
+    pub fn get_judge_score(&self, answer_id: i64) -> Result<Option<JudgeScore>> {
+        let conn = self.conn.lock().unwrap();
+        if !Self::owns_answer(&conn, answer_id)? {
+            return Ok(None);
+        }
+        let mut stmt = conn.prepare(
+            "SELECT id, answer_id, heuristic_logic, heuristic_job_match, heuristic_overall, judge_logic, judge_job_match, judge_overall, blended_overall, heuristic_weight, judge_weight, rationale, created_at FROM judge_scores WHERE answer_id = ?1"
+        )?;
+        
+        let score = stmt.query_row(params![answer_id], Self::row_to_judge_score).optional()?;
+        
+        Ok(score)
+    }
+
+    /// Get all judge scores, oldest first
+    pub fn get_judge_scores(&self) -> Result<Vec<JudgeScore>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT j.id, j.answer_id, j.heuristic_logic, j.heuristic_job_match, j.heuristic_overall, j.judge_logic, j.judge_job_match, j.judge_overall, j.blended_overall, j.heuristic_weight, j.judge_weight, j.rationale, j.created_at \
+             FROM judge_scores j \
+             JOIN interview_answers ia ON ia.id = j.answer_id \
+             JOIN interview_sessions s ON s.id = ia.session_id \
+             WHERE s.user_id = ?1 ORDER BY j.created_at ASC"
+        )?;
+        
+        let scores = stmt
+            .query_map(params![Self::current_user(&conn)], Self::row_to_judge_score)?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(scores)
+    }
+
+    fn row_to_judge_score(row: &rusqlite::Row) -> rusqlite::Result<JudgeScore> {
+        Ok(JudgeScore {
+            id: Some(row.get(0)?),
+            answer_id: row.get(1)?,
+            heuristic_logic: row.get(2)?,
+            heuristic_job_match: row.get(3)?,
+            heuristic_overall: row.get(4)?,
+            judge_logic: row.get(5)?,
+            judge_job_match: row.get(6)?,
+            judge_overall: row.get(7)?,
+            blended_overall: row.get(8)?,
+            heuristic_weight: row.get(9)?,
+            judge_weight: row.get(10)?,
+            rationale: row.get(11)?,
+            created_at: row.get(12)?,
+        })
+    }
+
+    // ===== Rubric Operations =====
+    //
+    // Rubrics are shared by all users and are not scoped to the current user
+
+    /// Insert a rubric, or update it when `id` is set
+    ///
+    /// A new rubric replaces any stored rubric for the same role and question
+    /// type; an update that would collide with another rubric is rejected
+    pub fn save_rubric(&self, rubric: &Rubric) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        let timestamp = now();
+        let criteria_json = serde_json::to_string(&rubric.criteria)?;
+        
+        if let Some(id) = rubric.id {
+            // Moving a rubric onto another rubric's role and question type would break UNIQUE(role, question_type)
+            let conflict: Option<i64> = conn.query_row(
+                "SELECT id FROM rubrics WHERE role = ?1 AND question_type = ?2 AND id != ?3",
+                params![rubric.role, rubric.question_type, id],
+                |row| row.get(0),
+            ).optional()?;
+            if conflict.is_some() {
+                anyhow::bail!(
+                    "A rubric for role '{}' and question type '{}' already exists",
+                    rubric.role,
+                    rubric.question_type
+                );
+            }
+            
+            conn.execute(
+                "UPDATE rubrics SET name = ?1, role = ?2, question_type = ?3, criteria = ?4, updated_at = ?5 WHERE id = ?6",
+                params![rubric.name, rubric.role, rubric.question_type, criteria_json, timestamp, id],
+            )?;
+            return Ok(id);
+        }
+        
+        conn.execute(
+            "INSERT INTO rubrics (name, role, question_type, criteria, created_at, updated_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?5) \
+             ON CONFLICT(role, question_type) DO UPDATE SET \
+                name = excluded.name, criteria = excluded.criteria, updated_at = excluded.updated_at",
+            params![rubric.name, rubric.role, rubric.question_type, criteria_json, timestamp],
+        )?;
+        
+        let id = conn.query_row(
+            "SELECT id FROM rubrics WHERE role = ?1 AND question_type = ?2",
+            params![rubric.role, rubric.question_type],
+            |row| row.get(0),
+        )?;
+        
+        Ok(id)
+    }
+
+    /// Get all stored rubrics
+    pub fn get_rubrics(&self) -> Result<Vec<Rubric>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, name, role, question_type, criteria, created_at, updated_at FROM rubrics ORDER BY role, question_type"
+        )?;
+        
+        let rubrics = stmt
+            .query_map([], |row| {
+                let criteria_json: String = row.get(4)?;
+                
+                Ok(Rubric {
+                    id: Some(row.get(0)?),
+                    name: row.get(1)?,
+                    role: row.get(2)?,
+                    question_type: row.get(3)?,
+                    criteria: serde_json::from_str(&criteria_json).unwrap_or_default(),
+                    created_at: row.get(5)?,
+                    updated_at: row.get(6)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(rubrics)
+    }
+
+    /// Delete a stored rubric, the built-in default applies again
+    pub fn delete_rubric(&self, id: i64) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM rubrics WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
     // ===== Session Report Operations =====
 
     /// Save report for a session
@@ -450,6 +914,9 @@ impl Repository {
         api_response_time: Option<i32>,
     ) -> Result<i64> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_session(&conn, session_id)? {
+            anyhow::bail!("Session {} not found", session_id);
+        }
         let timestamp = now();
         
         conn.execute(
@@ -466,6 +933,9 @@ impl Repository {
     /// Get report for a session
     pub fn get_session_report(&self, session_id: i64) -> Result<Option<SessionReport>> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_session(&conn, session_id)? {
+            return Ok(None);
+        }
         let mut stmt = conn.prepare(
             "SELECT id, session_id, overall_score, content_analysis, expression_analysis, summary, improvements, key_takeaways, reference_answers, generated_at, api_response_time FROM session_reports WHERE session_id = ?1"
         )?;
@@ -509,9 +979,9 @@ impl Repository {
         let timestamp = now();
         
         conn.execute(
-            "INSERT OR REPLACE INTO performance_stats (session_date, total_sessions, average_score, content_avg, expression_avg, highest_score, lowest_score, improvement_trend, recorded_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            "INSERT OR REPLACE INTO performance_stats (user_id, session_date, total_sessions, average_score, content_avg, expression_avg, highest_score, lowest_score, improvement_trend, recorded_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
             params![
-                session_date, total_sessions, average_score, content_avg, expression_avg,
+                Self::current_user(&conn), session_date, total_sessions, average_score, content_avg, expression_avg,
                 highest_score, lowest_score, improvement_trend, timestamp
             ],
         )?;
@@ -523,11 +993,11 @@ impl Repository {
     pub fn get_performance_history(&self) -> Result<Vec<PerformanceStats>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, session_date, total_sessions, average_score, content_avg, expression_avg, highest_score, lowest_score, improvement_trend, recorded_at FROM performance_stats ORDER BY session_date DESC LIMIT 30"
+            "SELECT id, session_date, total_sessions, average_score, content_avg, expression_avg, highest_score, lowest_score, improvement_trend, recorded_at FROM performance_stats WHERE user_id = ?1 ORDER BY session_date DESC LIMIT 30"
         )?;
         
         let stats = stmt
-            .query_map([], |row| {
+            .query_map(params![Self::current_user(&conn)], |row| {
                 Ok(PerformanceStats {
                     id: Some(row.get(0)?),
                     session_date: row.get(1)?,
@@ -562,7 +1032,7 @@ impl Repository {
                      COALESCE(json_extract(r.content_analysis, '$.presentation_score'), 0.0) \
                      FROM session_reports r \
                      JOIN interview_sessions s ON r.session_id = s.id \
-                     WHERE s.created_at >= {} \
+                     WHERE s.user_id = ?1 AND s.created_at >= {} \
                      ORDER BY s.created_at ASC", timestamp
                 )
             },
@@ -574,6 +1044,7 @@ impl Repository {
                  COALESCE(json_extract(r.content_analysis, '$.presentation_score'), 0.0) \
                  FROM session_reports r \
                  JOIN interview_sessions s ON r.session_id = s.id \
+                 WHERE s.user_id = ?1 \
                  ORDER BY s.created_at ASC".to_string()
             }
         };
@@ -581,7 +1052,7 @@ impl Repository {
         let mut stmt = conn.prepare(&query)?;
         
         let reports = stmt
-            .query_map([], |row| {
+            .query_map(params![Self::current_user(&conn)], |row| {
                 Ok((
                     row.get(0)?,  // timestamp
                     row.get(1)?,  // overall_score
@@ -599,6 +1070,7 @@ impl Repository {
     /// Get statistics for performance analytics
     pub fn get_statistics(&self, time_range: Option<i64>) -> Result<(i32, f32, f32, f32, String)> {
         let conn = self.conn.lock().unwrap();
+        let user_id = Self::current_user(&conn);
         
         let query = match time_range {
             Some(timestamp) => {
@@ -606,18 +1078,19 @@ impl Repository {
                     "SELECT COUNT(*), AVG(r.overall_score), MAX(r.overall_score) \
                      FROM session_reports r \
                      JOIN interview_sessions s ON r.session_id = s.id \
-                     WHERE s.created_at >= {}", timestamp
+                     WHERE s.user_id = ?1 AND s.created_at >= {}", timestamp
                 )
             },
             None => {
                 "SELECT COUNT(*), AVG(r.overall_score), MAX(r.overall_score) \
                  FROM session_reports r \
-                 JOIN interview_sessions s ON r.session_id = s.id".to_string()
+                 JOIN interview_sessions s ON r.session_id = s.id \
+                 WHERE s.user_id = ?1".to_string()
             }
         };
         
         let (total_sessions, average_overall, highest_overall): (i32, f32, f32) = conn
-            .query_row(&query, [], |row| {
+            .query_row(&query, params![user_id], |row| {
                 Ok((
                     row.get(0).unwrap_or(0),
                     row.get(1).unwrap_or(0.0),
@@ -632,8 +1105,9 @@ impl Repository {
                     "SELECT AVG(r.overall_score) \
                      FROM session_reports r \
                      JOIN interview_sessions s ON r.session_id = s.id \
+                     WHERE s.user_id = ?1 \
                      ORDER BY s.created_at ASC LIMIT 5",
-                    [],
+                    params![user_id],
                     |row| row.get(0),
                 )
                 .unwrap_or(0.0);
@@ -643,8 +1117,9 @@ impl Repository {
                     "SELECT AVG(r.overall_score) \
                      FROM session_reports r \
                      JOIN interview_sessions s ON r.session_id = s.id \
+                     WHERE s.user_id = ?1 \
                      ORDER BY s.created_at DESC LIMIT 5",
-                    [],
+                    params![user_id],
                     |row| row.get(0),
                 )
                 .unwrap_or(0.0);
@@ -665,8 +1140,9 @@ impl Repository {
                     "SELECT AVG(r.overall_score) \
                      FROM session_reports r \
                      JOIN interview_sessions s ON r.session_id = s.id \
+                     WHERE s.user_id = ?1 \
                      ORDER BY s.created_at DESC LIMIT 3",
-                    [],
+                    params![user_id],
                     |row| row.get(0),
                 )
                 .unwrap_or(0.0);
@@ -676,8 +1152,9 @@ impl Repository {
                     "SELECT AVG(r.overall_score) \
                      FROM session_reports r \
                      JOIN interview_sessions s ON r.session_id = s.id \
+                     WHERE s.user_id = ?1 \
                      ORDER BY s.created_at DESC LIMIT 3 OFFSET 3",
-                    [],
+                    params![user_id],
                     |row| row.get(0),
                 )
                 .unwrap_or(0.0);
@@ -703,8 +1180,8 @@ impl Repository {
     pub fn get_total_sessions_count(&self) -> Result<i32> {
         let conn = self.conn.lock().unwrap();
         let count: i32 = conn.query_row(
-            "SELECT COUNT(*) FROM interview_sessions",
-            [],
+            "SELECT COUNT(*) FROM interview_sessions WHERE user_id = ?1",
+            params![Self::current_user(&conn)],
             |row| row.get(0),
         )?;
         Ok(count)
@@ -714,8 +1191,9 @@ impl Repository {
     pub fn get_average_score(&self) -> Result<f32> {
         let conn = self.conn.lock().unwrap();
         let avg: f32 = conn.query_row(
-            "SELECT AVG(overall_score) FROM session_reports",
-            [],
+            "SELECT AVG(r.overall_score) FROM session_reports r \
+             JOIN interview_sessions s ON r.session_id = s.id WHERE s.user_id = ?1",
+            params![Self::current_user(&conn)],
             |row| row.get(0),
         ).unwrap_or(0.0);
         Ok(avg)
@@ -725,8 +1203,9 @@ impl Repository {
     pub fn get_highest_score(&self) -> Result<f32> {
         let conn = self.conn.lock().unwrap();
         let highest: f32 = conn.query_row(
-            "SELECT MAX(overall_score) FROM session_reports",
-            [],
+            "SELECT MAX(r.overall_score) FROM session_reports r \
+             JOIN interview_sessions s ON r.session_id = s.id WHERE s.user_id = ?1",
+            params![Self::current_user(&conn)],
             |row| row.get(0),
         ).unwrap_or(0.0);
         Ok(highest)
@@ -736,14 +1215,16 @@ impl Repository {
     pub fn get_top_questions(&self, limit: i32) -> Result<Vec<(String, i32)>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT question, COUNT(*) as count FROM interview_answers \
-             GROUP BY question \
+            "SELECT ia.question, COUNT(*) as count FROM interview_answers ia \
+             JOIN interview_sessions s ON ia.session_id = s.id \
+             WHERE s.user_id = ?1 \
+             GROUP BY ia.question \
              ORDER BY count DESC \
-             LIMIT ?1"
+             LIMIT ?2"
         )?;
 
         let questions = stmt
-            .query_map([limit], |row| {
+            .query_map(params![Self::current_user(&conn), limit], |row| {
                 Ok((
                     row.get(0)?,  // question
                     row.get(1)?,  // count
@@ -776,12 +1257,14 @@ impl Repository {
              END as score \
              FROM ( \
                SELECT \
-                 AVG(overall_score) as avg_overall, \
-                 AVG(COALESCE(json_extract(content_analysis, '$.communication_score'), 0)) as avg_communication, \
-                 AVG(COALESCE(json_extract(content_analysis, '$.problem_solving_score'), 0)) as avg_problem_solving, \
-                 AVG(COALESCE(json_extract(content_analysis, '$.technical_depth_score'), 0)) as avg_technical_depth, \
-                 AVG(COALESCE(json_extract(content_analysis, '$.presentation_score'), 0)) as avg_presentation \
-               FROM session_reports \
+                 AVG(r.overall_score) as avg_overall, \
+                 AVG(COALESCE(json_extract(r.content_analysis, '$.communication_score'), 0)) as avg_communication, \
+                 AVG(COALESCE(json_extract(r.content_analysis, '$.problem_solving_score'), 0)) as avg_problem_solving, \
+                 AVG(COALESCE(json_extract(r.content_analysis, '$.technical_depth_score'), 0)) as avg_technical_depth, \
+                 AVG(COALESCE(json_extract(r.content_analysis, '$.presentation_score'), 0)) as avg_presentation \
+               FROM session_reports r \
+               JOIN interview_sessions s ON r.session_id = s.id \
+               WHERE s.user_id = ?1 \
              ) \
              WHERE area IS NOT NULL \
              ORDER BY score ASC \
@@ -789,7 +1272,7 @@ impl Repository {
         )?;
 
         let weak_areas = stmt
-            .query_map([], |row| {
+            .query_map(params![Self::current_user(&conn)], |row| {
                 Ok((
                     row.get(0)?,  // area name
                     row.get(1)?,  // score
@@ -806,12 +1289,13 @@ impl Repository {
         let mut stmt = conn.prepare(
             "SELECT id, user_id, resume_id, job_description_id, questions, created_at \
              FROM interview_sessions \
+             WHERE user_id = ?1 \
              ORDER BY created_at DESC \
-             LIMIT ?1"
+             LIMIT ?2"
         )?;
 
         let sessions = stmt
-            .query_map([limit], |row| {
+            .query_map(params![Self::current_user(&conn), limit], |row| {
                 let questions_json: String = row.get(4)?;
                 let questions: Vec<String> = serde_json::from_str(&questions_json)
                     .unwrap_or_default();
@@ -841,12 +1325,12 @@ impl Repository {
              FROM interview_answers ia \
              JOIN interview_sessions s ON ia.session_id = s.id \
              LEFT JOIN session_reports sr ON s.id = sr.session_id \
-             WHERE ia.question = ?1 \
+             WHERE ia.question = ?1 AND s.user_id = ?2 \
              ORDER BY s.created_at ASC"
         )?;
 
         let results = stmt
-            .query_map([question], |row| {
+            .query_map(params![question, Self::current_user(&conn)], |row| {
                 Ok((
                     row.get::<_, String>(0)?,  // created_at
                     row.get::<_, String>(1)?,  // answer
@@ -862,6 +1346,9 @@ impl Repository {
     /// Delete a specific interview session and related data
     pub fn delete_session(&self, session_id: i64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_session(&conn, session_id)? {
+            return Ok(());
+        }
         
         // Delete in reverse dependency order
         conn.execute(
@@ -869,6 +1356,11 @@ impl Repository {
             [session_id],
         )?;
         
+        conn.execute(
+            "DELETE FROM answer_analysis WHERE answer_id IN (SELECT id FROM interview_answers WHERE session_id = ?1)",
+            [session_id],
+        )?;
+        
         conn.execute(
             "DELETE FROM interview_answers WHERE session_id = ?1",
             [session_id],
@@ -886,9 +1378,22 @@ impl Repository {
     pub fn delete_all_sessions(&self) -> Result<()> {
         let conn = self.conn.lock().unwrap();
         
-        conn.execute("DELETE FROM session_reports", [])?;
-        conn.execute("DELETE FROM interview_answers", [])?;
-        conn.execute("DELETE FROM interview_sessions", [])?;
+        let user_id = Self::current_user(&conn);
+        
+        conn.execute(
+            "DELETE FROM session_reports WHERE session_id IN (SELECT id FROM interview_sessions WHERE user_id = ?1)",
+            [user_id],
+        )?;
+        conn.execute(
+            "DELETE FROM answer_analysis WHERE answer_id IN (SELECT ia.id FROM interview_answers ia \
+             JOIN interview_sessions s ON s.id = ia.session_id WHERE s.user_id = ?1)",
+            [user_id],
+        )?;
+        conn.execute(
+            "DELETE FROM interview_answers WHERE session_id IN (SELECT id FROM interview_sessions WHERE user_id = ?1)",
+            [user_id],
+        )?;
+        conn.execute("DELETE FROM interview_sessions WHERE user_id = ?1", [user_id])?;
         
         Ok(())
     }
@@ -898,11 +1403,12 @@ impl Repository {
     /// Get paginated interview sessions
     pub fn get_sessions_paginated(&self, page: i32, page_size: i32) -> Result<(Vec<InterviewSession>, i32)> {
         let conn = self.conn.lock().unwrap();
+        let user_id = Self::current_user(&conn);
         
         // Get total count
         let total: i32 = conn.query_row(
-            "SELECT COUNT(*) FROM interview_sessions",
-            [],
+            "SELECT COUNT(*) FROM interview_sessions WHERE user_id = ?1",
+            [user_id],
             |row| row.get(0),
         )?;
         
@@ -913,12 +1419,13 @@ impl Repository {
         let mut stmt = conn.prepare(
             "SELECT id, user_id, resume_id, job_description_id, questions, created_at \
              FROM interview_sessions \
+             WHERE user_id = ?1 \
              ORDER BY created_at DESC \
-             LIMIT ?1 OFFSET ?2"
+             LIMIT ?2 OFFSET ?3"
         )?;
         
         let sessions = stmt
-            .query_map([page_size, offset], |row| {
+            .query_map(params![user_id, page_size, offset], |row| {
                 let questions_json: String = row.get(4)?;
                 let questions: Vec<String> = serde_json::from_str(&questions_json)
                     .unwrap_or_default();
@@ -940,6 +1447,9 @@ impl Repository {
     /// Get paginated answers for a session
     pub fn get_answers_paginated(&self, session_id: i64, page: i32, page_size: i32) -> Result<(Vec<InterviewAnswer>, i32)> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_session(&conn, session_id)? {
+            return Ok((Vec::new(), 0));
+        }
         
         // Get total count
         let total: i32 = conn.query_row(
@@ -953,7 +1463,7 @@ impl Repository {
         
         // Get paginated data
         let mut stmt = conn.prepare(
-            "SELECT id, session_id, question_index, question, answer, feedback, created_at \
+            "SELECT id, session_id, question_index, question, answer, feedback, question_issued_at, answer_submitted_at, created_at, question_id \
              FROM interview_answers \
              WHERE session_id = ?1 \
              ORDER BY question_index ASC \
@@ -969,7 +1479,10 @@ impl Repository {
                     question: row.get(3)?,
                     answer: row.get(4)?,
                     feedback: row.get(5)?,
-                    created_at: row.get(6)?,
+                    question_issued_at: row.get(6)?,
+                    answer_submitted_at: row.get(7)?,
+                    created_at: row.get(8)?,
+                    question_id: row.get(9)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -983,12 +1496,12 @@ impl Repository {
         let mut stmt = conn.prepare(
             "SELECT id, user_id, resume_id, job_description_id, questions, created_at \
              FROM interview_sessions \
-             WHERE created_at >= ?1 AND created_at <= ?2 \
+             WHERE user_id = ?1 AND created_at >= ?2 AND created_at <= ?3 \
              ORDER BY created_at DESC"
         )?;
         
         let sessions = stmt
-            .query_map(params![start_date, end_date], |row| {
+            .query_map(params![Self::current_user(&conn), start_date, end_date], |row| {
                 let questions_json: String = row.get(4)?;
                 let questions: Vec<String> = serde_json::from_str(&questions_json)
                     .unwrap_or_default();
@@ -1016,12 +1529,12 @@ impl Repository {
              r.reference_answers, r.generated_at, r.api_response_time \
              FROM session_reports r \
              JOIN interview_sessions s ON r.session_id = s.id \
-             WHERE s.created_at >= ?1 AND s.created_at <= ?2 \
+             WHERE s.user_id = ?1 AND s.created_at >= ?2 AND s.created_at <= ?3 \
              ORDER BY s.created_at DESC"
         )?;
         
         let reports = stmt
-            .query_map(params![start_date, end_date], |row| {
+            .query_map(params![Self::current_user(&conn), start_date, end_date], |row| {
                 Ok(SessionReport {
                     id: Some(row.get(0)?),
                     session_id: row.get(1)?,
@@ -1041,63 +1554,386 @@ impl Repository {
         Ok(reports)
     }
 
-    // ===== Question Tag Operations =====
+    // ===== Custom Skill Operations =====
 
-    /// Create a new tag
-    pub fn create_tag(&self, name: String, color: String) -> Result<i64> {
+    /// Insert or replace a user-defined taxonomy skill by name
+    pub fn save_custom_skill(
+        &self,
+        name: &str,
+        aliases: &[String],
+        category: &str,
+        parent: Option<&str>,
+    ) -> Result<i64> {
         let conn = self.conn.lock().unwrap();
-        let timestamp = now();
-        
+        let aliases_json = serde_json::to_string(aliases)?;
         conn.execute(
-            "INSERT INTO question_tags (name, color, created_at) VALUES (?1, ?2, ?3)",
-            params![name, color, timestamp],
+            "INSERT INTO custom_skills (name, aliases, category, parent, created_at) VALUES (?1, ?2, ?3, ?4, ?5) \
+             ON CONFLICT(name) DO UPDATE SET aliases = excluded.aliases, category = excluded.category, parent = excluded.parent",
+            params![name, aliases_json, category, parent, now()],
         )?;
-        
-        Ok(conn.last_insert_rowid())
+        let id = conn.query_row(
+            "SELECT id FROM custom_skills WHERE name = ?1",
+            params![name],
+            |row| row.get(0),
+        )?;
+        Ok(id)
     }
 
-    /// Get all tags
-    pub fn get_all_tags(&self) -> Result<Vec<QuestionTag>> {
+    /// All user-defined taxonomy skills in insertion order
+    pub fn get_custom_skills(&self) -> Result<Vec<CustomSkill>> {
         let conn = self.conn.lock().unwrap();
         let mut stmt = conn.prepare(
-            "SELECT id, user_id, name, color, created_at FROM question_tags ORDER BY name"
+            "SELECT id, name, aliases, category, parent, created_at FROM custom_skills ORDER BY id"
         )?;
-        
-        let tags = stmt
+        let skills = stmt
             .query_map([], |row| {
-                Ok(QuestionTag {
+                Ok(CustomSkill {
                     id: Some(row.get(0)?),
-                    user_id: row.get(1)?,
-                    name: row.get(2)?,
-                    color: row.get(3)?,
-                    created_at: row.get(4)?,
+                    name: row.get(1)?,
+                    aliases: row.get(2)?,
+                    category: row.get(3)?,
+                    parent: row.get(4)?,
+                    created_at: row.get(5)?,
                 })
             })?
             .collect::<Result<Vec<_>, _>>()?;
-        
-        Ok(tags)
+        Ok(skills)
     }
 
-    /// Update a tag
-    pub fn update_tag(&self, id: i64, name: String, color: String) -> Result<()> {
+    /// Delete a user-defined taxonomy skill
+    pub fn delete_custom_skill(&self, id: i64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
-        conn.execute(
-            "UPDATE question_tags SET name = ?1, color = ?2 WHERE id = ?3",
-            params![name, color, id],
-        )?;
+        conn.execute("DELETE FROM custom_skills WHERE id = ?1", params![id])?;
         Ok(())
     }
 
-    /// Delete a tag
-    pub fn delete_tag(&self, id: i64) -> Result<()> {
-        let conn = self.conn.lock().unwrap();
-        conn.execute("DELETE FROM question_tags WHERE id = ?1", params![id])?;
-        Ok(())
+    // ===== Benchmark Operations =====
+
+    /// Store a benchmark dataset with its samples
+    pub fn save_benchmark_dataset(&self, name: &str, source: &str, samples: &[BenchmarkSample]) -> Result<i64> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+
+        tx.execute(
+            "INSERT INTO benchmark_datasets (name, source, created_at) VALUES (?1, ?2, ?3)",
+            params![name, source, now()],
+        )?;
+        let dataset_id = tx.last_insert_rowid();
+
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO benchmark_samples (dataset_id, role, seniority, region, dimension, score) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+            )?;
+            for sample in samples {
+                stmt.execute(params![
+                    dataset_id,
+                    sample.role,
+                    sample.seniority,
+                    sample.region,
+                    sample.dimension,
+                    sample.score,
+                ])?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(dataset_id)
+    }
+
+    /// All benchmark datasets with their sample counts, newest first
+    pub fn get_benchmark_datasets(&self) -> Result<Vec<BenchmarkDataset>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT d.id, d.name, d.source, COUNT(s.id), d.created_at \
+             FROM benchmark_datasets d LEFT JOIN benchmark_samples s ON s.dataset_id = d.id \
+             GROUP BY d.id ORDER BY d.created_at DESC, d.id DESC"
+        )?;
+        let datasets = stmt
+            .query_map([], |row| {
+                Ok(BenchmarkDataset {
+                    id: Some(row.get(0)?),
+                    name: row.get(1)?,
+                    source: row.get(2)?,
+                    sample_count: row.get(3)?,
+                    created_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(datasets)
+    }
+
+    /// Benchmark samples of one dimension across all datasets, with their dataset name
+    pub fn get_benchmark_samples(&self, dimension: &str) -> Result<Vec<(String, BenchmarkSample)>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT d.name, s.role, s.seniority, s.region, s.dimension, s.score \
+             FROM benchmark_samples s JOIN benchmark_datasets d ON d.id = s.dataset_id \
+             WHERE s.dimension = ?1"
+        )?;
+        let samples = stmt
+            .query_map(params![dimension], |row| {
+                Ok((
+                    row.get(0)?,
+                    BenchmarkSample {
+                        role: row.get(1)?,
+                        seniority: row.get(2)?,
+                        region: row.get(3)?,
+                        dimension: row.get(4)?,
+                        score: row.get(5)?,
+                    },
+                ))
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(samples)
+    }
+
+    /// Distinct role / seniority / region combinations present in benchmark samples
+    pub fn get_benchmark_segments(&self) -> Result<Vec<BenchmarkSegment>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT role, seniority, region FROM benchmark_samples \
+             WHERE role != '' OR seniority != '' OR region != '' \
+             ORDER BY role, seniority, region"
+        )?;
+        let non_empty = |value: String| Some(value).filter(|v| !v.is_empty());
+        let segments = stmt
+            .query_map([], |row| {
+                Ok(BenchmarkSegment {
+                    role: non_empty(row.get(0)?),
+                    seniority: non_empty(row.get(1)?),
+                    region: non_empty(row.get(2)?),
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(segments)
+    }
+
+    /// Delete a benchmark dataset and its samples
+    pub fn delete_benchmark_dataset(&self, id: i64) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM benchmark_datasets WHERE id = ?1", params![id])?;
+        Ok(())
+    }
+
+    /// Delete all datasets of a source, e.g. before rebuilding the local benchmark
+    pub fn delete_benchmark_datasets_by_source(&self, source: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM benchmark_datasets WHERE source = ?1", params![source])?;
+        Ok(())
+    }
+
+    /// Average analysis scores per user, for anonymized local benchmarks
+    ///
+    /// The one read that spans all users; only aggregates leave this method
+    pub fn get_user_score_averages(&self) -> Result<Vec<UserScoreAverages>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT COUNT(aa.id), AVG(aa.content_score), AVG(aa.expression_score), AVG(aa.logic_score), \
+             AVG(aa.job_match_score), AVG(aa.keyword_coverage) \
+             FROM answer_analysis aa \
+             JOIN interview_answers ia ON ia.id = aa.answer_id \
+             JOIN interview_sessions s ON s.id = ia.session_id \
+             GROUP BY s.user_id"
+        )?;
+        let averages = stmt
+            .query_map([], |row| {
+                Ok(UserScoreAverages {
+                    answer_count: row.get(0)?,
+                    content_score: row.get::<_, f64>(1)? as f32,
+                    expression_score: row.get::<_, Option<f64>>(2)?.map(|v| v as f32),
+                    logic_score: row.get::<_, f64>(3)? as f32,
+                    job_match_score: row.get::<_, f64>(4)? as f32,
+                    keyword_coverage: row.get::<_, f64>(5)? as f32,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(averages)
+    }
+
+    /// Average analysis scores per session of the active user, oldest first
+    ///
+    /// Sessions without analyzed answers are left out
+    pub fn get_session_score_averages(&self) -> Result<Vec<SessionScoreAverages>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT s.id, s.created_at, COUNT(aa.id), AVG(aa.overall_score), AVG(aa.content_score), \
+             AVG(aa.expression_score), AVG(aa.logic_score), AVG(aa.job_match_score), AVG(aa.keyword_coverage) \
+             FROM interview_sessions s \
+             JOIN interview_answers ia ON ia.session_id = s.id \
+             JOIN answer_analysis aa ON aa.answer_id = ia.id \
+             WHERE s.user_id = ?1 \
+             GROUP BY s.id ORDER BY s.created_at ASC, s.id ASC"
+        )?;
+        let averages = stmt
+            .query_map(params![Self::current_user(&conn)], |row| {
+                Ok(SessionScoreAverages {
+                    session_id: row.get(0)?,
+                    created_at: row.get(1)?,
+                    answer_count: row.get(2)?,
+                    overall_score: row.get::<_, f64>(3)? as f32,
+                    content_score: row.get::<_, f64>(4)? as f32,
+                    expression_score: row.get::<_, Option<f64>>(5)?.map(|v| v as f32),
+                    logic_score: row.get::<_, f64>(6)? as f32,
+                    job_match_score: row.get::<_, f64>(7)? as f32,
+                    keyword_coverage: row.get::<_, f64>(8)? as f32,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(averages)
+    }
+
+    // ===== Question Classification Operations =====
+
+    /// Insert or replace the classification of a question bank entry
+    pub fn save_question_classification(&self, classification: &QuestionClassification) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        if !Self::owns_question(&conn, classification.question_id)? {
+            anyhow::bail!("Question {} not found", classification.question_id);
+        }
+        conn.execute(
+            "INSERT INTO question_classifications (question_id, question_type, dimension, difficulty, skills, method, confidence, created_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) \
+             ON CONFLICT(question_id) DO UPDATE SET question_type = excluded.question_type, dimension = excluded.dimension, \
+             difficulty = excluded.difficulty, skills = excluded.skills, method = excluded.method, \
+             confidence = excluded.confidence, created_at = excluded.created_at",
+            params![
+                classification.question_id,
+                classification.question_type,
+                classification.dimension,
+                classification.difficulty,
+                classification.skills,
+                classification.method,
+                classification.confidence,
+                now(),
+            ],
+        )?;
+        let id = conn.query_row(
+            "SELECT id FROM question_classifications WHERE question_id = ?1",
+            params![classification.question_id],
+            |row| row.get(0),
+        )?;
+        Ok(id)
+    }
+
+    /// Classifications of all question bank entries
+    pub fn get_question_classifications(&self) -> Result<Vec<QuestionClassification>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT c.id, c.question_id, c.question_type, c.dimension, c.difficulty, c.skills, c.method, c.confidence, c.created_at \
+             FROM question_classifications c JOIN question_bank q ON q.id = c.question_id \
+             WHERE q.user_id = ?1 ORDER BY c.question_id"
+        )?;
+        let classifications = stmt
+            .query_map(params![Self::current_user(&conn)], Self::row_to_question_classification)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(classifications)
+    }
+
+    /// Question bank entries without a stored classification
+    pub fn get_unclassified_questions(&self) -> Result<Vec<QuestionBankItem>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT q.id, q.user_id, q.question, q.best_answer, q.notes, q.job_category, q.created_at, q.updated_at \
+             FROM question_bank q LEFT JOIN question_classifications c ON c.question_id = q.id \
+             WHERE q.user_id = ?1 AND c.id IS NULL ORDER BY q.id"
+        )?;
+        let items = stmt
+            .query_map(params![Self::current_user(&conn)], |row| {
+                Ok(QuestionBankItem {
+                    id: Some(row.get(0)?),
+                    user_id: row.get(1)?,
+                    question: row.get(2)?,
+                    best_answer: row.get(3)?,
+                    notes: row.get(4)?,
+                    job_category: row.get(5)?,
+                    created_at: row.get(6)?,
+                    updated_at: row.get(7)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(items)
+    }
+
+    fn row_to_question_classification(row: &rusqlite::Row) -> rusqlite::Result<QuestionClassification> {
+        Ok(QuestionClassification {
+            id: Some(row.get(0)?),
+            question_id: row.get(1)?,
+            question_type: row.get(2)?,
+            dimension: row.get(3)?,
+            difficulty: row.get(4)?,
+            skills: row.get(5)?,
+            method: row.get(6)?,
+            confidence: row.get(7)?,
+            created_at: row.get(8)?,
+        })
+    }
+
+    // ===== Question Tag Operations =====
+
+    /// Create a new tag
+    pub fn create_tag(&self, name: String, color: String) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        let timestamp = now();
+        
+        conn.execute(
+            "INSERT INTO question_tags (user_id, name, color, created_at) VALUES (?1, ?2, ?3, ?4)",
+            params![Self::current_user(&conn), name, color, timestamp],
+        )?;
+        
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Get all tags
+    pub fn get_all_tags(&self) -> Result<Vec<QuestionTag>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, user_id, name, color, created_at FROM question_tags WHERE user_id = ?1 ORDER BY name"
+        )?;
+        
+        let tags = stmt
+            .query_map(params![Self::current_user(&conn)], |row| {
+                Ok(QuestionTag {
+                    id: Some(row.get(0)?),
+                    user_id: row.get(1)?,
+                    name: row.get(2)?,
+                    color: row.get(3)?,
+                    created_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        
+        Ok(tags)
+    }
+
+    /// Update a tag
+    pub fn update_tag(&self, id: i64, name: String, color: String) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "UPDATE question_tags SET name = ?1, color = ?2 WHERE id = ?3 AND user_id = ?4",
+            params![name, color, id, Self::current_user(&conn)],
+        )?;
+        Ok(())
+    }
+
+    /// Delete a tag
+    pub fn delete_tag(&self, id: i64) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute("DELETE FROM question_tags WHERE id = ?1 AND user_id = ?2", params![id, Self::current_user(&conn)])?;
+        Ok(())
     }
 
     /// Add a tag to a question
     pub fn add_tag_to_question(&self, question_id: i64, tag_id: i64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
+        let tag_owned: bool = conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM question_tags WHERE id = ?1 AND user_id = ?2)",
+            params![tag_id, Self::current_user(&conn)],
+            |row| row.get(0),
+        )?;
+        if !tag_owned || !Self::owns_question(&conn, question_id)? {
+            anyhow::bail!("Question {} or tag {} not found", question_id, tag_id);
+        }
         let timestamp = now();
         
         conn.execute(
@@ -1111,6 +1947,9 @@ impl Repository {
     /// Remove a tag from a question
     pub fn remove_tag_from_question(&self, question_id: i64, tag_id: i64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
+        if !Self::owns_question(&conn, question_id)? {
+            return Ok(());
+        }
         conn.execute(
             "DELETE FROM question_tag_mappings WHERE question_bank_id = ?1 AND tag_id = ?2",
             params![question_id, tag_id],
@@ -1125,12 +1964,12 @@ impl Repository {
             "SELECT t.id, t.user_id, t.name, t.color, t.created_at \
              FROM question_tags t \
              JOIN question_tag_mappings m ON t.id = m.tag_id \
-             WHERE m.question_bank_id = ?1 \
+             WHERE m.question_bank_id = ?1 AND t.user_id = ?2 \
              ORDER BY t.name"
         )?;
         
         let tags = stmt
-            .query_map(params![question_id], |row| {
+            .query_map(params![question_id, Self::current_user(&conn)], |row| {
                 Ok(QuestionTag {
                     id: Some(row.get(0)?),
                     user_id: row.get(1)?,
@@ -1151,12 +1990,12 @@ impl Repository {
             "SELECT q.id, q.user_id, q.question, q.best_answer, q.notes, q.job_category, q.created_at, q.updated_at \
              FROM question_bank q \
              JOIN question_tag_mappings m ON q.id = m.question_bank_id \
-             WHERE m.tag_id = ?1 \
+             WHERE m.tag_id = ?1 AND q.user_id = ?2 \
              ORDER BY q.updated_at DESC"
         )?;
         
         let questions = stmt
-            .query_map(params![tag_id], |row| {
+            .query_map(params![tag_id, Self::current_user(&conn)], |row| {
                 Ok(QuestionBankItem {
                     id: Some(row.get(0)?),
                     user_id: row.get(1)?,
@@ -1179,13 +2018,13 @@ impl Repository {
         let mut stmt = conn.prepare(
             "SELECT DATE(created_at) as date, COUNT(*) as count \
              FROM interview_sessions \
-             WHERE created_at >= DATE('now', '-365 days') \
+             WHERE user_id = ?1 AND created_at >= DATE('now', '-365 days') \
              GROUP BY DATE(created_at) \
              ORDER BY date ASC"
         )?;
         
         let activity = stmt
-            .query_map([], |row| {
+            .query_map(params![Self::current_user(&conn)], |row| {
                 Ok((row.get::<_, String>(0)?, row.get::<_, i32>(1)?))
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -1275,18 +2114,7 @@ impl Repository {
     /// Get current user ID from config
     pub fn get_current_user_id(&self) -> Result<i64> {
         let conn = self.conn.lock().unwrap();
-        let user_id: i64 = conn
-            .query_row(
-                "SELECT value FROM user_config WHERE key = 'current_user_id'",
-                [],
-                |row| {
-                    let value_str: String = row.get(0)?;
-                    Ok(value_str.parse::<i64>().unwrap_or(1))
-                }
-            )
-            .unwrap_or(1);
-        
-        Ok(user_id)
+        Ok(Self::current_user(&conn))
     }
 
     /// Set current user ID in config
@@ -1302,6 +2130,33 @@ impl Repository {
         Ok(())
     }
 
+    /// Get a configuration value by key
+    pub fn get_config_value(&self, key: &str) -> Result<Option<String>> {
+        let conn = self.conn.lock().unwrap();
+        let value = conn
+            .query_row(
+                "SELECT value FROM user_config WHERE key = ?1",
+                params![key],
+                |row| row.get(0),
+            )
+            .optional()?;
+        
+        Ok(value)
+    }
+
+    /// Set a configuration value
+    pub fn set_config_value(&self, key: &str, value: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        let timestamp = now();
+        
+        conn.execute(
+            "INSERT OR REPLACE INTO user_config (key, value, updated_at) VALUES (?1, ?2, ?3)",
+            params![key, value, timestamp],
+        )?;
+        
+        Ok(())
+    }
+
     // ===== Question Best Answer Operations =====
 
     /// Get best answer by question hash
@@ -1310,11 +2165,11 @@ impl Repository {
         let mut stmt = conn.prepare(
             "SELECT id, question_hash, question_text, generated_answer, source_answer_count, \
              version, needs_update, job_context, created_at, updated_at \
-             FROM question_best_answers WHERE question_hash = ?1"
+             FROM question_best_answers WHERE question_hash = ?1 AND user_id = ?2"
         )?;
         
         let answer = stmt
-            .query_row(params![question_hash], |row| {
+            .query_row(params![question_hash, Self::current_user(&conn)], |row| {
                 Ok(QuestionBestAnswer {
                     id: Some(row.get(0)?),
                     question_hash: row.get(1)?,
@@ -1344,12 +2199,13 @@ impl Repository {
     ) -> Result<i64> {
         let conn = self.conn.lock().unwrap();
         let timestamp = now();
+        let user_id = Self::current_user(&conn);
         
         // Check if exists
         let existing: Option<(i64, i32)> = conn
             .query_row(
-                "SELECT id, version FROM question_best_answers WHERE question_hash = ?1",
-                params![question_hash],
+                "SELECT id, version FROM question_best_answers WHERE question_hash = ?1 AND user_id = ?2",
+                params![question_hash, user_id],
                 |row| Ok((row.get(0)?, row.get(1)?))
             )
             .optional()?;
@@ -1368,9 +2224,9 @@ impl Repository {
             // Insert new
             conn.execute(
                 "INSERT INTO question_best_answers \
-                 (question_hash, question_text, generated_answer, source_answer_count, version, needs_update, job_context, created_at, updated_at) \
-                 VALUES (?1, ?2, ?3, ?4, 1, 0, ?5, ?6, ?7)",
-                params![question_hash, question_text, generated_answer, source_answer_count, job_context, timestamp, timestamp],
+                 (user_id, question_hash, question_text, generated_answer, source_answer_count, version, needs_update, job_context, created_at, updated_at) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, 1, 0, ?6, ?7, ?8)",
+                params![user_id, question_hash, question_text, generated_answer, source_answer_count, job_context, timestamp, timestamp],
             )?;
             Ok(conn.last_insert_rowid())
         }
@@ -1380,8 +2236,8 @@ impl Repository {
     pub fn mark_answer_needs_update(&self, question_hash: &str) -> Result<bool> {
         let conn = self.conn.lock().unwrap();
         let rows_affected = conn.execute(
-            "UPDATE question_best_answers SET needs_update = 1 WHERE question_hash = ?1",
-            params![question_hash],
+            "UPDATE question_best_answers SET needs_update = 1 WHERE question_hash = ?1 AND user_id = ?2",
+            params![question_hash, Self::current_user(&conn)],
         )?;
         Ok(rows_affected > 0)
     }
@@ -1392,8 +2248,9 @@ impl Repository {
         let conn = self.conn.lock().unwrap();
         let count: i32 = conn
             .query_row(
-                "SELECT COUNT(*) FROM interview_answers WHERE question = ?1",
-                params![question],
+                "SELECT COUNT(*) FROM interview_answers ia JOIN interview_sessions s ON ia.session_id = s.id \
+                 WHERE ia.question = ?1 AND s.user_id = ?2",
+                params![question, Self::current_user(&conn)],
                 |row| row.get(0),
             )
             .unwrap_or(0);
@@ -1406,13 +2263,14 @@ impl Repository {
         let mut stmt = conn.prepare(
             "SELECT ia.answer, COALESCE(aa.overall_score, 0) as score \
              FROM interview_answers ia \
+             JOIN interview_sessions s ON ia.session_id = s.id \
              LEFT JOIN answer_analysis aa ON ia.id = aa.answer_id \
-             WHERE ia.question = ?1 \
+             WHERE ia.question = ?1 AND s.user_id = ?2 \
              ORDER BY score DESC"
         )?;
         
         let results = stmt
-            .query_map(params![question], |row| {
+            .query_map(params![question, Self::current_user(&conn)], |row| {
                 Ok((row.get(0)?, row.get(1)?))
             })?
             .collect::<Result<Vec<_>, _>>()?;
@@ -1420,24 +2278,593 @@ impl Repository {
         Ok(results)
     }
 
+    // ===== Answer Comparison Operations =====
+
+    /// Save an answer comparison together with its compared points
+    pub fn save_answer_comparison(&self, comparison: &AnswerComparison) -> Result<i64> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let user_id = Self::current_user(&tx);
+
+        tx.execute(
+            "INSERT INTO answer_comparisons \
+             (user_id, answer_id, question_hash, question, user_answer, best_answer_version, overall_match, missing_points, extra_points, created_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            params![
+                user_id,
+                comparison.answer_id,
+                comparison.question_hash,
+                comparison.question,
+                comparison.user_answer,
+                comparison.best_answer_version,
+                comparison.overall_match,
+                serde_json::to_string(&comparison.missing_points)?,
+                serde_json::to_string(&comparison.extra_points)?,
+                comparison.created_at,
+            ],
+        )?;
+        let comparison_id = tx.last_insert_rowid();
+
+        for point in &comparison.points {
+            tx.execute(
+                "INSERT INTO comparison_points \
+                 (comparison_id, aspect, best_answer_point, user_answer_point, match_status, suggestion) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    comparison_id,
+                    point.aspect,
+                    point.best_answer_point,
+                    point.user_answer_point,
+                    point.match_status,
+                    point.suggestion,
+                ],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(comparison_id)
+    }
+
+    /// Get all comparisons for a question, oldest first
+    pub fn get_answer_comparisons(&self, question_hash: &str) -> Result<Vec<AnswerComparison>> {
+        let conn = self.conn.lock().unwrap();
+        let user_id = Self::current_user(&conn);
+        let mut stmt = conn.prepare(
+            "SELECT id, answer_id, question_hash, question, user_answer, best_answer_version, \
+             overall_match, missing_points, extra_points, created_at \
+             FROM answer_comparisons WHERE user_id = ?1 AND question_hash = ?2 \
+             ORDER BY created_at ASC, id ASC"
+        )?;
+
+        let mut comparisons = stmt
+            .query_map(params![user_id, question_hash], |row| {
+                let missing_points: String = row.get(7)?;
+                let extra_points: String = row.get(8)?;
+                Ok(AnswerComparison {
+                    id: Some(row.get(0)?),
+                    answer_id: row.get(1)?,
+                    question_hash: row.get(2)?,
+                    question: row.get(3)?,
+                    user_answer: row.get(4)?,
+                    best_answer_version: row.get(5)?,
+                    overall_match: row.get(6)?,
+                    points: Vec::new(),
+                    missing_points: serde_json::from_str(&missing_points).unwrap_or_default(),
+                    extra_points: serde_json::from_str(&extra_points).unwrap_or_default(),
+                    created_at: row.get(9)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut points_stmt = conn.prepare(
+            "SELECT aspect, best_answer_point, user_answer_point, match_status, suggestion \
+             FROM comparison_points WHERE comparison_id = ?1 ORDER BY id"
+        )?;
+
+        for comparison in &mut comparisons {
+            comparison.points = points_stmt
+                .query_map(params![comparison.id], |row| {
+                    Ok(ComparisonPoint {
+                        aspect: row.get(0)?,
+                        best_answer_point: row.get(1)?,
+                        user_answer_point: row.get(2)?,
+                        match_status: row.get(3)?,
+                        suggestion: row.get(4)?,
+                    })
+                })?
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        Ok(comparisons)
+    }
+
+    /// Open improvement items for missing points and resolve items covered by the latest comparison
+    ///
+    /// Dismissed items stay dismissed even if the point is missed again
+    pub fn sync_improvement_items(
+        &self,
+        question_hash: &str,
+        question: &str,
+        comparison_id: i64,
+        missing_points: &[String],
+    ) -> Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        let user_id = Self::current_user(&tx);
+        let timestamp = now();
+
+        for point in missing_points {
+            tx.execute(
+                "INSERT INTO improvement_items \
+                 (user_id, question_hash, question, point, status, occurrences, source_comparison_id, created_at, updated_at) \
+                 VALUES (?1, ?2, ?3, ?4, 'open', 1, ?5, ?6, ?6) \
+                 ON CONFLICT(user_id, question_hash, point) DO UPDATE SET \
+                 occurrences = occurrences + 1, \
+                 status = CASE WHEN status = 'dismissed' THEN status ELSE 'open' END, \
+                 resolved_comparison_id = CASE WHEN status = 'dismissed' THEN resolved_comparison_id ELSE NULL END, \
+                 updated_at = excluded.updated_at",
+                params![user_id, question_hash, question, point, comparison_id, timestamp],
+            )?;
+        }
+
+        let open_items: Vec<(i64, String)> = {
+            let mut stmt = tx.prepare(
+                "SELECT id, point FROM improvement_items \
+                 WHERE user_id = ?1 AND question_hash = ?2 AND status = 'open'"
+            )?;
+            let rows = stmt
+                .query_map(params![user_id, question_hash], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<Vec<_>, _>>()?;
+            rows
+        };
+
+        for (id, point) in open_items {
+            if !missing_points.contains(&point) {
+                tx.execute(
+                    "UPDATE improvement_items SET status = 'resolved', resolved_comparison_id = ?1, updated_at = ?2 WHERE id = ?3",
+                    params![comparison_id, timestamp, id],
+                )?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Get improvement items, optionally filtered by status and question
+    pub fn get_improvement_items(
+        &self,
+        status: Option<&str>,
+        question_hash: Option<&str>,
+    ) -> Result<Vec<ImprovementItem>> {
+        let conn = self.conn.lock().unwrap();
+        let user_id = Self::current_user(&conn);
+        let mut stmt = conn.prepare(
+            "SELECT id, question_hash, question, point, status, occurrences, \
+             source_comparison_id, resolved_comparison_id, created_at, updated_at \
+             FROM improvement_items \
+             WHERE user_id = ?1 AND (?2 IS NULL OR status = ?2) AND (?3 IS NULL OR question_hash = ?3) \
+             ORDER BY occurrences DESC, updated_at DESC"
+        )?;
+
+        let items = stmt
+            .query_map(params![user_id, status, question_hash], |row| {
+                Ok(ImprovementItem {
+                    id: Some(row.get(0)?),
+                    question_hash: row.get(1)?,
+                    question: row.get(2)?,
+                    point: row.get(3)?,
+                    status: row.get(4)?,
+                    occurrences: row.get(5)?,
+                    source_comparison_id: row.get(6)?,
+                    resolved_comparison_id: row.get(7)?,
+                    created_at: row.get(8)?,
+                    updated_at: row.get(9)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(items)
+    }
+
+    /// Update status of an improvement item
+    pub fn update_improvement_item_status(&self, id: i64, status: &str) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "UPDATE improvement_items SET status = ?1, updated_at = ?2 WHERE id = ?3 AND user_id = ?4",
+            params![status, now(), id, Self::current_user(&conn)],
+        )?;
+        Ok(())
+    }
+
+    // ===== Reverse Interview Operations =====
+
+    /// Save the reverse-interview turns and evaluation of a multi-agent session
+    pub fn save_reverse_evaluation(
+        &self,
+        session_id: Option<i64>,
+        turns: &str,
+        evaluation: Option<&str>,
+    ) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        conn.execute(
+            "INSERT INTO reverse_evaluations (user_id, session_id, turns, evaluation, created_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![Self::current_user(&conn), session_id, turns, evaluation, now()],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Reverse-interview evaluations of the active user, newest first
+    pub fn get_reverse_evaluations(&self, limit: i64) -> Result<Vec<ReverseEvaluation>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, session_id, turns, evaluation, created_at FROM reverse_evaluations \
+             WHERE user_id = ?1 ORDER BY created_at DESC, id DESC LIMIT ?2",
+        )?;
+        let evaluations = stmt
+            .query_map(params![Self::current_user(&conn), limit], |row| {
+                Ok(ReverseEvaluation {
+                    id: Some(row.get(0)?),
+                    session_id: row.get(1)?,
+                    turns: row.get(2)?,
+                    evaluation: row.get(3)?,
+                    created_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(evaluations)
+    }
+
+    // ===== Goal Operations =====
+
+    /// Create a goal for the active user
+    pub fn create_goal(
+        &self,
+        goal_type: &str,
+        dimension: Option<&str>,
+        target_value: f32,
+        baseline_value: f32,
+        deadline: &str,
+    ) -> Result<i64> {
+        let conn = self.conn.lock().unwrap();
+        let timestamp = now();
+        conn.execute(
+            "INSERT INTO goals (user_id, goal_type, dimension, target_value, baseline_value, deadline, status, created_at, updated_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, 'active', ?7, ?7)",
+            params![Self::current_user(&conn), goal_type, dimension, target_value, baseline_value, deadline, timestamp],
+        )?;
+        Ok(conn.last_insert_rowid())
+    }
+
+    /// Goals of the active user, nearest deadline first
+    pub fn get_goals(&self) -> Result<Vec<Goal>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, goal_type, dimension, target_value, baseline_value, deadline, status, \
+             achieved_at, created_at, updated_at \
+             FROM goals WHERE user_id = ?1 ORDER BY deadline, id"
+        )?;
+        let goals = stmt
+            .query_map(params![Self::current_user(&conn)], |row| {
+                Ok(Goal {
+                    id: Some(row.get(0)?),
+                    goal_type: row.get(1)?,
+                    dimension: row.get(2)?,
+                    target_value: row.get(3)?,
+                    baseline_value: row.get(4)?,
+                    deadline: row.get(5)?,
+                    status: row.get(6)?,
+                    achieved_at: row.get(7)?,
+                    created_at: row.get(8)?,
+                    updated_at: row.get(9)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(goals)
+    }
+
+    /// Update the status of a goal, stamping `achieved_at` when it becomes achieved
+    pub fn update_goal_status(&self, id: i64, status: &str) -> Result<bool> {
+        let conn = self.conn.lock().unwrap();
+        let timestamp = now();
+        let updated = conn.execute(
+            "UPDATE goals SET status = ?1, updated_at = ?2, \
+             achieved_at = CASE WHEN ?1 = 'achieved' THEN COALESCE(achieved_at, ?2) ELSE NULL END \
+             WHERE id = ?3 AND user_id = ?4",
+            params![status, timestamp, id, Self::current_user(&conn)],
+        )?;
+        Ok(updated > 0)
+    }
+
+    /// Delete a goal of the active user
+    pub fn delete_goal(&self, id: i64) -> Result<()> {
+        let conn = self.conn.lock().unwrap();
+        let deleted = conn.execute(
+            "DELETE FROM goals WHERE id = ?1 AND user_id = ?2",
+            params![id, Self::current_user(&conn)],
+        )?;
+        if deleted == 0 {
+            anyhow::bail!("Goal {} not found", id);
+        }
+        Ok(())
+    }
+
+    // ===== Spaced Repetition Operations =====
+
+    /// ID of the active user's question bank entry with this question text
+    pub fn find_bank_question(&self, question: &str) -> Result<Option<i64>> {
+        let conn = self.conn.lock().unwrap();
+        let id = conn
+            .query_row(
+                "SELECT id FROM question_bank WHERE user_id = ?1 AND TRIM(question) = TRIM(?2) ORDER BY id LIMIT 1",
+                params![Self::current_user(&conn), question],
+                |row| row.get(0),
+            )
+            .optional()?;
+        Ok(id)
+    }
+
+    /// Analyzed attempts of the active user grouped by question
+    ///
+    /// Attempts are grouped by the bank entry they practised; answers saved
+    /// without a bank id count for the bank entry with the same text. Attempts
+    /// at questions outside the bank are grouped by text.
+    pub fn get_question_practice_stats(&self) -> Result<Vec<QuestionPracticeStats>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "WITH attempts AS ( \
+               SELECT COALESCE(a.question_id, \
+                        (SELECT qb.id FROM question_bank qb \
+                         WHERE qb.user_id = ?1 AND TRIM(qb.question) = TRIM(a.question) \
+                         ORDER BY qb.id LIMIT 1)) AS question_id, \
+                      TRIM(a.question) AS question, aa.overall_score AS score, a.created_at, a.id \
+               FROM interview_answers a \
+               JOIN answer_analysis aa ON aa.answer_id = a.id \
+               JOIN interview_sessions s ON s.id = a.session_id \
+               WHERE s.user_id = ?1) \
+             SELECT t.question_id, MIN(t.question), COUNT(*), AVG(t.score), MAX(t.created_at), \
+             (SELECT t2.score FROM attempts t2 \
+              WHERE t2.question_id IS t.question_id AND (t.question_id IS NOT NULL OR t2.question = t.question) \
+              ORDER BY t2.created_at DESC, t2.id DESC LIMIT 1) \
+             FROM attempts t \
+             GROUP BY t.question_id, CASE WHEN t.question_id IS NULL THEN t.question END"
+        )?;
+        let stats = stmt
+            .query_map(params![Self::current_user(&conn)], |row| {
+                Ok(QuestionPracticeStats {
+                    question_id: row.get(0)?,
+                    question: row.get(1)?,
+                    attempts: row.get(2)?,
+                    average_score: row.get::<_, f64>(3)? as f32,
+                    last_score: row.get(5)?,
+                    last_practiced_at: row.get(4)?,
+                })
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(stats)
+    }
+
+    /// Review schedule of a question bank entry, `None` if never reviewed
+    pub fn get_question_review(&self, question_id: i64) -> Result<Option<QuestionReview>> {
+        let conn = self.conn.lock().unwrap();
+        let review = conn
+            .query_row(
+                "SELECT question_id, ease, interval_days, repetitions, lapses, due_date, last_reviewed_at \
+                 FROM question_reviews WHERE question_id = ?1 AND user_id = ?2",
+                params![question_id, Self::current_user(&conn)],
+                Self::row_to_question_review,
+            )
+            .optional()?;
+        Ok(review)
+    }
+
+    /// Review schedules of the active user, earliest due first
+    pub fn get_question_reviews(&self) -> Result<Vec<QuestionReview>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT question_id, ease, interval_days, repetitions, lapses, due_date, last_reviewed_at \
+             FROM question_reviews WHERE user_id = ?1 ORDER BY due_date, ease"
+        )?;
+        let reviews = stmt
+            .query_map(params![Self::current_user(&conn)], Self::row_to_question_review)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(reviews)
+    }
+
+    fn row_to_question_review(row: &rusqlite::Row) -> rusqlite::Result<QuestionReview> {
+        Ok(QuestionReview {
+            question_id: row.get(0)?,
+            ease: row.get(1)?,
+            interval_days: row.get(2)?,
+            repetitions: row.get(3)?,
+            lapses: row.get(4)?,
+            due_date: row.get(5)?,
+            last_reviewed_at: row.get(6)?,
+        })
+    }
+
+    /// Store the updated schedule of a question and append the attempt to its history
+    pub fn save_question_review(
+        &self,
+        review: &QuestionReview,
+        answer_id: Option<i64>,
+        score: f32,
+        grade: i64,
+    ) -> Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        if !Self::owns_question(&tx, review.question_id)? {
+            anyhow::bail!("Question {} not found", review.question_id);
+        }
+        let user_id = Self::current_user(&tx);
+        let reviewed_at = review.last_reviewed_at.clone().unwrap_or_else(now);
+
+        tx.execute(
+            "INSERT INTO question_reviews \
+             (user_id, question_id, ease, interval_days, repetitions, lapses, due_date, last_reviewed_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8) \
+             ON CONFLICT(question_id) DO UPDATE SET \
+             ease = excluded.ease, interval_days = excluded.interval_days, repetitions = excluded.repetitions, \
+             lapses = excluded.lapses, due_date = excluded.due_date, last_reviewed_at = excluded.last_reviewed_at",
+            params![
+                user_id,
+                review.question_id,
+                review.ease,
+                review.interval_days,
+                review.repetitions,
+                review.lapses,
+                review.due_date,
+                reviewed_at,
+            ],
+        )?;
+        tx.execute(
+            "INSERT INTO review_history \
+             (user_id, question_id, answer_id, score, grade, ease, interval_days, due_date, reviewed_at) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                user_id,
+                review.question_id,
+                answer_id,
+                score,
+                grade,
+                review.ease,
+                review.interval_days,
+                review.due_date,
+                reviewed_at,
+            ],
+        )?;
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Rescore the review recorded for an answer
+    ///
+    /// # Returns
+    /// The bank question the answer reviewed, `None` if it was never recorded
+    pub fn update_review_score(&self, answer_id: i64, score: f32) -> Result<Option<i64>> {
+        let conn = self.conn.lock().unwrap();
+        let user_id = Self::current_user(&conn);
+        let question_id = conn
+            .query_row(
+                "SELECT question_id FROM review_history WHERE answer_id = ?1 AND user_id = ?2 ORDER BY id LIMIT 1",
+                params![answer_id, user_id],
+                |row| row.get(0),
+            )
+            .optional()?;
+        if question_id.is_some() {
+            conn.execute(
+                "UPDATE review_history SET score = ?1 WHERE answer_id = ?2 AND user_id = ?3",
+                params![score, answer_id, user_id],
+            )?;
+        }
+        Ok(question_id)
+    }
+
+    /// Replace the schedule of a question and the grades of its history after a replay
+    pub fn rewrite_question_reviews(&self, review: &QuestionReview, records: &[ReviewRecord]) -> Result<()> {
+        let mut conn = self.conn.lock().unwrap();
+        let tx = conn.transaction()?;
+        if !Self::owns_question(&tx, review.question_id)? {
+            anyhow::bail!("Question {} not found", review.question_id);
+        }
+        let user_id = Self::current_user(&tx);
+
+        tx.execute(
+            "UPDATE question_reviews SET ease = ?1, interval_days = ?2, repetitions = ?3, lapses = ?4, \
+             due_date = ?5, last_reviewed_at = ?6 WHERE question_id = ?7 AND user_id = ?8",
+            params![
+                review.ease,
+                review.interval_days,
+                review.repetitions,
+                review.lapses,
+                review.due_date,
+                review.last_reviewed_at,
+                review.question_id,
+                user_id,
+            ],
+        )?;
+        for record in records {
+            tx.execute(
+                "UPDATE review_history SET grade = ?1, ease = ?2, interval_days = ?3, due_date = ?4 \
+                 WHERE id = ?5 AND question_id = ?6 AND user_id = ?7",
+                params![
+                    record.grade,
+                    record.ease,
+                    record.interval_days,
+                    record.due_date,
+                    record.id,
+                    review.question_id,
+                    user_id,
+                ],
+            )?;
+        }
+
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Review history of the active user, newest first, optionally for one question
+    pub fn get_review_history(&self, question_id: Option<i64>, limit: usize) -> Result<Vec<ReviewRecord>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, question_id, answer_id, score, grade, ease, interval_days, due_date, reviewed_at \
+             FROM review_history WHERE user_id = ?1 AND (?2 IS NULL OR question_id = ?2) \
+             ORDER BY reviewed_at DESC, id DESC LIMIT ?3"
+        )?;
+        let records = stmt
+            .query_map(params![Self::current_user(&conn), question_id, limit as i64], Self::row_to_review_record)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(records)
+    }
+
+    /// Full review history of a question of the active user, oldest first
+    pub fn get_question_history(&self, question_id: i64) -> Result<Vec<ReviewRecord>> {
+        let conn = self.conn.lock().unwrap();
+        let mut stmt = conn.prepare(
+            "SELECT id, question_id, answer_id, score, grade, ease, interval_days, due_date, reviewed_at \
+             FROM review_history WHERE user_id = ?1 AND question_id = ?2 \
+             ORDER BY reviewed_at, id"
+        )?;
+        let records = stmt
+            .query_map(params![Self::current_user(&conn), question_id], Self::row_to_review_record)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(records)
+    }
+
+    fn row_to_review_record(row: &rusqlite::Row) -> rusqlite::Result<ReviewRecord> {
+        Ok(ReviewRecord {
+            id: Some(row.get(0)?),
+            question_id: row.get(1)?,
+            answer_id: row.get(2)?,
+            score: row.get(3)?,
+            grade: row.get(4)?,
+            ease: row.get(5)?,
+            interval_days: row.get(6)?,
+            due_date: row.get(7)?,
+            reviewed_at: row.get(8)?,
+        })
+    }
+
     // ===== Knowledge Base Operations =====
 
-    /// Get total count of knowledge vectors
+    /// Get total count of knowledge vectors, shared entries included
     pub fn get_knowledge_count(&self) -> i64 {
         let conn = self.conn.lock().unwrap();
         conn.query_row(
-            "SELECT COUNT(*) FROM knowledge_vectors",
-            [],
+            "SELECT COUNT(*) FROM knowledge_vectors WHERE user_id IN (?1, ?2)",
+            params![Self::current_user(&conn), Self::SHARED_KNOWLEDGE_OWNER],
             |row| row.get(0),
         ).unwrap_or(0)
     }
 
-    /// Get count of knowledge vectors by type
+    /// Get count of knowledge vectors by type, shared entries included
     pub fn get_knowledge_count_by_type(&self, content_type: &str) -> i64 {
         let conn = self.conn.lock().unwrap();
         conn.query_row(
-            "SELECT COUNT(*) FROM knowledge_vectors WHERE content_type = ?1",
-            params![content_type],
+            "SELECT COUNT(*) FROM knowledge_vectors WHERE content_type = ?1 AND user_id IN (?2, ?3)",
+            params![content_type, Self::current_user(&conn), Self::SHARED_KNOWLEDGE_OWNER],
             |row| row.get(0),
         ).unwrap_or(0)
     }
@@ -1455,8 +2882,8 @@ impl Repository {
         let now = now();
         
         conn.execute(
-            "INSERT INTO knowledge_vectors (content_type, content, embedding, metadata, created_at) VALUES (?1, ?2, ?3, ?4, ?5)",
-            params![content_type, content, embedding, metadata, now],
+            "INSERT INTO knowledge_vectors (user_id, content_type, content, embedding, metadata, created_at) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![Self::current_user(&conn), content_type, content, embedding, metadata, now],
         )?;
         
         Ok(conn.last_insert_rowid())
@@ -1470,6 +2897,7 @@ impl Repository {
         content_type_filter: Option<&str>,
     ) -> Result<Vec<crate::db::models::KnowledgeEntry>> {
         let conn = self.conn.lock().unwrap();
+        let user_id = Self::current_user(&conn);
         let offset = (page - 1) * page_size;
         
         // Use parameterized queries to prevent SQL injection
@@ -1478,12 +2906,12 @@ impl Repository {
                 let mut stmt = conn.prepare(
                     "SELECT id, content_type, content, metadata, created_at 
                      FROM knowledge_vectors 
-                     WHERE content_type = ?1 
+                     WHERE content_type = ?1 AND user_id IN (?2, ?3) 
                      ORDER BY created_at DESC 
-                     LIMIT ?2 OFFSET ?3"
+                     LIMIT ?4 OFFSET ?5"
                 )?;
                 let entries = stmt
-                    .query_map(params![ct, page_size, offset], |row| {
+                    .query_map(params![ct, user_id, Self::SHARED_KNOWLEDGE_OWNER, page_size, offset], |row| {
                         Ok(crate::db::models::KnowledgeEntry {
                             id: row.get(0)?,
                             content_type: row.get(1)?,
@@ -1499,11 +2927,12 @@ impl Repository {
                 let mut stmt = conn.prepare(
                     "SELECT id, content_type, content, metadata, created_at 
                      FROM knowledge_vectors 
+                     WHERE user_id IN (?1, ?2) 
                      ORDER BY created_at DESC 
-                     LIMIT ?1 OFFSET ?2"
+                     LIMIT ?3 OFFSET ?4"
                 )?;
                 let entries = stmt
-                    .query_map(params![page_size, offset], |row| {
+                    .query_map(params![user_id, Self::SHARED_KNOWLEDGE_OWNER, page_size, offset], |row| {
                         Ok(crate::db::models::KnowledgeEntry {
                             id: row.get(0)?,
                             content_type: row.get(1)?,
@@ -1518,10 +2947,10 @@ impl Repository {
         }
     }
 
-    /// Delete knowledge entry by id
+    /// Delete knowledge entry by id; shared entries are left alone
     pub fn delete_knowledge_entry(&self, id: i64) -> Result<()> {
         let conn = self.conn.lock().unwrap();
-        conn.execute("DELETE FROM knowledge_vectors WHERE id = ?1", params![id])?;
+        conn.execute("DELETE FROM knowledge_vectors WHERE id = ?1 AND user_id = ?2", params![id, Self::current_user(&conn)])?;
         Ok(())
     }
 
@@ -1537,12 +2966,12 @@ impl Repository {
         let mut stmt = conn.prepare(
             "SELECT id, content_type, content, metadata, created_at 
              FROM knowledge_vectors 
-             WHERE content LIKE ?1 
+             WHERE content LIKE ?1 AND user_id IN (?2, ?3) 
              ORDER BY created_at DESC 
-             LIMIT ?2"
+             LIMIT ?4"
         )?;
         
-        let entries = stmt.query_map(params![search_pattern, limit], |row| {
+        let entries = stmt.query_map(params![search_pattern, Self::current_user(&conn), Self::SHARED_KNOWLEDGE_OWNER, limit], |row| {
             Ok(crate::db::models::KnowledgeEntry {
                 id: row.get(0)?,
                 content_type: row.get(1)?,
@@ -1567,10 +2996,151 @@ impl Repository {
         let id_pattern = format!("%\"source_id\":{}", source_id);
         
         let count = conn.execute(
-            "DELETE FROM knowledge_vectors WHERE metadata LIKE ?1 AND metadata LIKE ?2",
-            params![source_pattern, id_pattern],
+            "DELETE FROM knowledge_vectors WHERE metadata LIKE ?1 AND metadata LIKE ?2 AND user_id = ?3",
+            params![source_pattern, id_pattern, Self::current_user(&conn)],
         )?;
         
         Ok(count)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::init_database;
+
+    /// Fresh database with a second user; the default user is active
+    fn repository() -> Repository {
+        let repo = Repository::new(init_database(":memory:".into()).unwrap());
+        assert_eq!(repo.create_user("第二用户".to_string(), "#22c55e".to_string(), None).unwrap(), 2);
+        repo
+    }
+
+    /// Resume, JD, session with an analyzed answer, bank question and tag for the active user
+    fn seed(repo: &Repository) -> (i64, i64) {
+        repo.save_resume("简历".to_string(), "Rust 工程师".to_string()).unwrap();
+        let jd_id = repo.save_job_description("后端".to_string(), "熟悉 Rust".to_string()).unwrap();
+        let session_id = repo.create_interview_session(None, Some(jd_id), vec!["介绍一个项目".to_string()]).unwrap();
+        let answer_id = repo
+            .save_answer(session_id, 0, "介绍一个项目".to_string(), "我负责存储引擎".to_string(), String::new(), None, None, None)
+            .unwrap();
+        repo.save_answer_analysis(answer_id, 7.0, 7.0, 7.0, 50.0, None, 7.0, "[]".to_string(), "[]".to_string(), "[]".to_string())
+            .unwrap();
+        repo.save_session_report(session_id, 7.0, "{}".to_string(), None, String::new(), String::new(), String::new(), None, None)
+            .unwrap();
+        let question_id = repo.add_to_question_bank("介绍一个项目".to_string(), None, None, None).unwrap();
+        let tag_id = repo.create_tag("项目".to_string(), "#000000".to_string()).unwrap();
+        repo.add_tag_to_question(question_id, tag_id).unwrap();
+        (session_id, answer_id)
+    }
+
+    #[test]
+    fn test_other_user_sees_nothing() {
+        let repo = repository();
+        let (session_id, answer_id) = seed(&repo);
+        repo.insert_knowledge_vector("answer", "我负责存储引擎", &[0u8; 4], None).unwrap();
+        repo.upsert_best_answer("hash", "介绍一个项目", "参考答案", 1, None).unwrap();
+
+        repo.set_current_user_id(2).unwrap();
+        assert!(repo.get_resumes().unwrap().is_empty());
+        assert!(repo.get_job_descriptions().unwrap().is_empty());
+        assert!(repo.get_interview_sessions().unwrap().is_empty());
+        assert!(repo.get_session_by_id(session_id).unwrap().is_none());
+        assert!(repo.get_answers_by_session(session_id).unwrap().is_empty());
+        assert!(repo.get_answer_analysis(answer_id).unwrap().is_none());
+        assert!(repo.get_session_report(session_id).unwrap().is_none());
+        assert!(repo.get_question_bank().unwrap().is_empty());
+        assert!(repo.get_all_tags().unwrap().is_empty());
+        assert!(repo.get_all_answers_for_question("介绍一个项目").unwrap().is_empty());
+        assert!(repo.get_best_answer_by_hash("hash").unwrap().is_none());
+        assert_eq!(repo.get_knowledge_count(), 0);
+        assert_eq!(repo.get_total_sessions_count().unwrap(), 0);
+        assert!(repo.get_top_questions(5).unwrap().is_empty());
+        assert!(repo.get_daily_activity().unwrap().is_empty());
+        assert_eq!(repo.get_statistics(None).unwrap().0, 0);
+        assert!(repo.get_question_practice_stats().unwrap().is_empty());
+
+        let profile = crate::analysis::ProfileGenerator::generate_profile(&repo, None).unwrap();
+        assert_eq!(profile.user_id, "2");
+        assert_eq!(profile.total_sessions, 0);
+
+        repo.set_current_user_id(1).unwrap();
+        assert_eq!(repo.get_interview_sessions().unwrap().len(), 1);
+        assert!(repo.get_answer_analysis(answer_id).unwrap().is_some());
+        assert_eq!(repo.get_knowledge_count(), 1);
+        let practice = repo.get_question_practice_stats().unwrap();
+        assert_eq!((practice[0].question.as_str(), practice[0].attempts, practice[0].last_score), ("介绍一个项目", 1, 7.0));
+        assert_eq!(practice[0].question_id, repo.get_question_bank().unwrap()[0].id);
+        assert_eq!(crate::analysis::ProfileGenerator::generate_profile(&repo, None).unwrap().total_sessions, 1);
+    }
+
+    #[test]
+    fn test_writes_stay_within_user() {
+        let repo = repository();
+        let (session_id, answer_id) = seed(&repo);
+
+        repo.set_current_user_id(2).unwrap();
+        assert!(repo
+            .save_answer(session_id, 1, "问题".to_string(), "回答".to_string(), String::new(), None, None, None)
+            .is_err());
+        assert!(!repo.update_answer_scores(answer_id, 1.0, 1.0, 1.0, 1.0).unwrap());
+        seed(&repo);
+        repo.delete_all_sessions().unwrap();
+        assert!(repo.get_interview_sessions().unwrap().is_empty());
+
+        repo.set_current_user_id(1).unwrap();
+        assert_eq!(repo.get_answers_by_session(session_id).unwrap().len(), 1);
+        assert_eq!(repo.get_answer_analysis(answer_id).unwrap().unwrap().overall_score, 7.0);
+        assert_eq!(repo.get_question_bank().unwrap().len(), 1);
+        assert_eq!(repo.get_all_tags().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_shared_knowledge_is_visible_to_every_user() {
+        let repo = repository();
+        repo.insert_knowledge_vector("answer", "我负责存储引擎", &[0u8; 4], None).unwrap();
+        let shared_id = {
+            let conn = repo.conn.lock().unwrap();
+            conn.execute(
+                "INSERT INTO knowledge_vectors (user_id, content_type, content, embedding, metadata, created_at) \
+                 VALUES (?1, 'question', '介绍一个项目', x'00000000', NULL, ?2)",
+                params![Repository::SHARED_KNOWLEDGE_OWNER, now()],
+            )
+            .unwrap();
+            conn.last_insert_rowid()
+        };
+
+        repo.set_current_user_id(2).unwrap();
+        assert_eq!(repo.get_knowledge_count(), 1);
+        assert_eq!(repo.get_knowledge_count_by_type("question"), 1);
+        assert_eq!(repo.list_knowledge_entries(1, 10, None).unwrap()[0].id, shared_id);
+        repo.delete_knowledge_entry(shared_id).unwrap();
+        assert_eq!(repo.search_knowledge_by_keyword("项目", 10).unwrap().len(), 1);
+
+        repo.set_current_user_id(1).unwrap();
+        assert_eq!(repo.get_knowledge_count(), 2);
+    }
+
+    #[test]
+    fn test_rubric_update_cannot_take_another_slot() {
+        let repo = Repository::new(init_database(":memory:".into()).unwrap());
+        let mut rubrics = crate::analysis::RubricEngine::default_rubrics(crate::language::Language::Zh);
+        let mut technical = rubrics.remove(0);
+        let hr = rubrics.remove(0);
+        let technical_id = repo.save_rubric(&technical).unwrap();
+        let hr_id = repo.save_rubric(&hr).unwrap();
+
+        technical.id = Some(technical_id);
+        technical.role = hr.role.clone();
+        let error = repo.save_rubric(&technical).unwrap_err().to_string();
+        assert!(error.contains("already exists"), "{}", error);
+
+        technical.name = "改名".to_string();
+        technical.role = "technical".to_string();
+        assert_eq!(repo.save_rubric(&technical).unwrap(), technical_id);
+        let stored = repo.get_rubrics().unwrap();
+        assert_eq!(stored.len(), 2);
+        assert!(stored.iter().any(|r| r.id == Some(hr_id) && r.name == hr.name));
+        assert!(stored.iter().any(|r| r.id == Some(technical_id) && r.name == "改名"));
+    }
+}
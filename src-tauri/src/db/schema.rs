@@ -29,6 +29,7 @@ CREATE TABLE IF NOT EXISTS resumes (
     user_id INTEGER NOT NULL DEFAULT 1,
     title TEXT NOT NULL,
     content TEXT NOT NULL,
+    structured TEXT NOT NULL DEFAULT '{}',
     created_at TEXT NOT NULL,
     updated_at TEXT NOT NULL,
     FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
@@ -40,6 +41,7 @@ CREATE TABLE IF NOT EXISTS job_descriptions (
     user_id INTEGER NOT NULL DEFAULT 1,
     title TEXT NOT NULL,
     content TEXT NOT NULL,
+    structured TEXT NOT NULL DEFAULT '{}',
     created_at TEXT NOT NULL,
     updated_at TEXT NOT NULL,
     FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
@@ -66,12 +68,16 @@ CREATE TABLE IF NOT EXISTS interview_answers (
     question TEXT NOT NULL,
     answer TEXT NOT NULL,
     feedback TEXT NOT NULL,
+    question_issued_at TEXT,
+    answer_submitted_at TEXT,
+    question_id INTEGER,
     overall_score REAL DEFAULT 0,
     logic_score REAL DEFAULT 0,
     match_score REAL DEFAULT 0,
     keyword_score REAL DEFAULT 0,
     created_at TEXT NOT NULL,
-    FOREIGN KEY (session_id) REFERENCES interview_sessions(id)
+    FOREIGN KEY (session_id) REFERENCES interview_sessions(id),
+    FOREIGN KEY (question_id) REFERENCES question_bank(id) ON DELETE SET NULL
 );
 
 -- Question bank table
@@ -122,6 +128,10 @@ CREATE TABLE IF NOT EXISTS answer_analysis (
     strengths TEXT NOT NULL,
     weaknesses TEXT NOT NULL,
     suggestions TEXT NOT NULL,
+    rubric_id INTEGER,
+    criterion_scores TEXT NOT NULL DEFAULT '[]',
+    lint_findings TEXT NOT NULL DEFAULT '[]',
+    score_trace TEXT NOT NULL DEFAULT '{}',
     created_at TEXT NOT NULL,
     FOREIGN KEY (answer_id) REFERENCES interview_answers(id)
 );
@@ -145,7 +155,8 @@ CREATE TABLE IF NOT EXISTS session_reports (
 -- Performance statistics table
 CREATE TABLE IF NOT EXISTS performance_stats (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
-    session_date TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    session_date TEXT NOT NULL,
     total_sessions INTEGER NOT NULL,
     average_score REAL NOT NULL,
     content_avg REAL NOT NULL,
@@ -153,13 +164,16 @@ CREATE TABLE IF NOT EXISTS performance_stats (
     highest_score REAL NOT NULL,
     lowest_score REAL NOT NULL,
     improvement_trend REAL NOT NULL,
-    recorded_at TEXT NOT NULL
+    recorded_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE(user_id, session_date)
 );
 
 -- Question best answers table (AI-generated optimal answers)
 CREATE TABLE IF NOT EXISTS question_best_answers (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
-    question_hash TEXT NOT NULL UNIQUE,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    question_hash TEXT NOT NULL,
     question_text TEXT NOT NULL,
     generated_answer TEXT NOT NULL,
     source_answer_count INTEGER NOT NULL DEFAULT 0,
@@ -167,12 +181,195 @@ CREATE TABLE IF NOT EXISTS question_best_answers (
     needs_update INTEGER NOT NULL DEFAULT 0,
     job_context TEXT,
     created_at TEXT NOT NULL,
-    updated_at TEXT NOT NULL
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE(user_id, question_hash)
+);
+
+-- Answer comparisons table (point-by-point comparison against best answer)
+CREATE TABLE IF NOT EXISTS answer_comparisons (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    answer_id INTEGER,
+    question_hash TEXT NOT NULL,
+    question TEXT NOT NULL,
+    user_answer TEXT NOT NULL,
+    best_answer_version INTEGER NOT NULL DEFAULT 1,
+    overall_match REAL NOT NULL,
+    missing_points TEXT NOT NULL,
+    extra_points TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (answer_id) REFERENCES interview_answers(id) ON DELETE SET NULL
+);
+
+-- Comparison points table (one row per compared aspect)
+CREATE TABLE IF NOT EXISTS comparison_points (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    comparison_id INTEGER NOT NULL,
+    aspect TEXT NOT NULL,
+    best_answer_point TEXT NOT NULL,
+    user_answer_point TEXT NOT NULL,
+    match_status TEXT NOT NULL,
+    suggestion TEXT NOT NULL,
+    FOREIGN KEY (comparison_id) REFERENCES answer_comparisons(id) ON DELETE CASCADE
+);
+
+-- Improvement items table (missing points tracked until covered)
+CREATE TABLE IF NOT EXISTS improvement_items (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    question_hash TEXT NOT NULL,
+    question TEXT NOT NULL,
+    point TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'open',
+    occurrences INTEGER NOT NULL DEFAULT 1,
+    source_comparison_id INTEGER,
+    resolved_comparison_id INTEGER,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    UNIQUE(user_id, question_hash, point)
+);
+
+-- Rubrics table (criteria stored as JSON, one rubric per role and question type)
+CREATE TABLE IF NOT EXISTS rubrics (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    role TEXT NOT NULL,
+    question_type TEXT NOT NULL DEFAULT 'general',
+    criteria TEXT NOT NULL,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    UNIQUE(role, question_type)
+);
+
+-- LLM judge scores next to the heuristic scores they were blended with
+CREATE TABLE IF NOT EXISTS judge_scores (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    answer_id INTEGER NOT NULL UNIQUE,
+    heuristic_logic REAL NOT NULL,
+    heuristic_job_match REAL NOT NULL,
+    heuristic_overall REAL NOT NULL,
+    judge_logic REAL NOT NULL,
+    judge_job_match REAL NOT NULL,
+    judge_overall REAL NOT NULL,
+    blended_overall REAL NOT NULL,
+    heuristic_weight REAL NOT NULL,
+    judge_weight REAL NOT NULL,
+    rationale TEXT NOT NULL DEFAULT '',
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (answer_id) REFERENCES interview_answers(id) ON DELETE CASCADE
+);
+
+-- User additions to the bundled skill taxonomy
+CREATE TABLE IF NOT EXISTS custom_skills (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL UNIQUE,
+    aliases TEXT NOT NULL DEFAULT '[]',
+    category TEXT NOT NULL DEFAULT 'concept',
+    parent TEXT,
+    created_at TEXT NOT NULL
+);
+
+-- Question bank classification (type, target dimension, difficulty, skills)
+CREATE TABLE IF NOT EXISTS question_classifications (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    question_id INTEGER NOT NULL UNIQUE,
+    question_type TEXT NOT NULL,
+    dimension TEXT NOT NULL,
+    difficulty TEXT NOT NULL,
+    skills TEXT NOT NULL DEFAULT '[]',
+    method TEXT NOT NULL,
+    confidence REAL NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (question_id) REFERENCES question_bank(id) ON DELETE CASCADE
+);
+
+-- Benchmark datasets (imported files or anonymized local aggregates)
+CREATE TABLE IF NOT EXISTS benchmark_datasets (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    source TEXT NOT NULL DEFAULT 'import',
+    created_at TEXT NOT NULL
+);
+
+-- Benchmark scores, one row per sample and dimension
+CREATE TABLE IF NOT EXISTS benchmark_samples (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    dataset_id INTEGER NOT NULL,
+    role TEXT NOT NULL DEFAULT '',
+    seniority TEXT NOT NULL DEFAULT '',
+    region TEXT NOT NULL DEFAULT '',
+    dimension TEXT NOT NULL,
+    score REAL NOT NULL,
+    FOREIGN KEY (dataset_id) REFERENCES benchmark_datasets(id) ON DELETE CASCADE
+);
+
+-- Practice goals: a target dimension score or session count by a deadline
+CREATE TABLE IF NOT EXISTS goals (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    goal_type TEXT NOT NULL,
+    dimension TEXT,
+    target_value REAL NOT NULL,
+    baseline_value REAL NOT NULL DEFAULT 0,
+    deadline TEXT NOT NULL,
+    status TEXT NOT NULL DEFAULT 'active',
+    achieved_at TEXT,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE
+);
+
+-- Spaced-repetition schedule of question bank entries (SM-2)
+CREATE TABLE IF NOT EXISTS question_reviews (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    question_id INTEGER NOT NULL UNIQUE,
+    ease REAL NOT NULL DEFAULT 2.5,
+    interval_days INTEGER NOT NULL DEFAULT 0,
+    repetitions INTEGER NOT NULL DEFAULT 0,
+    lapses INTEGER NOT NULL DEFAULT 0,
+    due_date TEXT NOT NULL,
+    last_reviewed_at TEXT,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (question_id) REFERENCES question_bank(id) ON DELETE CASCADE
+);
+
+-- Every graded practice attempt of a question bank entry
+CREATE TABLE IF NOT EXISTS review_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    question_id INTEGER NOT NULL,
+    answer_id INTEGER,
+    score REAL NOT NULL,
+    grade INTEGER NOT NULL,
+    ease REAL NOT NULL,
+    interval_days INTEGER NOT NULL,
+    due_date TEXT NOT NULL,
+    reviewed_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (question_id) REFERENCES question_bank(id) ON DELETE CASCADE,
+    FOREIGN KEY (answer_id) REFERENCES interview_answers(id) ON DELETE SET NULL
+);
+
+-- Reverse-interview questions and their evaluation, kept even when no answer was scored
+CREATE TABLE IF NOT EXISTS reverse_evaluations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL DEFAULT 1,
+    session_id INTEGER,
+    turns TEXT NOT NULL,
+    evaluation TEXT,
+    created_at TEXT NOT NULL,
+    FOREIGN KEY (user_id) REFERENCES users(id) ON DELETE CASCADE,
+    FOREIGN KEY (session_id) REFERENCES interview_sessions(id) ON DELETE SET NULL
 );
 
 -- Knowledge vectors table for RAG (Retrieval-Augmented Generation)
 CREATE TABLE IF NOT EXISTS knowledge_vectors (
     id INTEGER PRIMARY KEY AUTOINCREMENT,
+    user_id INTEGER NOT NULL DEFAULT 1,
     content_type TEXT NOT NULL,
     content TEXT NOT NULL,
     embedding BLOB NOT NULL,
@@ -188,6 +385,7 @@ CREATE INDEX IF NOT EXISTS idx_interview_sessions_user_id ON interview_sessions(
 CREATE INDEX IF NOT EXISTS idx_question_bank_user_id ON question_bank(user_id);
 CREATE INDEX IF NOT EXISTS idx_question_tags_user_id ON question_tags(user_id);
 CREATE INDEX IF NOT EXISTS idx_answer_analysis_answer_id ON answer_analysis(answer_id);
+CREATE INDEX IF NOT EXISTS idx_benchmark_samples_dimension ON benchmark_samples(dimension);
 CREATE INDEX IF NOT EXISTS idx_session_reports_session_id ON session_reports(session_id);
 CREATE INDEX IF NOT EXISTS idx_performance_stats_date ON performance_stats(session_date);
 CREATE INDEX IF NOT EXISTS idx_interview_answers_session_id ON interview_answers(session_id);
@@ -200,6 +398,12 @@ CREATE INDEX IF NOT EXISTS idx_interview_answers_question ON interview_answers(q
 CREATE INDEX IF NOT EXISTS idx_knowledge_type ON knowledge_vectors(content_type);
 CREATE INDEX IF NOT EXISTS idx_answers_session_question ON interview_answers(session_id, question_index);
 CREATE INDEX IF NOT EXISTS idx_sessions_user_created ON interview_sessions(user_id, created_at);
+CREATE INDEX IF NOT EXISTS idx_comparisons_question ON answer_comparisons(user_id, question_hash, created_at);
+CREATE INDEX IF NOT EXISTS idx_comparison_points_comparison ON comparison_points(comparison_id);
+CREATE INDEX IF NOT EXISTS idx_improvement_items_status ON improvement_items(user_id, status);
+CREATE INDEX IF NOT EXISTS idx_goals_user_status ON goals(user_id, status);
+CREATE INDEX IF NOT EXISTS idx_question_reviews_due ON question_reviews(user_id, due_date);
+CREATE INDEX IF NOT EXISTS idx_review_history_question ON review_history(question_id, reviewed_at);
 
 -- P0 Performance Optimization Indices
 CREATE INDEX IF NOT EXISTS idx_resumes_updated ON resumes(updated_at DESC);
@@ -219,6 +423,29 @@ fn column_exists(conn: &Connection, table: &str, column: &str) -> Result<bool> {
     Ok(columns.contains(&column.to_string()))
 }
 
+/// Recreate a table whose unique key predates `user_id`
+///
+/// SQLite cannot change a UNIQUE constraint in place, so the old table is
+/// renamed, recreated from the schema and copied over; existing rows go to
+/// the default user.
+fn rebuild_with_user_id(conn: &Connection, table: &str, columns: &str) -> Result<()> {
+    if column_exists(conn, table, "user_id")? {
+        return Ok(());
+    }
+    log::info!("Rebuilding table {} with per-user unique key", table);
+    let legacy = format!("{}_legacy", table);
+    conn.execute(&format!("ALTER TABLE {} RENAME TO {}", table, legacy), [])?;
+    conn.execute_batch(CREATE_TABLES_SQL)?;
+    conn.execute(
+        &format!("INSERT INTO {} ({}) SELECT {} FROM {}", table, columns, columns, legacy),
+        []
+    )?;
+    conn.execute(&format!("DROP TABLE {}", legacy), [])?;
+    // The legacy indexes went with the dropped table
+    conn.execute_batch(CREATE_TABLES_SQL)?;
+    Ok(())
+}
+
 /// Migrate existing tables to add user_id column
 fn migrate_tables(conn: &Connection) -> Result<()> {
     // Tables that need user_id column
@@ -227,7 +454,8 @@ fn migrate_tables(conn: &Connection) -> Result<()> {
         "job_descriptions",
         "interview_sessions",
         "question_bank",
-        "question_tags"
+        "question_tags",
+        "knowledge_vectors"
     ];
     
     for table in tables {
@@ -248,6 +476,29 @@ fn migrate_tables(conn: &Connection) -> Result<()> {
             )?;
         }
     }
+    conn.execute("CREATE INDEX IF NOT EXISTS idx_knowledge_user ON knowledge_vectors(user_id, content_type)", [])?;
+    
+    // Bootstrapped questions and answers were stored for whoever ran the bootstrap; share them once
+    conn.execute(
+        "UPDATE knowledge_vectors SET user_id = ?1 \
+         WHERE content_type IN ('question', 'answer') \
+         AND metadata LIKE '%\"category\":%' \
+         AND (metadata LIKE '%\"jd_name\":%' OR metadata LIKE '%\"score\":%') \
+         AND NOT EXISTS (SELECT 1 FROM knowledge_vectors WHERE user_id = ?1)",
+        [super::Repository::SHARED_KNOWLEDGE_OWNER],
+    )?;
+    
+    // Per-day stats and best answers were keyed globally; rebuild them keyed per user
+    rebuild_with_user_id(
+        conn,
+        "performance_stats",
+        "id, session_date, total_sessions, average_score, content_avg, expression_avg, highest_score, lowest_score, improvement_trend, recorded_at",
+    )?;
+    rebuild_with_user_id(
+        conn,
+        "question_best_answers",
+        "id, question_hash, question_text, generated_answer, source_answer_count, version, needs_update, job_context, created_at, updated_at",
+    )?;
     
     // Add avatar_path column to users table if it doesn't exist
     let users_exists: bool = conn
@@ -266,6 +517,73 @@ fn migrate_tables(conn: &Connection) -> Result<()> {
         )?;
     }
     
+    // Add answer timing columns to interview_answers table
+    for column in ["question_issued_at", "answer_submitted_at"] {
+        if !column_exists(conn, "interview_answers", column)? {
+            log::info!("Migrating interview_answers table to add {} column", column);
+            conn.execute(
+                &format!("ALTER TABLE interview_answers ADD COLUMN {} TEXT", column),
+                []
+            )?;
+        }
+    }
+    
+    // Link answers to the question bank entry they practised
+    if !column_exists(conn, "interview_answers", "question_id")? {
+        log::info!("Migrating interview_answers table to add question_id column");
+        conn.execute(
+            "ALTER TABLE interview_answers ADD COLUMN question_id INTEGER REFERENCES question_bank(id) ON DELETE SET NULL",
+            []
+        )?;
+    }
+    
+    // Add rubric breakdown columns to answer_analysis table
+    if !column_exists(conn, "answer_analysis", "rubric_id")? {
+        log::info!("Migrating answer_analysis table to add rubric_id column");
+        conn.execute("ALTER TABLE answer_analysis ADD COLUMN rubric_id INTEGER", [])?;
+    }
+    if !column_exists(conn, "answer_analysis", "criterion_scores")? {
+        log::info!("Migrating answer_analysis table to add criterion_scores column");
+        conn.execute(
+            "ALTER TABLE answer_analysis ADD COLUMN criterion_scores TEXT NOT NULL DEFAULT '[]'",
+            []
+        )?;
+    }
+    
+    if !column_exists(conn, "answer_analysis", "lint_findings")? {
+        log::info!("Migrating answer_analysis table to add lint_findings column");
+        conn.execute(
+            "ALTER TABLE answer_analysis ADD COLUMN lint_findings TEXT NOT NULL DEFAULT '[]'",
+            []
+        )?;
+    }
+    
+    if !column_exists(conn, "answer_analysis", "score_trace")? {
+        log::info!("Migrating answer_analysis table to add score_trace column");
+        conn.execute(
+            "ALTER TABLE answer_analysis ADD COLUMN score_trace TEXT NOT NULL DEFAULT '{}'",
+            []
+        )?;
+    }
+    
+    // Add structured (parsed sections) column to resumes table
+    if !column_exists(conn, "resumes", "structured")? {
+        log::info!("Migrating resumes table to add structured column");
+        conn.execute(
+            "ALTER TABLE resumes ADD COLUMN structured TEXT NOT NULL DEFAULT '{}'",
+            []
+        )?;
+    }
+    
+    // Add structured (parsed fields) column to job_descriptions table
+    if !column_exists(conn, "job_descriptions", "structured")? {
+        log::info!("Migrating job_descriptions table to add structured column");
+        conn.execute(
+            "ALTER TABLE job_descriptions ADD COLUMN structured TEXT NOT NULL DEFAULT '{}'",
+            []
+        )?;
+    }
+    
     Ok(())
 }
 
@@ -289,10 +607,7 @@ pub fn init_database(db_path: PathBuf) -> Result<Connection> {
     conn.execute_batch(CREATE_TABLES_SQL)?;
     log::info!("Schema created successfully");
     
-    // Migrate existing tables after schema creation
-    migrate_tables(&conn)?;
-    
-    // Create default user if not exists
+    // Create default user if not exists, rebuilt tables copy rows to it
     let default_user_exists: bool = conn
         .query_row(
             "SELECT EXISTS(SELECT 1 FROM users WHERE id = 1)",
@@ -310,5 +625,8 @@ pub fn init_database(db_path: PathBuf) -> Result<Connection> {
         log::info!("Created default user");
     }
     
+    // Migrate existing tables after schema creation
+    migrate_tables(&conn)?;
+    
     Ok(conn)
 }
@@ -0,0 +1,66 @@
+//! Interview language setting shared by generation, analysis and reports
+
+use serde::{Deserialize, Serialize};
+
+/// Session language
+///
+/// `Mixed` asks questions in Chinese with English technical terms and gives
+/// bilingual feedback for heuristic analyzers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Language {
+    #[default]
+    Zh,
+    En,
+    Mixed,
+}
+
+impl Language {
+    /// Pick the localized variant of a fixed string
+    pub fn text(&self, zh: &str, en: &str) -> String {
+        match self {
+            Language::Zh => zh.to_string(),
+            Language::En => en.to_string(),
+            Language::Mixed => format!("{} / {}", zh, en),
+        }
+    }
+
+    /// Pick the system prompt variant; mixed sessions keep the Chinese prompt
+    /// and get their style from `prompt_instruction`
+    pub fn prompt<'a>(&self, zh: &'a str, en: &'a str) -> &'a str {
+        match self {
+            Language::En => en,
+            _ => zh,
+        }
+    }
+
+    /// Instruction appended to model prompts
+    pub fn prompt_instruction(&self) -> &'static str {
+        match self {
+            Language::Zh => "请全部使用中文输出。",
+            Language::En => "Conduct the interview in English. Write every question, answer and piece of feedback in English only, including all JSON string values.",
+            Language::Mixed => "使用中英文混合的面试风格：以中文为主，专业术语保留英文；反馈内容同时给出中文和英文要点。",
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_and_prompt() {
+        assert_eq!(Language::Zh.text("好", "good"), "好");
+        assert_eq!(Language::En.text("好", "good"), "good");
+        assert_eq!(Language::Mixed.text("好", "good"), "好 / good");
+
+        assert_eq!(Language::En.prompt("面试官", "interviewer"), "interviewer");
+        assert_eq!(Language::Mixed.prompt("面试官", "interviewer"), "面试官");
+    }
+
+    #[test]
+    fn test_serde_codes() {
+        assert_eq!(serde_json::to_string(&Language::Mixed).unwrap(), "\"mixed\"");
+        assert_eq!(serde_json::from_str::<Language>("\"en\"").unwrap(), Language::En);
+    }
+}
@@ -10,23 +10,26 @@ mod api;
 mod db;
 mod analysis;
 mod rag;
+mod language;
 mod rig_adapter;
 
 use api::SiliconFlowClient;
 use api::siliconflow::SiliconFlowClient as SFClient;
 #[allow(unused_imports)]
-use db::{init_database, Repository, Resume, JobDescription, InterviewSession, InterviewAnswer, QuestionBankItem, AnswerAnalysis, SessionReport, PerformanceStats, QuestionTag, InterviewProfile, RecommendationResult, BestPracticesResult, IndustryComparisonResult, User, QuestionBestAnswer};
-use analysis::{ContentAnalyzer, ScoringEngine, STARScoringEngine, ReportGenerator, ReportExporter, AnalyticsEngine, TrendAnalytics, DashboardService, DashboardData, BackupManager, CacheManager, ProfileGenerator, RecommendationEngine, BestPracticesExtractor, IndustryComparisonGenerator};
+use db::{init_database, Repository, Resume, JobDescription, InterviewSession, InterviewAnswer, QuestionBankItem, AnswerAnalysis, SessionReport, PerformanceStats, QuestionTag, InterviewProfile, RecommendationResult, BestPracticesResult, IndustryComparisonResult, User, QuestionBestAnswer, AnswerComparison, ComparisonEvolution, ImprovementItem, Rubric, JudgeScore, LintFinding, CustomSkill, QuestionClassification, BenchmarkDataset, BenchmarkSample, BenchmarkSegment, ReviewRecord, ReverseEvaluation};
+use analysis::{ContentAnalyzer, ScoringEngine, STARScoringEngine, ReportGenerator, ReportExporter, AnalyticsEngine, TrendAnalytics, TrendsAnalyzer, TrendsReport, GoalTracker, GoalType, GoalProgress, ReviewScheduler, DueReview, DashboardService, DashboardData, BackupManager, CacheManager, ProfileGenerator, RecommendationEngine, BestPracticesExtractor, IndustryComparisonGenerator, BenchmarkImporter, MultiAgentReportGenerator, AnswerComparisonTracker, RubricEngine, RubricEvaluation, SemanticMatcher, BlendWeights, JudgeScorer, ConsistencyAnalyzer, ConsistencyReport, NoiseReport, LintEngine, SkillGapAnalyzer, SkillGapReport, ResumeParser, JobDescriptionParser, StructuredJobDescription, SkillTaxonomy, SkillDefinition, QuestionClassifier, ScoreTrace};
+use analysis::judge::{HeuristicScores, JudgeAgreement};
 #[allow(unused_imports)]
 use rag::{KnowledgeStatus, KnowledgeStats, BootstrapResult, BootstrapProgress, RagService};
 use rag::vectordb::SearchResult;
-use rig_adapter::{
-    SiliconFlowProvider, VectorStoreAdapter,
-    InterviewContext, ConversationTurn, AnalysisResult,
-    AgentScheduler, RotationStrategy,
-    InterviewStateMachine, InterviewPhase, InterviewProgress,
-};
-use rig_adapter::agents::{TechInterviewer, HRInterviewer, BusinessInterviewer};
+use rig_adapter::{SiliconFlowProvider, VectorStoreAdapter, InterviewProgress};
+use rig_adapter::agents::{ReverseTurn, CandidateQuestionEvaluation};
+use rig_adapter::agents::comparison::ComparisonAgent;
+
+// Model-free multi-agent session API, also driven by the integration tests
+pub use language::Language;
+pub use rig_adapter::{AnalysisResult, ConversationTurn, InterviewPhase, MultiAgentSession, TimingConfig};
+pub use rig_adapter::agents::{InterviewScript, InterviewerRole};
 use futures::StreamExt;
 use std::sync::{Arc, Mutex};
 use std::collections::HashMap;
@@ -34,44 +37,6 @@ use tokio::sync::Mutex as TokioMutex;
 use tauri::{State, Emitter};
 use base64::Engine;
 
-/// Multi-Agent interview session
-struct MultiAgentSession {
-    context: InterviewContext,
-    scheduler: AgentScheduler,
-    state_machine: InterviewStateMachine,
-}
-
-impl MultiAgentSession {
-    fn new(
-        resume: String,
-        job_description: String,
-        provider: SiliconFlowProvider,
-        vector_store: VectorStoreAdapter,
-    ) -> Self {
-        // Create agents
-        let tech = Box::new(TechInterviewer::new(provider.clone(), vector_store.clone())) as Box<dyn rig_adapter::agents::InterviewerAgent>;
-        let hr = Box::new(HRInterviewer::new(provider.clone())) as Box<dyn rig_adapter::agents::InterviewerAgent>;
-        let business = Box::new(BusinessInterviewer::new(provider)) as Box<dyn rig_adapter::agents::InterviewerAgent>;
-        
-        let agents = vec![tech, hr, business];
-        let scheduler = AgentScheduler::new(agents).with_strategy(RotationStrategy::PhaseBased);
-        let state_machine = InterviewStateMachine::new();
-        
-        let context = InterviewContext {
-            resume,
-            job_description,
-            conversation_history: Vec::new(),
-            current_phase: InterviewPhase::WarmUp,
-        };
-        
-        Self {
-            context,
-            scheduler,
-            state_machine,
-        }
-    }
-}
-
 /// Application state holding the SiliconFlow API client and database
 /// 
 /// The client is wrapped in Mutex<Option<>> to handle:
@@ -127,6 +92,7 @@ fn greet(name: &str) -> String {
 /// * `resume` - User's resume text
 /// * `job_description` - Target job description
 /// * `count` - Number of questions to generate
+/// * `language` - Interview language, defaults to Chinese
 /// * `state` - Application state containing API client
 ///
 /// # Returns
@@ -138,10 +104,24 @@ async fn generate_questions(
     job_description: String,
     count: u32,
     persona: String,
+    language: Option<Language>,
+    target_gaps: Option<bool>,
     state: State<'_, AppState>,
 ) -> Result<Vec<String>, String> {
     let client = get_client(&state)?;
     
+    // Structured JD summary, plus what the resume lacks for this JD when targeting gaps
+    let structured_jd = structured_job_description(&state, &job_description);
+    let gap_focus = if target_gaps.unwrap_or(false) {
+        SkillGapAnalyzer::analyze(&resume, &job_description, &structured_jd).question_focus()
+    } else {
+        None
+    };
+    let focus = [structured_jd.prompt_summary(), gap_focus]
+        .into_iter()
+        .flatten()
+        .reduce(|a, b| format!("{}\n\n{}", a, b));
+    
     // Use tokio::join! to parallelize RAG retrieval and API warm-up
     let (context, _) = tokio::join!(
         async {
@@ -180,38 +160,291 @@ async fn generate_questions(
             count, 
             &persona,
             context.as_deref(),
+            focus.as_deref(),
+            language.unwrap_or_default(),
         )
         .await
         .map_err(|e| e.to_string())
 }
 
-/// Analyze user's answer and provide constructive feedback
+/// Compare a resume against a job description: matched, missing and overqualified items
+#[tauri::command]
+fn analyze_skill_gap(resume: String, job_description: String, state: State<'_, AppState>) -> SkillGapReport {
+    let structured_jd = structured_job_description(&state, &job_description);
+    SkillGapAnalyzer::analyze(&resume, &job_description, &structured_jd)
+}
+
+/// Analyze user's answer against a rubric
 ///
 /// # Arguments
 /// * `question` - The interview question being answered
 /// * `answer` - User's answer to analyze
 /// * `job_description` - Target job description for relevance check
+/// * `role` - Rubric role ("technical", "hr", "business"), defaults to the general rubric
+/// * `question_type` - Rubric question type, defaults to the question's classified type
+/// * `answer_id` - Saved answer whose analysis receives the per-criterion breakdown
+/// * `language` - Feedback language, defaults to Chinese
 /// * `state` - Application state containing API client
 ///
 /// # Returns
-/// * `Ok(String)` - AI feedback including strengths, improvements, suggestions
+/// * `Ok(RubricEvaluation)` - Per-criterion scores with evidence, strengths and improvements
 /// * `Err(String)` - Error message if analysis fails
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 async fn analyze_answer(
     question: String,
     answer: String,
     job_description: String,
     persona: String,
+    role: Option<String>,
+    question_type: Option<String>,
+    answer_id: Option<i64>,
+    language: Option<Language>,
     state: State<'_, AppState>,
-) -> Result<String, String> {
+) -> Result<RubricEvaluation, String> {
     let client = get_client(&state)?;
+    let language = language.unwrap_or_default();
+    let question_type = question_type.unwrap_or_else(|| RubricEngine::question_type(&question));
+    let rubric = resolve_rubric(&state, role.as_deref(), &question_type, language)?;
     
-    client
-        .analyze_answer(&question, &answer, &job_description, &persona)
+    let evaluation = evaluate_with_rubric(
+        &client,
+        &rubric,
+        &question,
+        &answer,
+        &job_description,
+        &persona,
+        language,
+        load_evaluation_temperature(&state),
+    )
+    .await?;
+    
+    if let Some(answer_id) = answer_id {
+        let saved = RubricEngine::store(&state.db, answer_id, rubric.id, &evaluation.criterion_scores)
+            .map_err(|e| e.to_string())?;
+        if !saved {
+            log::warn!("Answer {} has no analysis yet, criterion scores not persisted", answer_id);
+        }
+    }
+    
+    Ok(evaluation)
+}
+
+/// Pick the stored or built-in rubric for a role and question type
+fn resolve_rubric(
+    state: &State<'_, AppState>,
+    role: Option<&str>,
+    question_type: &str,
+    language: Language,
+) -> Result<Rubric, String> {
+    let stored = state.db.get_rubrics().map_err(|e| e.to_string())?;
+    Ok(RubricEngine::resolve(
+        &stored,
+        role.unwrap_or(analysis::rubric::GENERAL_ROLE),
+        question_type,
+        language,
+    ))
+}
+
+/// Run one LLM evaluation of an answer against a rubric
+#[allow(clippy::too_many_arguments)]
+async fn evaluate_with_rubric(
+    client: &SiliconFlowClient,
+    rubric: &Rubric,
+    question: &str,
+    answer: &str,
+    job_description: &str,
+    persona: &str,
+    language: Language,
+    temperature: f32,
+) -> Result<RubricEvaluation, String> {
+    let response = client
+        .analyze_answer(
+            question,
+            answer,
+            job_description,
+            persona,
+            &RubricEngine::render_prompt(rubric, language),
+            language,
+            Some(temperature),
+        )
         .await
+        .map_err(|e| e.to_string())?;
+    
+    RubricEngine::parse_evaluation(&response, rubric, answer).map_err(|e| e.to_string())
+}
+
+/// Evaluate an answer `runs` times concurrently, dropping failed runs
+#[allow(clippy::too_many_arguments)]
+async fn evaluate_repeatedly(
+    client: &SiliconFlowClient,
+    rubric: &Rubric,
+    question: &str,
+    answer: &str,
+    job_description: &str,
+    persona: &str,
+    language: Language,
+    temperature: f32,
+    runs: usize,
+) -> Vec<RubricEvaluation> {
+    let evaluations = futures::future::join_all((0..runs).map(|_| {
+        evaluate_with_rubric(client, rubric, question, answer, job_description, persona, language, temperature)
+    }))
+    .await;
+    
+    evaluations
+        .into_iter()
+        .filter_map(|result| result.map_err(|e| log::warn!("Consistency run failed: {}", e)).ok())
+        .collect()
+}
+
+/// Read the configured evaluation temperature
+fn load_evaluation_temperature(state: &State<'_, AppState>) -> f32 {
+    state.db.get_config_value(analysis::consistency::EVALUATION_TEMPERATURE_CONFIG_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(analysis::consistency::DEFAULT_EVALUATION_TEMPERATURE)
+}
+
+/// Get the temperature used for answer evaluation
+#[tauri::command]
+fn get_evaluation_temperature(state: State<'_, AppState>) -> Result<f32, String> {
+    Ok(load_evaluation_temperature(&state))
+}
+
+/// Set the temperature used for answer evaluation (0.0 - 2.0)
+#[tauri::command]
+fn set_evaluation_temperature(temperature: f32, state: State<'_, AppState>) -> Result<(), String> {
+    if !(0.0..=2.0).contains(&temperature) {
+        return Err("Temperature must be between 0.0 and 2.0".to_string());
+    }
+    state.db.set_config_value(analysis::consistency::EVALUATION_TEMPERATURE_CONFIG_KEY, &temperature.to_string())
         .map_err(|e| e.to_string())
 }
 
+/// Evaluate an answer several times and report per-dimension mean and variance
+///
+/// With `store_median` the median scores become the answer's canonical rubric
+/// scores and the runs, blended with the heuristic score like a judge score,
+/// settle its stored overall score
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+async fn evaluate_answer_consistency(
+    question: String,
+    answer: String,
+    job_description: String,
+    persona: String,
+    role: Option<String>,
+    question_type: Option<String>,
+    answer_id: Option<i64>,
+    runs: Option<usize>,
+    store_median: Option<bool>,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<ConsistencyReport, String> {
+    let client = get_client(&state)?;
+    let language = language.unwrap_or_default();
+    let question_type = question_type.unwrap_or_else(|| RubricEngine::question_type(&question));
+    let rubric = resolve_rubric(&state, role.as_deref(), &question_type, language)?;
+    let temperature = load_evaluation_temperature(&state);
+    let runs = runs.unwrap_or(analysis::consistency::DEFAULT_RUNS).clamp(2, analysis::consistency::MAX_RUNS);
+    
+    let evaluations = evaluate_repeatedly(
+        &client,
+        &rubric,
+        &question,
+        &answer,
+        &job_description,
+        &persona,
+        language,
+        temperature,
+        runs,
+    )
+    .await;
+    
+    let mut report = ConsistencyAnalyzer::summarize(answer_id, &evaluations, temperature)
+        .ok_or_else(|| format!("Only {} of {} evaluations succeeded", evaluations.len(), runs))?;
+    
+    if let (Some(answer_id), true) = (answer_id, store_median.unwrap_or(false)) {
+        report.stored = ConsistencyAnalyzer::store_median(&state.db, answer_id, rubric.id, &report, load_blend_weights(&state))
+            .map_err(|e| e.to_string())?;
+        if report.stored {
+            if let Err(e) = ReviewScheduler::record_answer(&state.db, answer_id) {
+                log::warn!("Review scheduling failed: {}", e);
+            }
+        }
+    }
+    
+    Ok(report)
+}
+
+/// Measure scoring noise by re-evaluating a random sample of historical answers
+#[tauri::command]
+async fn measure_scoring_noise(
+    sample_size: Option<usize>,
+    runs: Option<usize>,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<NoiseReport, String> {
+    let client = get_client(&state)?;
+    let language = language.unwrap_or_default();
+    // One rubric for every sample keeps the per-criterion noise comparable
+    let rubric = resolve_rubric(&state, None, analysis::rubric::GENERAL_QUESTION_TYPE, language)?;
+    let temperature = load_evaluation_temperature(&state);
+    let runs = runs.unwrap_or(3).clamp(2, analysis::consistency::MAX_RUNS);
+    
+    let sample = state.db.sample_answers_with_jd(sample_size.unwrap_or(10).clamp(1, 50))
+        .map_err(|e| e.to_string())?;
+    
+    let mut reports = Vec::new();
+    for (answer, job_description) in sample {
+        let evaluations = evaluate_repeatedly(
+            &client,
+            &rubric,
+            &answer.question,
+            &answer.answer,
+            &job_description,
+            "balanced",
+            language,
+            temperature,
+            runs,
+        )
+        .await;
+        
+        match ConsistencyAnalyzer::summarize(answer.id, &evaluations, temperature) {
+            Some(report) => reports.push(report),
+            None => log::warn!("Skipping answer {:?}: not enough successful evaluations", answer.id),
+        }
+    }
+    
+    Ok(ConsistencyAnalyzer::noise(reports, runs))
+}
+
+/// Get stored rubrics together with the built-in defaults they do not override
+///
+/// Rubrics are shared by all users; `language` only selects the language of the built-in defaults
+#[tauri::command]
+fn get_rubrics(language: Option<Language>, state: State<'_, AppState>) -> Result<Vec<Rubric>, String> {
+    let stored = state.db.get_rubrics().map_err(|e| e.to_string())?;
+    Ok(RubricEngine::merged(stored, language.unwrap_or_default()))
+}
+
+/// Create or update a rubric, replacing any rubric for the same role and question type
+///
+/// Rubrics are shared, so the change applies to every user
+#[tauri::command]
+fn save_rubric(rubric: Rubric, state: State<'_, AppState>) -> Result<i64, String> {
+    RubricEngine::validate(&rubric).map_err(|e| e.to_string())?;
+    state.db.save_rubric(&rubric).map_err(|e| e.to_string())
+}
+
+/// Delete a stored rubric, restoring the built-in default
+#[tauri::command]
+fn delete_rubric(id: i64, state: State<'_, AppState>) -> Result<(), String> {
+    state.db.delete_rubric(id).map_err(|e| e.to_string())
+}
+
 /// Analyze answer for follow-up question generation
 ///
 /// # Arguments
@@ -340,6 +573,37 @@ fn db_delete_resume(id: i64, state: State<'_, AppState>) -> Result<(), String> {
         .map_err(|e| e.to_string())
 }
 
+/// Import a PDF or DOCX resume, storing raw text and detected sections
+///
+/// Scanned or unparseable documents are still saved; `structured.warnings`
+/// explains what could not be extracted.
+#[tauri::command]
+fn import_resume_file(
+    file_path: String,
+    title: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    let path = std::path::Path::new(&file_path);
+    let parsed = ResumeParser::parse_file(path).map_err(|e| e.to_string())?;
+    for warning in &parsed.structured.warnings {
+        log::warn!("Resume import {}: {}", file_path, warning);
+    }
+
+    let title = title
+        .filter(|t| !t.trim().is_empty())
+        .or_else(|| path.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+        .unwrap_or_else(|| "导入的简历".to_string());
+    let structured = serde_json::to_string(&parsed.structured).map_err(|e| e.to_string())?;
+    let id = state.db.save_imported_resume(title, parsed.text.clone(), structured)
+        .map_err(|e| e.to_string())?;
+
+    Ok(serde_json::json!({
+        "id": id,
+        "text": parsed.text,
+        "structured": parsed.structured,
+    }))
+}
+
 // ===== Job Description Commands =====
 
 /// Save job description to database
@@ -349,8 +613,56 @@ fn db_save_job_description(
     content: String,
     state: State<'_, AppState>,
 ) -> Result<i64, String> {
-    state.db.save_job_description(title, content)
-        .map_err(|e| e.to_string())
+    let structured = serde_json::to_string(&JobDescriptionParser::parse(&content))
+        .map_err(|e| e.to_string())?;
+    let id = state.db.save_job_description(title, content)
+        .map_err(|e| e.to_string())?;
+    state.db.update_job_description_structured(id, &structured)
+        .map_err(|e| e.to_string())?;
+    Ok(id)
+}
+
+/// Re-parse a saved job description, optionally refining the rule-based fields with the LLM
+///
+/// A failed refinement keeps the rule-based result and is only logged.
+#[tauri::command]
+async fn parse_job_description(
+    id: i64,
+    refine: Option<bool>,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<StructuredJobDescription, String> {
+    let jd = state.db.get_job_description(id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Job description {} not found", id))?;
+    let mut structured = JobDescriptionParser::parse(&jd.content);
+    
+    if refine.unwrap_or(false) {
+        let client = get_client(&state)?;
+        let rules_json = serde_json::to_string(&structured).map_err(|e| e.to_string())?;
+        match client.refine_job_description(&jd.content, &rules_json, language.unwrap_or_default()).await {
+            Ok(response) => match JobDescriptionParser::refine(structured.clone(), &response) {
+                Ok(refined) => structured = refined,
+                Err(e) => log::warn!("Ignoring JD refinement for {}: {}", id, e),
+            },
+            Err(e) => log::warn!("JD refinement request failed for {}: {}", id, e),
+        }
+    }
+    
+    let json = serde_json::to_string(&structured).map_err(|e| e.to_string())?;
+    state.db.update_job_description_structured(id, &json)
+        .map_err(|e| e.to_string())?;
+    Ok(structured)
+}
+
+/// Structured fields for a JD text: the stored (possibly refined) record of a
+/// saved JD with the same content, rule-based parsing otherwise
+fn structured_job_description(state: &State<'_, AppState>, job_description: &str) -> StructuredJobDescription {
+    state.db.find_job_description_structured(job_description)
+        .ok()
+        .flatten()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_else(|| JobDescriptionParser::parse(job_description))
 }
 
 /// Get all job descriptions from database
@@ -403,16 +715,22 @@ fn db_get_session(session_id: i64, state: State<'_, AppState>) -> Result<Option<
 }
 
 /// Save interview answer
+///
+/// `question_issued_at` is the RFC 3339 time the question was shown; submit time is recorded on save.
+/// `question_id` links the answer to the question bank entry it practises.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 fn db_save_answer(
     session_id: i64,
     question_index: i32,
     question: String,
     answer: String,
     feedback: String,
+    question_issued_at: Option<String>,
+    question_id: Option<i64>,
     state: State<'_, AppState>,
 ) -> Result<i64, String> {
-    state.db.save_answer(session_id, question_index, question, answer, feedback)
+    state.db.save_answer(session_id, question_index, question, answer, feedback, question_issued_at, None, question_id)
         .map_err(|e| e.to_string())
 }
 
@@ -457,6 +775,15 @@ async fn db_add_to_bank(
         Err(e) => log::warn!("Failed to sync question to knowledge base: {}", e)
     }
     
+    let classification = classify_questions(&state, vec![(question_id, question)], false, Language::default())
+        .await
+        .pop();
+    if let Some(classification) = classification {
+        if let Err(e) = state.db.save_question_classification(&classification) {
+            log::warn!("Failed to store classification for question {}: {}", question_id, e);
+        }
+    }
+    
     Ok(question_id)
 }
 
@@ -507,8 +834,20 @@ async fn db_delete_from_bank(id: i64, state: State<'_, AppState>) -> Result<(),
 // ===== Question Tag Commands =====
 
 /// Create a new tag
+///
+/// Skill names are stored under their canonical taxonomy name; creating "k8s"
+/// when a "Kubernetes" tag exists returns the existing tag.
 #[tauri::command]
 fn db_create_tag(name: String, color: String, state: State<'_, AppState>) -> Result<i64, String> {
+    let taxonomy = SkillTaxonomy::global();
+    let name = taxonomy.canonical(&name);
+    let existing = state.db.get_all_tags()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|tag| taxonomy.canonical(&tag.name).eq_ignore_ascii_case(&name));
+    if let Some(id) = existing.and_then(|tag| tag.id) {
+        return Ok(id);
+    }
     state.db.create_tag(name, color)
         .map_err(|e| e.to_string())
 }
@@ -523,6 +862,7 @@ fn db_get_all_tags(state: State<'_, AppState>) -> Result<Vec<QuestionTag>, Strin
 /// Update a tag
 #[tauri::command]
 fn db_update_tag(id: i64, name: String, color: String, state: State<'_, AppState>) -> Result<(), String> {
+    let name = SkillTaxonomy::global().canonical(&name);
     state.db.update_tag(id, name, color)
         .map_err(|e| e.to_string())
 }
@@ -562,6 +902,182 @@ fn db_get_questions_by_tag(tag_id: i64, state: State<'_, AppState>) -> Result<Ve
         .map_err(|e| e.to_string())
 }
 
+/// Suggest tags for a question: canonical names of the skills it mentions
+#[tauri::command]
+fn suggest_question_tags(question: String) -> Vec<String> {
+    SkillTaxonomy::global()
+        .extract(&question)
+        .into_iter()
+        .map(|skill| skill.name.clone())
+        .collect()
+}
+
+// ===== Skill Taxonomy Commands =====
+
+/// Get the installed skill taxonomy (bundled plus user skills)
+#[tauri::command]
+fn get_skill_taxonomy() -> Vec<SkillDefinition> {
+    SkillTaxonomy::global().skills().to_vec()
+}
+
+/// Resolve terms to canonical skill names, dropping duplicates
+#[tauri::command]
+fn normalize_skills(terms: Vec<String>) -> Vec<String> {
+    SkillTaxonomy::global().dedup_terms(terms)
+}
+
+/// Get user-defined taxonomy skills
+#[tauri::command]
+fn get_custom_skills(state: State<'_, AppState>) -> Result<Vec<CustomSkill>, String> {
+    state.db.get_custom_skills()
+        .map_err(|e| e.to_string())
+}
+
+/// Add or update a user-defined skill and reinstall the taxonomy
+///
+/// Naming a bundled skill adds aliases to it and may change its category or parent;
+/// leaving the category out keeps the bundled one.
+#[tauri::command]
+fn save_custom_skill(
+    name: String,
+    aliases: Vec<String>,
+    category: Option<String>,
+    parent: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<i64, String> {
+    let name = name.trim().to_string();
+    let category = category.unwrap_or_default().trim().to_lowercase();
+    SkillTaxonomy::global()
+        .validate_custom(&name, Some(&category), parent.as_deref())
+        .map_err(|e| e.to_string())?;
+    let aliases: Vec<String> = aliases.into_iter()
+        .map(|alias| alias.trim().to_string())
+        .filter(|alias| !alias.is_empty())
+        .collect();
+    let id = state.db.save_custom_skill(&name, &aliases, &category, parent.as_deref())
+        .map_err(|e| e.to_string())?;
+    reload_skill_taxonomy(&state.db)?;
+    Ok(id)
+}
+
+/// Delete a user-defined skill and reinstall the taxonomy
+#[tauri::command]
+fn delete_custom_skill(id: i64, state: State<'_, AppState>) -> Result<(), String> {
+    state.db.delete_custom_skill(id)
+        .map_err(|e| e.to_string())?;
+    reload_skill_taxonomy(&state.db)
+}
+
+/// Install the bundled taxonomy merged with the stored user skills
+fn reload_skill_taxonomy(db: &Repository) -> Result<(), String> {
+    let custom = db.get_custom_skills().map_err(|e| e.to_string())?;
+    SkillTaxonomy::install(&custom);
+    Ok(())
+}
+
+// ===== Question Classification Commands =====
+
+/// Classify a question text without storing it (question id 0)
+#[tauri::command]
+async fn classify_question(
+    question: String,
+    use_llm: Option<bool>,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<QuestionClassification, String> {
+    classify_questions(&state, vec![(0, question)], use_llm.unwrap_or(false), language.unwrap_or_default())
+        .await
+        .pop()
+        .ok_or_else(|| "Classification failed".to_string())
+}
+
+/// Re-classify one question bank entry and store the result
+#[tauri::command]
+async fn classify_bank_question(
+    id: i64,
+    use_llm: Option<bool>,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<QuestionClassification, String> {
+    let question = state.db.get_question_bank()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|q| q.id == Some(id))
+        .ok_or_else(|| format!("Question {} not found", id))?;
+    let mut classification = classify_questions(&state, vec![(id, question.question)], use_llm.unwrap_or(false), language.unwrap_or_default())
+        .await
+        .pop()
+        .ok_or_else(|| "Classification failed".to_string())?;
+    classification.id = Some(state.db.save_question_classification(&classification).map_err(|e| e.to_string())?);
+    Ok(classification)
+}
+
+/// Classify question bank entries that have no classification yet (all entries with `force`)
+#[tauri::command]
+async fn backfill_question_classifications(
+    use_llm: Option<bool>,
+    force: Option<bool>,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<Vec<QuestionClassification>, String> {
+    let questions = if force.unwrap_or(false) {
+        state.db.get_question_bank()
+    } else {
+        state.db.get_unclassified_questions()
+    }
+    .map_err(|e| e.to_string())?;
+    let pending: Vec<(i64, String)> = questions
+        .into_iter()
+        .filter_map(|q| q.id.map(|id| (id, q.question)))
+        .collect();
+    
+    let mut classifications = classify_questions(&state, pending, use_llm.unwrap_or(false), language.unwrap_or_default()).await;
+    for classification in classifications.iter_mut() {
+        classification.id = Some(state.db.save_question_classification(classification).map_err(|e| e.to_string())?);
+    }
+    log::info!("Classified {} question bank entries", classifications.len());
+    Ok(classifications)
+}
+
+/// Stored classifications of all question bank entries
+#[tauri::command]
+fn get_question_classifications(state: State<'_, AppState>) -> Result<Vec<QuestionClassification>, String> {
+    state.db.get_question_classifications()
+        .map_err(|e| e.to_string())
+}
+
+/// Classify questions locally, then optionally refine each with the LLM
+///
+/// A failed or invalid LLM response keeps the local result and is only logged.
+async fn classify_questions(
+    state: &State<'_, AppState>,
+    questions: Vec<(i64, String)>,
+    use_llm: bool,
+    language: Language,
+) -> Vec<QuestionClassification> {
+    let mut classifications = QuestionClassifier::classify_batch(&state.rag, &questions).await;
+    if !use_llm {
+        return classifications;
+    }
+    let client = match get_client(state) {
+        Ok(client) => client,
+        Err(e) => {
+            log::warn!("Skipping LLM question classification: {}", e);
+            return classifications;
+        }
+    };
+    for (classification, (id, question)) in classifications.iter_mut().zip(&questions) {
+        match client.classify_question(question, language).await {
+            Ok(response) => match QuestionClassifier::refine(classification.clone(), &response) {
+                Ok(refined) => *classification = refined,
+                Err(e) => log::warn!("Ignoring LLM classification for question {}: {}", id, e),
+            },
+            Err(e) => log::warn!("LLM classification request failed for question {}: {}", id, e),
+        }
+    }
+    classifications
+}
+
 // ===== User Management Commands =====
 
 /// Create a new user
@@ -591,7 +1107,12 @@ fn get_current_user(state: State<'_, AppState>) -> Result<Option<db::User>, Stri
 #[tauri::command]
 fn switch_user(user_id: i64, state: State<'_, AppState>) -> Result<(), String> {
     state.db.set_current_user_id(user_id)
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?;
+    
+    // Cached dashboard and analytics belong to the previous user
+    state.cache.invalidate_all();
+    
+    Ok(())
 }
 
 /// Update user information
@@ -672,25 +1193,71 @@ fn get_avatar_path(
 // ===== Answer Analysis Commands =====
 
 /// Analyze answer and save analysis results
+///
+/// `language` selects the language of strengths, weaknesses and suggestions.
+/// `answer_budget_secs` overrides the default answer time budget; answers saved
+/// with a question issue time lose the overtime penalty on their overall score.
+/// With an API client the answer is also scored against the general rubric and
+/// the per-criterion breakdown is stored; `persona` sets the evaluator's tone.
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 async fn analyze_answer_with_scoring(
     answer_id: i64,
     answer: String,
     question: String,
     job_description: String,
+    language: Option<Language>,
+    answer_budget_secs: Option<u64>,
+    persona: Option<String>,
+    app: tauri::AppHandle,
     state: State<'_, AppState>,
 ) -> Result<serde_json::Value, String> {
+    let language = language.unwrap_or_default();
+    
     // Perform content analysis
-    let analysis = ContentAnalyzer::analyze(&answer, &question, &job_description)
+    let mut analysis = ContentAnalyzer::analyze(&answer, &question, &job_description, language)
         .map_err(|e| e.to_string())?;
+    let structured_jd = structured_job_description(&state, &job_description);
+    ContentAnalyzer::apply_job_keywords(&mut analysis, &answer, &structured_jd.keywords());
+    
+    // Semantic job match with the local embedding model; keep the lexical score if unavailable
+    if !job_description.trim().is_empty() {
+        let requirements = match structured_jd.requirements() {
+            requirements if requirements.is_empty() => SemanticMatcher::split_requirements(&job_description),
+            requirements => requirements,
+        };
+        match SemanticMatcher::analyze(&state.rag, &answer, requirements).await {
+            Ok(semantic) => ContentAnalyzer::blend_semantic_match(&mut analysis, semantic),
+            Err(e) => log::warn!("Semantic job match unavailable, using keyword match: {}", e),
+        }
+    }
     
     // Calculate scores
-    let scoring_result = ScoringEngine::calculate_score(&analysis, None);
+    let mut scoring_result = ScoringEngine::calculate_score(&analysis, None);
+    
+    // Answers past the time budget lose points on the stored overall score
+    let mut timing = TimingConfig::default();
+    if let Some(budget) = answer_budget_secs {
+        timing.answer_budget_secs = budget;
+    }
+    let latency_secs = state.db.get_answer(answer_id)
+        .map_err(|e| e.to_string())?
+        .and_then(|stored| stored.latency_secs());
+    let overtime_penalty = latency_secs.map(|latency| timing.overtime_penalty(latency)).unwrap_or(0.0);
+    if let Some(latency) = latency_secs {
+        ScoringEngine::apply_penalty(
+            &mut scoring_result,
+            "overtime",
+            format!("{:.0}s / {}s", latency, timing.answer_budget_secs),
+            overtime_penalty,
+        );
+    }
     
     // Save analysis to database
     let strengths_json = serde_json::to_string(&analysis.strengths).unwrap_or_default();
     let weaknesses_json = serde_json::to_string(&analysis.weaknesses).unwrap_or_default();
-    let suggestions_json = serde_json::to_string(&ScoringEngine::get_improvement_suggestions(&scoring_result.score_breakdown)).unwrap_or_default();
+    let suggestions = ScoringEngine::get_improvement_suggestions(&scoring_result.score_breakdown, language);
+    let suggestions_json = serde_json::to_string(&suggestions).unwrap_or_default();
     
     state.db.save_answer_analysis(
         answer_id,
@@ -705,6 +1272,55 @@ async fn analyze_answer_with_scoring(
         suggestions_json,
     ).map_err(|e| e.to_string())?;
     
+    // Offline lint findings are stored next to the scores
+    let lint_findings = LintEngine::default().lint(&answer, language);
+    state.db.save_lint_findings(answer_id, &lint_findings)
+        .map_err(|e| e.to_string())?;
+    
+    let trace_json = serde_json::to_string(&scoring_result.trace).map_err(|e| e.to_string())?;
+    state.db.save_score_trace(answer_id, &trace_json)
+        .map_err(|e| e.to_string())?;
+    
+    // Rubric breakdown needs the LLM; offline the heuristic scores stand alone
+    let criterion_scores = match get_client(&state) {
+        Ok(client) => {
+            let rubric = resolve_rubric(&state, None, &RubricEngine::question_type(&question), language)?;
+            match evaluate_with_rubric(
+                &client,
+                &rubric,
+                &question,
+                &answer,
+                &job_description,
+                persona.as_deref().unwrap_or("balanced"),
+                language,
+                load_evaluation_temperature(&state),
+            )
+            .await
+            {
+                Ok(evaluation) => {
+                    RubricEngine::store(&state.db, answer_id, rubric.id, &evaluation.criterion_scores)
+                        .map_err(|e| e.to_string())?;
+                    evaluation.criterion_scores
+                }
+                Err(e) => {
+                    log::warn!("Rubric evaluation skipped: {}", e);
+                    Vec::new()
+                }
+            }
+        }
+        Err(_) => Vec::new(),
+    };
+    
+    // Practicing a bank question counts as a review of it
+    if let Err(e) = ReviewScheduler::record_answer(&state.db, answer_id) {
+        log::warn!("Review scheduling failed: {}", e);
+    }
+    
+    // New scores can reach a goal or leave it behind
+    if let Err(e) = check_goals(&app, &state.db, language) {
+        log::warn!("Goal check failed: {}", e);
+    }
+    
     Ok(serde_json::json!({
         "overall_score": scoring_result.overall_score,
         "content_score": scoring_result.content_score,
@@ -716,10 +1332,115 @@ async fn analyze_answer_with_scoring(
         },
         "strengths": analysis.strengths,
         "weaknesses": analysis.weaknesses,
-        "suggestions": ScoringEngine::get_improvement_suggestions(&scoring_result.score_breakdown),
+        "suggestions": suggestions,
+        "requirements": analysis.semantic_match,
+        "lint_findings": lint_findings,
+        "criterion_scores": criterion_scores,
+        "latency_secs": latency_secs,
+        "overtime_penalty": overtime_penalty,
+        "trace": scoring_result.trace,
     }))
 }
 
+/// Lint an answer offline, returning findings with byte spans
+///
+/// `language` selects the language of messages and suggestions, defaults to Chinese
+#[tauri::command]
+fn lint_answer(answer: String, language: Option<Language>) -> Vec<LintFinding> {
+    LintEngine::default().lint(&answer, language.unwrap_or_default())
+}
+
+/// Score an analyzed answer with the LLM judge and blend it into the heuristic scores
+///
+/// The heuristic scores are read from the score trace, where the blend is an
+/// override step, so re-judging always blends against the original heuristics.
+/// The content score stays heuristic.
+#[tauri::command]
+async fn judge_answer_score(
+    answer_id: i64,
+    answer: String,
+    question: String,
+    job_description: String,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<JudgeScore, String> {
+    let client = get_client(&state)?;
+    
+    let analysis = state.db.get_answer_analysis(answer_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| format!("Answer {} has not been analyzed yet", answer_id))?;
+    let mut trace: ScoreTrace = serde_json::from_str(&analysis.score_trace).unwrap_or_default();
+    
+    // Heuristic scores come from the trace, which keeps them apart from earlier blends
+    let heuristic = match HeuristicScores::from_trace(&trace) {
+        Some(heuristic) => heuristic,
+        None if state.db.get_judge_score(answer_id).map_err(|e| e.to_string())?.is_none() => HeuristicScores {
+            logic: analysis.logic_score,
+            job_match: analysis.job_match_score,
+            overall: analysis.overall_score,
+        },
+        None => return Err(format!("Answer {} has no score trace to recover its heuristic scores, analyze it again", answer_id)),
+    };
+    
+    let response = client
+        .judge_answer(&question, &answer, &job_description, language.unwrap_or_default())
+        .await
+        .map_err(|e| e.to_string())?;
+    let judge = JudgeScorer::parse(&response).map_err(|e| e.to_string())?;
+    
+    let weights = load_blend_weights(&state);
+    let mut record = JudgeScorer::blend(answer_id, heuristic, &judge, weights);
+    let (logic, job_match) = JudgeScorer::blended_dimensions(&record);
+    JudgeScorer::trace_blend(&mut trace, &record);
+    // Stored consistency runs are blended too, so the order of judging and consistency runs does not matter
+    let overall = JudgeScorer::settle_overall(&mut trace, analysis.overall_score, Some(&record), weights);
+    
+    state.db.update_answer_scores(answer_id, analysis.content_score, logic, job_match, overall)
+        .map_err(|e| e.to_string())?;
+    let trace_json = serde_json::to_string(&trace).map_err(|e| e.to_string())?;
+    state.db.save_score_trace(answer_id, &trace_json)
+        .map_err(|e| e.to_string())?;
+    record.id = Some(state.db.save_judge_score(&record).map_err(|e| e.to_string())?);
+    
+    // The blended overall score re-grades the answer's review
+    if let Err(e) = ReviewScheduler::record_answer(&state.db, answer_id) {
+        log::warn!("Review scheduling failed: {}", e);
+    }
+    
+    Ok(record)
+}
+
+/// Read the heuristic/judge blend weights, falling back to the defaults
+fn load_blend_weights(state: &State<'_, AppState>) -> BlendWeights {
+    state.db.get_config_value(analysis::judge::BLEND_WEIGHTS_CONFIG_KEY)
+        .ok()
+        .flatten()
+        .and_then(|value| serde_json::from_str(&value).ok())
+        .unwrap_or_default()
+}
+
+/// Get the heuristic/judge blend weights
+#[tauri::command]
+fn get_judge_blend_weights(state: State<'_, AppState>) -> Result<BlendWeights, String> {
+    Ok(load_blend_weights(&state))
+}
+
+/// Set the heuristic/judge blend weights
+#[tauri::command]
+fn set_judge_blend_weights(weights: BlendWeights, state: State<'_, AppState>) -> Result<(), String> {
+    weights.validate().map_err(|e| e.to_string())?;
+    let value = serde_json::to_string(&weights).map_err(|e| e.to_string())?;
+    state.db.set_config_value(analysis::judge::BLEND_WEIGHTS_CONFIG_KEY, &value)
+        .map_err(|e| e.to_string())
+}
+
+/// Get heuristic-vs-judge agreement over all judged answers
+#[tauri::command]
+fn get_judge_agreement(state: State<'_, AppState>) -> Result<JudgeAgreement, String> {
+    let records = state.db.get_judge_scores().map_err(|e| e.to_string())?;
+    Ok(JudgeScorer::agreement(&records))
+}
+
 /// Get answer analysis
 #[tauri::command]
 fn db_get_answer_analysis(answer_id: i64, state: State<'_, AppState>) -> Result<Option<AnswerAnalysis>, String> {
@@ -727,10 +1448,23 @@ fn db_get_answer_analysis(answer_id: i64, state: State<'_, AppState>) -> Result<
         .map_err(|e| e.to_string())
 }
 
+/// Get the score trace of an analyzed answer: rules fired, points and evidence per score
+///
+/// Analyses stored before traces existed return an empty trace.
+#[tauri::command]
+fn get_score_trace(answer_id: i64, state: State<'_, AppState>) -> Result<Option<ScoreTrace>, String> {
+    let analysis = state.db.get_answer_analysis(answer_id)
+        .map_err(|e| e.to_string())?;
+    Ok(analysis.map(|a| serde_json::from_str(&a.score_trace).unwrap_or_default()))
+}
+
 /// Analyze all answers that don't have analysis records yet
 /// Returns the number of answers analyzed
+///
+/// `language` selects the language of feedback and lint findings, defaults to Chinese
 #[tauri::command]
-fn analyze_missing_answers(state: State<'_, AppState>) -> Result<i32, String> {
+fn analyze_missing_answers(language: Option<Language>, state: State<'_, AppState>) -> Result<i32, String> {
+    let language = language.unwrap_or_default();
     let sessions = state.db.get_interview_sessions()
         .map_err(|e| e.to_string())?;
     
@@ -770,13 +1504,14 @@ fn analyze_missing_answers(state: State<'_, AppState>) -> Result<i32, String> {
                         if let Ok(analysis) = ContentAnalyzer::analyze(
                             &answer.answer,
                             &answer.question,
-                            &job_desc
+                            &job_desc,
+                            language,
                         ) {
                             let scoring_result = ScoringEngine::calculate_score(&analysis, None);
                             
                             let strengths_json = serde_json::to_string(&analysis.strengths).unwrap_or_default();
                             let weaknesses_json = serde_json::to_string(&analysis.weaknesses).unwrap_or_default();
-                            let suggestions_json = serde_json::to_string(&ScoringEngine::get_improvement_suggestions(&scoring_result.score_breakdown)).unwrap_or_default();
+                            let suggestions_json = serde_json::to_string(&ScoringEngine::get_improvement_suggestions(&scoring_result.score_breakdown, language)).unwrap_or_default();
                             
                             if state.db.save_answer_analysis(
                                 answer_id,
@@ -790,6 +1525,14 @@ fn analyze_missing_answers(state: State<'_, AppState>) -> Result<i32, String> {
                                 weaknesses_json,
                                 suggestions_json,
                             ).is_ok() {
+                                let trace_json = serde_json::to_string(&scoring_result.trace).unwrap_or_default();
+                                if let Err(e) = state.db.save_score_trace(answer_id, &trace_json) {
+                                    log::warn!("Failed to store score trace for answer {}: {}", answer_id, e);
+                                }
+                                let lint_findings = LintEngine::default().lint(&answer.answer, language);
+                                if let Err(e) = state.db.save_lint_findings(answer_id, &lint_findings) {
+                                    log::warn!("Failed to store lint findings for answer {}: {}", answer_id, e);
+                                }
                                 analyzed_count += 1;
                             }
                         }
@@ -884,12 +1627,19 @@ const FLAGSHIP_MODEL: &str = "Qwen/Qwen3-235B-A22B-Thinking-2507";
 async fn generate_comprehensive_report(
     session_id: i64,
     _use_premium_model: Option<bool>,  // Deprecated: always uses flagship model
+    language: Option<Language>,
     state: State<'_, AppState>,
 ) -> Result<SessionReport, String> {
     let client = get_client(&state)?;
     
     // Always use flagship model for best quality analysis
-    ReportGenerator::generate_report_with_model(session_id, &client, state.db.as_ref(), Some(FLAGSHIP_MODEL))
+    ReportGenerator::generate_report_with_model(
+        session_id,
+        &client,
+        state.db.as_ref(),
+        Some(FLAGSHIP_MODEL),
+        language.unwrap_or_default(),
+    )
         .await
         .map_err(|e| e.to_string())
 }
@@ -972,6 +1722,86 @@ async fn get_trend_analytics(
     Ok(data)
 }
 
+/// Get per-dimension trends: moving averages, regression slopes, plateau and
+/// regression detection, and a short forecast
+#[tauri::command]
+fn get_performance_trends(
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<TrendsReport, String> {
+    TrendsAnalyzer::analyze(&state.db, language.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+// ===== Goal Commands =====
+
+/// Re-evaluate the active user's goals, emitting `goal-achieved` and
+/// `goal-at-risk` for goals whose status just changed
+fn check_goals(app: &tauri::AppHandle, db: &Repository, language: Language) -> Result<Vec<GoalProgress>, String> {
+    let check = GoalTracker::check(db, language).map_err(|e| e.to_string())?;
+    for progress in &check.achieved {
+        let _ = app.emit("goal-achieved", progress);
+    }
+    for progress in &check.at_risk {
+        let _ = app.emit("goal-at-risk", progress);
+    }
+    Ok(check.goals)
+}
+
+/// Create a goal: a target dimension score (0-100) or session count by a
+/// deadline in YYYY-MM-DD
+#[tauri::command]
+fn create_goal(
+    goal_type: GoalType,
+    dimension: Option<String>,
+    target_value: f32,
+    deadline: String,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<i64, String> {
+    GoalTracker::create_goal(&state.db, goal_type, dimension.as_deref(), target_value, &deadline, language.unwrap_or_default())
+        .map_err(|e| e.to_string())
+}
+
+/// Get the active user's goals with progress and projections
+///
+/// `language` selects the language of the goal labels
+#[tauri::command]
+fn get_goals(
+    language: Option<Language>,
+    app: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<Vec<GoalProgress>, String> {
+    check_goals(&app, &state.db, language.unwrap_or_default())
+}
+
+/// Delete a goal
+#[tauri::command]
+fn delete_goal(id: i64, state: State<'_, AppState>) -> Result<(), String> {
+    state.db.delete_goal(id)
+        .map_err(|e| e.to_string())
+}
+
+// ===== Review Commands =====
+
+/// Get the review queue: bank questions due today or earlier, then a few new ones
+#[tauri::command]
+fn get_due_reviews(limit: Option<usize>, state: State<'_, AppState>) -> Result<Vec<DueReview>, String> {
+    ReviewScheduler::due_queue(&state.db, limit.unwrap_or(20))
+        .map_err(|e| e.to_string())
+}
+
+/// Get graded practice attempts, newest first, optionally for one question
+#[tauri::command]
+fn get_review_history(
+    question_id: Option<i64>,
+    limit: Option<usize>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ReviewRecord>, String> {
+    state.db.get_review_history(question_id, limit.unwrap_or(50))
+        .map_err(|e| e.to_string())
+}
+
 // ===== Dashboard Commands =====
 
 /// Get complete dashboard data (with caching)
@@ -1098,6 +1928,9 @@ async fn get_comparison_with_best_answer(
         None
     };
     
+    let evolution = AnswerComparisonTracker::evolution(&state.db, &hash, &question)
+        .map_err(|e| e.to_string())?;
+    
     Ok(serde_json::json!({
         "history": history.iter().map(|(ts, ans, fb, score)| {
             serde_json::json!({
@@ -1108,10 +1941,87 @@ async fn get_comparison_with_best_answer(
             })
         }).collect::<Vec<_>>(),
         "bestAnswer": best_answer,
-        "needsGeneration": best_answer.is_none() || best_answer.as_ref().map(|b| b.needs_update).unwrap_or(false)
+        "needsGeneration": best_answer.is_none() || best_answer.as_ref().map(|b| b.needs_update).unwrap_or(false),
+        "evolution": evolution
     }))
 }
 
+/// Compare an answer with the best answer point by point
+///
+/// Persists the comparison and turns missing points into improvement items
+///
+/// # Arguments
+/// * `question` - The interview question
+/// * `answer` - User's answer to compare
+/// * `answer_id` - Saved interview answer this comparison belongs to, if any
+/// * `job_description` - Job context used when the best answer must be generated
+#[tauri::command]
+async fn compare_answer_with_best(
+    question: String,
+    answer: String,
+    answer_id: Option<i64>,
+    job_description: String,
+    state: State<'_, AppState>,
+) -> Result<AnswerComparison, String> {
+    let best_answer = get_or_generate_best_answer(question.clone(), job_description, state.clone()).await?;
+    
+    let provider = SiliconFlowProvider::from_env()
+        .map_err(|e| format!("Failed to initialize provider: {}", e))?;
+    let result = ComparisonAgent::new(provider)
+        .compare(&question, &answer, &best_answer.generated_answer)
+        .await
+        .map_err(|e| e.to_string())?;
+    
+    let comparison = AnswerComparisonTracker::from_result(
+        &best_answer.question_hash,
+        &question,
+        answer_id,
+        &answer,
+        best_answer.version,
+        &result,
+    );
+    
+    AnswerComparisonTracker::record(&state.db, comparison)
+        .map_err(|e| e.to_string())
+}
+
+/// Get how the match against the best answer evolves across attempts
+#[tauri::command]
+fn get_comparison_evolution(
+    question: String,
+    state: State<'_, AppState>,
+) -> Result<ComparisonEvolution, String> {
+    AnswerComparisonTracker::evolution(&state.db, &compute_question_hash(&question), &question)
+        .map_err(|e| e.to_string())
+}
+
+/// Get improvement items created from missing points
+///
+/// # Arguments
+/// * `status` - Optional filter: "open", "resolved" or "dismissed"
+#[tauri::command]
+fn get_improvement_items(
+    status: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<Vec<ImprovementItem>, String> {
+    state.db.get_improvement_items(status.as_deref(), None)
+        .map_err(|e| e.to_string())
+}
+
+/// Update status of an improvement item
+#[tauri::command]
+fn update_improvement_item_status(
+    id: i64,
+    status: String,
+    state: State<'_, AppState>,
+) -> Result<(), String> {
+    if !matches!(status.as_str(), "open" | "resolved" | "dismissed") {
+        return Err(format!("Invalid improvement item status: {}", status));
+    }
+    state.db.update_improvement_item_status(id, &status)
+        .map_err(|e| e.to_string())
+}
+
 /// Delete a specific interview session
 #[tauri::command]
 fn delete_session(session_id: i64, state: State<'_, AppState>) -> Result<(), String> {
@@ -1222,25 +2132,47 @@ fn get_reports_by_date_range(
 
 // ===== Profile Commands =====
 
-/// Generate interview profile for user
+/// Generate interview profile for the active user
 #[tauri::command]
 fn generate_interview_profile(
-    user_id: String,
     session_limit: Option<usize>,
     state: State<'_, AppState>,
 ) -> Result<InterviewProfile, String> {
-    ProfileGenerator::generate_profile(&state.db, &user_id, session_limit)
+    ProfileGenerator::generate_profile(&state.db, session_limit)
         .map_err(|e| e.to_string())
 }
 
-/// Generate practice recommendations for user
+/// Generate practice recommendations for the active user
+///
+/// `job_description_id` selects the target JD, defaulting to the most
+/// recently updated one; questions are matched against its requirements.
+/// `language` selects the language of the explanations.
 #[tauri::command]
-fn generate_practice_recommendations(
-    user_id: String,
+async fn generate_practice_recommendations(
     limit: usize,
+    job_description_id: Option<i64>,
+    language: Option<Language>,
     state: State<'_, AppState>,
 ) -> Result<RecommendationResult, String> {
-    RecommendationEngine::generate_recommendations(&state.db, &user_id, limit)
+    let job_descriptions = state.db.get_job_descriptions()
+        .map_err(|e| e.to_string())?;
+    let target = match job_description_id {
+        Some(id) => job_descriptions.into_iter().find(|jd| jd.id == Some(id)),
+        None => job_descriptions.into_iter().next(),
+    };
+    let requirements = target
+        .map(|jd| {
+            let structured: StructuredJobDescription = serde_json::from_str(&jd.structured)
+                .unwrap_or_else(|_| JobDescriptionParser::parse(&jd.content));
+            match structured.requirements() {
+                requirements if requirements.is_empty() => SemanticMatcher::split_requirements(&jd.content),
+                requirements => requirements,
+            }
+        })
+        .unwrap_or_default();
+
+    RecommendationEngine::generate_recommendations(&state.db, &state.rag, &requirements, limit, language.unwrap_or_default())
+        .await
         .map_err(|e| e.to_string())
 }
 
@@ -1255,16 +2187,88 @@ fn extract_best_practices(
         .map_err(|e| e.to_string())
 }
 
-/// Generate industry comparison for user
+/// Generate industry comparison for the active user
+///
+/// `role`, `seniority` and `region` select the benchmark segment; sparse
+/// segments are widened and the result reports the segment actually used.
 #[tauri::command]
 fn generate_industry_comparison(
-    user_id: String,
+    role: Option<String>,
+    seniority: Option<String>,
+    region: Option<String>,
     state: State<'_, AppState>,
 ) -> Result<IndustryComparisonResult, String> {
-    IndustryComparisonGenerator::generate_comparison(&state.db, &user_id)
+    let segment = BenchmarkSegment { role, seniority, region };
+    IndustryComparisonGenerator::generate_comparison(&state.db, &segment)
         .map_err(|e| e.to_string())
 }
 
+/// Import a CSV or JSON benchmark dataset
+#[tauri::command]
+fn import_benchmark_dataset(
+    file_path: String,
+    name: Option<String>,
+    state: State<'_, AppState>,
+) -> Result<BenchmarkDataset, String> {
+    let path = std::path::Path::new(&file_path);
+    let samples = BenchmarkImporter::parse_file(path).map_err(|e| e.to_string())?;
+    if samples.is_empty() {
+        return Err("Benchmark file contains no scores".to_string());
+    }
+    let name = name
+        .filter(|n| !n.trim().is_empty())
+        .or_else(|| path.file_stem().and_then(|s| s.to_str()).map(str::to_string))
+        .unwrap_or_else(|| "benchmark".to_string());
+    save_benchmark_dataset(&state, &name, "import", &samples)
+}
+
+/// Rebuild the local benchmark from anonymized per-user averages on this machine
+#[tauri::command]
+fn build_local_benchmark(state: State<'_, AppState>) -> Result<BenchmarkDataset, String> {
+    let averages = state.db.get_user_score_averages().map_err(|e| e.to_string())?;
+    let samples = BenchmarkImporter::local_samples(&averages).map_err(|e| e.to_string())?;
+    state.db.delete_benchmark_datasets_by_source(analysis::industry::LOCAL_SOURCE)
+        .map_err(|e| e.to_string())?;
+    save_benchmark_dataset(&state, "本机用户", analysis::industry::LOCAL_SOURCE, &samples)
+}
+
+/// Get all benchmark datasets
+#[tauri::command]
+fn get_benchmark_datasets(state: State<'_, AppState>) -> Result<Vec<BenchmarkDataset>, String> {
+    state.db.get_benchmark_datasets()
+        .map_err(|e| e.to_string())
+}
+
+/// Get the role / seniority / region combinations benchmark data exists for
+#[tauri::command]
+fn get_benchmark_segments(state: State<'_, AppState>) -> Result<Vec<BenchmarkSegment>, String> {
+    state.db.get_benchmark_segments()
+        .map_err(|e| e.to_string())
+}
+
+/// Delete a benchmark dataset
+#[tauri::command]
+fn delete_benchmark_dataset(id: i64, state: State<'_, AppState>) -> Result<(), String> {
+    state.db.delete_benchmark_dataset(id)
+        .map_err(|e| e.to_string())
+}
+
+/// Store samples as a dataset and return its summary
+fn save_benchmark_dataset(
+    state: &State<'_, AppState>,
+    name: &str,
+    source: &str,
+    samples: &[BenchmarkSample],
+) -> Result<BenchmarkDataset, String> {
+    let id = state.db.save_benchmark_dataset(name, source, samples)
+        .map_err(|e| e.to_string())?;
+    state.db.get_benchmark_datasets()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .find(|d| d.id == Some(id))
+        .ok_or_else(|| format!("Benchmark dataset {} not found", id))
+}
+
 /// Update API configuration at runtime
 /// 
 /// # Arguments
@@ -1326,8 +2330,9 @@ async fn transcribe_audio(
 #[tauri::command]
 fn analyze_star_score(
     answer: String,
+    language: Option<Language>,
 ) -> Result<String, String> {
-    let star_result = STARScoringEngine::calculate_star_score(&answer);
+    let star_result = STARScoringEngine::calculate_star_score(&answer, language.unwrap_or_default());
     serde_json::to_string(&star_result)
         .map_err(|e| format!("Failed to serialize STAR result: {}", e))
 }
@@ -1404,6 +2409,7 @@ async fn get_knowledge_base_stats(
 
 /// Initialize knowledge base in background (non-blocking)
 /// Returns immediately, initialization runs asynchronously
+/// Generated questions are shared by all users, so it runs once per installation
 #[tauri::command]
 async fn init_knowledge_base_background(
     app: tauri::AppHandle,
@@ -1459,7 +2465,7 @@ async fn init_knowledge_base_background(
                             "jd_name": name,
                         }).to_string();
                         
-                        match rag.embed_and_store(
+                        match rag.embed_and_store_shared(
                             "question",
                             question,
                             Some(&metadata),
@@ -1673,10 +2679,17 @@ async fn sync_question_bank_to_knowledge(
 // ============ Multi-Agent Interview Commands ============
 
 /// Start multi-agent interview session
+///
+/// `company_notes` grounds interviewer answers during the reverse interview,
+/// `timing` overrides the default time budgets and overtime penalty,
+/// `language` selects the interview language (defaults to Chinese)
 #[tauri::command]
 async fn start_multi_agent_interview(
     resume: String,
     job_description: String,
+    company_notes: Option<String>,
+    timing: Option<TimingConfig>,
+    language: Option<Language>,
     state: State<'_, AppState>,
 ) -> Result<String, String> {
     let session_id = format!("ma-{}", chrono::Utc::now().timestamp_millis());
@@ -1690,12 +2703,17 @@ async fn start_multi_agent_interview(
     log::info!("Multi-Agent session started without RAG (optional enhancement)");
     
     // Create session
-    let session = MultiAgentSession::new(
+    let mut session = MultiAgentSession::new(
         resume,
         job_description,
+        company_notes.unwrap_or_default(),
+        timing.unwrap_or_default(),
+        language.unwrap_or_default(),
         provider,
         vector_store,
     );
+    session.context.rubrics = state.db.get_rubrics().unwrap_or_default();
+    session.context.structured_jd = structured_job_description(&state, &session.context.job_description);
     
     // Store session
     state.multi_agent_sessions.lock().await
@@ -1704,6 +2722,51 @@ async fn start_multi_agent_interview(
     Ok(session_id)
 }
 
+/// Start multi-agent interview that rehearses a fixed question list
+///
+/// `script_path` points to a JSON script with questions and optional canned analyses
+#[tauri::command]
+async fn start_scripted_interview(
+    script_path: String,
+    resume: String,
+    job_description: String,
+    timing: Option<TimingConfig>,
+    language: Option<Language>,
+    state: State<'_, AppState>,
+) -> Result<String, String> {
+    let script = InterviewScript::load(&script_path)
+        .map_err(|e| e.to_string())?;
+
+    // Linked questions must belong to the active user's bank
+    let bank_ids: std::collections::HashSet<i64> = state.db.get_question_bank()
+        .map_err(|e| e.to_string())?
+        .into_iter()
+        .filter_map(|item| item.id)
+        .collect();
+    if let Some(unknown) = script.questions.iter()
+        .filter_map(|q| q.question_id)
+        .find(|id| !bank_ids.contains(id))
+    {
+        return Err(format!("Script links unknown question bank entry {}", unknown));
+    }
+
+    let session_id = format!("ma-{}", chrono::Utc::now().timestamp_millis());
+    let mut session = MultiAgentSession::scripted(
+        &script,
+        resume,
+        job_description,
+        timing.unwrap_or_default(),
+        language.unwrap_or_default(),
+    );
+    session.context.rubrics = state.db.get_rubrics().unwrap_or_default();
+    session.context.structured_jd = structured_job_description(&state, &session.context.job_description);
+    
+    state.multi_agent_sessions.lock().await
+        .insert(session_id.clone(), session);
+    
+    Ok(session_id)
+}
+
 /// Get next interview question
 #[tauri::command]
 async fn multi_agent_next_question(
@@ -1715,13 +2778,11 @@ async fn multi_agent_next_question(
     let session = sessions.get_mut(&session_id)
         .ok_or_else(|| "Session not found".to_string())?;
     
-    // Select agent by current phase
-    session.scheduler.select_by_phase(session.state_machine.current_phase());
-    
-    // Execute turn
-    session.scheduler.execute_turn(&mut session.context)
+    let language = session.context.language;
+    session.next_question()
         .await
-        .map_err(|e| e.to_string())
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| language.text("面试已结束", "The interview is over"))
 }
 
 /// Submit user answer
@@ -1736,18 +2797,9 @@ async fn multi_agent_submit_answer(
     let session = sessions.get_mut(&session_id)
         .ok_or_else(|| "Session not found".to_string())?;
     
-    // Process answer
-    let analysis = session.scheduler.process_answer(&mut session.context, answer)
+    session.submit_answer(answer)
         .await
-        .map_err(|e| e.to_string())?;
-    
-    // Update state machine
-    session.state_machine.record_question();
-    if let Some(_new_phase) = session.state_machine.maybe_advance(&analysis) {
-        log::info!("Phase advanced to {:?}", session.state_machine.current_phase());
-    }
-    
-    Ok(analysis)
+        .map_err(|e| e.to_string())
 }
 
 /// Get interview progress
@@ -1756,24 +2808,109 @@ async fn multi_agent_get_progress(
     session_id: String,
     state: State<'_, AppState>,
 ) -> Result<InterviewProgress, String> {
-    let sessions = state.multi_agent_sessions.lock().await;
+    let mut sessions = state.multi_agent_sessions.lock().await;
     
-    let session = sessions.get(&session_id)
+    let session = sessions.get_mut(&session_id)
         .ok_or_else(|| "Session not found".to_string())?;
     
+    session.state_machine.check_timeout();
     Ok(session.state_machine.progress())
 }
 
+/// Jump to the reverse-interview phase where the candidate asks questions
+#[tauri::command]
+async fn multi_agent_start_reverse_interview(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<InterviewProgress, String> {
+    let mut sessions = state.multi_agent_sessions.lock().await;
+    
+    let session = sessions.get_mut(&session_id)
+        .ok_or_else(|| "Session not found".to_string())?;
+    
+    session.state_machine.skip_to_questions();
+    session.context.current_phase = session.state_machine.current_phase();
+    
+    Ok(session.state_machine.progress())
+}
+
+/// Ask the interviewers a question during the reverse interview
+///
+/// HR or business interviewer answers based on the JD and company notes
+#[tauri::command]
+async fn multi_agent_ask_question(
+    session_id: String,
+    question: String,
+    state: State<'_, AppState>,
+) -> Result<ReverseTurn, String> {
+    let mut sessions = state.multi_agent_sessions.lock().await;
+    
+    let session = sessions.get_mut(&session_id)
+        .ok_or_else(|| "Session not found".to_string())?;
+    
+    if session.state_machine.current_phase() != InterviewPhase::Questions {
+        return Err(session.context.language.text("反问环节尚未开始", "The reverse interview has not started"));
+    }
+    
+    session.scheduler.answer_candidate_question(&mut session.context, question)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Evaluate insightfulness, preparation and relevance of the candidate's questions
+#[tauri::command]
+async fn multi_agent_evaluate_questions(
+    session_id: String,
+    state: State<'_, AppState>,
+) -> Result<CandidateQuestionEvaluation, String> {
+    let mut sessions = state.multi_agent_sessions.lock().await;
+    
+    let session = sessions.get_mut(&session_id)
+        .ok_or_else(|| "Session not found".to_string())?;
+    
+    let evaluation = session.scheduler.evaluate_candidate_questions(&session.context)
+        .await
+        .map_err(|e| e.to_string())?;
+    
+    session.question_evaluation = Some(evaluation.clone());
+    Ok(evaluation)
+}
+
+/// Get saved reverse-interview evaluations of the active user, newest first
+#[tauri::command]
+fn get_reverse_evaluations(
+    limit: Option<i64>,
+    state: State<AppState>,
+) -> Result<Vec<ReverseEvaluation>, String> {
+    state.db.get_reverse_evaluations(limit.unwrap_or(20))
+        .map_err(|e| e.to_string())
+}
+
 /// End multi-agent interview session
+/// Generates and saves the multi-agent report when at least one answer was analyzed
 #[tauri::command]
 async fn multi_agent_end_session(
     session_id: String,
     state: State<'_, AppState>,
-) -> Result<(), String> {
-    state.multi_agent_sessions.lock().await
-        .remove(&session_id);
+) -> Result<Option<SessionReport>, String> {
+    let session = match state.multi_agent_sessions.lock().await.remove(&session_id) {
+        Some(session) => session,
+        None => return Ok(None),
+    };
     
-    Ok(())
+    let report = MultiAgentReportGenerator::generate_and_save(
+        state.db.as_ref(),
+        &session.context,
+        session.question_evaluation.as_ref(),
+        &session.state_machine,
+    ).map_err(|e| e.to_string())?;
+    
+    if report.is_some() {
+        // New session and report affect dashboard and analytics
+        state.cache.invalidate_all();
+    }
+    
+    Ok(report)
 }
 
 /// Recursively copy directory contents
@@ -1859,6 +2996,9 @@ pub fn run() {
         .expect("Failed to initialize database");
       
       let repository = Arc::new(Repository::new(conn));
+      if let Err(e) = reload_skill_taxonomy(&repository) {
+        log::warn!("Using bundled skill taxonomy only: {}", e);
+      }
       let cache_manager = Arc::new(CacheManager::new());
       
       // Initialize RAG service (lazy-loaded on first use)
@@ -1882,13 +3022,23 @@ pub fn run() {
     .invoke_handler(tauri::generate_handler![
       greet,
       generate_questions,
+      analyze_skill_gap,
       analyze_answer,
+      evaluate_answer_consistency,
+      measure_scoring_noise,
+      get_evaluation_temperature,
+      set_evaluation_temperature,
+      get_rubrics,
+      save_rubric,
+      delete_rubric,
       analyze_answer_stream,
       analyze_for_followup,
       db_save_resume,
       db_get_resumes,
       db_delete_resume,
+      import_resume_file,
       db_save_job_description,
+      parse_job_description,
       db_get_job_descriptions,
       db_delete_job_description,
       db_create_session,
@@ -1909,9 +3059,25 @@ pub fn run() {
       db_remove_tag_from_question,
       db_get_tags_for_question,
       db_get_questions_by_tag,
+      suggest_question_tags,
+      get_skill_taxonomy,
+      normalize_skills,
+      get_custom_skills,
+      save_custom_skill,
+      delete_custom_skill,
+      classify_question,
+      classify_bank_question,
+      backfill_question_classifications,
+      get_question_classifications,
       analyze_answer_with_scoring,
+      lint_answer,
+      judge_answer_score,
+      get_judge_blend_weights,
+      set_judge_blend_weights,
+      get_judge_agreement,
       analyze_missing_answers,
       db_get_answer_analysis,
+      get_score_trace,
       db_save_session_report,
       db_get_session_report,
       db_save_performance_stats,
@@ -1921,12 +3087,22 @@ pub fn run() {
       export_report_text,
       export_report_html,
       get_trend_analytics,
+      get_performance_trends,
+      create_goal,
+      get_goals,
+      delete_goal,
+      get_due_reviews,
+      get_review_history,
       get_dashboard_data,
       get_activity_data,
       get_answers_comparison,
       mark_best_answer_needs_update,
       get_or_generate_best_answer,
       get_comparison_with_best_answer,
+      compare_answer_with_best,
+      get_comparison_evolution,
+      get_improvement_items,
+      update_improvement_item_status,
       delete_session,
       delete_all_sessions,
       backup_data,
@@ -1939,6 +3115,11 @@ pub fn run() {
       generate_practice_recommendations,
       extract_best_practices,
       generate_industry_comparison,
+      import_benchmark_dataset,
+      build_local_benchmark,
+      get_benchmark_datasets,
+      get_benchmark_segments,
+      delete_benchmark_dataset,
       update_api_config,
       analyze_star_score,
       create_user,
@@ -1963,9 +3144,14 @@ pub fn run() {
       sync_question_bank_to_knowledge,
       // Multi-Agent interview commands
       start_multi_agent_interview,
+      start_scripted_interview,
       multi_agent_next_question,
       multi_agent_submit_answer,
       multi_agent_get_progress,
+      multi_agent_start_reverse_interview,
+      multi_agent_ask_question,
+      multi_agent_evaluate_questions,
+      get_reverse_evaluations,
       multi_agent_end_session
     ])
     .run(tauri::generate_context!())
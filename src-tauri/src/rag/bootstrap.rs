@@ -145,6 +145,7 @@ impl KnowledgeBootstrap {
 
     /// Bootstrap knowledge base with initial data
     /// Takes a progress callback for UI updates
+    /// The generated questions and answers are shared by all users
     pub async fn bootstrap<F>(&self, mut on_progress: F) -> Result<BootstrapResult>
     where
         F: FnMut(BootstrapProgress),
@@ -203,7 +204,7 @@ impl KnowledgeBootstrap {
                             "jd_name": template.name,
                         }).to_string();
                         
-                        if let Err(e) = self.vector_store.insert(
+                        if let Err(e) = self.vector_store.insert_shared(
                             "question",
                             question,
                             &q_embedding,
@@ -245,7 +246,7 @@ impl KnowledgeBootstrap {
                                     "score": 8.5,
                                 }).to_string();
                                 
-                                if let Err(e) = self.vector_store.insert(
+                                if let Err(e) = self.vector_store.insert_shared(
                                     "answer",
                                     &answer,
                                     &a_embedding,
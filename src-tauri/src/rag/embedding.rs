@@ -105,7 +105,6 @@ impl EmbeddingService {
     }
 
     /// Embed multiple texts in batch
-    #[allow(dead_code)]
     pub async fn embed_batch(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
         let mut model = self.model.lock().await;
         let embeddings = model
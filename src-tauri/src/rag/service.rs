@@ -112,7 +112,7 @@ impl RagService {
             .map(Arc::clone)
     }
 
-    /// Embed text and store in knowledge base
+    /// Embed text and store in the active user's knowledge base
     pub async fn embed_and_store(
         &self,
         content_type: &str,
@@ -135,6 +135,24 @@ impl RagService {
         Ok(id)
     }
 
+    /// Embed text and store it in the knowledge shared by all users
+    pub async fn embed_and_store_shared(
+        &self,
+        content_type: &str,
+        content: &str,
+        metadata: Option<&str>,
+    ) -> Result<i64> {
+        let internals = self.ensure_initialized().await?;
+        let embedding = internals.embedding_service.embed_text(content).await?;
+        internals.vector_store.insert_shared(content_type, content, &embedding, metadata).await
+    }
+
+    /// Embed texts without storing them (used for semantic matching)
+    pub async fn embed_texts(&self, texts: &[String]) -> Result<Vec<Vec<f32>>> {
+        let internals = self.ensure_initialized().await?;
+        internals.embedding_service.embed_batch(texts).await
+    }
+
     /// Retrieve similar questions from knowledge base
     pub async fn retrieve_similar_questions(
         &self,
@@ -1,9 +1,11 @@
 //! Vector database for storing and retrieving embeddings
 
+use crate::db::Repository;
 use anyhow::{Context, Result};
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 use hnsw_rs::prelude::*;
+use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -17,10 +19,25 @@ pub struct SearchResult {
     pub similarity: f32,
 }
 
+/// HNSW indexes over one owner's vectors, overall and per content type
+///
+/// Filtering by content type after an overall search would cut the results
+/// short, so each content type gets its own index. The owner is a user, or
+/// `Repository::SHARED_KNOWLEDGE_OWNER` for knowledge shared by all users.
+struct OwnerIndex {
+    owner: i64,
+    all: Option<Hnsw<'static, f32, DistCosine>>,
+    by_type: HashMap<String, Hnsw<'static, f32, DistCosine>>,
+}
+
 /// Vector store for managing embeddings
+///
+/// Bootstrapped knowledge lives in a shared index every user searches next to
+/// their own, so switching users keeps it available.
 pub struct VectorStore {
     conn: Arc<Mutex<Connection>>,
-    index: Arc<Mutex<Option<Hnsw<'static, f32, DistCosine>>>>,
+    shared: Arc<Mutex<Option<OwnerIndex>>>,
+    user: Arc<Mutex<Option<OwnerIndex>>>,
 }
 
 impl VectorStore {
@@ -28,17 +45,41 @@ impl VectorStore {
     pub fn new(conn: Connection) -> Self {
         Self {
             conn: Arc::new(Mutex::new(conn)),
-            index: Arc::new(Mutex::new(None)),
+            shared: Arc::new(Mutex::new(None)),
+            user: Arc::new(Mutex::new(None)),
         }
     }
 
-    /// Insert new vector into store
+    /// Insert new vector for the active user
     pub async fn insert(
         &self,
         content_type: &str,
         content: &str,
         embedding: &[f32],
         metadata: Option<&str>,
+    ) -> Result<i64> {
+        let owner = Repository::current_user(&*self.conn.lock().await);
+        self.insert_for(owner, content_type, content, embedding, metadata).await
+    }
+
+    /// Insert new vector shared by all users
+    pub async fn insert_shared(
+        &self,
+        content_type: &str,
+        content: &str,
+        embedding: &[f32],
+        metadata: Option<&str>,
+    ) -> Result<i64> {
+        self.insert_for(Repository::SHARED_KNOWLEDGE_OWNER, content_type, content, embedding, metadata).await
+    }
+
+    async fn insert_for(
+        &self,
+        owner: i64,
+        content_type: &str,
+        content: &str,
+        embedding: &[f32],
+        metadata: Option<&str>,
     ) -> Result<i64> {
         let conn = self.conn.lock().await;
         let now = chrono::Utc::now().to_rfc3339();
@@ -48,9 +89,10 @@ impl VectorStore {
             .context("Failed to serialize embedding")?;
         
         conn.execute(
-            "INSERT INTO knowledge_vectors (content_type, content, embedding, metadata, created_at) 
-             VALUES (?1, ?2, ?3, ?4, ?5)",
+            "INSERT INTO knowledge_vectors (user_id, content_type, content, embedding, metadata, created_at) 
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
             rusqlite::params![
+                owner,
                 content_type,
                 content,
                 embedding_bytes,
@@ -62,35 +104,67 @@ impl VectorStore {
         Ok(conn.last_insert_rowid())
     }
 
-    /// Build HNSW index from database
+    /// Build the HNSW indexes for shared knowledge and the active user from the database
     pub async fn build_index(&self) -> Result<()> {
+        let user_id = Repository::current_user(&*self.conn.lock().await);
+        *self.shared.lock().await = Some(self.load_index(Repository::SHARED_KNOWLEDGE_OWNER).await?);
+        *self.user.lock().await = Some(self.load_index(user_id).await?);
+        Ok(())
+    }
+
+    /// HNSW indexes over the vectors of one owner
+    async fn load_index(&self, owner: i64) -> Result<OwnerIndex> {
         let conn = self.conn.lock().await;
         
-        let embeddings: Vec<(usize, Vec<f32>)> = {
+        let embeddings: Vec<(usize, String, Vec<f32>)> = {
             let mut stmt = conn.prepare(
-                "SELECT id, embedding FROM knowledge_vectors"
+                "SELECT id, content_type, embedding FROM knowledge_vectors WHERE user_id = ?1"
             )?;
             
             let result = stmt
-                .query_map([], |row| {
+                .query_map([owner], |row| {
                     let id: i64 = row.get(0)?;
-                    let embedding_bytes: Vec<u8> = row.get(1)?;
+                    let content_type: String = row.get(1)?;
+                    let embedding_bytes: Vec<u8> = row.get(2)?;
                     let embedding: Vec<f32> = bincode::deserialize(&embedding_bytes)
                         .map_err(|e| rusqlite::Error::ToSqlConversionFailure(Box::new(e)))?;
-                    Ok((id as usize, embedding))
+                    Ok((id as usize, content_type, embedding))
                 })?
                 .collect::<Result<Vec<_>, _>>()?;
             result
         }; // stmt is dropped here
+        drop(conn);
         
-        if embeddings.is_empty() {
-            return Ok(());
+        let mut groups: HashMap<String, Vec<(usize, &Vec<f32>)>> = HashMap::new();
+        for (id, content_type, embedding) in &embeddings {
+            groups.entry(content_type.clone()).or_default().push((*id, embedding));
         }
+        let all: Vec<(usize, &Vec<f32>)> = embeddings.iter().map(|(id, _, e)| (*id, e)).collect();
+        
+        let index = OwnerIndex {
+            owner,
+            all: Self::build_hnsw(&all),
+            by_type: groups
+                .into_iter()
+                .filter_map(|(content_type, group)| Self::build_hnsw(&group).map(|hnsw| (content_type, hnsw)))
+                .collect(),
+        };
         
-        // Get dimension from first embedding
-        let dim = embeddings[0].1.len();
+        log::info!(
+            "Built HNSW index for owner {} with {} vectors, dim={}",
+            owner,
+            embeddings.len(),
+            embeddings.first().map_or(0, |(_, _, e)| e.len())
+        );
+        Ok(index)
+    }
+
+    /// HNSW graph over `(id, embedding)` pairs, `None` when empty
+    fn build_hnsw(embeddings: &[(usize, &Vec<f32>)]) -> Option<Hnsw<'static, f32, DistCosine>> {
+        if embeddings.is_empty() {
+            return None;
+        }
         
-        // Build HNSW index
         let nb_layer = 16.min((embeddings.len() as f32).ln() as usize);
         let max_nb_connection = 48;
         let ef_construction = 200;
@@ -103,47 +177,59 @@ impl VectorStore {
             DistCosine {},
         );
         
-        for (id, embedding) in &embeddings {
-            hnsw.insert((embedding, *id));
+        for (id, embedding) in embeddings {
+            hnsw.insert((*embedding, *id));
         }
-        
-        let mut index = self.index.lock().await;
-        *index = Some(hnsw);
-        
-        log::info!("Built HNSW index with {} vectors, dim={}", embeddings.len(), dim);
-        Ok(())
+        Some(hnsw)
     }
 
-    /// Search similar vectors using HNSW
+    /// Search similar vectors in the shared and the active user's indexes using HNSW
+    ///
+    /// The user index is rebuilt when another user became active since it was
+    /// built. Results of both indexes are merged, most similar first.
     pub async fn search(
         &self,
         embedding: &[f32],
         top_k: usize,
         content_type: Option<&str>,
     ) -> Result<Vec<SearchResult>> {
-        // Get neighbors from HNSW
-        let index_guard = self.index.lock().await;
-        let hnsw = index_guard.as_ref().context("Index not built")?;
+        let user_id = Repository::current_user(&*self.conn.lock().await);
+        let stale = self.shared.lock().await.is_none()
+            || self.user.lock().await.as_ref().is_none_or(|index| index.owner != user_id);
+        if stale {
+            self.build_index().await?;
+        }
         
+        // Get neighbors from both HNSW indexes
         let ef_search = (top_k * 2).max(50);
-        let neighbors = hnsw.search(embedding, top_k, ef_search);
+        let mut neighbors: Vec<(i64, i64, f32)> = Vec::new();
+        for index in [&self.shared, &self.user] {
+            let index_guard = index.lock().await;
+            let index = index_guard.as_ref().context("Index not built")?;
+            let hnsw = match content_type {
+                Some(ct) => index.by_type.get(ct),
+                None => index.all.as_ref(),
+            };
+            if let Some(hnsw) = hnsw {
+                neighbors.extend(
+                    hnsw.search(embedding, top_k, ef_search)
+                        .into_iter()
+                        // Convert distance to similarity
+                        .map(|neighbor| (index.owner, neighbor.d_id as i64, 1.0 - neighbor.distance)),
+                );
+            }
+        }
+        neighbors.sort_by(|a, b| b.2.partial_cmp(&a.2).unwrap_or(std::cmp::Ordering::Equal));
+        neighbors.truncate(top_k);
         
         // Retrieve content from database
         let conn = self.conn.lock().await;
         let mut results = Vec::new();
         
-        for neighbor in neighbors {
-            let id = neighbor.d_id as i64;
-            let similarity = 1.0 - neighbor.distance; // Convert distance to similarity
-            
-            let mut query = "SELECT id, content, content_type, metadata FROM knowledge_vectors WHERE id = ?1".to_string();
-            if let Some(ct) = content_type {
-                query = format!("{} AND content_type = '{}'", query, ct);
-            }
-            
+        for (owner, id, similarity) in neighbors {
             let result: Result<SearchResult, _> = conn.query_row(
-                &query,
-                rusqlite::params![id],
+                "SELECT id, content, content_type, metadata FROM knowledge_vectors WHERE id = ?1 AND user_id = ?2",
+                rusqlite::params![id, owner],
                 |row| {
                     Ok(SearchResult {
                         id: row.get(0)?,
@@ -167,29 +253,32 @@ impl VectorStore {
     #[allow(dead_code)]
     pub async fn delete(&self, id: i64) -> Result<()> {
         let conn = self.conn.lock().await;
-        conn.execute("DELETE FROM knowledge_vectors WHERE id = ?1", [id])?;
+        conn.execute(
+            "DELETE FROM knowledge_vectors WHERE id = ?1 AND user_id = ?2",
+            rusqlite::params![id, Repository::current_user(&conn)],
+        )?;
         Ok(())
     }
 
-    /// Count total vectors
+    /// Count total vectors, shared ones included
     #[allow(dead_code)]
     pub async fn count(&self) -> Result<i64> {
         let conn = self.conn.lock().await;
         let count: i64 = conn.query_row(
-            "SELECT COUNT(*) FROM knowledge_vectors",
-            [],
+            "SELECT COUNT(*) FROM knowledge_vectors WHERE user_id IN (?1, ?2)",
+            rusqlite::params![Repository::current_user(&conn), Repository::SHARED_KNOWLEDGE_OWNER],
             |row| row.get(0),
         )?;
         Ok(count)
     }
 
-    /// Count vectors by type
+    /// Count vectors by type, shared ones included
     #[allow(dead_code)]
     pub async fn count_by_type(&self, content_type: &str) -> Result<i64> {
         let conn = self.conn.lock().await;
         let count: i64 = conn.query_row(
-            "SELECT COUNT(*) FROM knowledge_vectors WHERE content_type = ?1",
-            [content_type],
+            "SELECT COUNT(*) FROM knowledge_vectors WHERE content_type = ?1 AND user_id IN (?2, ?3)",
+            rusqlite::params![content_type, Repository::current_user(&conn), Repository::SHARED_KNOWLEDGE_OWNER],
             |row| row.get(0),
         )?;
         Ok(count)
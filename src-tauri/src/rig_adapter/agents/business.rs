@@ -1,7 +1,9 @@
 // Business interviewer agent
 
 use super::{InterviewerAgent, InterviewerRole, InterviewContext, AnalysisResult};
+use super::reverse::ReverseInterview;
 use crate::rig_adapter::SiliconFlowProvider;
+use crate::analysis::RubricEngine;
 use async_trait::async_trait;
 use anyhow::Result;
 
@@ -42,6 +44,37 @@ impl BusinessInterviewer {
   "summary": "候选人具备较强的业务落地能力..."
 }"#;
 
+    const SYSTEM_PROMPT_EN: &'static str = r#"You are the head of a business unit, focused on whether the candidate can ramp up quickly and deliver business value.
+
+Focus areas:
+- Business understanding: depth of industry and business insight
+- Execution: turning ideas into actionable plans
+- Results orientation: actual outcomes of past projects
+- Learning ability: picking up new domains quickly
+
+Questioning style:
+- Start from real business scenarios
+- Focus on how problems were approached and solved
+- Probe data-driven decision making
+
+Tone: pragmatic, results-oriented and detail-minded"#;
+
+    const ANALYSIS_PROMPT_EN: &'static str = r#"Analyze the quality of the candidate's answer.
+
+Dimensions:
+1. Business insight: understanding of what drives the business
+2. Methodology: a systematic approach to problems
+3. Data sensitivity: whether judgments are backed by data
+4. Tangible results: quantifiable project outcomes
+
+Output format (JSON):
+{
+  "score": 8.2,
+  "strengths": ["Thorough business understanding", "Backed by data"],
+  "improvements": ["Could show more of XXX"],
+  "summary": "The candidate delivers business results well..."
+}"#;
+
     pub fn new(provider: SiliconFlowProvider) -> Self {
         Self { provider }
     }
@@ -64,22 +97,25 @@ impl InterviewerAgent for BusinessInterviewer {
     async fn generate_question(&self, context: &InterviewContext) -> Result<String> {
         let agent = self.provider
             .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
-            .agent(Self::SYSTEM_PROMPT)
+            .agent(context.language.prompt(Self::SYSTEM_PROMPT, Self::SYSTEM_PROMPT_EN))
             .build();
         
         let prompt = format!(
-            r#"基于以下JD和候选人简历，生成一个业务理解类问题。
+            r#"基于以下JD和候选人简历，生成一个业务理解类问题，优先围绕岗位核心职责提问。
 
 JD: {}
-
+{}
 简历: {}
 
 要求：
 1. 只输出问题本身，不要包含任何引导说明、评估标准或内部提示
 2. 使用纯文本格式，禁止使用Markdown（如**加粗**、#标题等）
-3. 直接以面试官口吴提问，简洁自然，像真实面试一样"#,
+3. 直接以面试官口吴提问，简洁自然，像真实面试一样
+4. {}"#,
             context.job_description,
-            context.resume
+            context.structured_jd.prompt_summary().map(|summary| format!("\n{}\n", summary)).unwrap_or_default(),
+            context.resume,
+            context.language.prompt_instruction()
         );
         
         agent.prompt(&prompt).await
@@ -89,31 +125,37 @@ JD: {}
         &self,
         question: &str,
         answer: &str,
-        _context: &InterviewContext,
+        context: &InterviewContext,
     ) -> Result<AnalysisResult> {
+        let rubric = context.rubric_for(self.role(), question);
         let agent = self.provider
             .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
-            .agent(Self::ANALYSIS_PROMPT)
+            .agent(context.language.prompt(Self::ANALYSIS_PROMPT, Self::ANALYSIS_PROMPT_EN))
             .build();
         
         let prompt = format!(
-            "问题：{}\n\n候选人回答：{}\n\n请分析回答质量并输出JSON格式结果。",
+            "问题：{}\n\n候选人回答：{}\n\n{}\n\n请分析回答质量并输出JSON格式结果。{}",
             question,
-            answer
+            answer,
+            RubricEngine::render_prompt(&rubric, context.language),
+            context.language.prompt_instruction()
         );
         
         let response = agent.prompt(&prompt).await?;
         
         // Parse JSON response
-        let result: AnalysisResult = serde_json::from_str(&response)
+        let mut result: AnalysisResult = serde_json::from_str(&response)
             .unwrap_or_else(|_| {
+                let language = context.language;
                 AnalysisResult {
                     score: 7.5,
-                    strengths: vec!["思路清晰".to_string()],
-                    improvements: vec!["可以更关注业务指标".to_string()],
-                    summary: "候选人对业务有基本理解。".to_string(),
+                    strengths: vec![language.text("思路清晰", "Clear line of thought")],
+                    improvements: vec![language.text("可以更关注业务指标", "Could focus more on business metrics")],
+                    summary: language.text("候选人对业务有基本理解。", "The candidate has a basic understanding of the business."),
+                    criterion_scores: Vec::new(),
                 }
             });
+        result.apply_rubric(&rubric, answer);
         
         Ok(result)
     }
@@ -122,4 +164,12 @@ JD: {}
         // Follow up if lacking business depth or score is low
         answer.len() < 120 || analysis.score < 7.5
     }
+    
+    async fn answer_candidate_question(
+        &self,
+        question: &str,
+        context: &InterviewContext,
+    ) -> Result<String> {
+        ReverseInterview::answer(&self.provider, context.language.prompt(Self::SYSTEM_PROMPT, Self::SYSTEM_PROMPT_EN), question, context).await
+    }
 }
@@ -1,7 +1,5 @@
 // Answer comparison agent
 
-#![allow(dead_code)]
-
 use crate::rig_adapter::SiliconFlowProvider;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
@@ -106,3 +104,14 @@ pub enum MatchStatus {
     Partial,
     Missing,
 }
+
+impl MatchStatus {
+    /// Storage representation, same as the serialized form
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MatchStatus::Matched => "matched",
+            MatchStatus::Partial => "partial",
+            MatchStatus::Missing => "missing",
+        }
+    }
+}
@@ -1,7 +1,9 @@
 // HR interviewer agent
 
 use super::{InterviewerAgent, InterviewerRole, InterviewContext, AnalysisResult};
+use super::reverse::{ReverseInterview, ReverseTurn, CandidateQuestionEvaluation};
 use crate::rig_adapter::SiliconFlowProvider;
+use crate::analysis::RubricEngine;
 use async_trait::async_trait;
 use anyhow::Result;
 
@@ -42,6 +44,37 @@ impl HRInterviewer {
   "summary": "候选人具备良好的团队协作能力..."
 }"#;
 
+    const SYSTEM_PROMPT_EN: &'static str = r#"You are an experienced HR interviewer focused on the candidate's soft skills and culture fit.
+
+Focus areas:
+- Communication: clarity and logic of expression
+- Teamwork: past collaboration and conflict handling
+- Career planning: fit between goals and the role
+- Values: work attitude and professionalism
+
+Questioning style:
+- Use behavioral interviewing (STAR)
+- Ask for concrete examples from past experience
+- Draw out the candidate's genuine views
+
+Tone: approachable, professional and good at guiding"#;
+
+    const ANALYSIS_PROMPT_EN: &'static str = r#"Analyze the quality of the candidate's answer.
+
+Dimensions:
+1. STAR structure: whether situation, task, action and result are covered
+2. Authenticity: how real and specific the example is
+3. Communication: whether the answer is clear and organized
+4. Culture fit: whether the values match the company culture
+
+Output format (JSON):
+{
+  "score": 8.0,
+  "strengths": ["Concrete, real example", "Clear communication"],
+  "improvements": ["Could show more of XXX"],
+  "summary": "The candidate collaborates well in a team..."
+}"#;
+
     pub fn new(provider: SiliconFlowProvider) -> Self {
         Self { provider }
     }
@@ -64,7 +97,7 @@ impl InterviewerAgent for HRInterviewer {
     async fn generate_question(&self, context: &InterviewContext) -> Result<String> {
         let agent = self.provider
             .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
-            .agent(Self::SYSTEM_PROMPT)
+            .agent(context.language.prompt(Self::SYSTEM_PROMPT, Self::SYSTEM_PROMPT_EN))
             .build();
         
         let prompt = format!(
@@ -77,9 +110,11 @@ JD: {}
 要求：
 1. 只输出问题本身，不要包含任何引导说明、评估标准、STAR结构提示或内部注释
 2. 使用纯文本格式，禁止使用Markdown（如**加粗**、#标题等）
-3. 直接以面试官口吴提问，简洁自然，像真实面试一样"#,
+3. 直接以面试官口吴提问，简洁自然，像真实面试一样
+4. {}"#,
             context.job_description,
-            context.resume
+            context.resume,
+            context.language.prompt_instruction()
         );
         
         agent.prompt(&prompt).await
@@ -89,31 +124,37 @@ JD: {}
         &self,
         question: &str,
         answer: &str,
-        _context: &InterviewContext,
+        context: &InterviewContext,
     ) -> Result<AnalysisResult> {
+        let rubric = context.rubric_for(self.role(), question);
         let agent = self.provider
             .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
-            .agent(Self::ANALYSIS_PROMPT)
+            .agent(context.language.prompt(Self::ANALYSIS_PROMPT, Self::ANALYSIS_PROMPT_EN))
             .build();
         
         let prompt = format!(
-            "问题：{}\n\n候选人回答：{}\n\n请分析回答质量并输出JSON格式结果。",
+            "问题：{}\n\n候选人回答：{}\n\n{}\n\n请分析回答质量并输出JSON格式结果。{}",
             question,
-            answer
+            answer,
+            RubricEngine::render_prompt(&rubric, context.language),
+            context.language.prompt_instruction()
         );
         
         let response = agent.prompt(&prompt).await?;
         
         // Parse JSON response
-        let result: AnalysisResult = serde_json::from_str(&response)
+        let mut result: AnalysisResult = serde_json::from_str(&response)
             .unwrap_or_else(|_| {
+                let language = context.language;
                 AnalysisResult {
                     score: 7.5,
-                    strengths: vec!["案例真实".to_string()],
-                    improvements: vec!["可以更结构化表达".to_string()],
-                    summary: "候选人具备基本的软技能。".to_string(),
+                    strengths: vec![language.text("案例真实", "Genuine example")],
+                    improvements: vec![language.text("可以更结构化表达", "Could be expressed in a more structured way")],
+                    summary: language.text("候选人具备基本的软技能。", "The candidate shows solid basic soft skills."),
+                    criterion_scores: Vec::new(),
                 }
             });
+        result.apply_rubric(&rubric, answer);
         
         Ok(result)
     }
@@ -122,4 +163,20 @@ JD: {}
         // Follow up if missing STAR structure or score is low
         answer.len() < 150 || analysis.score < 7.5
     }
+    
+    async fn answer_candidate_question(
+        &self,
+        question: &str,
+        context: &InterviewContext,
+    ) -> Result<String> {
+        ReverseInterview::answer(&self.provider, context.language.prompt(Self::SYSTEM_PROMPT, Self::SYSTEM_PROMPT_EN), question, context).await
+    }
+    
+    async fn evaluate_candidate_questions(
+        &self,
+        turns: &[ReverseTurn],
+        context: &InterviewContext,
+    ) -> Result<CandidateQuestionEvaluation> {
+        ReverseInterview::evaluate(&self.provider, turns, context).await
+    }
 }
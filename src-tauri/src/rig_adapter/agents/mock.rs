@@ -0,0 +1,104 @@
+// Configurable mock interviewer agent for model-free tests
+
+use super::{InterviewerAgent, InterviewerRole, InterviewContext, AnalysisResult};
+use async_trait::async_trait;
+use anyhow::{Result, anyhow};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Mock interviewer returning generated questions and configured scores
+pub struct MockInterviewer {
+    role: InterviewerRole,
+    scores: Vec<f32>,
+    follow_up_below: Option<f32>,
+    max_questions: Option<usize>,
+    asked: AtomicUsize,
+    analyzed: AtomicUsize,
+}
+
+impl MockInterviewer {
+    /// Create mock that scores every answer 7.0 and never follows up
+    pub fn new(role: InterviewerRole) -> Self {
+        Self {
+            role,
+            scores: vec![7.0],
+            follow_up_below: None,
+            max_questions: None,
+            asked: AtomicUsize::new(0),
+            analyzed: AtomicUsize::new(0),
+        }
+    }
+
+    /// Scores returned for consecutive answers, cycling when exhausted
+    pub fn with_scores(mut self, scores: Vec<f32>) -> Self {
+        if !scores.is_empty() {
+            self.scores = scores;
+        }
+        self
+    }
+
+    /// Request a follow-up when the score is below the threshold
+    pub fn with_follow_up_below(mut self, threshold: f32) -> Self {
+        self.follow_up_below = Some(threshold);
+        self
+    }
+
+    /// Fail question generation after `count` questions
+    pub fn with_max_questions(mut self, count: usize) -> Self {
+        self.max_questions = Some(count);
+        self
+    }
+
+    /// Number of questions generated so far
+    pub fn asked_count(&self) -> usize {
+        self.asked.load(Ordering::SeqCst)
+    }
+
+    /// Number of answers analyzed so far
+    pub fn analyzed_count(&self) -> usize {
+        self.analyzed.load(Ordering::SeqCst)
+    }
+}
+
+#[async_trait]
+impl InterviewerAgent for MockInterviewer {
+    fn role(&self) -> InterviewerRole {
+        self.role
+    }
+
+    fn role_name(&self) -> &'static str {
+        self.role.default_name()
+    }
+
+    fn avatar(&self) -> &'static str {
+        self.role.default_avatar()
+    }
+
+    async fn generate_question(&self, context: &InterviewContext) -> Result<String> {
+        let index = self.asked.fetch_add(1, Ordering::SeqCst);
+        if self.max_questions.is_some_and(|max| index >= max) {
+            return Err(anyhow!("Mock {:?} interviewer has no more questions", self.role));
+        }
+        Ok(format!("{:?} question {} ({:?})", self.role, index + 1, context.current_phase))
+    }
+
+    async fn analyze_answer(
+        &self,
+        question: &str,
+        _answer: &str,
+        _context: &InterviewContext,
+    ) -> Result<AnalysisResult> {
+        let index = self.analyzed.fetch_add(1, Ordering::SeqCst);
+        let score = self.scores[index % self.scores.len()];
+        Ok(AnalysisResult {
+            score,
+            strengths: vec![format!("strength for {}", question)],
+            improvements: vec![format!("improvement for {}", question)],
+            summary: format!("mock analysis {}", index + 1),
+            criterion_scores: Vec::new(),
+        })
+    }
+
+    async fn should_follow_up(&self, _answer: &str, analysis: &AnalysisResult) -> bool {
+        self.follow_up_below.is_some_and(|threshold| analysis.score < threshold)
+    }
+}
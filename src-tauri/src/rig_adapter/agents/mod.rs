@@ -4,11 +4,19 @@ pub mod tech;
 pub mod hr;
 pub mod business;
 pub mod comparison;
+pub mod reverse;
+pub mod scripted;
+#[cfg(test)]
+pub mod mock;
 
 use async_trait::async_trait;
-use anyhow::Result;
+use anyhow::{Result, anyhow};
 use serde::{Deserialize, Serialize};
 use super::state_machine::InterviewPhase;
+use crate::language::Language;
+use crate::analysis::RubricEngine;
+use crate::analysis::StructuredJobDescription;
+use crate::db::{CriterionScore, Rubric};
 
 /// Interviewer role enum
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -18,6 +26,44 @@ pub enum InterviewerRole {
     Business,    // Business interviewer
 }
 
+impl InterviewerRole {
+    /// Default display name (Chinese), shared by agents without their own persona
+    pub fn default_name(&self) -> &'static str {
+        match self {
+            InterviewerRole::Technical => "技术面试官",
+            InterviewerRole::HR => "HR面试官",
+            InterviewerRole::Business => "业务面试官",
+        }
+    }
+    
+    /// Display name in the session language
+    pub fn display_name(&self, language: Language) -> String {
+        match self {
+            InterviewerRole::Technical => language.text("技术面试官", "Technical interviewer"),
+            InterviewerRole::HR => language.text("HR面试官", "HR interviewer"),
+            InterviewerRole::Business => language.text("业务面试官", "Business interviewer"),
+        }
+    }
+    
+    /// Default avatar identifier
+    pub fn default_avatar(&self) -> &'static str {
+        match self {
+            InterviewerRole::Technical => "tech",
+            InterviewerRole::HR => "hr",
+            InterviewerRole::Business => "business",
+        }
+    }
+    
+    /// Role key used to look up rubrics
+    pub fn rubric_role(&self) -> &'static str {
+        match self {
+            InterviewerRole::Technical => "technical",
+            InterviewerRole::HR => "hr",
+            InterviewerRole::Business => "business",
+        }
+    }
+}
+
 /// Interviewer Agent unified interface
 #[allow(dead_code)]
 #[async_trait]
@@ -31,6 +77,16 @@ pub trait InterviewerAgent: Send + Sync {
     /// Get role avatar identifier
     fn avatar(&self) -> &'static str;
     
+    /// Whether the agent can ask another question; fixed question lists run out
+    fn has_more_questions(&self) -> bool {
+        true
+    }
+    
+    /// Question bank entry of the question asked last, `None` for generated questions
+    fn question_id(&self) -> Option<i64> {
+        None
+    }
+    
     /// Generate interview question
     async fn generate_question(&self, context: &InterviewContext) -> Result<String>;
     
@@ -44,6 +100,24 @@ pub trait InterviewerAgent: Send + Sync {
     
     /// Decide whether to follow up
     async fn should_follow_up(&self, answer: &str, analysis: &AnalysisResult) -> bool;
+    
+    /// Answer a candidate question in the reverse-interview phase
+    async fn answer_candidate_question(
+        &self,
+        _question: &str,
+        _context: &InterviewContext,
+    ) -> Result<String> {
+        Err(anyhow!("{} does not answer candidate questions", self.role_name()))
+    }
+    
+    /// Evaluate the questions asked by the candidate
+    async fn evaluate_candidate_questions(
+        &self,
+        _turns: &[ReverseTurn],
+        _context: &InterviewContext,
+    ) -> Result<CandidateQuestionEvaluation> {
+        Err(anyhow!("{} does not evaluate candidate questions", self.role_name()))
+    }
 }
 
 /// Interview context
@@ -52,8 +126,25 @@ pub trait InterviewerAgent: Send + Sync {
 pub struct InterviewContext {
     pub resume: String,
     pub job_description: String,
+    pub structured_jd: StructuredJobDescription, // Parsed JD fields for prompts
+    pub company_notes: String,
     pub conversation_history: Vec<ConversationTurn>,
+    pub reverse_turns: Vec<ReverseTurn>,
     pub current_phase: InterviewPhase,
+    pub language: Language,
+    pub rubrics: Vec<Rubric>,          // Stored rubrics, built-in defaults apply otherwise
+}
+
+impl InterviewContext {
+    /// Rubric used by an interviewer role for a question, resolved by the question's type
+    pub fn rubric_for(&self, role: InterviewerRole, question: &str) -> Rubric {
+        RubricEngine::resolve(
+            &self.rubrics,
+            role.rubric_role(),
+            &RubricEngine::question_type(question),
+            self.language,
+        )
+    }
 }
 
 /// Conversation turn
@@ -61,9 +152,16 @@ pub struct InterviewContext {
 pub struct ConversationTurn {
     pub role: InterviewerRole,
     pub role_name: String,
+    pub phase: InterviewPhase,
     pub question: String,
+    #[serde(default)]
+    pub question_id: Option<i64>,      // Question bank entry the question was taken from
     pub answer: Option<String>,
     pub analysis: Option<AnalysisResult>,
+    pub asked_at: String,              // RFC 3339, when the question was issued
+    pub answered_at: Option<String>,   // RFC 3339, when the answer was submitted
+    pub latency_secs: Option<f32>,
+    pub overtime_penalty: f32,         // Already deducted from analysis score
 }
 
 /// Analysis result
@@ -73,9 +171,25 @@ pub struct AnalysisResult {
     pub strengths: Vec<String>,
     pub improvements: Vec<String>,
     pub summary: String,
+    #[serde(default)]
+    pub criterion_scores: Vec<CriterionScore>,
+}
+
+impl AnalysisResult {
+    /// Normalize criterion scores against the rubric and derive the score from them
+    pub fn apply_rubric(&mut self, rubric: &Rubric, answer: &str) {
+        self.criterion_scores = RubricEngine::normalize(rubric, std::mem::take(&mut self.criterion_scores), answer);
+        if let Some(score) = RubricEngine::weighted_score(&self.criterion_scores) {
+            self.score = score;
+        }
+    }
 }
 
 // Re-export agent implementations
 pub use tech::TechInterviewer;
 pub use hr::HRInterviewer;
 pub use business::BusinessInterviewer;
+pub use reverse::{ReverseInterview, ReverseTurn, CandidateQuestionEvaluation};
+pub use scripted::{ScriptedInterviewer, InterviewScript};
+#[cfg(test)]
+pub use mock::MockInterviewer;
@@ -0,0 +1,227 @@
+// Reverse interview: interviewer answers candidate questions
+
+use super::{InterviewContext, InterviewerRole};
+use crate::language::Language;
+use crate::rig_adapter::SiliconFlowProvider;
+use anyhow::{bail, Context, Result};
+use serde::{Deserialize, Serialize};
+
+/// Chinese keywords routing a candidate question to the business interviewer
+const BUSINESS_KEYWORDS_ZH: &[&str] = &[
+    "业务", "产品", "用户", "客户", "市场", "竞品", "竞争", "战略", "营收", "收入", "盈利",
+    "商业模式", "增长", "指标", "行业", "项目", "技术栈", "架构", "挑战", "规划", "方向",
+];
+
+/// English keywords routing a candidate question to the business interviewer
+const BUSINESS_KEYWORDS_EN: &[&str] = &[
+    "business", "product", "user", "customer", "client", "market", "competitor", "strategy",
+    "roadmap", "revenue", "profit", "growth", "metric", "kpi", "industry", "project",
+    "tech stack", "architecture", "challenge",
+];
+
+/// Candidate question answered by an interviewer
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReverseTurn {
+    pub role: InterviewerRole,
+    pub role_name: String,
+    pub candidate_question: String,
+    pub answer: String,
+}
+
+/// Evaluation of the questions a candidate asked
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CandidateQuestionEvaluation {
+    pub score: f32,
+    pub insightfulness: f32,
+    pub preparation: f32,
+    pub relevance: f32,
+    pub strengths: Vec<String>,
+    pub improvements: Vec<String>,
+    pub summary: String,
+}
+
+/// Shared prompts and helpers for the reverse-interview phase
+pub struct ReverseInterview;
+
+impl ReverseInterview {
+    const ANSWER_RULES: &'static str = r#"现在是面试的反问环节，候选人向你提问。
+
+回答要求：
+1. 只依据提供的JD和公司资料作答，资料中没有的信息要坦诚说明"这部分需要后续和团队确认"，不要编造具体数字
+2. 站在你的角色视角回答，语气真诚、专业
+3. 使用纯文本格式，禁止使用Markdown，控制在200字以内"#;
+
+    const EVALUATION_PROMPT: &'static str = r#"你是一位资深面试官，请评估候选人在反问环节提出的问题质量。
+
+评估维度（1-10分）：
+1. insightfulness 洞察力：问题是否有深度，能否体现对岗位和行业的思考
+2. preparation 准备程度：是否体现对公司、团队和JD的事先了解
+3. relevance 相关性：问题是否与岗位职责和自身发展相关
+
+输出格式（JSON）：
+{
+  "score": 7.5,
+  "insightfulness": 7.0,
+  "preparation": 8.0,
+  "relevance": 7.5,
+  "strengths": ["关注团队技术挑战"],
+  "improvements": ["可以询问岗位的考核标准"],
+  "summary": "候选人提问有针对性..."
+}"#;
+
+    const ANSWER_RULES_EN: &'static str = r#"This is the reverse-interview part of the interview: the candidate asks you questions.
+
+Rules:
+1. Answer only from the provided JD and company notes. If the information is not there, say honestly that "this needs to be confirmed with the team later", and never invent specific numbers
+2. Answer from the perspective of your role, sincerely and professionally
+3. Use plain text without Markdown, within 150 words"#;
+
+    const EVALUATION_PROMPT_EN: &'static str = r#"You are a senior interviewer. Evaluate the quality of the questions the candidate asked in the reverse interview.
+
+Dimensions (1-10):
+1. insightfulness: whether the questions show depth and thinking about the role and industry
+2. preparation: whether they show prior research into the company, team and JD
+3. relevance: whether they relate to the role's responsibilities and the candidate's growth
+
+Output format (JSON):
+{
+  "score": 7.5,
+  "insightfulness": 7.0,
+  "preparation": 8.0,
+  "relevance": 7.5,
+  "strengths": ["Asked about the team's technical challenges"],
+  "improvements": ["Could ask how the role is evaluated"],
+  "summary": "The candidate's questions were well targeted..."
+}"#;
+
+    /// Route a candidate question to the interviewer best placed to answer
+    ///
+    /// Matches the keywords of the interview language, both lists for mixed interviews
+    pub fn route(question: &str, language: Language) -> InterviewerRole {
+        let lower = question.to_lowercase();
+        let keywords: &[&[&str]] = match language {
+            Language::Zh => &[BUSINESS_KEYWORDS_ZH],
+            Language::En => &[BUSINESS_KEYWORDS_EN],
+            Language::Mixed => &[BUSINESS_KEYWORDS_ZH, BUSINESS_KEYWORDS_EN],
+        };
+        if keywords.iter().flat_map(|list| list.iter()).any(|k| lower.contains(k)) {
+            InterviewerRole::Business
+        } else {
+            InterviewerRole::HR
+        }
+    }
+
+    /// Answer a candidate question grounded in JD and company notes
+    pub async fn answer(
+        provider: &SiliconFlowProvider,
+        persona: &str,
+        question: &str,
+        context: &InterviewContext,
+    ) -> Result<String> {
+        let agent = provider
+            .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
+            .agent(&format!("{}\n\n{}", persona, context.language.prompt(Self::ANSWER_RULES, Self::ANSWER_RULES_EN)))
+            .build();
+
+        let company_notes = if context.company_notes.trim().is_empty() {
+            "（未提供）"
+        } else {
+            context.company_notes.as_str()
+        };
+
+        let prompt = format!(
+            "JD: {}\n\n公司资料: {}\n\n候选人的问题：{}\n\n请直接回答候选人。{}",
+            context.job_description,
+            company_notes,
+            question,
+            context.language.prompt_instruction()
+        );
+
+        agent.prompt(&prompt).await
+    }
+
+    /// Evaluate insightfulness, preparation and relevance of candidate questions
+    pub async fn evaluate(
+        provider: &SiliconFlowProvider,
+        turns: &[ReverseTurn],
+        context: &InterviewContext,
+    ) -> Result<CandidateQuestionEvaluation> {
+        let agent = provider
+            .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
+            .agent(context.language.prompt(Self::EVALUATION_PROMPT, Self::EVALUATION_PROMPT_EN))
+            .build();
+
+        let questions = turns
+            .iter()
+            .enumerate()
+            .map(|(i, t)| format!("{}. {}", i + 1, t.candidate_question))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let prompt = format!(
+            "JD: {}\n\n候选人提出的问题：\n{}\n\n请评估问题质量并输出JSON格式结果。{}",
+            context.job_description,
+            questions,
+            context.language.prompt_instruction()
+        );
+
+        let response = agent.prompt(&prompt).await?;
+        Self::parse_evaluation(&response)
+    }
+
+    /// Parse the evaluation JSON, tolerating text or code fences around it
+    ///
+    /// A response without a usable evaluation is an error rather than a
+    /// made-up score, so callers can report the questions as not evaluated.
+    fn parse_evaluation(response: &str) -> Result<CandidateQuestionEvaluation> {
+        let json = match (response.find('{'), response.rfind('}')) {
+            (Some(start), Some(end)) if start < end => &response[start..=end],
+            _ => bail!("Evaluation response contains no JSON object"),
+        };
+        let mut result: CandidateQuestionEvaluation =
+            serde_json::from_str(json).context("Failed to parse question evaluation")?;
+
+        for score in [
+            &mut result.score,
+            &mut result.insightfulness,
+            &mut result.preparation,
+            &mut result.relevance,
+        ] {
+            if !score.is_finite() {
+                bail!("Question evaluation contains an invalid score");
+            }
+            *score = score.clamp(0.0, 10.0);
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_evaluation() {
+        let response = "评估如下：\n```json\n{\"score\": 8.0, \"insightfulness\": 12.0, \"preparation\": 7.0, \"relevance\": 7.5, \
+                        \"strengths\": [\"关注团队挑战\"], \"improvements\": [], \"summary\": \"有针对性\"}\n```";
+        let result = ReverseInterview::parse_evaluation(response).unwrap();
+        assert_eq!((result.score, result.insightfulness), (8.0, 10.0));
+        assert_eq!(result.strengths, vec!["关注团队挑战".to_string()]);
+    }
+
+    #[test]
+    fn test_unparsable_evaluation_is_an_error() {
+        assert!(ReverseInterview::parse_evaluation("候选人表现不错").is_err());
+        assert!(ReverseInterview::parse_evaluation("{\"score\": 7.0}").is_err());
+    }
+
+    #[test]
+    fn test_route() {
+        assert_eq!(ReverseInterview::route("团队今年的业务重点是什么？", Language::Zh), InterviewerRole::Business);
+        assert_eq!(ReverseInterview::route("公司的商业模式怎么盈利？", Language::Zh), InterviewerRole::Business);
+        assert_eq!(ReverseInterview::route("公司的晋升机制是怎样的？", Language::Zh), InterviewerRole::HR);
+        assert_eq!(ReverseInterview::route("What is the product roadmap?", Language::En), InterviewerRole::Business);
+        assert_eq!(ReverseInterview::route("How is performance reviewed?", Language::En), InterviewerRole::HR);
+        assert_eq!(ReverseInterview::route("团队的 KPI 怎么定？", Language::Mixed), InterviewerRole::Business);
+    }
+}
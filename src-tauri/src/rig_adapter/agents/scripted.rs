@@ -0,0 +1,178 @@
+// Scripted interviewer agent for rehearsing a fixed question list
+
+use super::{InterviewerAgent, InterviewerRole, InterviewContext, AnalysisResult};
+use async_trait::async_trait;
+use anyhow::{Context, Result, anyhow};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Interview script loaded from a JSON file
+///
+/// ```json
+/// {
+///   "title": "后端一面",
+///   "questions": [
+///     { "role": "Technical", "question": "介绍一下Rust的所有权机制", "question_id": 12 },
+///     { "role": "HR", "question": "为什么离职？", "analysis": { "score": 7.0, "strengths": [], "improvements": [], "summary": "..." } }
+///   ]
+/// }
+/// ```
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct InterviewScript {
+    #[serde(default)]
+    pub title: Option<String>,
+    pub questions: Vec<ScriptedQuestion>,
+}
+
+/// Single scripted question with optional canned analysis
+///
+/// `question_id` links the question to a question bank entry, so answering it
+/// counts as a review of that entry.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScriptedQuestion {
+    pub role: InterviewerRole,
+    pub question: String,
+    #[serde(default)]
+    pub question_id: Option<i64>,
+    #[serde(default)]
+    pub analysis: Option<AnalysisResult>,
+}
+
+impl InterviewScript {
+    /// Load and validate a script file
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)
+            .with_context(|| format!("Failed to read interview script {:?}", path))?;
+        Self::parse(&content)
+    }
+
+    /// Parse and validate script JSON
+    pub fn parse(content: &str) -> Result<Self> {
+        let script: InterviewScript = serde_json::from_str(content)
+            .context("Invalid interview script format")?;
+
+        if script.questions.is_empty() {
+            return Err(anyhow!("Interview script contains no questions"));
+        }
+        if script.questions.iter().any(|q| q.question.trim().is_empty()) {
+            return Err(anyhow!("Interview script contains an empty question"));
+        }
+
+        Ok(script)
+    }
+}
+
+/// Interviewer that asks the scripted questions in script order
+///
+/// One agent plays every role of the script: its role is the role of the
+/// question it asked last, so turns are attributed to the scripted role
+/// whichever phase the state machine is in.
+pub struct ScriptedInterviewer {
+    questions: Vec<ScriptedQuestion>,
+    cursor: Mutex<usize>,
+}
+
+impl ScriptedInterviewer {
+    pub fn new(questions: Vec<ScriptedQuestion>) -> Self {
+        Self {
+            questions,
+            cursor: Mutex::new(0),
+        }
+    }
+
+    /// Create the agents rehearsing a script, a single cursor over all its questions
+    pub fn agents_from_script(script: &InterviewScript) -> Vec<Box<dyn InterviewerAgent>> {
+        vec![Box::new(ScriptedInterviewer::new(script.questions.clone()))]
+    }
+
+    /// Number of scripted questions not asked yet
+    pub fn remaining(&self) -> usize {
+        let cursor = *self.cursor.lock().unwrap();
+        self.questions.len().saturating_sub(cursor)
+    }
+
+    /// Question asked last, or the first one before anything was asked
+    fn current(&self) -> &ScriptedQuestion {
+        let cursor = *self.cursor.lock().unwrap();
+        &self.questions[cursor.saturating_sub(1).min(self.questions.len() - 1)]
+    }
+}
+
+#[async_trait]
+impl InterviewerAgent for ScriptedInterviewer {
+    fn role(&self) -> InterviewerRole {
+        self.current().role
+    }
+
+    fn role_name(&self) -> &'static str {
+        self.role().default_name()
+    }
+
+    fn avatar(&self) -> &'static str {
+        self.role().default_avatar()
+    }
+
+    fn has_more_questions(&self) -> bool {
+        self.remaining() > 0
+    }
+
+    fn question_id(&self) -> Option<i64> {
+        self.current().question_id
+    }
+
+    async fn generate_question(&self, _context: &InterviewContext) -> Result<String> {
+        let mut cursor = self.cursor.lock().unwrap();
+        let question = self.questions
+            .get(*cursor)
+            .ok_or_else(|| anyhow!("Interview script has no more questions"))?;
+        *cursor += 1;
+        Ok(question.question.clone())
+    }
+
+    async fn analyze_answer(
+        &self,
+        _question: &str,
+        _answer: &str,
+        context: &InterviewContext,
+    ) -> Result<AnalysisResult> {
+        // Neutral result when the script has no analysis for this question
+        Ok(self.current().analysis.clone().unwrap_or_else(|| AnalysisResult {
+            score: 7.0,
+            strengths: vec![],
+            improvements: vec![],
+            summary: context.language.text("脚本未提供该题的评价。", "The script provides no evaluation for this question."),
+            criterion_scores: Vec::new(),
+        }))
+    }
+
+    async fn should_follow_up(&self, _answer: &str, _analysis: &AnalysisResult) -> bool {
+        // Scripts define the full question list, never improvise follow-ups
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_script() {
+        let path = std::env::temp_dir().join(format!("interview-script-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{ "questions": [{ "role": "HR", "question": "请做一下自我介绍" }] }"#).unwrap();
+        let script = InterviewScript::load(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        let script = script.unwrap();
+        assert!(script.title.is_none());
+        assert_eq!(script.questions[0].role, InterviewerRole::HR);
+    }
+
+    #[test]
+    fn test_invalid_scripts_are_rejected() {
+        assert!(InterviewScript::parse(r#"{ "questions": [] }"#).is_err());
+        assert!(InterviewScript::parse(r#"{ "questions": [{ "role": "HR", "question": " " }] }"#).is_err());
+        assert!(InterviewScript::parse("not json").is_err());
+    }
+}
@@ -2,6 +2,7 @@
 
 use super::{InterviewerAgent, InterviewerRole, InterviewContext, AnalysisResult};
 use crate::rig_adapter::{SiliconFlowProvider, VectorStoreAdapter};
+use crate::analysis::RubricEngine;
 use async_trait::async_trait;
 use anyhow::Result;
 
@@ -43,6 +44,37 @@ impl TechInterviewer {
   "summary": "候选人对该技术有扎实理解..."
 }"#;
 
+    const SYSTEM_PROMPT_EN: &'static str = r#"You are a senior technical interviewer with over ten years of engineering management experience.
+
+Focus areas:
+- Technical depth: understanding of core principles
+- Problem solving: analyzing problems and designing solutions
+- System design: architectural thinking and technology choices
+- Code quality: coding standards and best practices
+
+Questioning style:
+- Start from fundamentals and dig into underlying principles
+- Probe implementation details and edge cases
+- Ground questions in real scenarios
+
+Tone: professional, rigorous and in-depth"#;
+
+    const ANALYSIS_PROMPT_EN: &'static str = r#"Analyze the quality of the candidate's answer.
+
+Dimensions:
+1. Technical accuracy: whether the answer is correct
+2. Depth and breadth: how deep and how complete the understanding is
+3. Logical expression: whether the answer is clear and well structured
+4. Practical experience: whether real project experience backs it up
+
+Output format (JSON):
+{
+  "score": 8.5,
+  "strengths": ["Deep technical understanding", "Hands-on experience"],
+  "improvements": ["Could explain XXX in more detail"],
+  "summary": "The candidate has a solid grasp of the technology..."
+}"#;
+
     pub fn new(provider: SiliconFlowProvider, vector_store: VectorStoreAdapter) -> Self {
         Self {
             provider,
@@ -80,7 +112,7 @@ impl InterviewerAgent for TechInterviewer {
         // Build prompt
         let agent = self.provider
             .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
-            .agent(Self::SYSTEM_PROMPT)
+            .agent(context.language.prompt(Self::SYSTEM_PROMPT, Self::SYSTEM_PROMPT_EN))
             .build();
         
         let prompt = format!(
@@ -95,10 +127,12 @@ JD: {}
 要求：
 1. 只输出问题本身，不要包含任何引导说明、评估标准或内部提示
 2. 使用纯文本格式，禁止使用Markdown（如**加粗**、#标题等）
-3. 直接以面试官口吴提问，简洁自然"#,
+3. 直接以面试官口吴提问，简洁自然
+4. {}"#,
             context.job_description,
             context.resume,
-            rag_questions
+            rag_questions,
+            context.language.prompt_instruction()
         );
         
         agent.prompt(&prompt).await
@@ -108,32 +142,38 @@ JD: {}
         &self,
         question: &str,
         answer: &str,
-        _context: &InterviewContext,
+        context: &InterviewContext,
     ) -> Result<AnalysisResult> {
+        let rubric = context.rubric_for(self.role(), question);
         let agent = self.provider
             .completion_model("Pro/Qwen/Qwen2.5-7B-Instruct")
-            .agent(Self::ANALYSIS_PROMPT)
+            .agent(context.language.prompt(Self::ANALYSIS_PROMPT, Self::ANALYSIS_PROMPT_EN))
             .build();
         
         let prompt = format!(
-            "问题：{}\n\n候选人回答：{}\n\n请分析回答质量并输出JSON格式结果。",
+            "问题：{}\n\n候选人回答：{}\n\n{}\n\n请分析回答质量并输出JSON格式结果。{}",
             question,
-            answer
+            answer,
+            RubricEngine::render_prompt(&rubric, context.language),
+            context.language.prompt_instruction()
         );
         
         let response = agent.prompt(&prompt).await?;
         
         // Parse JSON response
-        let result: AnalysisResult = serde_json::from_str(&response)
+        let mut result: AnalysisResult = serde_json::from_str(&response)
             .unwrap_or_else(|_| {
                 // Fallback if JSON parsing fails
+                let language = context.language;
                 AnalysisResult {
                     score: 7.0,
-                    strengths: vec!["回答完整".to_string()],
-                    improvements: vec!["可以更详细展开".to_string()],
-                    summary: "回答基本到位，有改进空间。".to_string(),
+                    strengths: vec![language.text("回答完整", "Complete answer")],
+                    improvements: vec![language.text("可以更详细展开", "Could be elaborated in more detail")],
+                    summary: language.text("回答基本到位，有改进空间。", "The answer covers the basics, with room for improvement."),
+                    criterion_scores: Vec::new(),
                 }
             });
+        result.apply_rubric(&rubric, answer);
         
         Ok(result)
     }
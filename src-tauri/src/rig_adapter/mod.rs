@@ -6,9 +6,11 @@ pub mod vector_store;
 pub mod agents;
 pub mod scheduler;
 pub mod state_machine;
+pub mod session;
 
 pub use provider::SiliconFlowProvider;
 pub use vector_store::VectorStoreAdapter;
 pub use agents::{InterviewContext, ConversationTurn, AnalysisResult};
 pub use scheduler::{AgentScheduler, RotationStrategy};
-pub use state_machine::{InterviewStateMachine, InterviewPhase, InterviewProgress};
+pub use state_machine::{InterviewStateMachine, InterviewPhase, InterviewProgress, TimingConfig};
+pub use session::MultiAgentSession;
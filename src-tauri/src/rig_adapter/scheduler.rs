@@ -1,7 +1,9 @@
 // Agent scheduler for multi-agent interview rotation
 
 use super::agents::{InterviewerAgent, InterviewerRole, InterviewContext, ConversationTurn, AnalysisResult};
-use super::state_machine::InterviewPhase;
+use super::agents::{ReverseInterview, ReverseTurn, CandidateQuestionEvaluation};
+use super::state_machine::{InterviewPhase, TimingConfig};
+use chrono::{DateTime, Utc};
 use anyhow::{Result, anyhow};
 
 /// Agent scheduler
@@ -45,6 +47,11 @@ impl AgentScheduler {
         self.agents[self.current_index].as_ref()
     }
     
+    /// Whether the current agent has another question, false once a script is used up
+    pub fn has_more_questions(&self) -> bool {
+        self.current_agent().has_more_questions()
+    }
+    
     /// Switch to next agent
     #[allow(dead_code)]
     pub fn next_agent(&mut self) -> &dyn InterviewerAgent {
@@ -82,6 +89,50 @@ impl AgentScheduler {
         self.current_agent()
     }
     
+    /// Select agent by role, keeping the current agent if no agent has that role
+    pub fn select_by_role(&mut self, role: InterviewerRole) -> &dyn InterviewerAgent {
+        if let Some(index) = self.agents.iter().position(|a| a.role() == role) {
+            self.current_index = index;
+        }
+        self.current_agent()
+    }
+    
+    /// Answer a candidate question (reverse interview)
+    ///
+    /// Routes the question to the HR or business interviewer and records the turn
+    pub async fn answer_candidate_question(
+        &mut self,
+        context: &mut InterviewContext,
+        question: String,
+    ) -> Result<ReverseTurn> {
+        let agent = self.select_by_role(ReverseInterview::route(&question, context.language));
+        
+        let answer = agent.answer_candidate_question(&question, context).await?;
+        
+        let turn = ReverseTurn {
+            role: agent.role(),
+            role_name: agent.role().display_name(context.language),
+            candidate_question: question,
+            answer,
+        };
+        
+        context.reverse_turns.push(turn.clone());
+        Ok(turn)
+    }
+    
+    /// Evaluate the questions asked during the reverse interview
+    pub async fn evaluate_candidate_questions(
+        &mut self,
+        context: &InterviewContext,
+    ) -> Result<CandidateQuestionEvaluation> {
+        if context.reverse_turns.is_empty() {
+            return Err(anyhow!("Candidate has not asked any questions"));
+        }
+        
+        let agent = self.select_by_role(InterviewerRole::HR);
+        agent.evaluate_candidate_questions(&context.reverse_turns, context).await
+    }
+    
     /// Execute one interview turn (generate question)
     pub async fn execute_turn(
         &mut self,
@@ -95,10 +146,16 @@ impl AgentScheduler {
         // Create conversation turn (waiting for user answer)
         let turn = ConversationTurn {
             role: agent.role(),
-            role_name: agent.role_name().to_string(),
+            role_name: agent.role().display_name(context.language),
+            phase: context.current_phase,
             question,
+            question_id: agent.question_id(),
             answer: None,
             analysis: None,
+            asked_at: Utc::now().to_rfc3339(),
+            answered_at: None,
+            latency_secs: None,
+            overtime_penalty: 0.0,
         };
         
         context.conversation_history.push(turn.clone());
@@ -106,31 +163,53 @@ impl AgentScheduler {
     }
     
     /// Process user answer
+    ///
+    /// Records answer timing and deducts the overtime penalty from the score
     pub async fn process_answer(
         &self,
         context: &mut InterviewContext,
         answer: String,
+        timing: &TimingConfig,
     ) -> Result<AnalysisResult> {
         let agent = self.current_agent();
+        let answered_at = Utc::now();
         
         // Extract question and record answer
-        let question = {
+        let (question, latency_secs) = {
             let last_turn = context.conversation_history.last_mut()
                 .ok_or_else(|| anyhow!("No conversation turn"))?;
+            let latency_secs = DateTime::parse_from_rfc3339(&last_turn.asked_at)
+                .map(|asked_at| (answered_at - asked_at.with_timezone(&Utc)).num_milliseconds().max(0) as f32 / 1000.0)
+                .ok();
             last_turn.answer = Some(answer.clone());
-            last_turn.question.clone()
+            last_turn.answered_at = Some(answered_at.to_rfc3339());
+            last_turn.latency_secs = latency_secs;
+            (last_turn.question.clone(), latency_secs)
         };
         
         // Analyze answer (no mutable borrow here)
-        let analysis = agent.analyze_answer(
+        let mut analysis = agent.analyze_answer(
             &question,
             &answer,
             context,
         ).await?;
         
+        let penalty = latency_secs.map(|l| timing.overtime_penalty(l)).unwrap_or(0.0);
+        if penalty > 0.0 {
+            analysis.score = (analysis.score - penalty).max(1.0);
+            analysis.improvements.push(context.language.text(
+                &format!("回答用时超出{}秒的建议时长，已扣{:.1}分", timing.answer_budget_secs, penalty),
+                &format!(
+                    "Answer took longer than the suggested {} seconds, {:.1} points deducted",
+                    timing.answer_budget_secs, penalty
+                ),
+            ));
+        }
+        
         // Update analysis
         if let Some(last_turn) = context.conversation_history.last_mut() {
             last_turn.analysis = Some(analysis.clone());
+            last_turn.overtime_penalty = penalty;
         }
         
         Ok(analysis)
@@ -147,3 +226,202 @@ impl AgentScheduler {
         agent.should_follow_up(answer, analysis).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::language::Language;
+    use crate::rig_adapter::agents::{InterviewScript, MockInterviewer, ScriptedInterviewer};
+    use crate::rig_adapter::InterviewStateMachine;
+
+    /// Upper bound of turns, guards against a state machine that never completes
+    const MAX_TURNS: usize = 50;
+
+    fn interview_context() -> InterviewContext {
+        InterviewContext {
+            resume: "Rust 后端工程师，5年经验".to_string(),
+            job_description: "负责分布式存储系统开发".to_string(),
+            structured_jd: Default::default(),
+            company_notes: String::new(),
+            conversation_history: Vec::new(),
+            reverse_turns: Vec::new(),
+            current_phase: InterviewPhase::WarmUp,
+            language: Language::Zh,
+            rubrics: Vec::new(),
+        }
+    }
+
+    fn mock_agents(score: f32) -> Vec<Box<dyn InterviewerAgent>> {
+        [InterviewerRole::Technical, InterviewerRole::HR, InterviewerRole::Business]
+            .into_iter()
+            .map(|role| Box::new(MockInterviewer::new(role).with_scores(vec![score])) as Box<dyn InterviewerAgent>)
+            .collect()
+    }
+
+    /// Drive a session the way the Tauri commands do until the state machine completes
+    async fn run_session(
+        scheduler: &mut AgentScheduler,
+        strategy: &RotationStrategy,
+        state_machine: &mut InterviewStateMachine,
+        context: &mut InterviewContext,
+    ) -> usize {
+        let mut turns = 0;
+
+        while state_machine.current_phase() != InterviewPhase::Completed {
+            assert!(turns < MAX_TURNS, "session did not complete");
+
+            context.current_phase = state_machine.current_phase();
+            match strategy {
+                RotationStrategy::PhaseBased => {
+                    scheduler.select_by_phase(context.current_phase);
+                }
+                _ if turns > 0 => {
+                    scheduler.next_agent();
+                }
+                _ => {}
+            }
+            if !scheduler.has_more_questions() {
+                state_machine.complete();
+                break;
+            }
+
+            scheduler.execute_turn(context).await.unwrap();
+            let analysis = scheduler
+                .process_answer(context, "我会先分析瓶颈，再给出方案。".to_string(), state_machine.timing())
+                .await
+                .unwrap();
+
+            state_machine.record_question();
+            state_machine.maybe_advance(&analysis);
+            turns += 1;
+        }
+
+        turns
+    }
+
+    #[tokio::test]
+    async fn test_sessions_complete_with_every_strategy() {
+        for strategy in [RotationStrategy::FixedOrder, RotationStrategy::PhaseBased, RotationStrategy::Random] {
+            let mut scheduler = AgentScheduler::new(mock_agents(7.0)).with_strategy(strategy.clone());
+            let mut state_machine = InterviewStateMachine::new();
+            let mut context = interview_context();
+
+            let turns = run_session(&mut scheduler, &strategy, &mut state_machine, &mut context).await;
+
+            // Scores below 8 never advance early, every phase runs to its maximum
+            assert_eq!(turns, 15, "{:?}", strategy);
+            assert!(state_machine.progress().is_completed);
+            assert_eq!(context.conversation_history.len(), turns);
+            for turn in &context.conversation_history {
+                assert!(turn.answered_at.is_some());
+                assert_eq!(turn.analysis.as_ref().unwrap().score, 7.0);
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_rotation_strategies_pick_roles() {
+        let strategy = RotationStrategy::PhaseBased;
+        let mut scheduler = AgentScheduler::new(mock_agents(7.0)).with_strategy(strategy.clone());
+        let mut state_machine = InterviewStateMachine::new();
+        let mut context = interview_context();
+        run_session(&mut scheduler, &strategy, &mut state_machine, &mut context).await;
+        for turn in &context.conversation_history {
+            let expected = match turn.phase {
+                InterviewPhase::Technical => InterviewerRole::Technical,
+                InterviewPhase::Business => InterviewerRole::Business,
+                _ => InterviewerRole::HR,
+            };
+            assert_eq!(turn.role, expected, "{}", turn.question);
+        }
+
+        let strategy = RotationStrategy::FixedOrder;
+        let mut scheduler = AgentScheduler::new(mock_agents(7.0)).with_strategy(strategy.clone());
+        let mut state_machine = InterviewStateMachine::new();
+        let mut context = interview_context();
+        run_session(&mut scheduler, &strategy, &mut state_machine, &mut context).await;
+        let expected = [InterviewerRole::Technical, InterviewerRole::HR, InterviewerRole::Business];
+        for (index, turn) in context.conversation_history.iter().enumerate() {
+            assert_eq!(turn.role, expected[index % expected.len()]);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_high_scores_advance_phases_at_minimum() {
+        let strategy = RotationStrategy::PhaseBased;
+        let mut scheduler = AgentScheduler::new(mock_agents(9.0)).with_strategy(strategy.clone());
+        let mut state_machine = InterviewStateMachine::new();
+        let mut context = interview_context();
+
+        // Sum of per-phase minimum question counts
+        assert_eq!(run_session(&mut scheduler, &strategy, &mut state_machine, &mut context).await, 9);
+    }
+
+    #[tokio::test]
+    async fn test_mock_follow_up_and_question_limit() {
+        let mock = MockInterviewer::new(InterviewerRole::Technical)
+            .with_scores(vec![5.0, 9.0])
+            .with_follow_up_below(6.0)
+            .with_max_questions(1);
+        let mut context = interview_context();
+        let timing = TimingConfig::default();
+
+        let mut scheduler = AgentScheduler::new(vec![Box::new(mock)]);
+        scheduler.execute_turn(&mut context).await.unwrap();
+        let analysis = scheduler.process_answer(&mut context, "不太清楚".to_string(), &timing).await.unwrap();
+        assert!(scheduler.should_follow_up("不太清楚", &analysis).await);
+        assert!(scheduler.execute_turn(&mut context).await.is_err());
+
+        let mock = MockInterviewer::new(InterviewerRole::HR);
+        let analysis = mock.analyze_answer("q", "a", &context).await.unwrap();
+        assert!(!mock.should_follow_up("a", &analysis).await);
+        assert_eq!((mock.asked_count(), mock.analyzed_count()), (0, 1));
+    }
+
+    #[tokio::test]
+    async fn test_script_keeps_order_across_roles_and_finishes() {
+        let script = InterviewScript::parse(
+            r#"{
+                "title": "rehearsal",
+                "questions": [
+                    { "role": "HR", "question": "请做一下自我介绍" },
+                    { "role": "Technical", "question": "讲讲所有权", "analysis": {
+                        "score": 9.0, "strengths": ["概念准确"], "improvements": [], "summary": "很好"
+                    } },
+                    { "role": "HR", "question": "为什么离职？" }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let strategy = RotationStrategy::PhaseBased;
+        let mut scheduler = AgentScheduler::new(ScriptedInterviewer::agents_from_script(&script))
+            .with_strategy(strategy.clone());
+        let mut state_machine = InterviewStateMachine::new();
+        let mut context = interview_context();
+
+        let turns = run_session(&mut scheduler, &strategy, &mut state_machine, &mut context).await;
+        assert_eq!(turns, 3);
+        assert!(state_machine.progress().is_completed);
+
+        let asked: Vec<(&str, InterviewerRole)> = context
+            .conversation_history
+            .iter()
+            .map(|turn| (turn.question.as_str(), turn.role))
+            .collect();
+        assert_eq!(
+            asked,
+            vec![
+                ("请做一下自我介绍", InterviewerRole::HR),
+                ("讲讲所有权", InterviewerRole::Technical),
+                ("为什么离职？", InterviewerRole::HR),
+            ]
+        );
+        let scores: Vec<f32> = context
+            .conversation_history
+            .iter()
+            .map(|turn| turn.analysis.as_ref().unwrap().score)
+            .collect();
+        assert_eq!(scores, vec![7.0, 9.0, 7.0]);
+    }
+}
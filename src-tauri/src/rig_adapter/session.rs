@@ -0,0 +1,138 @@
+// Multi-agent interview session: agents, shared context and phase state
+
+use super::agents::{
+    BusinessInterviewer, CandidateQuestionEvaluation, HRInterviewer, InterviewScript, InterviewerAgent,
+    ScriptedInterviewer, TechInterviewer,
+};
+use super::{
+    AgentScheduler, AnalysisResult, ConversationTurn, InterviewContext, InterviewPhase, InterviewStateMachine,
+    RotationStrategy, SiliconFlowProvider, TimingConfig, VectorStoreAdapter,
+};
+use crate::analysis::JobDescriptionParser;
+use crate::language::Language;
+use anyhow::Result;
+
+/// Multi-Agent interview session
+pub struct MultiAgentSession {
+    pub context: InterviewContext,
+    pub scheduler: AgentScheduler,
+    pub state_machine: InterviewStateMachine,
+    pub question_evaluation: Option<CandidateQuestionEvaluation>,
+}
+
+impl MultiAgentSession {
+    pub fn new(
+        resume: String,
+        job_description: String,
+        company_notes: String,
+        timing: TimingConfig,
+        language: Language,
+        provider: SiliconFlowProvider,
+        vector_store: VectorStoreAdapter,
+    ) -> Self {
+        // Create agents
+        let tech = Box::new(TechInterviewer::new(provider.clone(), vector_store)) as Box<dyn InterviewerAgent>;
+        let hr = Box::new(HRInterviewer::new(provider.clone())) as Box<dyn InterviewerAgent>;
+        let business = Box::new(BusinessInterviewer::new(provider)) as Box<dyn InterviewerAgent>;
+
+        Self::with_agents(vec![tech, hr, business], resume, job_description, company_notes, timing, language)
+    }
+
+    /// Create session with a given set of agents (e.g. scripted interviewers)
+    pub fn with_agents(
+        agents: Vec<Box<dyn InterviewerAgent>>,
+        resume: String,
+        job_description: String,
+        company_notes: String,
+        timing: TimingConfig,
+        language: Language,
+    ) -> Self {
+        let scheduler = AgentScheduler::new(agents).with_strategy(RotationStrategy::PhaseBased);
+        let state_machine = InterviewStateMachine::new().with_timing(timing);
+
+        let structured_jd = JobDescriptionParser::parse(&job_description);
+        let context = InterviewContext {
+            resume,
+            job_description,
+            structured_jd,
+            company_notes,
+            conversation_history: Vec::new(),
+            reverse_turns: Vec::new(),
+            current_phase: InterviewPhase::WarmUp,
+            language,
+            rubrics: Vec::new(),
+        };
+
+        Self {
+            context,
+            scheduler,
+            state_machine,
+            question_evaluation: None,
+        }
+    }
+
+    /// Create session rehearsing a script, phases sized to ask every scripted question
+    pub fn scripted(
+        script: &InterviewScript,
+        resume: String,
+        job_description: String,
+        timing: TimingConfig,
+        language: Language,
+    ) -> Self {
+        let mut session = Self::with_agents(
+            ScriptedInterviewer::agents_from_script(script),
+            resume,
+            job_description,
+            String::new(),
+            timing,
+            language,
+        );
+        session.state_machine = std::mem::take(&mut session.state_machine)
+            .with_question_count(script.questions.len() as u32);
+        session
+    }
+
+    /// Ask the next question
+    ///
+    /// Advances phases whose time budget ran out first.
+    ///
+    /// # Returns
+    /// * `Ok(Some(ConversationTurn))` - Turn with the new question
+    /// * `Ok(None)` - The interview is over
+    pub async fn next_question(&mut self) -> Result<Option<ConversationTurn>> {
+        if let Some(new_phase) = self.state_machine.check_timeout() {
+            log::info!("Time budget exhausted, phase advanced to {:?}", new_phase);
+        }
+        if self.state_machine.current_phase() == InterviewPhase::Completed {
+            return Ok(None);
+        }
+
+        // Select agent by current phase
+        self.context.current_phase = self.state_machine.current_phase();
+        self.scheduler.select_by_phase(self.context.current_phase);
+        if !self.scheduler.has_more_questions() {
+            self.state_machine.complete();
+            return Ok(None);
+        }
+
+        self.scheduler.execute_turn(&mut self.context).await.map(Some)
+    }
+
+    /// Analyze the answer to the last question and update the phase
+    pub async fn submit_answer(&mut self, answer: String) -> Result<AnalysisResult> {
+        let analysis = self.scheduler
+            .process_answer(&mut self.context, answer, self.state_machine.timing())
+            .await?;
+
+        self.state_machine.record_question();
+        if self.state_machine.maybe_advance(&analysis).is_some() {
+            log::info!("Phase advanced to {:?}", self.state_machine.current_phase());
+        }
+        if !self.scheduler.has_more_questions() {
+            // A rehearsed script ends the interview with its last question
+            self.state_machine.complete();
+        }
+
+        Ok(analysis)
+    }
+}
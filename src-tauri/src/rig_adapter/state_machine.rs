@@ -1,10 +1,12 @@
 // Interview phase state machine
 
+use chrono::{DateTime, Duration, Utc};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use super::agents::{InterviewerRole, AnalysisResult};
 
 /// Interview phase enum
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum InterviewPhase {
     WarmUp,      // Warm-up (1-2 questions)
     Technical,   // Technical assessment (3-5 questions)
@@ -22,6 +24,46 @@ pub struct PhaseConfig {
     pub min_questions: u32,
     pub max_questions: u32,
     pub primary_role: InterviewerRole,
+    pub time_budget_secs: u64,
+}
+
+/// Time budgets and overtime penalty settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TimingConfig {
+    /// Overall interview budget, the interview completes when exceeded
+    pub overall_budget_secs: u64,
+    /// Per-phase budget overrides, defaults come from the phase configs
+    pub phase_budgets: HashMap<InterviewPhase, u64>,
+    /// Time allowed for a single answer before overtime starts
+    pub answer_budget_secs: u64,
+    /// Score deducted per minute of answer overtime
+    pub penalty_per_overtime_minute: f32,
+    /// Upper bound of the overtime deduction for one answer
+    pub max_overtime_penalty: f32,
+}
+
+impl Default for TimingConfig {
+    fn default() -> Self {
+        Self {
+            overall_budget_secs: 3600,
+            phase_budgets: HashMap::new(),
+            answer_budget_secs: 180,
+            penalty_per_overtime_minute: 0.5,
+            max_overtime_penalty: 2.0,
+        }
+    }
+}
+
+impl TimingConfig {
+    /// Score penalty for an answer that took `latency_secs`
+    pub fn overtime_penalty(&self, latency_secs: f32) -> f32 {
+        let overtime = latency_secs - self.answer_budget_secs as f32;
+        if overtime <= 0.0 {
+            return 0.0;
+        }
+        (overtime / 60.0 * self.penalty_per_overtime_minute).min(self.max_overtime_penalty)
+    }
 }
 
 /// Interview state machine
@@ -30,17 +72,71 @@ pub struct InterviewStateMachine {
     phase_question_count: u32,
     total_question_count: u32,
     phase_configs: Vec<PhaseConfig>,
+    timing: TimingConfig,
+    started_at: DateTime<Utc>,
+    phase_started_at: DateTime<Utc>,
 }
 
 impl InterviewStateMachine {
     /// Create new state machine
     pub fn new() -> Self {
+        let now = Utc::now();
         Self {
             current_phase: InterviewPhase::WarmUp,
             phase_question_count: 0,
             total_question_count: 0,
             phase_configs: Self::default_configs(),
+            timing: TimingConfig::default(),
+            started_at: now,
+            phase_started_at: now,
+        }
+    }
+    
+    /// Set time budgets
+    pub fn with_timing(mut self, timing: TimingConfig) -> Self {
+        self.timing = timing;
+        self
+    }
+    
+    /// Size the questioning phases so exactly `total` questions are asked
+    ///
+    /// Used for scripts: every phase up to the business round asks a fixed number of
+    /// questions, shared in proportion to the default maximums, so good answers
+    /// cannot end a phase early and long scripts are not cut off.
+    pub fn with_question_count(mut self, total: u32) -> Self {
+        let asking: Vec<usize> = self.phase_configs
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.phase != InterviewPhase::Questions)
+            .map(|(i, _)| i)
+            .collect();
+        let weight_sum: u32 = asking.iter().map(|&i| self.phase_configs[i].max_questions).sum();
+
+        // Every phase asks one question while the script lasts, the rest goes by weight
+        let mut shares: Vec<u32> = (0..asking.len() as u32).map(|i| u32::from(i < total)).collect();
+        let extra = total.saturating_sub(asking.len() as u32);
+        let mut remainders = Vec::with_capacity(asking.len());
+        for (slot, &i) in asking.iter().enumerate() {
+            let weighted = extra * self.phase_configs[i].max_questions;
+            shares[slot] += weighted / weight_sum;
+            remainders.push((weighted % weight_sum, slot));
         }
+        let assigned: u32 = shares.iter().sum();
+        remainders.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+        for &(_, slot) in remainders.iter().take((total - assigned) as usize) {
+            shares[slot] += 1;
+        }
+
+        for (&i, share) in asking.iter().zip(shares) {
+            self.phase_configs[i].min_questions = share;
+            self.phase_configs[i].max_questions = share;
+        }
+        self
+    }
+
+    /// Get time budgets
+    pub fn timing(&self) -> &TimingConfig {
+        &self.timing
     }
     
     /// Get default phase configurations
@@ -51,30 +147,35 @@ impl InterviewStateMachine {
                 min_questions: 1,
                 max_questions: 2,
                 primary_role: InterviewerRole::HR,
+                time_budget_secs: 300,
             },
             PhaseConfig {
                 phase: InterviewPhase::Technical,
                 min_questions: 3,
                 max_questions: 5,
                 primary_role: InterviewerRole::Technical,
+                time_budget_secs: 1200,
             },
             PhaseConfig {
                 phase: InterviewPhase::Behavioral,
                 min_questions: 2,
                 max_questions: 3,
                 primary_role: InterviewerRole::HR,
+                time_budget_secs: 600,
             },
             PhaseConfig {
                 phase: InterviewPhase::Business,
                 min_questions: 2,
                 max_questions: 3,
                 primary_role: InterviewerRole::Business,
+                time_budget_secs: 600,
             },
             PhaseConfig {
                 phase: InterviewPhase::Questions,
                 min_questions: 1,
                 max_questions: 2,
                 primary_role: InterviewerRole::HR,
+                time_budget_secs: 300,
             },
         ]
     }
@@ -115,9 +216,61 @@ impl InterviewStateMachine {
         None
     }
     
+    /// Advance phase or complete the interview when a time budget is exhausted
+    pub fn check_timeout(&mut self) -> Option<InterviewPhase> {
+        self.check_timeout_at(Utc::now())
+    }
+    
+    /// Timeout check against an explicit clock
+    ///
+    /// Advances through every phase whose budget ran out, so a long pause can skip
+    /// several phases. Returns the phase the interview ends up in.
+    pub fn check_timeout_at(&mut self, now: DateTime<Utc>) -> Option<InterviewPhase> {
+        if self.current_phase == InterviewPhase::Completed {
+            return None;
+        }
+        
+        if elapsed_secs(self.started_at, now) >= self.timing.overall_budget_secs {
+            self.phase_question_count = 0;
+            self.current_phase = InterviewPhase::Completed;
+            self.phase_started_at = now;
+            return Some(InterviewPhase::Completed);
+        }
+        
+        let mut advanced = None;
+        while let Some(budget) = self.phase_budget_secs(self.current_phase) {
+            if elapsed_secs(self.phase_started_at, now) < budget {
+                break;
+            }
+            // The next phase starts when the budget of this one ran out
+            let exhausted_at = self.phase_started_at + Duration::seconds(budget as i64);
+            match self.advance_phase_at(exhausted_at) {
+                Some(phase) => advanced = Some(phase),
+                None => break,
+            }
+        }
+        
+        advanced
+    }
+    
+    /// Time budget of a phase, honoring configured overrides
+    pub fn phase_budget_secs(&self, phase: InterviewPhase) -> Option<u64> {
+        self.timing.phase_budgets.get(&phase).copied().or_else(|| {
+            self.phase_configs
+                .iter()
+                .find(|c| c.phase == phase)
+                .map(|c| c.time_budget_secs)
+        })
+    }
+    
     /// Advance to next phase
     fn advance_phase(&mut self) -> Option<InterviewPhase> {
+        self.advance_phase_at(Utc::now())
+    }
+    
+    fn advance_phase_at(&mut self, now: DateTime<Utc>) -> Option<InterviewPhase> {
         self.phase_question_count = 0;
+        self.phase_started_at = now;
         
         let next_phase = match self.current_phase {
             InterviewPhase::WarmUp => InterviewPhase::Technical,
@@ -132,13 +285,34 @@ impl InterviewStateMachine {
         Some(next_phase)
     }
     
+    /// Jump straight to the reverse-interview phase
+    pub fn skip_to_questions(&mut self) {
+        self.phase_question_count = 0;
+        self.current_phase = InterviewPhase::Questions;
+        self.phase_started_at = Utc::now();
+    }
+    
+    /// End the interview, e.g. when a scripted question list is used up
+    pub fn complete(&mut self) {
+        self.phase_question_count = 0;
+        self.current_phase = InterviewPhase::Completed;
+        self.phase_started_at = Utc::now();
+    }
+    
     /// Get progress information
     pub fn progress(&self) -> InterviewProgress {
+        let now = Utc::now();
+        let total_elapsed_secs = elapsed_secs(self.started_at, now);
         InterviewProgress {
             current_phase: self.current_phase,
             phase_question_count: self.phase_question_count,
             total_question_count: self.total_question_count,
             is_completed: self.current_phase == InterviewPhase::Completed,
+            phase_elapsed_secs: elapsed_secs(self.phase_started_at, now),
+            phase_budget_secs: self.phase_budget_secs(self.current_phase),
+            total_elapsed_secs,
+            total_budget_secs: self.timing.overall_budget_secs,
+            total_over_budget_secs: total_elapsed_secs.saturating_sub(self.timing.overall_budget_secs),
         }
     }
     
@@ -159,6 +333,16 @@ pub struct InterviewProgress {
     pub phase_question_count: u32,
     pub total_question_count: u32,
     pub is_completed: bool,
+    pub phase_elapsed_secs: u64,
+    pub phase_budget_secs: Option<u64>,
+    pub total_elapsed_secs: u64,
+    pub total_budget_secs: u64,
+    pub total_over_budget_secs: u64,
+}
+
+/// Whole seconds between two instants, zero if `to` is earlier
+pub fn elapsed_secs(from: DateTime<Utc>, to: DateTime<Utc>) -> u64 {
+    (to - from).num_seconds().max(0) as u64
 }
 
 impl Default for InterviewStateMachine {
@@ -166,3 +350,71 @@ impl Default for InterviewStateMachine {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_time_budget_advances_phase_and_completes() {
+        let mut state_machine = InterviewStateMachine::new();
+        let start = Utc::now();
+
+        assert_eq!(state_machine.check_timeout_at(start), None);
+        assert_eq!(
+            state_machine.check_timeout_at(start + Duration::seconds(301)),
+            Some(InterviewPhase::Technical)
+        );
+        assert_eq!(
+            state_machine.check_timeout_at(start + Duration::seconds(3601)),
+            Some(InterviewPhase::Completed)
+        );
+        assert_eq!(state_machine.check_timeout_at(start + Duration::seconds(4000)), None);
+    }
+
+    #[test]
+    fn test_question_count_sizes_phases() {
+        let limits = |total: u32| -> Vec<(u32, u32)> {
+            InterviewStateMachine::new()
+                .with_question_count(total)
+                .phase_configs
+                .iter()
+                .filter(|c| c.phase != InterviewPhase::Questions)
+                .map(|c| (c.min_questions, c.max_questions))
+                .collect()
+        };
+
+        assert_eq!(limits(2), vec![(1, 1), (1, 1), (0, 0), (0, 0)]);
+        assert_eq!(limits(17), vec![(3, 3), (6, 6), (4, 4), (4, 4)]);
+        for total in [1, 4, 9, 15, 30] {
+            assert_eq!(limits(total).iter().map(|l| l.1).sum::<u32>(), total);
+        }
+    }
+
+    #[test]
+    fn test_long_pause_skips_every_exhausted_phase() {
+        let mut state_machine = InterviewStateMachine::new();
+        let start = Utc::now();
+
+        // Warm-up (300s) and technical (1200s) budgets both ran out
+        assert_eq!(
+            state_machine.check_timeout_at(start + Duration::seconds(1600)),
+            Some(InterviewPhase::Behavioral)
+        );
+        assert_eq!(state_machine.current_phase(), InterviewPhase::Behavioral);
+        assert_eq!(state_machine.check_timeout_at(start + Duration::seconds(1700)), None);
+        assert_eq!(
+            state_machine.check_timeout_at(start + Duration::seconds(2200)),
+            Some(InterviewPhase::Business)
+        );
+    }
+
+    #[test]
+    fn test_overtime_penalty_is_capped() {
+        let timing = TimingConfig::default();
+
+        assert_eq!(timing.overtime_penalty(60.0), 0.0);
+        assert_eq!(timing.overtime_penalty(240.0), 0.5);
+        assert_eq!(timing.overtime_penalty(3600.0), timing.max_overtime_penalty);
+    }
+}
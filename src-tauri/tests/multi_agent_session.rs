@@ -0,0 +1,95 @@
+//! Model-free multi-agent sessions driven through the public session API
+
+use app_lib::{InterviewPhase, InterviewScript, InterviewerRole, Language, MultiAgentSession, TimingConfig};
+
+/// Upper bound of turns, guards against a session that never completes
+const MAX_TURNS: usize = 100;
+
+fn script(questions: &[(&str, &str, Option<f32>)]) -> InterviewScript {
+    let questions: Vec<serde_json::Value> = questions
+        .iter()
+        .enumerate()
+        .map(|(i, (role, question, score))| {
+            let mut entry = serde_json::json!({ "role": role, "question": question, "question_id": i as i64 + 1 });
+            if let Some(score) = score {
+                entry["analysis"] = serde_json::json!({
+                    "score": score, "strengths": [], "improvements": [], "summary": "scripted"
+                });
+            }
+            entry
+        })
+        .collect();
+    InterviewScript::parse(&serde_json::json!({ "title": "rehearsal", "questions": questions }).to_string()).unwrap()
+}
+
+fn session(script: &InterviewScript) -> MultiAgentSession {
+    MultiAgentSession::scripted(
+        script,
+        "Rust 后端工程师，5年经验".to_string(),
+        "负责分布式存储系统开发".to_string(),
+        TimingConfig::default(),
+        Language::Zh,
+    )
+}
+
+/// Ask and answer until the session reports it is over, the way the Tauri commands do
+async fn run(session: &mut MultiAgentSession) -> usize {
+    let mut turns = 0;
+    while let Some(turn) = session.next_question().await.unwrap() {
+        assert!(turns < MAX_TURNS, "session did not complete");
+        assert!(turn.answer.is_none());
+        session.submit_answer("我会先分析瓶颈，再给出方案。".to_string()).await.unwrap();
+        turns += 1;
+    }
+    turns
+}
+
+#[tokio::test]
+async fn test_script_is_asked_in_order_and_completes() {
+    let script = script(&[
+        ("HR", "请做一下自我介绍", None),
+        ("Technical", "讲讲所有权", Some(9.0)),
+        ("Business", "这个岗位最大的挑战是什么？", Some(6.5)),
+    ]);
+    let mut session = session(&script);
+
+    assert_eq!(run(&mut session).await, 3);
+    assert_eq!(session.state_machine.current_phase(), InterviewPhase::Completed);
+    assert!(session.next_question().await.unwrap().is_none());
+
+    let history = &session.context.conversation_history;
+    let asked: Vec<(&str, InterviewerRole, Option<i64>)> =
+        history.iter().map(|t| (t.question.as_str(), t.role, t.question_id)).collect();
+    assert_eq!(
+        asked,
+        vec![
+            ("请做一下自我介绍", InterviewerRole::HR, Some(1)),
+            ("讲讲所有权", InterviewerRole::Technical, Some(2)),
+            ("这个岗位最大的挑战是什么？", InterviewerRole::Business, Some(3)),
+        ]
+    );
+    assert_eq!(history[1].analysis.as_ref().unwrap().score, 9.0);
+    assert!(history.iter().all(|t| t.answered_at.is_some()));
+}
+
+#[tokio::test]
+async fn test_long_script_with_top_scores_is_not_cut() {
+    // More questions than the default phase limits allow, all scored high enough to end phases early
+    let questions: Vec<String> = (1..=22).map(|i| format!("第{}题", i)).collect();
+    let entries: Vec<(&str, &str, Option<f32>)> =
+        questions.iter().map(|q| ("Technical", q.as_str(), Some(9.5))).collect();
+    let mut session = session(&script(&entries));
+
+    assert_eq!(run(&mut session).await, 22);
+    let progress = session.state_machine.progress();
+    assert!(progress.is_completed);
+    assert_eq!(progress.total_question_count, 22);
+
+    let asked: Vec<&str> = session.context.conversation_history.iter().map(|t| t.question.as_str()).collect();
+    assert_eq!(asked, questions.iter().map(String::as_str).collect::<Vec<_>>());
+
+    // Phases still follow each other in order
+    let phases: Vec<InterviewPhase> = session.context.conversation_history.iter().map(|t| t.phase).collect();
+    assert_eq!(phases.first(), Some(&InterviewPhase::WarmUp));
+    assert_eq!(phases.last(), Some(&InterviewPhase::Business));
+}
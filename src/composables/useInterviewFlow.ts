@@ -3,11 +3,12 @@
  * Manages the complete interview flow state and logic
  */
 
-import { ref, computed, nextTick } from 'vue'
+import { ref, computed, nextTick, watch } from 'vue'
 import { invoke } from '@tauri-apps/api/core'
-import { createSession, saveAnswer, analyzeAnswerWithScoring, markBestAnswerNeedsUpdate } from '../services/database'
+import { createSession, saveAnswer, analyzeAnswerWithScoring, judgeAnswerScore, markBestAnswerNeedsUpdate } from '../services/database'
 import { tts, stt } from '../services/voice'
 import { useSettingsStore } from '../stores/settings'
+import { TimerSettingsManager } from '../services/settings'
 import type { ConversationTurn, FollowUpAnalysis } from '../types/follow-up'
 
 export type InterviewStep = 'input' | 'questions' | 'interview' | 'feedback' | 'followup'
@@ -56,6 +57,9 @@ export function useInterviewFlow() {
   const showFinalReport = ref(false)
   const reportLoading = ref(false)
 
+  // Time the current question was shown, used to time the answer
+  const questionIssuedAt = ref<string | undefined>(undefined)
+
   // Computed values
   const canGenerate = computed(() => {
     return resume.value.trim().length > 50 && jobDescription.value.trim().length > 20
@@ -74,6 +78,11 @@ export function useInterviewFlow() {
     total: questions.value.length
   }))
 
+  // Stamp each question as it is shown during the interview
+  watch([currentStep, currentQuestionIndex], ([step]) => {
+    questionIssuedAt.value = step === 'interview' ? new Date().toISOString() : undefined
+  })
+
   // Generate interview questions
   async function generateQuestions() {
     if (!canGenerate.value) return
@@ -86,7 +95,9 @@ export function useInterviewFlow() {
         resume: resume.value,
         jobDescription: jobDescription.value,
         count: 5,
-        persona: settingsStore.persona
+        persona: settingsStore.persona,
+        language: settingsStore.language,
+        targetGaps: true
       })
 
       // Add fixed opening and closing questions
@@ -166,7 +177,8 @@ export function useInterviewFlow() {
           currentQuestionIndex.value,
           currentQuestion.value,
           currentAnswer.value,
-          ''
+          '',
+          questionIssuedAt.value
         )
 
         // Analyze and score answer for profile dimension calculation
@@ -175,8 +187,17 @@ export function useInterviewFlow() {
             answerId,
             currentAnswer.value,
             currentQuestion.value,
-            jobDescription.value
+            jobDescription.value,
+            TimerSettingsManager.getSettings().timePerQuestion
           )
+
+          // LLM judge runs in the background and refines the stored scores
+          judgeAnswerScore(
+            answerId,
+            currentAnswer.value,
+            currentQuestion.value,
+            jobDescription.value
+          ).catch((judgeErr) => console.warn('LLM judge scoring skipped:', judgeErr))
         } catch (analysisErr) {
           console.error('Failed to analyze answer:', analysisErr)
         }
@@ -4,6 +4,7 @@
 
 import { invoke } from '@tauri-apps/api/core'
 import { z } from 'zod'
+import { InterviewLanguageManager, InterviewerPersonaManager } from './settings'
 
 /**
  * Zod schemas for runtime validation
@@ -12,6 +13,7 @@ const ResumeSchema = z.object({
   id: z.number().optional(),
   title: z.string(),
   content: z.string(),
+  structured: z.string().optional(),
   created_at: z.string(),
   updated_at: z.string(),
 })
@@ -32,6 +34,9 @@ const InterviewAnswerSchema = z.object({
   question: z.string(),
   answer: z.string(),
   feedback: z.string(),
+  question_issued_at: z.string().nullable().optional(),
+  answer_submitted_at: z.string().nullable().optional(),
+  question_id: z.number().nullable().optional(),
   created_at: z.string(),
 })
 
@@ -94,6 +99,7 @@ export interface Resume {
   id?: number
   title: string
   content: string
+  structured?: string
   created_at: string
   updated_at: string
 }
@@ -102,6 +108,7 @@ export interface JobDescription {
   id?: number
   title: string
   content: string
+  structured?: string
   created_at: string
   updated_at: string
 }
@@ -121,6 +128,9 @@ export interface InterviewAnswer {
   question: string
   answer: string
   feedback: string
+  question_issued_at?: string | null
+  answer_submitted_at?: string | null
+  question_id?: number | null  // Question bank entry being practised
   created_at: string
 }
 
@@ -231,6 +241,31 @@ export async function deleteResume(id: number): Promise<void> {
   return await safeInvoke('db_delete_resume', { id })
 }
 
+export interface StructuredResume {
+  format: 'pdf' | 'docx'
+  sections: {
+    education: string[]
+    experience: string[]
+    projects: string[]
+    skills: string[]
+    other: string[]
+  }
+  warnings: string[]
+}
+
+export interface ImportedResume {
+  id: number
+  text: string
+  structured: StructuredResume
+}
+
+/**
+ * Import a PDF or DOCX resume; check `structured.warnings` for scanned or unparseable files
+ */
+export async function importResumeFile(filePath: string, title?: string): Promise<ImportedResume> {
+  return await safeInvoke('import_resume_file', { filePath, title })
+}
+
 // Job description operations
 export async function saveJobDescription(
   title: string,
@@ -247,6 +282,29 @@ export async function deleteJobDescription(id: number): Promise<void> {
   return await safeInvoke('db_delete_job_description', { id })
 }
 
+export interface StructuredJobDescription {
+  responsibilities: string[]
+  must_have: string[]
+  nice_to_have: string[]
+  tech_stack: string[]
+  seniority: 'intern' | 'junior' | 'mid' | 'senior' | 'lead' | null
+  years_experience: number | null
+  location: string | null
+  company: string | null
+  refined: boolean
+}
+
+/**
+ * Re-parse a saved job description; `refine` asks the LLM to correct the rule-based fields
+ */
+export async function parseJobDescription(id: number, refine = false): Promise<StructuredJobDescription> {
+  return await safeInvoke('parse_job_description', {
+    id,
+    refine,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
 // Interview session operations
 export async function createSession(
   resumeId: number | null,
@@ -273,7 +331,9 @@ export async function saveAnswer(
   questionIndex: number,
   question: string,
   answer: string,
-  feedback: string
+  feedback: string,
+  questionIssuedAt?: string,
+  questionId?: number
 ): Promise<number> {
   return await safeInvoke('db_save_answer', {
     sessionId,
@@ -281,6 +341,8 @@ export async function saveAnswer(
     question,
     answer,
     feedback,
+    questionIssuedAt,
+    questionId: questionId ?? null,
   })
 }
 
@@ -296,16 +358,245 @@ export async function analyzeAnswerWithScoring(
   answerId: number,
   answer: string,
   question: string,
-  jobDescription: string
+  jobDescription: string,
+  answerBudgetSecs?: number
 ): Promise<void> {
   await safeInvoke('analyze_answer_with_scoring', {
     answerId,
     answer,
     question,
-    jobDescription
+    jobDescription,
+    answerBudgetSecs: answerBudgetSecs ?? null,
+    language: InterviewLanguageManager.getLanguage(),
+    persona: InterviewerPersonaManager.getPersona()
   })
 }
 
+/**
+ * Score an analyzed answer with the LLM judge and blend it into the stored scores
+ */
+export async function judgeAnswerScore(
+  answerId: number,
+  answer: string,
+  question: string,
+  jobDescription: string
+): Promise<void> {
+  await safeInvoke('judge_answer_score', {
+    answerId,
+    answer,
+    question,
+    jobDescription,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+export interface BlendWeights {
+  heuristic: number
+  judge: number
+}
+
+export interface AgreementPoint {
+  date: string
+  samples: number
+  overall_mae: number
+  overall_bias: number
+}
+
+export interface JudgeAgreement {
+  samples: number
+  logic_mae: number
+  job_match_mae: number
+  overall_mae: number
+  logic_bias: number
+  job_match_bias: number
+  overall_bias: number
+  within_one_point: number
+  trend: AgreementPoint[]
+}
+
+/**
+ * Heuristic-vs-judge agreement over all judged answers; bias is judge minus heuristic
+ */
+export async function getJudgeAgreement(): Promise<JudgeAgreement> {
+  return await safeInvoke('get_judge_agreement')
+}
+
+export async function getJudgeBlendWeights(): Promise<BlendWeights> {
+  return await safeInvoke('get_judge_blend_weights')
+}
+
+export async function setJudgeBlendWeights(weights: BlendWeights): Promise<void> {
+  await safeInvoke('set_judge_blend_weights', { weights })
+}
+
+// Scoring consistency operations
+export interface DimensionStats {
+  dimension: string
+  samples: number
+  mean: number
+  variance: number
+  std_dev: number
+  median: number
+  min: number
+  max: number
+  unstable: boolean
+}
+
+export interface RubricEvaluation {
+  rubric_id: number | null
+  rubric_name: string
+  overall_score: number
+  criterion_scores: CriterionScore[]
+  strengths: string[]
+  improvements: string[]
+  summary: string
+}
+
+export interface ConsistencyReport {
+  answer_id: number | null
+  runs: number
+  temperature: number
+  overall: DimensionStats
+  overall_samples: number[]
+  dimensions: DimensionStats[]
+  unstable: boolean
+  canonical: RubricEvaluation
+  stored: boolean
+}
+
+export interface NoiseReport {
+  answers_evaluated: number
+  runs_per_answer: number
+  mean_overall_std_dev: number
+  max_overall_std_dev: number
+  unstable_rate: number
+  noisiest_dimensions: [string, number][]
+  reports: ConsistencyReport[]
+}
+
+/**
+ * Evaluate an answer several times; `storeMedian` makes the median the stored score
+ */
+export async function evaluateAnswerConsistency(
+  question: string,
+  answer: string,
+  jobDescription: string,
+  options: {
+    answerId?: number
+    runs?: number
+    storeMedian?: boolean
+    role?: string
+    questionType?: string
+  } = {}
+): Promise<ConsistencyReport> {
+  return await safeInvoke('evaluate_answer_consistency', {
+    question,
+    answer,
+    jobDescription,
+    persona: InterviewerPersonaManager.getPersona(),
+    role: options.role ?? null,
+    questionType: options.questionType ?? null,
+    answerId: options.answerId ?? null,
+    runs: options.runs ?? null,
+    storeMedian: options.storeMedian ?? null,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+/**
+ * Re-evaluate a random sample of past answers to measure scoring noise
+ */
+export async function measureScoringNoise(sampleSize?: number, runs?: number): Promise<NoiseReport> {
+  return await safeInvoke('measure_scoring_noise', {
+    sampleSize: sampleSize ?? null,
+    runs: runs ?? null,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+export async function getEvaluationTemperature(): Promise<number> {
+  return await safeInvoke('get_evaluation_temperature')
+}
+
+export async function setEvaluationTemperature(temperature: number): Promise<void> {
+  await safeInvoke('set_evaluation_temperature', { temperature })
+}
+
+// Rubric operations
+export interface RubricLevel {
+  score: number
+  description: string
+}
+
+export interface RubricCriterion {
+  name: string
+  description: string
+  weight: number
+  levels: RubricLevel[]
+}
+
+export interface Rubric {
+  id?: number | null
+  name: string
+  role: string
+  question_type: string
+  criteria: RubricCriterion[]
+  created_at: string
+  updated_at: string
+}
+
+export interface CriterionScore {
+  criterion: string
+  score: number
+  weight: number
+  evidence: string[]
+  comment: string
+}
+
+/**
+ * Stored rubrics plus the built-in defaults they do not override
+ *
+ * Rubrics are shared by all users; built-in defaults follow the interview language
+ */
+export async function getRubrics(): Promise<Rubric[]> {
+  return await safeInvoke('get_rubrics', { language: InterviewLanguageManager.getLanguage() })
+}
+
+export async function saveRubric(rubric: Rubric): Promise<number> {
+  return await safeInvoke('save_rubric', { rubric })
+}
+
+/**
+ * Delete a stored rubric; the built-in default applies again
+ */
+export async function deleteRubric(id: number): Promise<void> {
+  await safeInvoke('delete_rubric', { id })
+}
+
+export interface TraceEntry {
+  rule: string
+  detail: string
+  points: number
+  evidence: string | null
+}
+
+export interface ComponentTrace {
+  component: string
+  score: number
+  entries: TraceEntry[]
+}
+
+export interface ScoreTrace {
+  components: ComponentTrace[]
+}
+
+/**
+ * Rules, points and evidence behind the heuristic scores of an analyzed answer
+ */
+export async function getScoreTrace(answerId: number): Promise<ScoreTrace | null> {
+  return await invoke('get_score_trace', { answerId })
+}
+
 // Question bank operations
 export async function addToBank(
   question: string,
@@ -374,9 +665,118 @@ export async function getQuestionsByTag(tagId: number): Promise<QuestionBankItem
   return await invoke('db_get_questions_by_tag', { tagId })
 }
 
+export async function suggestQuestionTags(question: string): Promise<string[]> {
+  return await invoke('suggest_question_tags', { question })
+}
+
+// Skill taxonomy operations
+export type SkillCategory = 'language' | 'framework' | 'database' | 'tool' | 'platform' | 'concept' | 'soft'
+
+export interface SkillDefinition {
+  name: string
+  category: SkillCategory
+  aliases: string[]
+  parent: string | null
+}
+
+export interface CustomSkill {
+  id?: number
+  name: string
+  aliases: string
+  category: SkillCategory
+  parent: string | null
+  created_at: string
+}
+
+export async function getSkillTaxonomy(): Promise<SkillDefinition[]> {
+  return await invoke('get_skill_taxonomy')
+}
+
+export async function normalizeSkills(terms: string[]): Promise<string[]> {
+  return await invoke('normalize_skills', { terms })
+}
+
+export async function getCustomSkills(): Promise<CustomSkill[]> {
+  return await invoke('get_custom_skills')
+}
+
+/**
+ * Add or extend a skill; naming a bundled skill adds aliases to it, and
+ * leaving out the category keeps the bundled skill's category
+ */
+export async function saveCustomSkill(
+  name: string,
+  aliases: string[],
+  category?: SkillCategory,
+  parent?: string
+): Promise<number> {
+  return await invoke('save_custom_skill', { name, aliases, category, parent })
+}
+
+export async function deleteCustomSkill(id: number): Promise<void> {
+  return await invoke('delete_custom_skill', { id })
+}
+
+// Question classification operations
+export type QuestionType = 'behavioral' | 'technical' | 'system_design' | 'situational' | 'motivation'
+export type QuestionDimension = 'technical_depth' | 'communication' | 'problem_solving' | 'domain_knowledge' | 'adaptability'
+export type QuestionDifficulty = 'easy' | 'medium' | 'hard'
+
+export interface QuestionClassification {
+  id?: number
+  question_id: number
+  question_type: QuestionType
+  dimension: QuestionDimension
+  difficulty: QuestionDifficulty
+  skills: string // JSON array of skill names
+  method: 'embedding' | 'keywords' | 'llm'
+  confidence: number
+  created_at: string
+}
+
+/**
+ * Classify a question text without saving it
+ */
+export async function classifyQuestion(question: string, useLlm = false): Promise<QuestionClassification> {
+  return await safeInvoke('classify_question', {
+    question,
+    useLlm,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+export async function classifyBankQuestion(id: number, useLlm = false): Promise<QuestionClassification> {
+  return await safeInvoke('classify_bank_question', {
+    id,
+    useLlm,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+/**
+ * Classify unclassified bank questions (all questions with force)
+ */
+export async function backfillQuestionClassifications(
+  useLlm = false,
+  force = false
+): Promise<QuestionClassification[]> {
+  return await safeInvoke('backfill_question_classifications', {
+    useLlm,
+    force,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+export async function getQuestionClassifications(): Promise<QuestionClassification[]> {
+  return await invoke('get_question_classifications')
+}
+
 // Report operations
 export async function generateReport(sessionId: number): Promise<SessionReport> {
-  return await safeInvoke('generate_comprehensive_report', { sessionId })
+  return await safeInvoke('generate_comprehensive_report', {
+    sessionId,
+    language: InterviewLanguageManager.getLanguage()
+  })
 }
 
 export async function getReport(sessionId: number): Promise<SessionReport | null> {
@@ -475,6 +875,88 @@ export async function getComparisonWithBestAnswer(
   return await invoke('get_comparison_with_best_answer', { question, jobDescription })
 }
 
+// Point-by-point comparison against the best answer
+export interface ComparisonPoint {
+  aspect: string
+  best_answer_point: string
+  user_answer_point: string
+  match_status: 'matched' | 'partial' | 'missing'
+  suggestion: string
+}
+
+export interface AnswerComparisonResult {
+  id: number | null
+  answer_id: number | null
+  question_hash: string
+  question: string
+  user_answer: string
+  best_answer_version: number
+  overall_match: number
+  points: ComparisonPoint[]
+  missing_points: string[]
+  extra_points: string[]
+  created_at: string
+}
+
+export interface ImprovementItem {
+  id: number | null
+  question_hash: string
+  question: string
+  point: string
+  status: 'open' | 'resolved' | 'dismissed'
+  occurrences: number
+  source_comparison_id: number | null
+  resolved_comparison_id: number | null
+  created_at: string
+  updated_at: string
+}
+
+export interface ComparisonAttempt {
+  comparison_id: number
+  created_at: string
+  overall_match: number
+  match_delta: number | null
+  matched_count: number
+  partial_count: number
+  missing_count: number
+  newly_covered: string[]
+}
+
+export interface ComparisonEvolution {
+  question: string
+  attempts: ComparisonAttempt[]
+  best_match: number
+  total_improvement: number
+  open_items: ImprovementItem[]
+  resolved_items: ImprovementItem[]
+}
+
+export async function compareAnswerWithBest(
+  question: string,
+  answer: string,
+  answerId: number | null,
+  jobDescription: string
+): Promise<AnswerComparisonResult> {
+  return await invoke('compare_answer_with_best', { question, answer, answerId, jobDescription })
+}
+
+export async function getComparisonEvolution(question: string): Promise<ComparisonEvolution> {
+  return await invoke('get_comparison_evolution', { question })
+}
+
+export async function getImprovementItems(
+  status?: 'open' | 'resolved' | 'dismissed'
+): Promise<ImprovementItem[]> {
+  return await invoke('get_improvement_items', { status })
+}
+
+export async function updateImprovementItemStatus(
+  id: number,
+  status: 'open' | 'resolved' | 'dismissed'
+): Promise<void> {
+  return await invoke('update_improvement_item_status', { id, status })
+}
+
 /**
  * Transcribe audio to text using SiliconFlow API
  * @param audioBase64 - Base64 encoded audio data
@@ -565,20 +1047,187 @@ export interface InterviewProfile {
 }
 
 export async function generateInterviewProfile(
-  userId: string = 'default_user',
   sessionLimit?: number
 ): Promise<InterviewProfile> {
   return await invoke('generate_interview_profile', { 
-    userId, 
     sessionLimit: sessionLimit || null 
   })
 }
 
+// Performance trend operations
+export type TrendStatus =
+  | 'improving'
+  | 'declining'
+  | 'regressing'
+  | 'plateau'
+  | 'fluctuating'
+  | 'insufficient_data'
+
+export interface TrendPoint {
+  session_id: number
+  created_at: string
+  value: number
+  ema: number
+}
+
+export interface RegressionFit {
+  slope: number // Least squares, per session; the trend status follows this slope
+  intercept: number
+  robust_slope: number // Theil-Sen, only used to confirm the sign
+  r_squared: number
+  t_statistic: number
+  significant: boolean
+  window: number
+}
+
+export interface ForecastPoint {
+  sessions_ahead: number
+  value: number
+  lower: number
+  upper: number
+}
+
+export interface DimensionTrend {
+  dimension: string
+  label: string
+  points: TrendPoint[]
+  fit: RegressionFit | null
+  recent_change: number | null
+  status: TrendStatus
+  forecast: ForecastPoint[]
+}
+
+export interface TrendsReport {
+  session_count: number
+  dimensions: DimensionTrend[]
+  overall_status: TrendStatus
+  summary: string
+  generated_at: string
+}
+
+export async function getPerformanceTrends(
+  language?: 'zh' | 'en' | 'mixed'
+): Promise<TrendsReport> {
+  return await invoke('get_performance_trends', {
+    language: language || InterviewLanguageManager.getLanguage()
+  })
+}
+
+// Spaced-repetition review operations
+export interface QuestionReview {
+  question_id: number
+  ease: number
+  interval_days: number
+  repetitions: number
+  lapses: number
+  due_date: string
+  last_reviewed_at: string | null
+}
+
+export interface DueReview {
+  question: QuestionBankItem
+  review: QuestionReview | null
+  overdue_days: number
+}
+
+export interface ReviewRecord {
+  id: number
+  question_id: number
+  answer_id: number | null
+  score: number
+  grade: number
+  ease: number
+  interval_days: number
+  due_date: string
+  reviewed_at: string
+}
+
+export async function getDueReviews(limit?: number): Promise<DueReview[]> {
+  return await invoke('get_due_reviews', { limit: limit || null })
+}
+
+export async function getReviewHistory(
+  questionId?: number,
+  limit?: number
+): Promise<ReviewRecord[]> {
+  return await invoke('get_review_history', {
+    questionId: questionId || null,
+    limit: limit || null
+  })
+}
+
+// Goal operations
+export type GoalType = 'dimension_score' | 'session_count'
+
+export type GoalStatus = 'active' | 'at_risk' | 'achieved' | 'missed'
+
+export interface Goal {
+  id: number
+  goal_type: GoalType
+  dimension: string | null
+  target_value: number
+  baseline_value: number
+  deadline: string
+  status: GoalStatus
+  achieved_at: string | null
+  created_at: string
+  updated_at: string
+}
+
+export interface GoalProgress {
+  goal: Goal
+  label: string
+  current_value: number
+  progress: number
+  rate_per_day: number
+  projected_value: number
+  days_remaining: number
+  on_track: boolean
+  status: GoalStatus
+}
+
+export async function createGoal(
+  goalType: GoalType,
+  dimension: string | null,
+  targetValue: number,
+  deadline: string
+): Promise<number> {
+  return await invoke('create_goal', {
+    goalType,
+    dimension,
+    targetValue,
+    deadline,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+export async function getGoals(): Promise<GoalProgress[]> {
+  return await invoke('get_goals', { language: InterviewLanguageManager.getLanguage() })
+}
+
+export async function deleteGoal(id: number): Promise<void> {
+  return await invoke('delete_goal', { id })
+}
+
 // Recommendation operations
+export interface RecommendationSignals {
+  jd_match: number | null
+  weakness: number
+  performance: number
+  recency: number
+  due: number
+  redundancy: number
+}
+
 export interface PracticeRecommendation {
-  question_id: number
+  question_id: number | null
+  knowledge_id: number | null
+  source: 'bank' | 'knowledge'
   question: string
   reason: string
+  explanations: string[]
+  signals: RecommendationSignals
+  score: number
   priority: number
   dimension: string
   estimated_improvement: number
@@ -588,14 +1237,19 @@ export interface RecommendationResult {
   recommendations: PracticeRecommendation[]
   weak_dimensions: string[]
   total_available: number
+  semantic_ranking: boolean
   generated_at: string
 }
 
 export async function generatePracticeRecommendations(
-  userId: string = 'default_user',
-  limit: number = 5
+  limit: number = 5,
+  jobDescriptionId?: number
 ): Promise<RecommendationResult> {
-  return await invoke('generate_practice_recommendations', { userId, limit })
+  return await invoke('generate_practice_recommendations', {
+    limit,
+    jobDescriptionId: jobDescriptionId || null,
+    language: InterviewLanguageManager.getLanguage()
+  })
 }
 
 // Best practices operations
@@ -629,6 +1283,13 @@ export interface IndustryBenchmark {
   industry_avg: number
   industry_top: number
   percentile: number
+  sample_size: number // 0 when the built-in estimate was used
+}
+
+export interface BenchmarkSegment {
+  role: string | null
+  seniority: string | null
+  region: string | null
 }
 
 export interface IndustryComparisonResult {
@@ -636,13 +1297,52 @@ export interface IndustryComparisonResult {
   overall_percentile: number
   user_level: string
   comparison_count: number
+  data_source: string
+  segment: BenchmarkSegment
   generated_at: string
 }
 
+export interface BenchmarkDataset {
+  id?: number
+  name: string
+  source: 'import' | 'local'
+  sample_count: number
+  created_at: string
+}
+
 export async function generateIndustryComparison(
-  userId: string = 'default_user'
+  segment: Partial<BenchmarkSegment> = {}
 ): Promise<IndustryComparisonResult> {
-  return await invoke('generate_industry_comparison', { userId })
+  return await invoke('generate_industry_comparison', { ...segment })
+}
+
+/**
+ * Import a CSV or JSON benchmark file segmented by role, seniority and region
+ */
+export async function importBenchmarkDataset(filePath: string, name?: string): Promise<BenchmarkDataset> {
+  return await safeInvoke('import_benchmark_dataset', { filePath, name: name || null })
+}
+
+/**
+ * Rebuild the benchmark from anonymized averages of all users on this machine
+ */
+export async function buildLocalBenchmark(): Promise<BenchmarkDataset> {
+  return await safeInvoke('build_local_benchmark')
+}
+
+export async function getBenchmarkDatasets(): Promise<BenchmarkDataset[]> {
+  return await invoke('get_benchmark_datasets')
+}
+
+/**
+ * Role / seniority / region combinations the stored benchmark data covers
+ */
+export async function getBenchmarkSegments(): Promise<BenchmarkSegment[]> {
+  return await invoke('get_benchmark_segments')
+}
+
+export async function deleteBenchmarkDataset(id: number): Promise<void> {
+  return await invoke('delete_benchmark_dataset', { id })
 }
 
 // STAR scoring
@@ -653,17 +1353,40 @@ export interface STARScoreBreakdown {
   result: number
 }
 
+export type STARComponent = 'situation' | 'task' | 'action' | 'result' | 'filler'
+
+// start/end are UTF-16 offsets, usable with answer.slice(start, end)
+export interface STARSpan {
+  component: STARComponent
+  start: number
+  end: number
+  text: string
+}
+
+export interface STARSegmentation {
+  spans: STARSpan[]
+  proportions: Record<STARComponent, number>
+  missing: STARComponent[]
+  thin: STARComponent[]
+  out_of_order: { component: STARComponent; appears_before: STARComponent }[]
+  suggestions: string[]
+}
+
 export interface STARScoringResult {
   overall_score: number
   breakdown: STARScoreBreakdown
   completeness: number
   suggestions: string[]
+  segmentation: STARSegmentation
 }
 
 export async function analyzeSTARScore(
   answer: string
 ): Promise<STARScoringResult> {
-  const resultJson = await invoke<string>('analyze_star_score', { answer })
+  const resultJson = await invoke<string>('analyze_star_score', {
+    answer,
+    language: InterviewLanguageManager.getLanguage()
+  })
   return JSON.parse(resultJson)
 }
 
@@ -815,3 +1538,73 @@ export async function importKnowledgeFile(filePath: string): Promise<ImportResul
 export async function syncQuestionBankToKnowledge(): Promise<string> {
   return await invoke('sync_question_bank_to_knowledge')
 }
+
+// Reverse interview operations
+
+export interface ReverseEvaluation {
+  id: number | null
+  session_id: number | null
+  turns: string
+  evaluation: string | null
+  created_at: string
+}
+
+export async function getReverseEvaluations(limit?: number): Promise<ReverseEvaluation[]> {
+  return await invoke('get_reverse_evaluations', { limit: limit || null })
+}
+
+// Start a multi-agent session that rehearses the questions of a JSON script
+export async function startScriptedInterview(
+  scriptPath: string,
+  resume: string,
+  jobDescription: string
+): Promise<string> {
+  return await invoke('start_scripted_interview', {
+    scriptPath,
+    resume,
+    jobDescription,
+    language: InterviewLanguageManager.getLanguage()
+  })
+}
+
+// Reverse interview of a running multi-agent session
+export interface ReverseTurn {
+  role: 'Technical' | 'HR' | 'Business'
+  role_name: string
+  candidate_question: string
+  answer: string
+}
+
+export interface CandidateQuestionEvaluation {
+  score: number
+  insightfulness: number
+  preparation: number
+  relevance: number
+  strengths: string[]
+  improvements: string[]
+  summary: string
+}
+
+export interface MultiAgentProgress {
+  current_phase: string
+  phase_question_count: number
+  total_question_count: number
+  is_completed: boolean
+  phase_elapsed_secs: number
+  phase_budget_secs: number | null
+  total_elapsed_secs: number
+  total_budget_secs: number
+  total_over_budget_secs: number
+}
+
+export async function startReverseInterview(sessionId: string): Promise<MultiAgentProgress> {
+  return await invoke('multi_agent_start_reverse_interview', { sessionId })
+}
+
+export async function askReverseQuestion(sessionId: string, question: string): Promise<ReverseTurn> {
+  return await invoke('multi_agent_ask_question', { sessionId, question })
+}
+
+export async function evaluateReverseQuestions(sessionId: string): Promise<CandidateQuestionEvaluation> {
+  return await invoke('multi_agent_evaluate_questions', { sessionId })
+}
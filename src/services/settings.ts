@@ -12,13 +12,16 @@ const STORAGE_KEYS = {
   TIMER_SETTINGS: 'interview-spark-timer',
   FOLLOWUP_SETTINGS: 'interview-spark-followup',
   API_SETTINGS: 'interview-spark-api',
-  INTERVIEWER_PERSONA: 'interview-spark-persona'
+  INTERVIEWER_PERSONA: 'interview-spark-persona',
+  INTERVIEW_LANGUAGE: 'interview-spark-language'
 }
 
 export type Theme = 'light' | 'dark'
 
 export type InterviewerPersona = 'strict' | 'friendly' | 'stress' | 'balanced'
 
+export type InterviewLanguage = 'zh' | 'en' | 'mixed'
+
 export interface VoiceSettings {
   rate: number
   volume: number
@@ -342,3 +345,27 @@ export class InterviewerPersonaManager {
     return descriptions[persona]
   }
 }
+
+/**
+ * Interview language management
+ * Passed to the backend for questions, analysis and reports
+ */
+export class InterviewLanguageManager {
+  private static defaultLanguage: InterviewLanguage = 'zh'
+
+  static getLanguage(): InterviewLanguage {
+    const saved = localStorage.getItem(STORAGE_KEYS.INTERVIEW_LANGUAGE)
+    if (saved && ['zh', 'en', 'mixed'].includes(saved)) {
+      return saved as InterviewLanguage
+    }
+    return this.defaultLanguage
+  }
+
+  static setLanguage(language: InterviewLanguage): void {
+    localStorage.setItem(STORAGE_KEYS.INTERVIEW_LANGUAGE, language)
+  }
+
+  static reset(): void {
+    localStorage.removeItem(STORAGE_KEYS.INTERVIEW_LANGUAGE)
+  }
+}
@@ -49,6 +49,12 @@ export const useQuestionBankStore = defineStore('questionBank', () => {
 
     try {
       const result = await invoke<QuestionBankItem[]>('db_get_bank')
+      if (!isLoaded.value) {
+        // Classify questions saved before classification existed; runs in the background
+        invoke('backfill_question_classifications').catch((err) =>
+          console.warn('Question classification backfill failed:', err)
+        )
+      }
       questions.value = result
       isLoaded.value = true
       lastFetchTime.value = Date.now()
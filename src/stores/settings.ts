@@ -7,6 +7,8 @@ import { defineStore } from 'pinia'
 import { ref, computed, watch } from 'vue'
 import type { FollowUpSettings } from '../types/follow-up'
 import { DEFAULT_FOLLOWUP_SETTINGS } from '../types/follow-up'
+import type { InterviewLanguage } from '../services/settings'
+import { InterviewLanguageManager } from '../services/settings'
 
 // Storage keys
 const STORAGE_KEYS = {
@@ -146,6 +148,9 @@ export const useSettingsStore = defineStore('settings', () => {
   // Dismissed tooltips
   const dismissedTooltips = ref<string[]>(loadFromStorage(STORAGE_KEYS.TOOLTIP_DISMISSED, []))
   
+  // Interview language
+  const language = ref<InterviewLanguage>(InterviewLanguageManager.getLanguage())
+  
   // Multi-agent mode
   const multiAgentMode = ref<boolean>(loadFromStorage(STORAGE_KEYS.MULTI_AGENT_MODE, false))
 
@@ -253,6 +258,12 @@ export const useSettingsStore = defineStore('settings', () => {
     localStorage.removeItem(STORAGE_KEYS.TOOLTIP_DISMISSED)
   }
   
+  // Language actions
+  function setLanguage(newLanguage: InterviewLanguage) {
+    language.value = newLanguage
+    InterviewLanguageManager.setLanguage(newLanguage)
+  }
+  
   // Multi-agent mode actions
   function setMultiAgentMode(enabled: boolean) {
     multiAgentMode.value = enabled
@@ -267,6 +278,7 @@ export const useSettingsStore = defineStore('settings', () => {
     resetTooltips()
     setTheme('light')
     setPersona('balanced')
+    setLanguage('zh')
   }
 
   // Watch for system theme changes
@@ -292,6 +304,7 @@ export const useSettingsStore = defineStore('settings', () => {
     apiSettings,
     persona,
     dismissedTooltips,
+    language,
     multiAgentMode,
 
     // Computed
@@ -335,6 +348,9 @@ export const useSettingsStore = defineStore('settings', () => {
     dismissTooltip,
     resetTooltips,
     
+    // Language actions
+    setLanguage,
+    
     // Multi-agent mode actions
     setMultiAgentMode,
 